@@ -1,3 +1,4 @@
+use crate::target_directory_finder;
 use std::sync::{Arc, LazyLock};
 
 pub const CROSS_COMPILE_TARGETS: &[&str] = &[
@@ -18,6 +19,7 @@ pub static COMPILE_CONFIGURATION: LazyLock<Arc<app::module_compiler::CompileConf
             },
             mir: app::module_compiler::MirConfiguration {
                 yield_function_name: "_pen_yield".into(),
+                optimize: target_directory_finder::is_optimization_enabled(),
             },
             hir: app::module_compiler::HirConfiguration {
                 list_type: app::module_compiler::ListTypeConfiguration {
@@ -34,6 +36,8 @@ pub static COMPILE_CONFIGURATION: LazyLock<Arc<app::module_compiler::CompileConf
                     first_rest_type_name: "FirstRest".into(),
                     size_function_name: "ListSize".into(),
                     debug_function_name: "DebugList".into(),
+                    encode_function_name: "EncodeList".into(),
+                    decode_function_name: "DecodeList".into(),
                 },
                 map_type: app::module_compiler::MapTypeConfiguration {
                     context_function_name: "NewMapContext".into(),
@@ -49,12 +53,15 @@ pub static COMPILE_CONFIGURATION: LazyLock<Arc<app::module_compiler::CompileConf
                     set_function_name: "SetMap".into(),
                     size_function_name: "MapSize".into(),
                     debug_function_name: "DebugMap".into(),
+                    encode_function_name: "EncodeMap".into(),
+                    decode_function_name: "DecodeMap".into(),
                     hash: app::module_compiler::HashConfiguration {
                         combine_function_name: "CombineHashes".into(),
                         number_hash_function_name: "HashNumber".into(),
                         string_hash_function_name: "HashString".into(),
                         list_hash_function_name: "HashList".into(),
                         map_hash_function_name: "HashMap".into(),
+                        vector_hash_function_name: "HashVector".into(),
                     },
                     iteration: app::module_compiler::MapTypeIterationConfiguration {
                         iterator_type_name: "MapIterator".into(),
@@ -68,11 +75,25 @@ pub static COMPILE_CONFIGURATION: LazyLock<Arc<app::module_compiler::CompileConf
                     debug_function_name: "DebugNumber".into(),
                 },
                 string_type: app::module_compiler::StringTypeConfiguration {
+                    compare_function_name: "CompareStrings".into(),
                     equal_function_name: "EqualStrings".into(),
                 },
+                vector_type: app::module_compiler::VectorTypeConfiguration {
+                    empty_function_name: "EmptyVector".into(),
+                    equal_function_name: "EqualVectors".into(),
+                    maybe_equal_function_name: "MaybeEqualVectors".into(),
+                    get_function_name: "GetVector".into(),
+                    push_function_name: "PushVector".into(),
+                    set_function_name: "SetVector".into(),
+                    slice_function_name: "SliceVector".into(),
+                    size_function_name: "VectorSize".into(),
+                    debug_function_name: "DebugVector".into(),
+                    vector_type_name: "Vector".into(),
+                },
                 spawn_function_name: "_pen_spawn".into(),
                 race_function_name: "_pen_race".into(),
                 debug_function_name: "_pen_debug".into(),
+                debug_information: target_directory_finder::is_debug_enabled(),
             },
         }
         .into()
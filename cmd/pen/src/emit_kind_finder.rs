@@ -0,0 +1,26 @@
+use crate::file_path_configuration::EMIT_ENVIRONMENT_VARIABLE;
+use app::module_compiler::EmitKind;
+use std::env;
+
+// Emit kinds are given by an environment variable when modules are compiled by
+// build scripts.
+pub fn find() -> Vec<EmitKind> {
+    let mut kinds = env::var(EMIT_ENVIRONMENT_VARIABLE)
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|kind| match kind {
+            "ast" => Some(EmitKind::Ast),
+            "hir" => Some(EmitKind::Hir),
+            "mir" => Some(EmitKind::Mir),
+            "fmm" => Some(EmitKind::Fmm),
+            "llvm" => Some(EmitKind::Llvm),
+            "c" => Some(EmitKind::C),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    kinds.sort();
+    kinds.dedup();
+
+    kinds
+}
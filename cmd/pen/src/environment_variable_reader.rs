@@ -0,0 +1,10 @@
+use std::env;
+
+// Flags are enabled only by explicit truthy values so that `FOO=false` or
+// `FOO=` disables them.
+pub fn read_flag(name: &str) -> bool {
+    matches!(
+        env::var(name).as_deref().map(str::to_lowercase).as_deref(),
+        Ok("true" | "1" | "yes")
+    )
+}
@@ -1,6 +1,8 @@
 pub const BUILD_CONFIGURATION_FILENAME: &str = "pen.json";
+pub const LOCK_FILENAME: &str = "pen.lock";
 pub const OUTPUT_DIRECTORY: &str = ".pen";
 pub const DEFAULT_TARGET_DIRECTORY: &str = "default";
+pub const COVERAGE_TARGET_DIRECTORY: &str = "coverage";
 pub const DEPENDENCY_FILE_EXTENSION: &str = "d";
 pub const BIT_CODE_FILE_EXTENSION: &str = "bc";
 pub const NINJA_DYNAMIC_DEPENDENCY_FILE_EXTENSION: &str = "ninja";
@@ -9,6 +11,17 @@ pub const LANGUAGE_ROOT_SCHEME: &str = "pen";
 pub const LANGUAGE_ROOT_ENVIRONMENT_VARIABLE: &str = "PEN_ROOT";
 pub const PACKAGES_DIRECTORY: &str = "packages";
 
+pub const CACHE_DIRECTORY_ENVIRONMENT_VARIABLE: &str = "XDG_CACHE_HOME";
+pub const HOME_DIRECTORY_ENVIRONMENT_VARIABLE: &str = "HOME";
+pub const DEFAULT_CACHE_DIRECTORY: &str = ".cache";
+pub const CACHE_DIRECTORY: &str = "pen";
+pub const OFFLINE_ENVIRONMENT_VARIABLE: &str = "PEN_OFFLINE";
+pub const COVERAGE_ENVIRONMENT_VARIABLE: &str = "PEN_COVERAGE";
+pub const EMIT_ENVIRONMENT_VARIABLE: &str = "PEN_EMIT";
+pub const NO_OPTIMIZATION_ENVIRONMENT_VARIABLE: &str = "PEN_NO_OPTIMIZATION";
+pub const DEBUG_ENVIRONMENT_VARIABLE: &str = "PEN_DEBUG";
+pub const NO_WARNINGS_ENVIRONMENT_VARIABLE: &str = "PEN_NO_WARNINGS";
+
 pub const DEFAULT_SYSTEM_PACKAGE_NAME: &str = "Os";
 pub const DEFAULT_SYSTEM_PACKAGE_URL: &str = "pen:///os";
 pub const PRELUDE_PACKAGE_URL: &str = "pen:///prelude";
@@ -22,6 +35,8 @@ pub const FILE_PATH_CONFIGURATION: app::infra::FilePathConfiguration =
         object_file_extension: "o",
         interface_file_extension: "i",
         test_information_file_extension: "test.json",
+        coverage_file_extension: "coverage.json",
+        warning_file_extension: "warnings.json",
         archive_file_extension: "a",
         build_script_file_extension: "ninja",
         test_file_extension: "test.pen",
@@ -1,10 +1,20 @@
-use crate::file_path_configuration::{
-    BIT_CODE_FILE_EXTENSION, BUILD_CONFIGURATION_FILENAME, DEPENDENCY_FILE_EXTENSION,
-    FFI_BUILD_SCRIPT_BASENAME, FILE_PATH_CONFIGURATION, LANGUAGE_ROOT_ENVIRONMENT_VARIABLE,
-    LANGUAGE_ROOT_SCHEME, LINK_SCRIPT_BASENAME, NINJA_DYNAMIC_DEPENDENCY_FILE_EXTENSION,
-    PACKAGES_DIRECTORY,
+use crate::{
+    environment_variable_reader,
+    file_path_configuration::{
+        BIT_CODE_FILE_EXTENSION, BUILD_CONFIGURATION_FILENAME, CACHE_DIRECTORY,
+        CACHE_DIRECTORY_ENVIRONMENT_VARIABLE, DEFAULT_CACHE_DIRECTORY, DEPENDENCY_FILE_EXTENSION,
+        FFI_BUILD_SCRIPT_BASENAME, FILE_PATH_CONFIGURATION, HOME_DIRECTORY_ENVIRONMENT_VARIABLE,
+        LANGUAGE_ROOT_ENVIRONMENT_VARIABLE, LANGUAGE_ROOT_SCHEME, LINK_SCRIPT_BASENAME,
+        LOCK_FILENAME, NINJA_DYNAMIC_DEPENDENCY_FILE_EXTENSION, OFFLINE_ENVIRONMENT_VARIABLE,
+        PACKAGES_DIRECTORY,
+    },
+};
+use std::{
+    env,
+    error::Error,
+    path::{Path, PathBuf},
+    rc::Rc,
 };
-use std::{error::Error, path::Path, rc::Rc};
 
 pub fn create(
     file_path_converter: Rc<infra::FilePathConverter>,
@@ -32,6 +42,8 @@ pub fn create(
             LANGUAGE_ROOT_ENVIRONMENT_VARIABLE,
             PACKAGES_DIRECTORY,
             BUILD_CONFIGURATION_FILENAME,
+            find_cache_directory(),
+            environment_variable_reader::read_flag(OFFLINE_ENVIRONMENT_VARIABLE),
         )),
         file_system: file_system.clone(),
         file_path_displayer: Rc::new(infra::FilePathDisplayer::new(
@@ -49,9 +61,18 @@ pub fn create(
             BUILD_CONFIGURATION_FILENAME,
         )),
         package_configuration_writer: Rc::new(infra::JsonPackageConfigurationWriter::new(
-            file_system,
+            file_system.clone(),
+            file_path_converter.clone(),
             BUILD_CONFIGURATION_FILENAME,
         )),
+        package_lock_reader: Rc::new(infra::JsonPackageLockReader::new(
+            file_system.clone(),
+            LOCK_FILENAME,
+        )),
+        package_lock_writer: Rc::new(infra::JsonPackageLockWriter::new(
+            file_system,
+            LOCK_FILENAME,
+        )),
         command_runner: Rc::new(infra::CommandRunner::new(file_path_converter.clone())),
         test_linker: Rc::new(infra::TestLinker::new(
             file_path_converter,
@@ -59,3 +80,14 @@ pub fn create(
         )),
     })
 }
+
+fn find_cache_directory() -> PathBuf {
+    if let Ok(directory) = env::var(CACHE_DIRECTORY_ENVIRONMENT_VARIABLE) {
+        PathBuf::from(directory)
+    } else if let Ok(directory) = env::var(HOME_DIRECTORY_ENVIRONMENT_VARIABLE) {
+        PathBuf::from(directory).join(DEFAULT_CACHE_DIRECTORY)
+    } else {
+        env::temp_dir()
+    }
+    .join(CACHE_DIRECTORY)
+}
@@ -0,0 +1,440 @@
+use crate::{
+    application_configuration::APPLICATION_CONFIGURATION,
+    file_path_configuration::{DEFAULT_TARGET_DIRECTORY, OUTPUT_DIRECTORY, PRELUDE_PACKAGE_URL},
+    infrastructure, main_package_directory_finder,
+};
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs,
+    io::{self, stdin, stdout, BufRead, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+const METHOD_NOT_FOUND_ERROR_CODE: i64 = -32601;
+const INTERNAL_ERROR_CODE: i64 = -32603;
+
+pub fn run() -> Result<(), Box<dyn Error>> {
+    let mut input = stdin().lock();
+    let mut output = stdout().lock();
+    let mut documents = HashMap::<String, String>::new();
+
+    while let Some(message) = read_message(&mut input)? {
+        let Some(method) = message["method"].as_str() else {
+            continue;
+        };
+        let id = message.get("id").cloned();
+        let parameters = &message["params"];
+
+        let result = match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "documentFormattingProvider": true,
+                },
+                "serverInfo": { "name": "pen", "version": clap::crate_version!() },
+            })),
+            "shutdown" => Ok(Value::Null),
+            "exit" => return Ok(()),
+            "textDocument/didOpen" => {
+                let uri = parameters["textDocument"]["uri"]
+                    .as_str()
+                    .unwrap_or_default();
+
+                documents.insert(
+                    uri.into(),
+                    parameters["textDocument"]["text"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .into(),
+                );
+
+                publish_diagnostics(&mut output, uri, &documents)
+            }
+            "textDocument/didChange" => {
+                let uri = parameters["textDocument"]["uri"]
+                    .as_str()
+                    .unwrap_or_default();
+
+                if let Some(text) = parameters["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                {
+                    documents.insert(uri.into(), text.into());
+                }
+
+                publish_diagnostics(&mut output, uri, &documents)
+            }
+            "textDocument/didSave" => publish_diagnostics(
+                &mut output,
+                parameters["textDocument"]["uri"]
+                    .as_str()
+                    .unwrap_or_default(),
+                &documents,
+            ),
+            "textDocument/didClose" => {
+                let uri = parameters["textDocument"]["uri"]
+                    .as_str()
+                    .unwrap_or_default();
+
+                documents.remove(uri);
+
+                write_notification(
+                    &mut output,
+                    "textDocument/publishDiagnostics",
+                    json!({ "uri": uri, "diagnostics": [] }),
+                )
+                .map(|_| Value::Null)
+            }
+            "textDocument/hover" => hover(parameters, &documents),
+            "textDocument/definition" => find_definition(parameters, &documents),
+            "textDocument/formatting" => format(parameters, &documents),
+            _ => {
+                if let Some(id) = id {
+                    write_message(
+                        &mut output,
+                        &json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": {
+                                "code": METHOD_NOT_FOUND_ERROR_CODE,
+                                "message": format!("method not found: {method}"),
+                            },
+                        }),
+                    )?;
+                }
+
+                continue;
+            }
+        };
+
+        match (id, result) {
+            (Some(id), Ok(result)) => write_message(
+                &mut output,
+                &json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            )?,
+            (Some(id), Err(error)) => write_message(
+                &mut output,
+                &json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": { "code": INTERNAL_ERROR_CODE, "message": error.to_string() },
+                }),
+            )?,
+            (None, Ok(_)) => {}
+            (None, Err(error)) => infra::log_error(error.as_ref(), infra::MessageFormat::Human)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn publish_diagnostics(
+    output: &mut impl Write,
+    uri: &str,
+    documents: &HashMap<String, String>,
+) -> Result<Value, Box<dyn Error>> {
+    let path = convert_to_path(uri)?;
+    let (package_directory, diagnostics) = with_document(
+        &path,
+        &read_document(uri, documents)?,
+        |infrastructure, document| {
+            app::language_server::check(
+                infrastructure,
+                document,
+                &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+                &APPLICATION_CONFIGURATION,
+            )
+        },
+    )?;
+
+    write_notification(
+        output,
+        "textDocument/publishDiagnostics",
+        json!({
+            "uri": uri,
+            "diagnostics": diagnostics
+                .iter()
+                .map(|diagnostic| json!({
+                    "range": diagnostic
+                        .position()
+                        .filter(|position| package_directory.join(position.path()) == path)
+                        .map(convert_range)
+                        .unwrap_or_else(|| json!({
+                            "start": { "line": 0, "character": 0 },
+                            "end": { "line": 0, "character": 0 },
+                        })),
+                    "severity": match diagnostic.severity() {
+                        app::DiagnosticSeverity::Error => 1,
+                        app::DiagnosticSeverity::Warning => 2,
+                        app::DiagnosticSeverity::Information => 3,
+                    },
+                    "source": "pen",
+                    "message": diagnostic.message(),
+                }))
+                .collect::<Vec<_>>(),
+        }),
+    )?;
+
+    Ok(Value::Null)
+}
+
+fn hover(parameters: &Value, documents: &HashMap<String, String>) -> Result<Value, Box<dyn Error>> {
+    let uri = parameters["textDocument"]["uri"]
+        .as_str()
+        .unwrap_or_default();
+    let source = read_document(uri, documents)?;
+    let (line_number, column_number) = convert_from_position(&source, &parameters["position"]);
+
+    let (_, type_) = with_document(
+        &convert_to_path(uri)?,
+        &source,
+        |infrastructure, document| {
+            app::language_server::hover(
+                infrastructure,
+                document,
+                line_number,
+                column_number,
+                &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+                &APPLICATION_CONFIGURATION,
+            )
+        },
+    )?;
+
+    Ok(type_
+        .map(|type_| {
+            json!({
+                "contents": { "kind": "markdown", "value": format!("```pen\n{type_}\n```") },
+            })
+        })
+        .unwrap_or_default())
+}
+
+fn find_definition(
+    parameters: &Value,
+    documents: &HashMap<String, String>,
+) -> Result<Value, Box<dyn Error>> {
+    let uri = parameters["textDocument"]["uri"]
+        .as_str()
+        .unwrap_or_default();
+    let source = read_document(uri, documents)?;
+    let (line_number, column_number) = convert_from_position(&source, &parameters["position"]);
+
+    let (package_directory, position) = with_document(
+        &convert_to_path(uri)?,
+        &source,
+        |infrastructure, document| {
+            app::language_server::find_definition(
+                infrastructure,
+                document,
+                line_number,
+                column_number,
+                &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+                &APPLICATION_CONFIGURATION,
+            )
+        },
+    )?;
+
+    Ok(position
+        .and_then(|position| {
+            Some(json!({
+                "uri": url::Url::from_file_path(package_directory.join(position.path()))
+                    .ok()?
+                    .to_string(),
+                "range": convert_range(&position),
+            }))
+        })
+        .unwrap_or_default())
+}
+
+fn format(
+    parameters: &Value,
+    documents: &HashMap<String, String>,
+) -> Result<Value, Box<dyn Error>> {
+    let uri = parameters["textDocument"]["uri"]
+        .as_str()
+        .unwrap_or_default();
+    let source = read_document(uri, documents)?;
+    let lines = source.split('\n').collect::<Vec<_>>();
+
+    Ok(json!([{
+        "range": {
+            "start": { "line": 0, "character": 0 },
+            "end": {
+                "line": lines.len() - 1,
+                "character": lines.last().copied().unwrap_or_default().encode_utf16().count(),
+            },
+        },
+        "newText": app::module_formatter::format(
+            &source,
+            &convert_to_path(uri)?.display().to_string(),
+        )?,
+    }]))
+}
+
+fn with_document<T>(
+    path: &Path,
+    source: &str,
+    callback: impl FnOnce(
+        &app::infra::Infrastructure,
+        &app::language_server::Document,
+    ) -> Result<T, Box<dyn Error>>,
+) -> Result<(PathBuf, T), Box<dyn Error>> {
+    let package_directory =
+        main_package_directory_finder::find_from(path.parent().unwrap_or_else(|| Path::new("/")))?;
+    let file_path_converter = Rc::new(infra::FilePathConverter::new(&package_directory));
+    let infrastructure = infrastructure::create(file_path_converter.clone(), &package_directory)?;
+    let main_package_directory = file_path_converter.convert_to_file_path(&package_directory)?;
+
+    let value = callback(
+        &infrastructure,
+        &app::language_server::Document {
+            package_directory: &main_package_directory,
+            output_directory: &main_package_directory.join(&app::infra::FilePath::new([
+                OUTPUT_DIRECTORY,
+                DEFAULT_TARGET_DIRECTORY,
+            ])),
+            source_file: &file_path_converter.convert_to_file_path(path)?,
+            source,
+        },
+    )?;
+
+    Ok((package_directory, value))
+}
+
+fn read_document(uri: &str, documents: &HashMap<String, String>) -> Result<String, Box<dyn Error>> {
+    Ok(if let Some(source) = documents.get(uri) {
+        source.clone()
+    } else {
+        fs::read_to_string(convert_to_path(uri)?)?
+    })
+}
+
+fn convert_to_path(uri: &str) -> Result<PathBuf, Box<dyn Error>> {
+    let path = url::Url::parse(uri)?.to_file_path().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid file URI: {uri}"),
+        )
+    })?;
+
+    Ok(path.canonicalize().unwrap_or(path))
+}
+
+// Convert a position of 0-based lines and UTF-16 characters into 1-based line
+// and byte column numbers.
+fn convert_from_position(source: &str, position: &Value) -> (usize, usize) {
+    let line_index = position["line"].as_u64().unwrap_or_default() as usize;
+    let character = position["character"].as_u64().unwrap_or_default() as usize;
+    let line = source.split('\n').nth(line_index).unwrap_or_default();
+    let mut offset = 0;
+
+    let column_index = line
+        .char_indices()
+        .find(|(_, current)| {
+            offset += current.len_utf16();
+            offset > character
+        })
+        .map(|(index, _)| index)
+        .unwrap_or(line.len());
+
+    (line_index + 1, column_index + 1)
+}
+
+fn convert_range(position: &position::Position) -> Value {
+    let line = position.line();
+    let (start, end) = app::language_server::find_identifier_range(line, position.column_number());
+    // Spans over multiple lines are clipped at the ends of their first lines.
+    let end = if position.end_line_number() > position.line_number() {
+        line.len() + 1
+    } else if position.end_column_number() > start {
+        position.end_column_number()
+    } else if end > start {
+        end
+    } else {
+        start
+            + line[start - 1..]
+                .chars()
+                .next()
+                .map(char::len_utf8)
+                .unwrap_or_default()
+    };
+
+    json!({
+        "start": convert_to_position(position.line_number(), line, start),
+        "end": convert_to_position(position.line_number(), line, end),
+    })
+}
+
+fn convert_to_position(line_number: usize, line: &str, column_number: usize) -> Value {
+    json!({
+        "line": line_number.saturating_sub(1),
+        "character": line[..column_number.saturating_sub(1).min(line.len())]
+            .encode_utf16()
+            .count(),
+    })
+}
+
+fn read_message(input: &mut impl BufRead) -> Result<Option<Value>, Box<dyn Error>> {
+    let mut length = None;
+
+    loop {
+        let mut header = String::new();
+
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        } else if let Some((key, value)) = header.split_once(':') {
+            if key.trim().eq_ignore_ascii_case("content-length") {
+                length = Some(value.trim().parse::<usize>()?);
+            }
+        }
+    }
+
+    let mut content = vec![
+        0;
+        length.ok_or_else(|| io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing content length header"
+        ))?
+    ];
+
+    input.read_exact(&mut content)?;
+
+    Ok(Some(serde_json::from_slice(&content)?))
+}
+
+fn write_notification(
+    output: &mut impl Write,
+    method: &str,
+    parameters: Value,
+) -> Result<(), Box<dyn Error>> {
+    write_message(
+        output,
+        &json!({ "jsonrpc": "2.0", "method": method, "params": parameters }),
+    )
+}
+
+fn write_message(output: &mut impl Write, message: &Value) -> Result<(), Box<dyn Error>> {
+    let content = message.to_string();
+
+    write!(
+        output,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    output.flush()?;
+
+    Ok(())
+}
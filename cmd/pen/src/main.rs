@@ -2,35 +2,53 @@ mod application_configuration;
 mod compile_configuration;
 mod dependency_resolver;
 mod documentation_configuration;
+mod emit_kind_finder;
+mod environment_variable_reader;
 mod file_path_configuration;
 mod infrastructure;
+mod language_server;
 mod main_module_compiler;
 mod main_package_directory_finder;
 mod module_compiler;
 mod module_formatter;
 mod package_builder;
 mod package_creator;
+mod package_dependency_editor;
 mod package_documentation_generator;
+mod package_fixer;
 mod package_formatter;
+mod package_linter;
 mod package_test_information_compiler;
+mod package_updater;
+mod package_vendorer;
 mod prelude_module_compiler;
+mod repl;
+mod target_directory_finder;
 mod test_configuration;
 mod test_linker;
 mod test_module_compiler;
 mod test_runner;
+mod test_target_directory_finder;
+mod warning_logger;
 
 use compile_configuration::CROSS_COMPILE_TARGETS;
-use std::ops::Deref;
+use file_path_configuration::{
+    COVERAGE_ENVIRONMENT_VARIABLE, DEBUG_ENVIRONMENT_VARIABLE, EMIT_ENVIRONMENT_VARIABLE,
+    NO_OPTIMIZATION_ENVIRONMENT_VARIABLE, OFFLINE_ENVIRONMENT_VARIABLE,
+};
+use std::{env, ops::Deref, time::Duration};
+
+const MESSAGE_FORMAT_ENVIRONMENT_VARIABLE: &str = "PEN_MESSAGE_FORMAT";
 
 fn main() {
     if let Err(error) = run() {
-        infra::log_error(error.as_ref()).unwrap();
+        infra::log_error(error.as_ref(), message_format()).unwrap();
         std::process::exit(1);
     }
 }
 
 fn run() -> Result<(), Box<dyn std::error::Error>> {
-    match clap::Command::new("pen")
+    let matches = clap::Command::new("pen")
         .version(clap::crate_version!())
         .subcommand_required(true)
         .arg(
@@ -41,12 +59,89 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .global(true)
                 .action(clap::ArgAction::SetTrue),
         )
-        .subcommand(clap::Command::new("build").about("Build a package").arg(
-            build_target_triple_argument().value_parser(clap::builder::PossibleValuesParser::new(
-                CROSS_COMPILE_TARGETS,
-            )),
-        ))
-        .subcommand(clap::Command::new("test").about("Test modules in a package"))
+        .arg(
+            clap::Arg::new("message format")
+                .long("message-format")
+                .help("Set a format of error messages")
+                .global(true)
+                .num_args(1)
+                .value_parser(["human", "json"])
+                .env(MESSAGE_FORMAT_ENVIRONMENT_VARIABLE),
+        )
+        .subcommand(
+            clap::Command::new("build")
+                .about("Build a package")
+                .arg(build_target_triple_argument().value_parser(
+                    clap::builder::PossibleValuesParser::new(CROSS_COMPILE_TARGETS),
+                ))
+                .arg(offline_argument())
+                .arg(emit_argument())
+                .arg(
+                    clap::Arg::new("no optimization")
+                        .long("no-optimization")
+                        .help("Disable optimization of intermediate representations")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("debug")
+                        .long("debug")
+                        .help("Keep source positions in intermediate representations")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("test")
+                .about("Test modules in a package")
+                .arg(offline_argument())
+                .arg(
+                    clap::Arg::new("list")
+                        .long("list")
+                        .help("List tests without running them")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("exact")
+                        .long("exact")
+                        .help("Match test names exactly")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("jobs")
+                        .short('j')
+                        .long("jobs")
+                        .help("Set a number of tests run in parallel")
+                        .num_args(1)
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    clap::Arg::new("timeout")
+                        .long("timeout")
+                        .help("Set a timeout of each test in seconds")
+                        .num_args(1)
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    clap::Arg::new("format")
+                        .long("format")
+                        .help("Set a format of test reports")
+                        .num_args(1)
+                        .value_parser(["human", "json", "junit"])
+                        .default_value("human"),
+                )
+                .arg(
+                    clap::Arg::new("report file")
+                        .long("report-file")
+                        .help("Write test reports to a file")
+                        .num_args(1),
+                )
+                .arg(
+                    clap::Arg::new("coverage")
+                        .long("coverage")
+                        .help("Report code coverage in the lcov format")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(clap::Arg::new("pattern").help("Filter tests by their names")),
+        )
         .subcommand(
             clap::Command::new("create")
                 .about("Create a package")
@@ -79,6 +174,81 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                         .action(clap::ArgAction::SetTrue),
                 ),
         )
+        .subcommand(
+            clap::Command::new("lint")
+                .about("Lint a package")
+                .arg(offline_argument())
+                .arg(
+                    clap::Arg::new("deny")
+                        .long("deny")
+                        .help("Fail on warnings")
+                        .num_args(1)
+                        .value_parser(["warnings"]),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("fix")
+                .about("Fix warnings in a package")
+                .arg(offline_argument())
+                .arg(
+                    clap::Arg::new("dry run")
+                        .long("dry-run")
+                        .help("Show changes without writing them")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("add")
+                .about("Add a dependency package")
+                .arg(
+                    clap::Arg::new("tag")
+                        .long("tag")
+                        .help("Use a tag of a git repository")
+                        .conflicts_with_all(["branch", "commit"]),
+                )
+                .arg(
+                    clap::Arg::new("branch")
+                        .long("branch")
+                        .help("Use a branch of a git repository")
+                        .conflicts_with("commit"),
+                )
+                .arg(
+                    clap::Arg::new("commit")
+                        .long("commit")
+                        .help("Use a commit of a git repository"),
+                )
+                .arg(
+                    clap::Arg::new("name")
+                        .help("Set a package name")
+                        .required(true),
+                )
+                .arg(
+                    clap::Arg::new("url")
+                        .help("Set a package URL or path")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("remove")
+                .about("Remove a dependency package")
+                .arg(
+                    clap::Arg::new("name")
+                        .help("Set a package name")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("update")
+                .about("Update dependency packages")
+                .arg(
+                    clap::Arg::new("name")
+                        .help("Set package names to update")
+                        .num_args(0..),
+                ),
+        )
+        .subcommand(clap::Command::new("vendor").about("Copy dependency packages into a package"))
+        .subcommand(clap::Command::new("lsp").about("Run a language server"))
+        .subcommand(clap::Command::new("repl").about("Evaluate expressions interactively"))
         .subcommand(
             clap::Command::new("document")
                 .about("Generate documentation for a package")
@@ -112,7 +282,8 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(clap::Arg::new("dependency file").required(true))
                 .arg(clap::Arg::new("object file").required(true))
                 .arg(clap::Arg::new("interface file").required(true))
-                .arg(build_target_triple_argument()),
+                .arg(build_target_triple_argument())
+                .arg(emit_argument()),
         )
         .subcommand(
             clap::Command::new("compile-main")
@@ -211,15 +382,57 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 )
                 .arg(clap::Arg::new("archive file").required(true).num_args(1..)),
         )
-        .get_matches()
-        .subcommand()
-        .unwrap()
-    {
+        .get_matches();
+    let (name, matches) = matches.subcommand().unwrap();
+
+    // Pass the message format down to subcommands run by build scripts.
+    if let Some(format) = matches.get_one::<String>("message format") {
+        env::set_var(MESSAGE_FORMAT_ENVIRONMENT_VARIABLE, format);
+    }
+
+    if let Ok(Some(true)) = matches.try_get_one::<bool>("offline") {
+        env::set_var(OFFLINE_ENVIRONMENT_VARIABLE, "true");
+    }
+
+    if let Ok(Some(true)) = matches.try_get_one::<bool>("coverage") {
+        env::set_var(COVERAGE_ENVIRONMENT_VARIABLE, "true");
+    }
+
+    if let Ok(Some(true)) = matches.try_get_one::<bool>("no optimization") {
+        env::set_var(NO_OPTIMIZATION_ENVIRONMENT_VARIABLE, "true");
+    }
+
+    if let Ok(Some(true)) = matches.try_get_one::<bool>("debug") {
+        env::set_var(DEBUG_ENVIRONMENT_VARIABLE, "true");
+    }
+
+    // Pass intermediate representations to emit down to module compilation.
+    if let Ok(Some(kinds)) = matches.try_get_many::<String>("emit") {
+        env::set_var(
+            EMIT_ENVIRONMENT_VARIABLE,
+            kinds.map(Deref::deref).collect::<Vec<_>>().join(","),
+        );
+    }
+
+    match (name, matches) {
         ("build", matches) => package_builder::build(
             matches.get_one::<String>("target").map(Deref::deref),
             matches.get_one("verbose").copied().unwrap_or_default(),
         ),
-        ("test", _) => test_runner::run(),
+        ("test", matches) => test_runner::run(&app::TestRunConfiguration {
+            pattern: matches.get_one::<String>("pattern").cloned(),
+            exact: matches.get_one("exact").copied().unwrap_or_default(),
+            list: matches.get_one("list").copied().unwrap_or_default(),
+            jobs: matches.get_one("jobs").copied(),
+            timeout: matches.get_one("timeout").copied().map(Duration::from_secs),
+            format: match matches.get_one::<String>("format").map(Deref::deref) {
+                Some("json") => app::TestReportFormat::Json,
+                Some("junit") => app::TestReportFormat::Junit,
+                _ => app::TestReportFormat::Human,
+            },
+            report_file: matches.get_one::<String>("report file").cloned(),
+            coverage: matches.get_one("coverage").copied().unwrap_or_default(),
+        }),
         ("create", matches) => package_creator::create(
             matches.get_one::<String>("directory").unwrap(),
             matches.get_one("library").copied().unwrap_or_default(),
@@ -231,6 +444,40 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 package_formatter::format(matches.get_one("check").copied().unwrap_or_default())
             }
         }
+        ("lint", matches) => package_linter::lint(
+            matches.get_one::<String>("deny").is_some(),
+            matches.get_one("verbose").copied().unwrap_or_default(),
+        ),
+        ("fix", matches) => package_fixer::fix(
+            matches.get_one("dry run").copied().unwrap_or_default(),
+            matches.get_one("verbose").copied().unwrap_or_default(),
+        ),
+        ("add", matches) => package_dependency_editor::add(
+            matches.get_one::<String>("name").unwrap(),
+            matches.get_one::<String>("url").unwrap(),
+            if let Some(tag) = matches.get_one::<String>("tag") {
+                Some(app::PackageReference::Tag(tag.into()))
+            } else if let Some(branch) = matches.get_one::<String>("branch") {
+                Some(app::PackageReference::Branch(branch.into()))
+            } else {
+                matches
+                    .get_one::<String>("commit")
+                    .map(|commit| app::PackageReference::Commit(commit.into()))
+            },
+        ),
+        ("remove", matches) => {
+            package_dependency_editor::remove(matches.get_one::<String>("name").unwrap())
+        }
+        ("update", matches) => package_updater::update(
+            &matches
+                .get_many::<String>("name")
+                .unwrap_or_default()
+                .map(Deref::deref)
+                .collect::<Vec<_>>(),
+        ),
+        ("vendor", _) => package_vendorer::vendor(),
+        ("lsp", _) => language_server::run(),
+        ("repl", _) => repl::run(),
         ("document", matches) => package_documentation_generator::generate(
             matches.get_one::<String>("name").unwrap(),
             matches.get_one::<String>("url").unwrap(),
@@ -318,6 +565,13 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     }
 }
 
+fn message_format() -> infra::MessageFormat {
+    match env::var(MESSAGE_FORMAT_ENVIRONMENT_VARIABLE).as_deref() {
+        Ok("json") => infra::MessageFormat::Json,
+        _ => infra::MessageFormat::Human,
+    }
+}
+
 fn build_target_triple_argument() -> clap::Arg {
     clap::Arg::new("target")
         .short('t')
@@ -325,3 +579,20 @@ fn build_target_triple_argument() -> clap::Arg {
         .num_args(1)
         .help("Set a target triple")
 }
+
+fn emit_argument() -> clap::Arg {
+    clap::Arg::new("emit")
+        .long("emit")
+        .help("Emit intermediate representations next to object files")
+        .num_args(1)
+        .value_delimiter(',')
+        .value_parser(["ast", "hir", "mir", "fmm", "llvm", "c"])
+        .action(clap::ArgAction::Append)
+}
+
+fn offline_argument() -> clap::Arg {
+    clap::Arg::new("offline")
+        .long("offline")
+        .help("Use only cached or vendored packages without network access")
+        .action(clap::ArgAction::SetTrue)
+}
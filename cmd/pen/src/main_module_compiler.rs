@@ -12,9 +12,11 @@ pub fn compile(
     let main_package_directory = main_package_directory_finder::find()?;
     let file_path_converter = Rc::new(infra::FilePathConverter::new(&main_package_directory));
 
+    let source_file = file_path_converter.convert_to_file_path(source_file)?;
+
     app::module_compiler::compile_main(
         &infrastructure::create(file_path_converter.clone(), &main_package_directory)?,
-        &file_path_converter.convert_to_file_path(source_file)?,
+        &source_file,
         &file_path_converter.convert_to_file_path(dependency_file)?,
         &file_path_converter.convert_to_file_path(object_file)?,
         &context_interface_files
@@ -24,7 +26,5 @@ pub fn compile(
         target_triple,
         &COMPILE_CONFIGURATION,
         &APPLICATION_CONFIGURATION,
-    )?;
-
-    Ok(())
+    )
 }
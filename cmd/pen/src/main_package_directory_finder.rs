@@ -1,8 +1,11 @@
 use crate::file_path_configuration::BUILD_CONFIGURATION_FILENAME;
+use std::path::{Path, PathBuf};
 
-pub fn find() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-    let mut directory: &std::path::Path = &std::env::current_dir()?;
+pub fn find() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    find_from(&std::env::current_dir()?)
+}
 
+pub fn find_from(mut directory: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
     while !directory.join(BUILD_CONFIGURATION_FILENAME).exists() {
         directory = directory.parent().ok_or_else(|| {
             std::io::Error::new(
@@ -1,5 +1,8 @@
 use super::{compile_configuration::COMPILE_CONFIGURATION, main_package_directory_finder};
-use crate::infrastructure;
+use crate::{
+    emit_kind_finder, file_path_configuration::OUTPUT_DIRECTORY, infrastructure,
+    test_configuration::TEST_CONFIGURATION, test_target_directory_finder,
+};
 use std::rc::Rc;
 
 pub fn compile(
@@ -12,15 +15,40 @@ pub fn compile(
     let main_package_directory = main_package_directory_finder::find()?;
     let file_path_converter = Rc::new(infra::FilePathConverter::new(&main_package_directory));
 
-    app::module_compiler::compile(
-        &infrastructure::create(file_path_converter.clone(), &main_package_directory)?,
-        &file_path_converter.convert_to_file_path(source_file)?,
-        &file_path_converter.convert_to_file_path(dependency_file)?,
-        &file_path_converter.convert_to_file_path(object_file)?,
-        &file_path_converter.convert_to_file_path(interface_file)?,
-        target_triple,
-        &COMPILE_CONFIGURATION,
-    )?;
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+    let source_file = file_path_converter.convert_to_file_path(source_file)?;
+    let dependency_file = file_path_converter.convert_to_file_path(dependency_file)?;
+    let object_file = file_path_converter.convert_to_file_path(object_file)?;
+    let interface_file = file_path_converter.convert_to_file_path(interface_file)?;
+    let emit_kinds = emit_kind_finder::find();
 
-    Ok(())
+    // Modules in external packages are not instrumented for coverage as they
+    // are not tested by their users.
+    if test_target_directory_finder::is_coverage_enabled()
+        && source_file.components().next() != Some(OUTPUT_DIRECTORY)
+    {
+        app::module_compiler::compile_with_coverage(
+            &infrastructure,
+            &source_file,
+            &dependency_file,
+            &object_file,
+            &interface_file,
+            target_triple,
+            &COMPILE_CONFIGURATION,
+            &TEST_CONFIGURATION.test_module_configuration,
+            &emit_kinds,
+        )
+    } else {
+        app::module_compiler::compile(
+            &infrastructure,
+            &source_file,
+            &dependency_file,
+            &object_file,
+            &interface_file,
+            target_triple,
+            &COMPILE_CONFIGURATION,
+            &emit_kinds,
+        )
+    }
 }
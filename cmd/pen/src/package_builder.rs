@@ -4,7 +4,7 @@ use crate::{
     file_path_configuration::{
         DEFAULT_TARGET_DIRECTORY, FFI_PACKAGE_URL, OUTPUT_DIRECTORY, PRELUDE_PACKAGE_URL,
     },
-    infrastructure,
+    infrastructure, target_directory_finder, warning_logger,
 };
 use std::{error::Error, rc::Rc};
 
@@ -17,9 +17,11 @@ pub fn build(target_triple: Option<&str>, verbose: bool) -> Result<(), Box<dyn E
         infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
     let main_package_directory =
         file_path_converter.convert_to_file_path(&main_package_directory)?;
+    let target_directory =
+        target_directory_finder::find(target_triple.unwrap_or(DEFAULT_TARGET_DIRECTORY));
     let output_directory = main_package_directory.join(&app::infra::FilePath::new([
         OUTPUT_DIRECTORY,
-        target_triple.unwrap_or(DEFAULT_TARGET_DIRECTORY),
+        target_directory.as_str(),
     ]));
 
     if verbose {
@@ -46,5 +48,7 @@ pub fn build(target_triple: Option<&str>, verbose: bool) -> Result<(), Box<dyn E
         &url::Url::parse(PRELUDE_PACKAGE_URL)?,
         &url::Url::parse(FFI_PACKAGE_URL)?,
         &APPLICATION_CONFIGURATION,
-    )
+    )?;
+
+    warning_logger::log(&infrastructure, &main_package_directory, &output_directory)
 }
@@ -0,0 +1,46 @@
+use super::main_package_directory_finder;
+use crate::infrastructure;
+use std::{env, error::Error, rc::Rc};
+
+pub fn add(
+    name: &str,
+    url: &str,
+    reference: Option<app::PackageReference>,
+) -> Result<(), Box<dyn Error>> {
+    let main_package_directory = main_package_directory_finder::find()?;
+    let file_path_converter = Rc::new(infra::FilePathConverter::new(
+        main_package_directory.clone(),
+    ));
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+
+    app::package_dependency_editor::add(
+        &infrastructure,
+        &file_path_converter.convert_to_file_path(&main_package_directory)?,
+        name,
+        &app::PackageDependency::new(
+            // Resolve relative paths from a current directory.
+            url::Url::from_directory_path(env::current_dir()?)
+                .map_err(|_| {
+                    std::io::Error::new(std::io::ErrorKind::NotFound, "invalid current directory")
+                })?
+                .join(url)?,
+            reference,
+        ),
+    )
+}
+
+pub fn remove(name: &str) -> Result<(), Box<dyn Error>> {
+    let main_package_directory = main_package_directory_finder::find()?;
+    let file_path_converter = Rc::new(infra::FilePathConverter::new(
+        main_package_directory.clone(),
+    ));
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+
+    app::package_dependency_editor::remove(
+        &infrastructure,
+        &file_path_converter.convert_to_file_path(&main_package_directory)?,
+        name,
+    )
+}
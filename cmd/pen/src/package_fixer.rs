@@ -0,0 +1,52 @@
+use super::{main_package_directory_finder, package_builder};
+use crate::{
+    application_configuration::APPLICATION_CONFIGURATION,
+    file_path_configuration::{
+        DEFAULT_TARGET_DIRECTORY, NO_WARNINGS_ENVIRONMENT_VARIABLE, OUTPUT_DIRECTORY,
+        PRELUDE_PACKAGE_URL,
+    },
+    infrastructure,
+};
+use std::{
+    env,
+    error::Error,
+    io::{stdout, Write},
+    rc::Rc,
+};
+
+pub fn fix(dry_run: bool, verbose: bool) -> Result<(), Box<dyn Error>> {
+    env::set_var(NO_WARNINGS_ENVIRONMENT_VARIABLE, "true");
+
+    package_builder::build(None, verbose)?;
+
+    let main_package_directory = main_package_directory_finder::find()?;
+    let file_path_converter = Rc::new(infra::FilePathConverter::new(
+        main_package_directory.clone(),
+    ));
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+    let main_package_directory =
+        file_path_converter.convert_to_file_path(&main_package_directory)?;
+
+    if verbose {
+        infra::log_info("fixing modules")?;
+    }
+
+    let diff = app::package_fixer::fix(
+        &infrastructure,
+        &main_package_directory,
+        &main_package_directory.join(&app::infra::FilePath::new([
+            OUTPUT_DIRECTORY,
+            DEFAULT_TARGET_DIRECTORY,
+        ])),
+        &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+        &APPLICATION_CONFIGURATION,
+        dry_run,
+    )?;
+
+    if dry_run {
+        stdout().write_all(diff.as_bytes())?;
+    }
+
+    Ok(())
+}
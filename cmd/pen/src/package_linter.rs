@@ -0,0 +1,47 @@
+use super::{main_package_directory_finder, package_builder};
+use crate::{
+    file_path_configuration::{
+        DEFAULT_TARGET_DIRECTORY, NO_WARNINGS_ENVIRONMENT_VARIABLE, OUTPUT_DIRECTORY,
+    },
+    infrastructure, target_directory_finder,
+};
+use std::{env, error::Error, rc::Rc};
+
+pub fn lint(deny_warnings: bool, verbose: bool) -> Result<(), Box<dyn Error>> {
+    // Warnings are reported only once after modules are built.
+    env::set_var(NO_WARNINGS_ENVIRONMENT_VARIABLE, "true");
+
+    package_builder::build(None, verbose)?;
+
+    let main_package_directory = main_package_directory_finder::find()?;
+    let file_path_converter = Rc::new(infra::FilePathConverter::new(
+        main_package_directory.clone(),
+    ));
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+    let main_package_directory =
+        file_path_converter.convert_to_file_path(&main_package_directory)?;
+
+    if verbose {
+        infra::log_info("linting modules")?;
+    }
+
+    let diagnostics = app::package_linter::lint(
+        &infrastructure,
+        &main_package_directory,
+        &main_package_directory.join(&app::infra::FilePath::new([
+            OUTPUT_DIRECTORY,
+            target_directory_finder::find(DEFAULT_TARGET_DIRECTORY).as_str(),
+        ])),
+    )?;
+
+    for diagnostic in &diagnostics {
+        infra::log_diagnostic(diagnostic, crate::message_format())?;
+    }
+
+    if deny_warnings {
+        app::package_linter::deny_warnings(&diagnostics)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use super::main_package_directory_finder;
+use crate::{
+    file_path_configuration::{
+        DEFAULT_TARGET_DIRECTORY, FFI_PACKAGE_URL, OUTPUT_DIRECTORY, PRELUDE_PACKAGE_URL,
+    },
+    infrastructure,
+};
+use std::{error::Error, rc::Rc};
+
+pub fn vendor() -> Result<(), Box<dyn Error>> {
+    let main_package_directory = main_package_directory_finder::find()?;
+    let file_path_converter = Rc::new(infra::FilePathConverter::new(
+        main_package_directory.clone(),
+    ));
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+    let main_package_directory =
+        file_path_converter.convert_to_file_path(&main_package_directory)?;
+
+    app::package_vendorer::vendor(
+        &infrastructure,
+        &main_package_directory,
+        &main_package_directory.join(&app::infra::FilePath::new([
+            OUTPUT_DIRECTORY,
+            DEFAULT_TARGET_DIRECTORY,
+        ])),
+        &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+        &url::Url::parse(FFI_PACKAGE_URL)?,
+    )
+}
@@ -0,0 +1,94 @@
+use super::main_package_directory_finder;
+use crate::{
+    compile_configuration::COMPILE_CONFIGURATION,
+    file_path_configuration::{DEFAULT_TARGET_DIRECTORY, OUTPUT_DIRECTORY, PRELUDE_PACKAGE_URL},
+    infrastructure,
+};
+use std::{
+    env,
+    error::Error,
+    io::{stdin, stdout, BufRead, IsTerminal, Write},
+    rc::Rc,
+    thread,
+};
+
+const PROMPT: &str = "> ";
+const CONTINUATION_PROMPT: &str = "| ";
+// Evaluation of deeply recursive functions needs a large stack.
+const STACK_SIZE: usize = 1 << 30;
+
+pub fn run() -> Result<(), Box<dyn Error>> {
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(|| run_session().map_err(|error| error.to_string()))?
+        .join()
+        .map_err(|_| "REPL thread panicked")??;
+
+    Ok(())
+}
+
+fn run_session() -> Result<(), Box<dyn Error>> {
+    // A REPL can run outside packages.
+    let main_package_directory =
+        main_package_directory_finder::find().or_else(|_| env::current_dir())?;
+    let file_path_converter = Rc::new(infra::FilePathConverter::new(
+        main_package_directory.clone(),
+    ));
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+    let main_package_directory =
+        file_path_converter.convert_to_file_path(&main_package_directory)?;
+
+    let mut session = app::repl::start(
+        &infrastructure,
+        &main_package_directory,
+        &main_package_directory.join(&app::infra::FilePath::new([
+            OUTPUT_DIRECTORY,
+            DEFAULT_TARGET_DIRECTORY,
+        ])),
+        &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+        &COMPILE_CONFIGURATION,
+    )?;
+    let mut input = stdin().lock();
+    let interactive = input.is_terminal();
+    let mut source = String::new();
+
+    loop {
+        if interactive {
+            let mut output = stdout().lock();
+
+            write!(
+                output,
+                "{}",
+                if source.is_empty() {
+                    PROMPT
+                } else {
+                    CONTINUATION_PROMPT
+                }
+            )?;
+            output.flush()?;
+        }
+
+        if input.read_line(&mut source)? == 0 {
+            break;
+        } else if !app::repl::is_input_complete(&source) {
+            continue;
+        }
+
+        match session.evaluate(&source) {
+            Ok(Some(result)) => println!("{result}"),
+            Ok(None) => {}
+            Err(error) => infra::log_error(error.as_ref(), crate::message_format())?,
+        }
+
+        source.clear();
+    }
+
+    if !source.trim().is_empty() {
+        if let Some(result) = session.evaluate(&source)? {
+            println!("{result}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::{
+    emit_kind_finder, environment_variable_reader,
+    file_path_configuration::{DEBUG_ENVIRONMENT_VARIABLE, NO_OPTIMIZATION_ENVIRONMENT_VARIABLE},
+};
+
+// Modules compiled with different options are built in separate target
+// directories so that build scripts never mix their outputs. For example,
+// emitted intermediate representations are tracked as well as object files.
+pub fn find(target_directory: &str) -> String {
+    let mut directory = target_directory.to_owned();
+    let kinds = emit_kind_finder::find();
+
+    if !kinds.is_empty() {
+        directory += "-emit-";
+        directory += &kinds
+            .iter()
+            .map(|kind| kind.name())
+            .collect::<Vec<_>>()
+            .join("-");
+    }
+
+    if !is_optimization_enabled() {
+        directory += "-unoptimized";
+    }
+
+    if is_debug_enabled() {
+        directory += "-debug";
+    }
+
+    directory
+}
+
+pub fn is_optimization_enabled() -> bool {
+    !environment_variable_reader::read_flag(NO_OPTIMIZATION_ENVIRONMENT_VARIABLE)
+}
+
+pub fn is_debug_enabled() -> bool {
+    environment_variable_reader::read_flag(DEBUG_ENVIRONMENT_VARIABLE)
+}
@@ -4,5 +4,6 @@ pub static TEST_CONFIGURATION: LazyLock<app::TestConfiguration> =
     LazyLock::new(|| app::TestConfiguration {
         test_module_configuration: app::TestModuleConfiguration {
             test_function_prefix: "_pen_test_".into(),
+            coverage_function_name: "_pen_test_coverage".into(),
         },
     });
@@ -1,6 +1,6 @@
 use crate::{
-    file_path_configuration::{DEFAULT_TARGET_DIRECTORY, OUTPUT_DIRECTORY},
-    infrastructure, main_package_directory_finder,
+    file_path_configuration::OUTPUT_DIRECTORY, infrastructure, main_package_directory_finder,
+    test_target_directory_finder,
 };
 use std::rc::Rc;
 
@@ -24,7 +24,7 @@ pub fn link(
             .convert_to_file_path(main_package_directory)?
             .join(&app::infra::FilePath::new([
                 OUTPUT_DIRECTORY,
-                DEFAULT_TARGET_DIRECTORY,
+                test_target_directory_finder::find(),
             ])),
     )?;
 
@@ -14,16 +14,16 @@ pub fn compile(
         main_package_directory_finder::find()?,
     ));
 
+    let source_file = file_path_converter.convert_to_file_path(source_file)?;
+
     app::module_compiler::compile_test(
         &infrastructure::create(file_path_converter.clone(), &main_package_directory)?,
-        &file_path_converter.convert_to_file_path(source_file)?,
+        &source_file,
         &file_path_converter.convert_to_file_path(dependency_file)?,
         &file_path_converter.convert_to_file_path(object_file)?,
         &file_path_converter.convert_to_file_path(test_information_file)?,
         target_triple,
         &COMPILE_CONFIGURATION,
         &TEST_CONFIGURATION.test_module_configuration,
-    )?;
-
-    Ok(())
+    )
 }
@@ -1,13 +1,13 @@
 use crate::{
     application_configuration::APPLICATION_CONFIGURATION,
-    file_path_configuration::{
-        DEFAULT_TARGET_DIRECTORY, FFI_PACKAGE_URL, OUTPUT_DIRECTORY, PRELUDE_PACKAGE_URL,
-    },
-    infrastructure, main_package_directory_finder,
+    file_path_configuration::{FFI_PACKAGE_URL, OUTPUT_DIRECTORY, PRELUDE_PACKAGE_URL},
+    infrastructure, main_package_directory_finder, test_target_directory_finder, warning_logger,
 };
 use std::rc::Rc;
 
-pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+pub fn run(
+    test_run_configuration: &app::TestRunConfiguration,
+) -> Result<(), Box<dyn std::error::Error>> {
     let main_package_directory = main_package_directory_finder::find()?;
     let file_path_converter = Rc::new(infra::FilePathConverter::new(&main_package_directory));
     let infrastructure =
@@ -16,7 +16,7 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         file_path_converter.convert_to_file_path(&main_package_directory)?;
     let output_directory = main_package_directory.join(&app::infra::FilePath::new([
         OUTPUT_DIRECTORY,
-        DEFAULT_TARGET_DIRECTORY,
+        test_target_directory_finder::find(),
     ]));
 
     app::package_initializer::initialize(
@@ -27,14 +27,18 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         &url::Url::parse(FFI_PACKAGE_URL)?,
     )?;
 
-    app::test_runner::run(
+    let result = app::test_runner::run(
         &infrastructure,
         &main_package_directory,
         &output_directory,
         &url::Url::parse(PRELUDE_PACKAGE_URL)?,
         &url::Url::parse(FFI_PACKAGE_URL)?,
         &APPLICATION_CONFIGURATION,
-    )?;
+        test_run_configuration,
+    );
+
+    // Report warnings even if some tests fail.
+    warning_logger::log(&infrastructure, &main_package_directory, &output_directory)?;
 
-    Ok(())
+    result
 }
@@ -0,0 +1,20 @@
+use crate::{
+    environment_variable_reader,
+    file_path_configuration::{
+        COVERAGE_ENVIRONMENT_VARIABLE, COVERAGE_TARGET_DIRECTORY, DEFAULT_TARGET_DIRECTORY,
+    },
+};
+
+// Instrumented objects for coverage are built in a separate target directory
+// so that they never get mixed with normal ones.
+pub fn find() -> &'static str {
+    if is_coverage_enabled() {
+        COVERAGE_TARGET_DIRECTORY
+    } else {
+        DEFAULT_TARGET_DIRECTORY
+    }
+}
+
+pub fn is_coverage_enabled() -> bool {
+    environment_variable_reader::read_flag(COVERAGE_ENVIRONMENT_VARIABLE)
+}
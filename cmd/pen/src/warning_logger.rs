@@ -0,0 +1,25 @@
+use crate::file_path_configuration::NO_WARNINGS_ENVIRONMENT_VARIABLE;
+use std::{env, error::Error};
+
+// Warnings are logged after builds rather than on compilation of each module so
+// that they are reported even if modules are not recompiled. Warnings of modules
+// in external packages are not logged as their users cannot fix them.
+pub fn log(
+    infrastructure: &app::infra::Infrastructure,
+    main_package_directory: &app::infra::FilePath,
+    output_directory: &app::infra::FilePath,
+) -> Result<(), Box<dyn Error>> {
+    if env::var(NO_WARNINGS_ENVIRONMENT_VARIABLE).is_ok() {
+        return Ok(());
+    }
+
+    for diagnostic in app::package_warning_collector::collect(
+        infrastructure,
+        main_package_directory,
+        output_directory,
+    )? {
+        infra::log_diagnostic(&diagnostic, crate::message_format())?;
+    }
+
+    Ok(())
+}
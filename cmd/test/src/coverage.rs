@@ -0,0 +1,117 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, BufWriter, Write},
+    panic,
+    path::PathBuf,
+    process,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+const COVERAGE_DIRECTORY: &str = "coverage";
+// Counts are dumped periodically so that they survive crashes and timeouts of
+// tests.
+const DUMP_INTERVAL: Duration = Duration::from_millis(100);
+
+struct Counts {
+    counts: HashMap<Vec<u8>, Vec<u64>>,
+    dump_time: Instant,
+}
+
+static COUNTS: LazyLock<Mutex<Counts>> = LazyLock::new(|| {
+    Mutex::new(Counts {
+        counts: Default::default(),
+        dump_time: Instant::now(),
+    })
+});
+
+#[ffi::bindgen]
+fn _pen_test_coverage(path: ffi::ByteString, index: ffi::Number) {
+    let index = f64::from(index) as usize;
+    let mut counts = COUNTS.lock().unwrap();
+
+    if !counts.counts.contains_key(path.as_slice()) {
+        counts.counts.insert(path.as_slice().to_vec(), vec![]);
+    }
+
+    let path_counts = counts.counts.get_mut(path.as_slice()).unwrap();
+
+    if path_counts.len() <= index {
+        path_counts.resize(index + 1, 0);
+    }
+
+    path_counts[index] += 1;
+
+    if counts.dump_time.elapsed() >= DUMP_INTERVAL {
+        // Failures of intermediate dumps are ignored as counts are dumped
+        // again on exit.
+        write_counts(&counts.counts).unwrap_or_default();
+        counts.dump_time = Instant::now();
+    }
+}
+
+// Remove counts of previous test runs.
+pub fn clear() -> io::Result<()> {
+    let directory = directory()?;
+
+    if directory.exists() {
+        fs::remove_dir_all(&directory)?;
+    }
+
+    fs::create_dir_all(directory)
+}
+
+// Dump counts also on panics before processes abort.
+pub fn dump_on_panic() {
+    let hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |information| {
+        if let Ok(counts) = COUNTS.try_lock() {
+            write_counts(&counts.counts).unwrap_or_default();
+        }
+
+        hook(information);
+    }));
+}
+
+pub fn dump() -> io::Result<()> {
+    write_counts(&COUNTS.lock().unwrap().counts)
+}
+
+// Write counts in a current process to a file of lines in a format of
+// `<path>\t<index>\t<count>`.
+//
+// A file is written outside the coverage directory first and renamed so that
+// processes killed while writing never leave broken files.
+fn write_counts(counts: &HashMap<Vec<u8>, Vec<u64>>) -> io::Result<()> {
+    let directory = directory()?;
+    let temporary_file =
+        directory.with_file_name(format!(".{COVERAGE_DIRECTORY}-{}", process::id()));
+    let mut writer = BufWriter::new(fs::File::create(&temporary_file)?);
+
+    for (path, counts) in counts {
+        for (index, count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+            writeln!(
+                writer,
+                "{}\t{index}\t{count}",
+                String::from_utf8_lossy(path)
+            )?;
+        }
+    }
+
+    writer.flush()?;
+    drop(writer);
+
+    fs::rename(
+        temporary_file,
+        directory.join(format!("{}.txt", process::id())),
+    )
+}
+
+fn directory() -> io::Result<PathBuf> {
+    Ok(env::current_exe()?
+        .parent()
+        .ok_or_else(|| io::Error::other("test executable directory not found"))?
+        .join(COVERAGE_DIRECTORY))
+}
@@ -1,6 +1,11 @@
+mod coverage;
 mod debug;
 mod heap;
+mod reporter;
+mod runner;
 mod spawn;
 mod unreachable;
 
-fn main() {}
+fn main() {
+    runner::run(&[]);
+}
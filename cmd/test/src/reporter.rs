@@ -0,0 +1,445 @@
+use crate::runner::{Outcome, Test, TestResult};
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub duration: Duration,
+}
+
+pub trait Reporter {
+    fn report(&mut self, test: &Test, result: &TestResult) -> io::Result<()>;
+    fn finish(&mut self, summary: &Summary) -> io::Result<()>;
+}
+
+pub struct HumanReporter {
+    writer: Box<dyn Write>,
+    module: Option<&'static str>,
+}
+
+impl HumanReporter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer,
+            module: None,
+        }
+    }
+}
+
+impl Reporter for HumanReporter {
+    fn report(&mut self, test: &Test, result: &TestResult) -> io::Result<()> {
+        if self.module != Some(test.module) {
+            writeln!(self.writer, "{}", test.module)?;
+            self.module = Some(test.module);
+        }
+
+        writeln!(
+            self.writer,
+            "\t{}\t{} ({})",
+            match result.outcome {
+                Outcome::Success => "OK",
+                Outcome::Failure(_) => "FAIL",
+            },
+            test.name,
+            format_duration(result.duration)
+        )?;
+
+        if let Outcome::Failure(message) = &result.outcome {
+            writeln!(self.writer, "\t\tMessage: {message}")?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, summary: &Summary) -> io::Result<()> {
+        writeln!(self.writer, "summary")?;
+        writeln!(
+            self.writer,
+            "\t{}\t{} passed, {} failed ({})",
+            if summary.failed == 0 { "OK" } else { "FAIL" },
+            summary.passed,
+            summary.failed,
+            format_duration(summary.duration)
+        )?;
+        self.writer.flush()
+    }
+}
+
+// A reporter of JSON lines of test events
+pub struct JsonReporter {
+    writer: Box<dyn Write>,
+}
+
+impl JsonReporter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self { writer }
+    }
+}
+
+impl Reporter for JsonReporter {
+    fn report(&mut self, test: &Test, result: &TestResult) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            r#"{{"type":"test","module":{},"name":{},"result":{},"duration":{},"message":{},"stdout":{},"output":{}}}"#,
+            escape_json(test.module),
+            escape_json(test.name),
+            match result.outcome {
+                Outcome::Success => r#""ok""#,
+                Outcome::Failure(_) => r#""failed""#,
+            },
+            result.duration.as_secs_f64(),
+            match &result.outcome {
+                Outcome::Success => "null".into(),
+                Outcome::Failure(message) => escape_json(message),
+            },
+            escape_json(&String::from_utf8_lossy(&result.stdout)),
+            escape_json(&String::from_utf8_lossy(&result.output)),
+        )?;
+        self.writer.flush()
+    }
+
+    fn finish(&mut self, summary: &Summary) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            r#"{{"type":"summary","passed":{},"failed":{},"duration":{}}}"#,
+            summary.passed,
+            summary.failed,
+            summary.duration.as_secs_f64(),
+        )?;
+        self.writer.flush()
+    }
+}
+
+struct JunitTestCase {
+    name: &'static str,
+    message: Option<String>,
+    stdout: String,
+    output: String,
+    duration: Duration,
+}
+
+// A reporter of JUnit XML with modules as test suites and functions as test
+// cases
+pub struct JunitReporter {
+    writer: Box<dyn Write>,
+    suites: Vec<(&'static str, Vec<JunitTestCase>)>,
+}
+
+impl JunitReporter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer,
+            suites: vec![],
+        }
+    }
+}
+
+impl Reporter for JunitReporter {
+    fn report(&mut self, test: &Test, result: &TestResult) -> io::Result<()> {
+        let case = JunitTestCase {
+            name: test.name,
+            message: match &result.outcome {
+                Outcome::Success => None,
+                Outcome::Failure(message) => Some(message.clone()),
+            },
+            stdout: String::from_utf8_lossy(&result.stdout).into(),
+            output: String::from_utf8_lossy(&result.output).into(),
+            duration: result.duration,
+        };
+
+        match self.suites.last_mut() {
+            Some((module, cases)) if *module == test.module => cases.push(case),
+            _ => self.suites.push((test.module, vec![case])),
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, summary: &Summary) -> io::Result<()> {
+        writeln!(self.writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            self.writer,
+            r#"<testsuites tests="{}" failures="{}" time="{}">"#,
+            summary.passed + summary.failed,
+            summary.failed,
+            summary.duration.as_secs_f64()
+        )?;
+
+        for (module, cases) in &self.suites {
+            writeln!(
+                self.writer,
+                r#"  <testsuite name="{}" tests="{}" failures="{}" time="{}">"#,
+                escape_xml(module),
+                cases.len(),
+                cases.iter().filter(|case| case.message.is_some()).count(),
+                cases
+                    .iter()
+                    .map(|case| case.duration)
+                    .sum::<Duration>()
+                    .as_secs_f64()
+            )?;
+
+            for case in cases {
+                writeln!(
+                    self.writer,
+                    r#"    <testcase name="{}" classname="{}" time="{}">"#,
+                    escape_xml(case.name),
+                    escape_xml(module),
+                    case.duration.as_secs_f64()
+                )?;
+
+                if let Some(message) = &case.message {
+                    writeln!(
+                        self.writer,
+                        r#"      <failure message="{}"/>"#,
+                        escape_xml(message)
+                    )?;
+                }
+
+                if !case.stdout.is_empty() {
+                    writeln!(
+                        self.writer,
+                        "      <system-out>{}</system-out>",
+                        escape_xml(&case.stdout)
+                    )?;
+                }
+
+                if !case.output.is_empty() {
+                    writeln!(
+                        self.writer,
+                        "      <system-err>{}</system-err>",
+                        escape_xml(&case.output)
+                    )?;
+                }
+
+                writeln!(self.writer, "    </testcase>")?;
+            }
+
+            writeln!(self.writer, "  </testsuite>")?;
+        }
+
+        writeln!(self.writer, "</testsuites>")?;
+        self.writer.flush()
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3}s", duration.as_secs_f64())
+}
+
+fn escape_json(string: &str) -> String {
+    let mut escaped = String::from("\"");
+
+    for character in string.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if character.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => escaped.push(character),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn escape_xml(string: &str) -> String {
+    string
+        .chars()
+        .filter(|&character| !character.is_control() || matches!(character, '\n' | '\t' | '\r'))
+        .map(|character| match character {
+            '&' => "&amp;".into(),
+            '<' => "&lt;".into(),
+            '>' => "&gt;".into(),
+            '"' => "&quot;".into(),
+            '\'' => "&apos;".into(),
+            character => character.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Buffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(data)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    unsafe extern "C" fn run_nothing() -> ffi::ByteString {
+        ffi::ByteString::default()
+    }
+
+    fn test(module: &'static str, name: &'static str) -> Test {
+        Test {
+            module,
+            name,
+            function: run_nothing,
+        }
+    }
+
+    fn report(mut reporter: impl Reporter) {
+        reporter
+            .report(
+                &test("Foo.test.pen", "Add"),
+                &TestResult {
+                    outcome: Outcome::Success,
+                    stdout: b"hello".to_vec(),
+                    output: vec![],
+                    duration: Duration::from_millis(250),
+                },
+            )
+            .unwrap();
+        reporter
+            .report(
+                &test("Foo.test.pen", "Subtract"),
+                &TestResult {
+                    outcome: Outcome::Failure("1 != 2".into()),
+                    stdout: vec![],
+                    output: b"<debug>".to_vec(),
+                    duration: Duration::from_millis(500),
+                },
+            )
+            .unwrap();
+        reporter
+            .finish(&Summary {
+                passed: 1,
+                failed: 1,
+                duration: Duration::from_secs(1),
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn report_in_json() {
+        let buffer = Buffer::default();
+
+        report(JsonReporter::new(Box::new(buffer.clone())));
+
+        assert_eq!(
+            buffer.contents(),
+            [
+                r#"{"type":"test","module":"Foo.test.pen","name":"Add","result":"ok","duration":0.25,"message":null,"stdout":"hello","output":""}"#,
+                r#"{"type":"test","module":"Foo.test.pen","name":"Subtract","result":"failed","duration":0.5,"message":"1 != 2","stdout":"","output":"<debug>"}"#,
+                r#"{"type":"summary","passed":1,"failed":1,"duration":1}"#,
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn report_in_junit() {
+        let buffer = Buffer::default();
+
+        report(JunitReporter::new(Box::new(buffer.clone())));
+
+        assert_eq!(
+            buffer.contents(),
+            [
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                r#"<testsuites tests="2" failures="1" time="1">"#,
+                r#"  <testsuite name="Foo.test.pen" tests="2" failures="1" time="0.75">"#,
+                r#"    <testcase name="Add" classname="Foo.test.pen" time="0.25">"#,
+                r#"      <system-out>hello</system-out>"#,
+                r#"    </testcase>"#,
+                r#"    <testcase name="Subtract" classname="Foo.test.pen" time="0.5">"#,
+                r#"      <failure message="1 != 2"/>"#,
+                r#"      <system-err>&lt;debug&gt;</system-err>"#,
+                r#"    </testcase>"#,
+                r#"  </testsuite>"#,
+                r#"</testsuites>"#,
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn report_in_human_format() {
+        let buffer = Buffer::default();
+
+        report(HumanReporter::new(Box::new(buffer.clone())));
+
+        assert_eq!(
+            buffer.contents(),
+            [
+                "Foo.test.pen",
+                "\tOK\tAdd (0.250s)",
+                "\tFAIL\tSubtract (0.500s)",
+                "\t\tMessage: 1 != 2",
+                "summary",
+                "\tFAIL\t1 passed, 1 failed (1.000s)",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn escape_json_string() {
+        assert_eq!(escape_json("foo"), r#""foo""#);
+    }
+
+    #[test]
+    fn escape_json_quote_and_backslash() {
+        assert_eq!(escape_json(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+
+    #[test]
+    fn escape_json_control_characters() {
+        assert_eq!(escape_json("\n\r\t\u{0}\u{1b}"), r#""\n\r\t\u0000\u001b""#);
+    }
+
+    #[test]
+    fn escape_json_non_ascii_characters() {
+        assert_eq!(escape_json("テスト🐧"), r#""テスト🐧""#);
+    }
+
+    #[test]
+    fn escape_xml_string() {
+        assert_eq!(escape_xml("foo"), "foo");
+    }
+
+    #[test]
+    fn escape_xml_special_characters() {
+        assert_eq!(
+            escape_xml(r#"<a & 'b' "c">"#),
+            "&lt;a &amp; &apos;b&apos; &quot;c&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn escape_xml_control_characters() {
+        assert_eq!(escape_xml("a\u{0}b\u{1b}\n\t\r"), "ab\n\t\r");
+    }
+
+    #[test]
+    fn escape_xml_non_ascii_characters() {
+        assert_eq!(escape_xml("テスト🐧"), "テスト🐧");
+    }
+}
@@ -0,0 +1,548 @@
+use crate::{
+    coverage,
+    reporter::{format_duration, HumanReporter, JsonReporter, JunitReporter, Reporter, Summary},
+};
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    process::{self, Command, ExitStatus, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+const RUN_OPTION: &str = "--run";
+const COVERAGE_OPTION: &str = "--coverage";
+const MESSAGE_FILE_OPTION: &str = "--message-file";
+const POLLING_INTERVAL: Duration = Duration::from_millis(10);
+
+pub struct Test {
+    pub module: &'static str,
+    pub name: &'static str,
+    pub function: unsafe extern "C" fn() -> ffi::ByteString,
+}
+
+impl Test {
+    fn full_name(&self) -> String {
+        format!("{}:{}", self.module, self.name)
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+enum Format {
+    #[default]
+    Human,
+    Json,
+    Junit,
+}
+
+#[derive(Default)]
+struct Options {
+    pattern: Option<String>,
+    exact: bool,
+    list: bool,
+    jobs: Option<usize>,
+    timeout: Option<Duration>,
+    format: Format,
+    report_file: Option<String>,
+    coverage: bool,
+    run: Option<usize>,
+    message_file: Option<String>,
+}
+
+impl Options {
+    fn parse(arguments: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut arguments = arguments.into_iter();
+        let mut options = Self::default();
+
+        while let Some(argument) = arguments.next() {
+            match argument.as_str() {
+                "--exact" => options.exact = true,
+                "--list" => options.list = true,
+                "--jobs" => options.jobs = Some(parse_value(&argument, arguments.next())?),
+                "--timeout" => {
+                    options.timeout = Some(
+                        Duration::try_from_secs_f64(parse_value(&argument, arguments.next())?)
+                            .map_err(|error| error.to_string())?,
+                    )
+                }
+                "--format" => {
+                    options.format = match arguments.next().as_deref() {
+                        Some("human") => Format::Human,
+                        Some("json") => Format::Json,
+                        Some("junit") => Format::Junit,
+                        _ => return Err(format!("invalid value for {argument}")),
+                    }
+                }
+                "--report-file" => {
+                    options.report_file = Some(
+                        arguments
+                            .next()
+                            .ok_or_else(|| format!("invalid value for {argument}"))?,
+                    )
+                }
+                COVERAGE_OPTION => options.coverage = true,
+                RUN_OPTION => options.run = Some(parse_value(&argument, arguments.next())?),
+                MESSAGE_FILE_OPTION => {
+                    options.message_file = Some(parse_value(&argument, arguments.next())?)
+                }
+                _ if argument.starts_with("--") => {
+                    return Err(format!("unknown option {argument}"))
+                }
+                _ => options.pattern = Some(argument),
+            }
+        }
+
+        Ok(options)
+    }
+
+    fn matches(&self, test: &Test) -> bool {
+        let Some(pattern) = &self.pattern else {
+            return true;
+        };
+
+        if self.exact {
+            test.name == pattern || &test.full_name() == pattern
+        } else {
+            test.full_name().contains(pattern.as_str())
+        }
+    }
+}
+
+pub enum Outcome {
+    Success,
+    Failure(String),
+}
+
+pub struct TestResult {
+    pub outcome: Outcome,
+    pub stdout: Vec<u8>,
+    pub output: Vec<u8>,
+    pub duration: Duration,
+}
+
+pub fn run(tests: &[Test]) {
+    let options = Options::parse(env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{message}");
+        process::exit(2)
+    });
+
+    if let Some(index) = options.run {
+        run_test(
+            &tests[index],
+            options.coverage,
+            options.message_file.as_deref(),
+        );
+    }
+
+    let tests = tests
+        .iter()
+        .enumerate()
+        .filter(|(_, test)| options.matches(test))
+        .collect::<Vec<_>>();
+
+    if options.list {
+        for (_, test) in tests {
+            println!("{}", test.full_name());
+        }
+
+        return;
+    }
+
+    let mut reporters = create_reporters(&options)
+        .and_then(|reporters| {
+            if options.coverage {
+                coverage::clear()?;
+            }
+
+            Ok(reporters)
+        })
+        .unwrap_or_else(|error| {
+            eprintln!("{error}");
+            process::exit(2)
+        });
+    let mut summary = Summary {
+        passed: 0,
+        failed: 0,
+        duration: Duration::default(),
+    };
+    // Write outputs of tests to stderr not to break reports written to stdout.
+    let mut stdout: Box<dyn Write> = match (&options.report_file, options.format) {
+        (None, Format::Json | Format::Junit) => Box::new(io::stderr()),
+        _ => Box::new(io::stdout()),
+    };
+    let time = Instant::now();
+
+    run_tests(&tests, &options, |test, result| {
+        match result.outcome {
+            Outcome::Success => summary.passed += 1,
+            Outcome::Failure(_) => summary.failed += 1,
+        }
+
+        for reporter in &mut reporters {
+            reporter.report(test, &result).unwrap();
+        }
+
+        stdout.write_all(&result.stdout).unwrap();
+        io::stderr().write_all(&result.output).unwrap();
+    });
+
+    summary.duration = time.elapsed();
+
+    for reporter in &mut reporters {
+        reporter.finish(&summary).unwrap();
+    }
+
+    if summary.failed > 0 {
+        process::exit(1);
+    }
+}
+
+// Write reports in a given format to stdout, or to a report file along with
+// human-readable ones to stdout.
+fn create_reporters(options: &Options) -> io::Result<Vec<Box<dyn Reporter>>> {
+    let stdout = || -> Box<dyn Write> { Box::new(io::stdout()) };
+
+    Ok(match &options.report_file {
+        Some(path) => vec![
+            Box::new(HumanReporter::new(stdout())),
+            create_reporter(
+                options.format,
+                Box::new(BufWriter::new(File::create(path)?)),
+            ),
+        ],
+        None => vec![create_reporter(options.format, stdout())],
+    })
+}
+
+fn create_reporter(format: Format, writer: Box<dyn Write>) -> Box<dyn Reporter> {
+    match format {
+        Format::Human => Box::new(HumanReporter::new(writer)),
+        Format::Json => Box::new(JsonReporter::new(writer)),
+        Format::Junit => Box::new(JunitReporter::new(writer)),
+    }
+}
+
+// Run a test in a current process and report its failure message in a message
+// file. The message is empty if the test succeeds. Outputs of the test are
+// kept as they are.
+fn run_test(test: &Test, coverage: bool, message_file: Option<&str>) -> ! {
+    if coverage {
+        coverage::dump_on_panic();
+    }
+
+    let message = unsafe { (test.function)() };
+
+    if coverage {
+        coverage::dump().unwrap();
+    }
+
+    io::stdout().flush().unwrap();
+
+    if let Some(path) = message_file {
+        fs::write(path, message.as_slice()).unwrap();
+    } else if !message.as_slice().is_empty() {
+        io::stderr().write_all(message.as_slice()).unwrap();
+        process::exit(1);
+    }
+
+    process::exit(0)
+}
+
+// Run tests in subprocesses in parallel and report their results in order.
+fn run_tests(
+    tests: &[(usize, &Test)],
+    options: &Options,
+    mut report: impl FnMut(&Test, TestResult),
+) {
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let jobs = options
+        .jobs
+        .or_else(|| thread::available_parallelism().ok().map(Into::into))
+        .unwrap_or(1)
+        .clamp(1, tests.len().max(1));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let next_index = &next_index;
+
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::SeqCst);
+
+                let Some((test_index, _)) = tests.get(index) else {
+                    break;
+                };
+
+                sender
+                    .send((index, spawn_test(*test_index, options)))
+                    .unwrap();
+            });
+        }
+
+        drop(sender);
+
+        let mut results = BTreeMap::new();
+        let mut reported_index = 0;
+
+        for (index, result) in receiver {
+            results.insert(index, result);
+
+            while let Some(result) = results.remove(&reported_index) {
+                report(tests[reported_index].1, result);
+                reported_index += 1;
+            }
+        }
+    });
+}
+
+fn spawn_test(index: usize, options: &Options) -> TestResult {
+    let timeout = options.timeout;
+    let message_file = env::temp_dir().join(format!("pen-test-{}-{index}", process::id()));
+    let time = Instant::now();
+    let result = (|| -> io::Result<_> {
+        let mut child = Command::new(env::current_exe()?)
+            .arg(RUN_OPTION)
+            .arg(index.to_string())
+            .arg(MESSAGE_FILE_OPTION)
+            .arg(&message_file)
+            .args(options.coverage.then_some(COVERAGE_OPTION))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Drain outputs concurrently so that children never block on full pipes.
+        let stdout = read_output(child.stdout.take());
+        let stderr = read_output(child.stderr.take());
+
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break Some(status);
+            } else if timeout.is_some_and(|timeout| time.elapsed() >= timeout) {
+                child.kill()?;
+                child.wait()?;
+                break None;
+            }
+
+            thread::sleep(POLLING_INTERVAL);
+        };
+
+        Ok((
+            status,
+            stdout.join().unwrap_or_default(),
+            stderr.join().unwrap_or_default(),
+        ))
+    })();
+    let duration = time.elapsed();
+    // A message file is written only when a test finishes by itself.
+    let message = fs::read(&message_file).ok();
+    fs::remove_file(&message_file).unwrap_or_default();
+
+    let (outcome, stdout, output) = match result {
+        Ok((Some(status), stdout, output)) if status.success() => (
+            match message {
+                Some(message) if message.is_empty() => Outcome::Success,
+                Some(message) => Outcome::Failure(String::from_utf8_lossy(&message).into()),
+                None => Outcome::Failure("exited without a result".into()),
+            },
+            stdout,
+            output,
+        ),
+        Ok((Some(status), stdout, output)) => {
+            (Outcome::Failure(format_status(status)), stdout, output)
+        }
+        Ok((None, stdout, output)) => (
+            Outcome::Failure(format!(
+                "timed out after {}",
+                format_duration(timeout.unwrap_or_default())
+            )),
+            stdout,
+            output,
+        ),
+        Err(error) => (Outcome::Failure(error.to_string()), vec![], vec![]),
+    };
+
+    TestResult {
+        outcome,
+        stdout,
+        output,
+        duration,
+    }
+}
+
+fn read_output(reader: Option<impl io::Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut output = vec![];
+
+        if let Some(mut reader) = reader {
+            reader.read_to_end(&mut output).unwrap_or_default();
+        }
+
+        output
+    })
+}
+
+#[cfg(unix)]
+fn format_status(status: ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+
+    if let Some(signal) = status.signal() {
+        format!(
+            "crashed with signal {signal}{}",
+            match signal {
+                4 => " (SIGILL)",
+                6 => " (SIGABRT)",
+                7 => " (SIGBUS)",
+                8 => " (SIGFPE)",
+                9 => " (SIGKILL)",
+                11 => " (SIGSEGV)",
+                _ => "",
+            }
+        )
+    } else {
+        format_exit_code(status)
+    }
+}
+
+#[cfg(not(unix))]
+fn format_status(status: ExitStatus) -> String {
+    format_exit_code(status)
+}
+
+fn format_exit_code(status: ExitStatus) -> String {
+    match status.code() {
+        Some(code) => format!("crashed with exit code {code}"),
+        None => "crashed".into(),
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("invalid value for {option}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_options(arguments: &[&str]) -> Result<Options, String> {
+        Options::parse(arguments.iter().map(|&argument| argument.into()))
+    }
+
+    #[test]
+    fn parse_pattern() {
+        let options = parse_options(&["--exact", "Foo"]).unwrap();
+
+        assert!(options.exact);
+        assert_eq!(options.pattern.as_deref(), Some("Foo"));
+    }
+
+    #[test]
+    fn parse_jobs_and_timeout() {
+        let options = parse_options(&["--jobs", "2", "--timeout", "1.5"]).unwrap();
+
+        assert_eq!(options.jobs, Some(2));
+        assert_eq!(options.timeout, Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn parse_format_and_report_file() {
+        let options = parse_options(&["--format", "junit", "--report-file", "foo.xml"]).unwrap();
+
+        assert!(matches!(options.format, Format::Junit));
+        assert_eq!(options.report_file.as_deref(), Some("foo.xml"));
+    }
+
+    #[test]
+    fn fail_to_parse_invalid_jobs() {
+        assert_eq!(
+            parse_options(&["--jobs", "foo"]).err().as_deref(),
+            Some("invalid value for --jobs")
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_negative_timeout() {
+        assert!(parse_options(&["--timeout", "-1"]).is_err());
+    }
+
+    #[test]
+    fn fail_to_parse_missing_format() {
+        assert_eq!(
+            parse_options(&["--format"]).err().as_deref(),
+            Some("invalid value for --format")
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_unknown_option() {
+        assert_eq!(
+            parse_options(&["--foo"]).err().as_deref(),
+            Some("unknown option --foo")
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_unknown_option_after_pattern() {
+        assert!(parse_options(&["Foo", "--exat"]).is_err());
+    }
+
+    #[cfg(unix)]
+    mod status {
+        use super::*;
+        use std::os::unix::process::ExitStatusExt;
+
+        #[test]
+        fn format_exit_code_of_exited_process() {
+            assert_eq!(
+                format_exit_code(ExitStatus::from_raw(42 << 8)),
+                "crashed with exit code 42"
+            );
+        }
+
+        #[test]
+        fn format_exit_code_of_signaled_process() {
+            assert_eq!(format_exit_code(ExitStatus::from_raw(9)), "crashed");
+        }
+
+        #[test]
+        fn format_status_of_exited_process() {
+            assert_eq!(
+                format_status(ExitStatus::from_raw(1 << 8)),
+                "crashed with exit code 1"
+            );
+        }
+
+        #[test]
+        fn format_status_of_aborted_process() {
+            assert_eq!(
+                format_status(ExitStatus::from_raw(6)),
+                "crashed with signal 6 (SIGABRT)"
+            );
+        }
+
+        #[test]
+        fn format_status_of_segmentation_fault() {
+            assert_eq!(
+                format_status(ExitStatus::from_raw(11)),
+                "crashed with signal 11 (SIGSEGV)"
+            );
+        }
+
+        #[test]
+        fn format_status_of_unknown_signal() {
+            assert_eq!(
+                format_status(ExitStatus::from_raw(15)),
+                "crashed with signal 15"
+            );
+        }
+    }
+}
@@ -37,6 +37,28 @@ Feature: Building packages
     When I run `pen build`
     Then the exit status should be 0
 
+  Scenario: Build an application package without optimization
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "application",
+        "dependencies": {
+          "Os": "pen:///os"
+        }
+      }
+      """
+    And a file named "main.pen" with:
+      """pen
+      import Os'Context { Context }
+
+      main = \(ctx context) none {
+        none
+      }
+      """
+    When I successfully run `pen build --no-optimization`
+    Then I successfully run `./app`
+    And a directory named ".pen/default-unoptimized" should exist
+
   Scenario Outline: Cross-build an application package
     Given a file named "pen.json" with:
       """json
@@ -126,3 +148,107 @@ Feature: Building packages
     And I successfully run `pen build`
     Then I successfully run `./app`
     And the stdout from "./app" should contain exactly "hello"
+
+  Scenario: Emit intermediate representations
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "Foo.pen" with:
+      """pen
+      f = \(x number) number {
+        x
+      }
+      """
+    When I successfully run `pen build --emit hir,mir`
+    And I successfully run `sh -c 'cat .pen/default-emit-hir-mir/objects/*.hir .pen/default-emit-hir-mir/objects/*.mir'`
+    Then the stdout from "sh -c 'cat .pen/default-emit-hir-mir/objects/*.hir .pen/default-emit-hir-mir/objects/*.mir'" should contain "\(x number) number"
+    And the stdout from "sh -c 'cat .pen/default-emit-hir-mir/objects/*.hir .pen/default-emit-hir-mir/objects/*.mir'" should contain "# reference_count"
+
+  Scenario: Emit source positions in intermediate representations
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "Foo.pen" with:
+      """pen
+      f = \(x number) number {
+        x
+      }
+      """
+    When I successfully run `pen build --debug --emit mir`
+    And I successfully run `sh -c 'cat .pen/default-emit-mir-debug/objects/*.mir'`
+    Then the stdout from "sh -c 'cat .pen/default-emit-mir-debug/objects/*.mir'" should contain "Foo.pen:1:"
+
+  Scenario: Emit intermediate representations after a build
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "Foo.pen" with:
+      """pen
+      f = \(x number) number {
+        x
+      }
+      """
+    And I successfully run `pen build`
+    When I successfully run `pen build --emit ast,llvm`
+    And I successfully run `sh -c 'cat .pen/default-emit-ast-llvm/objects/*.ast .pen/default-emit-ast-llvm/objects/*.ll'`
+    Then the stdout from "sh -c 'cat .pen/default-emit-ast-llvm/objects/*.ast .pen/default-emit-ast-llvm/objects/*.ll'" should contain "f = \(x number) number {"
+    And the stdout from "sh -c 'cat .pen/default-emit-ast-llvm/objects/*.ast .pen/default-emit-ast-llvm/objects/*.ll'" should contain "define"
+
+  Scenario: Report errors in JSON
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "Foo.pen" with:
+      """pen
+      f = \() number {
+        x
+      }
+
+      g = \() number {
+        y
+      }
+      """
+    When I run `pen build --message-format json`
+    Then the exit status should not be 0
+    And the output should contain:
+      """
+      {"code":"E0035","column":3,"end_column":4,"end_line":2,"line":2,"message":"variable \"x\" not found","path":"Foo.pen",
+      """
+    And the output should contain:
+      """
+      {"code":"E0035","column":3,"end_column":4,"end_line":6,"line":6,"message":"variable \"y\" not found","path":"Foo.pen",
+      """
+
+  Scenario: Suggest a similar variable name
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "Foo.pen" with:
+      """pen
+      f = \(value number) number {
+        valeu
+      }
+      """
+    When I run `pen build`
+    Then the exit status should not be 0
+    And the stderr should contain "did you mean `value`?"
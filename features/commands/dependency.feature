@@ -0,0 +1,132 @@
+Feature: Managing dependency packages
+  Background:
+    Given I set the environment variable "XDG_CACHE_HOME" to "cache"
+    And a file named "foo/pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "foo/Foo.pen" with:
+      """pen
+      Foo = \() number {
+        42
+      }
+      """
+    And I successfully run `git init --quiet --initial-branch main foo`
+    And I successfully run `git -C foo add .`
+    And I successfully run `git -C foo -c user.name=foo -c user.email=foo@foo.com commit --quiet -m foo`
+    And I successfully run `git -C foo tag v0.1.0`
+    And a directory named "bar"
+    And I cd to "bar"
+    And a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "Bar.pen" with:
+      """pen
+      import Foo'Foo
+
+      Bar = \() number {
+        Foo'Foo()
+      }
+      """
+
+  Scenario: Add a dependency package
+    When I successfully run `pen add Foo ../foo`
+    Then the file "pen.json" should contain:
+      """
+      "Foo": "../foo"
+      """
+    And I successfully run `pen build`
+
+  Scenario: Add a dependency package with a tag
+    When I successfully run `pen add --tag v0.1.0 Foo ../foo`
+    Then the file "pen.json" should contain:
+      """
+      "tag": "v0.1.0"
+      """
+    And I successfully run `pen build`
+    And the file "pen.lock" should contain:
+      """
+      "tag": "v0.1.0"
+      """
+
+  Scenario: Remove a dependency package
+    Given I successfully run `pen add Foo ../foo`
+    When I successfully run `pen remove Foo`
+    Then the file "pen.json" should not contain "Foo"
+
+  Scenario: Fail to remove a missing dependency package
+    When I run `pen remove Foo`
+    Then the exit status should not be 0
+
+  Scenario: Update a dependency package
+    Given I successfully run `pen add --branch main Foo ../foo`
+    And I successfully run `pen build`
+    And I successfully run `git -C ../foo -c user.name=foo -c user.email=foo@foo.com commit --quiet --allow-empty -m bar`
+    When I successfully run `pen update Foo`
+    Then I successfully run `sh -c 'grep -q "$(git -C ../foo rev-parse HEAD)" pen.lock'`
+    And I successfully run `pen build`
+
+  Scenario: Fail to build a package with conflicting references to a dependency package
+    Given a file named "baz.sh" with:
+      """sh
+      mkdir -p ../baz
+      cat > ../baz/pen.json <<EOF
+      {
+        "type": "library",
+        "dependencies": {
+          "Foo": { "url": "file://$(cd ../foo && pwd)", "tag": "v0.1.0" }
+        }
+      }
+      EOF
+      """
+    And I successfully run `sh baz.sh`
+    And I successfully run `pen add --branch main Foo ../foo`
+    And I successfully run `pen add Baz ../baz`
+    When I run `pen build`
+    Then the exit status should not be 0
+    And the stderr should contain "conflicting references to package"
+
+  Scenario: Build a package offline with a package cache
+    Given I successfully run `pen add --tag v0.1.0 Foo ../foo`
+    And I successfully run `pen build`
+    And I successfully run `rm -rf ../foo .pen`
+    When I successfully run `pen build --offline`
+    Then the exit status should be 0
+
+  Scenario: Fail to build a package offline without a package cache
+    Given I successfully run `pen add --tag v0.1.0 Foo ../foo`
+    When I run `pen build --offline`
+    Then the exit status should not be 0
+
+  Scenario: Vendor dependency packages
+    Given I successfully run `pen add --tag v0.1.0 Foo ../foo`
+    When I successfully run `pen vendor`
+    Then a directory named "vendor" should exist
+    And I successfully run `rm -rf ../foo .pen`
+    And I successfully run `pen build --offline`
+
+  Scenario: Rebuild a package with re-vendored dependency packages
+    Given I successfully run `pen add --tag v0.1.0 Foo ../foo`
+    And I successfully run `pen vendor`
+    And I successfully run `pen build`
+    And I successfully run `rm pen.lock`
+    And I successfully run `pen vendor`
+    And I successfully run `rm -rf ../foo .pen`
+    When I successfully run `pen build --offline`
+    Then the file "pen.lock" should contain "v0.1.0"
+
+  Scenario: Fail to build a package with modified vendored dependency packages
+    Given I successfully run `pen add --tag v0.1.0 Foo ../foo`
+    And I successfully run `pen vendor`
+    And I successfully run `pen build`
+    And I successfully run `sh -c 'echo "# foo" >> vendor/*/Foo.pen'`
+    When I run `pen build --offline`
+    Then the exit status should not be 0
+    And the stderr should contain "package content hash not matched"
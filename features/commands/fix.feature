@@ -0,0 +1,55 @@
+Feature: Fixing packages
+  Background:
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "Bar.pen" with:
+      """pen
+      Bar = \() none {
+        none
+      }
+      """
+
+  Scenario: Remove an unused import
+    Given a file named "Foo.pen" with:
+      """pen
+      import 'Bar
+
+      Foo = \() none {
+        none
+      }
+      """
+    When I successfully run `pen fix`
+    Then the file "Foo.pen" should not contain "import"
+    And I successfully run `pen lint --deny warnings`
+
+  Scenario: Remove an unused private function
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \() none {
+        none
+      }
+
+      bar = \() none {
+        none
+      }
+      """
+    When I successfully run `pen fix`
+    Then the file "Foo.pen" should not contain "bar"
+
+  Scenario: Show changes without writing them
+    Given a file named "Foo.pen" with:
+      """pen
+      import 'Bar
+
+      Foo = \() none {
+        none
+      }
+      """
+    When I successfully run `pen fix --dry-run`
+    Then the stdout should contain "-import 'Bar"
+    And the file "Foo.pen" should contain "import 'Bar"
@@ -0,0 +1,71 @@
+Feature: Linting packages
+  Background:
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+
+  Scenario: Lint a package
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \() none {
+        none
+      }
+      """
+    When I successfully run `pen lint --deny warnings`
+    Then the exit status should be 0
+
+  Scenario: Report an unused variable
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \(x number) none {
+        none
+      }
+      """
+    When I successfully run `pen lint`
+    Then the stderr should contain "never used"
+
+  Scenario: Deny warnings
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \(x number) none {
+        none
+      }
+      """
+    When I run `pen lint --deny warnings`
+    Then the exit status should not be 0
+
+  Scenario: Suppress a warning
+    Given a file named "Foo.pen" with:
+      """pen
+      # pen:allow unused-variable
+      Foo = \(x number) none {
+        none
+      }
+      """
+    When I successfully run `pen lint --deny warnings`
+    Then the exit status should be 0
+
+  Scenario: Show warnings on build
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \(x number) none {
+        none
+      }
+      """
+    When I successfully run `pen build`
+    Then the stderr should contain "never used"
+
+  Scenario: Show warnings on rebuild
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \(x number) none {
+        none
+      }
+      """
+    And I successfully run `pen build`
+    When I successfully run `pen build --verbose`
+    Then the stderr from "pen build --verbose" should contain "never used"
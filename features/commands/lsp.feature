@@ -0,0 +1,139 @@
+Feature: Running a language server
+  Background:
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+    And a file named "lsp.sh" with:
+      """sh
+      send() {
+        printf 'Content-Length: %s\r\n\r\n%s' "$(printf %s "$1" | wc -c | tr -d ' ')" "$1"
+      }
+
+      document() {
+        echo '{"textDocument":{"uri":"file://'"$PWD/$1"'"}'"$2"'}'
+      }
+      """
+
+  Scenario: Initialize a language server
+    Given a file named "input.txt" with:
+      """
+      Content-Length: 59
+
+      {"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}
+      Content-Length: 45
+
+      {"jsonrpc":"2.0","id":2,"method":"shutdown"}
+      Content-Length: 33
+
+      {"jsonrpc":"2.0","method":"exit"}
+      """
+    When I run `sh -c "pen lsp < input.txt"`
+    Then the exit status should be 0
+    And the stdout should contain "hoverProvider"
+
+  Scenario: Publish diagnostics
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(x number) number {
+        y
+      }
+      """
+    And a file named "client.sh" with:
+      """sh
+      . ./lsp.sh
+
+      {
+        send '{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}'
+        send '{"jsonrpc":"2.0","method":"textDocument/didSave","params":'"$(document Foo.pen)"'}'
+        send '{"jsonrpc":"2.0","id":2,"method":"shutdown"}'
+        send '{"jsonrpc":"2.0","method":"exit"}'
+      } | pen lsp
+      """
+    When I run `sh client.sh`
+    Then the exit status should be 0
+    And the stdout should contain "textDocument/publishDiagnostics"
+    And the stdout should contain "Foo.pen"
+    And the stdout should contain "not found"
+
+  Scenario: Show a type on hover
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(x number) number {
+        x
+      }
+      """
+    And a file named "client.sh" with:
+      """sh
+      . ./lsp.sh
+
+      {
+        send '{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}'
+        send '{"jsonrpc":"2.0","id":2,"method":"textDocument/hover","params":'"$(document Foo.pen ',"position":{"line":1,"character":2}')"'}'
+        send '{"jsonrpc":"2.0","id":3,"method":"shutdown"}'
+        send '{"jsonrpc":"2.0","method":"exit"}'
+      } | pen lsp
+      """
+    When I run `sh client.sh`
+    Then the exit status should be 0
+    And the stdout should contain "number"
+
+  Scenario: Go to a definition
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \() number {
+        42
+      }
+
+      g = \() number {
+        f()
+      }
+      """
+    And a file named "client.sh" with:
+      """sh
+      . ./lsp.sh
+
+      {
+        send '{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}'
+        send '{"jsonrpc":"2.0","id":2,"method":"textDocument/definition","params":'"$(document Foo.pen ',"position":{"line":5,"character":2}')"'}'
+        send '{"jsonrpc":"2.0","id":3,"method":"shutdown"}'
+        send '{"jsonrpc":"2.0","method":"exit"}'
+      } | pen lsp
+      """
+    When I run `sh client.sh`
+    Then the exit status should be 0
+    And the stdout should contain "Foo.pen"
+
+  Scenario: Go to a definition in an imported module
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \() number {
+        42
+      }
+      """
+    And a file named "Bar.pen" with:
+      """pen
+      import 'Foo
+
+      f = \() number {
+        Foo'Foo()
+      }
+      """
+    And I successfully run `pen build`
+    And a file named "client.sh" with:
+      """sh
+      . ./lsp.sh
+
+      {
+        send '{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}'
+        send '{"jsonrpc":"2.0","id":2,"method":"textDocument/definition","params":'"$(document Bar.pen ',"position":{"line":3,"character":2}')"'}'
+        send '{"jsonrpc":"2.0","id":3,"method":"shutdown"}'
+        send '{"jsonrpc":"2.0","method":"exit"}'
+      } | pen lsp
+      """
+    When I run `sh client.sh`
+    Then the exit status should be 0
+    And the stdout should contain "Foo.pen"
@@ -0,0 +1,41 @@
+Feature: Running a REPL
+  Scenario: Evaluate an expression
+    Given a file named "input.txt" with:
+      """pen
+      1 + 2
+      """
+    When I run `sh -c "pen repl < input.txt"`
+    Then the exit status should be 0
+    And the stdout should contain exactly "3"
+
+  Scenario: Evaluate a function definition
+    Given a file named "input.txt" with:
+      """pen
+      f = \(x number) number {
+        x * 2
+      }
+      f(21)
+      """
+    When I run `sh -c "pen repl < input.txt"`
+    Then the exit status should be 0
+    And the stdout should contain exactly "42"
+
+  Scenario: Evaluate a record
+    Given a file named "input.txt" with:
+      """pen
+      type foo { x number }
+      foo{x: 1}
+      """
+    When I run `sh -c "pen repl < input.txt"`
+    Then the exit status should be 0
+    And the stdout should contain exactly "foo{x: 1}"
+
+  Scenario: Continue after an error
+    Given a file named "input.txt" with:
+      """pen
+      1 + "foo"
+      40 + 2
+      """
+    When I run `sh -c "pen repl < input.txt"`
+    Then the exit status should be 0
+    And the stdout should contain exactly "42"
@@ -74,3 +74,193 @@ Feature: Testing packages
       """
     When I run `pen test`
     Then the exit status should be 0
+
+  Scenario: Filter tests by a pattern
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 1), 42)
+      }
+
+      AddMore = \() none | error {
+        Assert'Equal(Foo'Add(41, 0), 42)
+      }
+      """
+    When I run `pen test --exact Add`
+    Then the exit status should be 0
+    And the stdout should contain "1 passed, 0 failed"
+
+  Scenario: List tests
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 1), 42)
+      }
+      """
+    When I run `pen test --list`
+    Then the exit status should be 0
+    And the stdout should contain "Foo.test.pen:Add"
+
+  Scenario: Run tests in parallel
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 1), 42)
+      }
+
+      AddMore = \() none | error {
+        Assert'Equal(Foo'Add(40, 2), 42)
+      }
+      """
+    When I run `pen test --jobs 2`
+    Then the exit status should be 0
+    And the stdout should contain "2 passed, 0 failed"
+
+  Scenario: Time out a test
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 1), 42)
+      }
+
+      Loop = \() none | error {
+        Loop()
+      }
+      """
+    When I run `pen test --timeout 1`
+    Then the exit status should not be 0
+    And the stdout should contain "timed out"
+    And the stdout should contain "1 passed, 1 failed"
+
+  Scenario: Report a crashed test
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      import foreign "c" _pen_unreachable \() none
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 1), 42)
+      }
+
+      Crash = \() none | error {
+        _pen_unreachable()
+      }
+      """
+    When I run `pen test`
+    Then the exit status should not be 0
+    And the stdout should contain "crashed with signal"
+    And the stdout should contain "1 passed, 1 failed"
+
+  Scenario: Report test results in JUnit XML
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 1), 42)
+      }
+      """
+    When I successfully run `pen test --format junit --report-file report.xml`
+    Then the file "report.xml" should contain:
+      """
+      <testsuite name="Foo.test.pen" tests="1" failures="0"
+      """
+    And the file "report.xml" should contain:
+      """
+      <testcase name="Add" classname="Foo.test.pen"
+      """
+
+  Scenario: Report outputs of tests in JUnit XML
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {
+          "Os": "pen:///os"
+        }
+      }
+      """
+    And a file named "Foo.test.pen" with:
+      """pen
+      import Os'Context
+      import Os'File
+
+      Print = \() none | error {
+        File'Write(Context'UnsafeNew(), File'StdOut(), "hello")?
+
+        none
+      }
+      """
+    When I successfully run `pen test --format junit`
+    Then the stdout should match /\A<\?xml/
+    And the stdout should contain "<system-out>hello</system-out>"
+    And the stderr should contain "hello"
+
+  Scenario: Report test results in JSON
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 0), 42)
+      }
+      """
+    When I run `pen test --format json`
+    Then the exit status should not be 0
+    And the stdout should contain:
+      """
+      {"type":"test","module":"Foo.test.pen","name":"Add","result":"failed",
+      """
+
+  Scenario: Report code coverage
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import Test'Assert
+      import 'Foo
+
+      Add = \() none | error {
+        Assert'Equal(Foo'Add(41, 1), 42)
+      }
+      """
+    When I successfully run `pen test --coverage`
+    Then the file ".pen/coverage/lcov.info" should contain "SF:Foo.pen"
+    And the file ".pen/coverage/lcov.info" should contain "FNDA:1,Add"
+
+  Scenario: Report code coverage of timed-out tests
+    Given a file named "Foo.test.pen" with:
+      """pen
+      import 'Foo
+
+      Loop = \() none | error {
+        if Foo'Add(41, 1) == 42 {
+          loop()
+        } else {
+          none
+        }
+      }
+
+      loop = \() none | error {
+        loop()
+      }
+      """
+    When I run `pen test --coverage --timeout 1`
+    Then the exit status should not be 0
+    And the file ".pen/coverage/lcov.info" should contain "FNDA:1,Loop"
+    And the file ".pen/coverage/lcov.info" should contain "FNDA:1,Add"
+    And the file ".pen/coverage/lcov.info" should not contain "SF:.pen"
@@ -121,3 +121,26 @@ Feature: Modules
       """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Specialize a function imported from a module
+    Given a file named "Foo.pen" with:
+      """pen
+      Foo = \(x any) number {
+        if x = x as number {
+          x
+        } else {
+          0
+        }
+      }
+      """
+    And a file named "Bar.pen" with:
+      """pen
+      import 'Foo
+
+      Bar = \() number {
+        Foo'Foo(42)
+      }
+      """
+    When I successfully run `pen build --emit mir`
+    And I successfully run `sh -c 'cat .pen/default-emit-mir/objects/*.mir'`
+    Then the stdout from "sh -c 'cat .pen/default-emit-mir/objects/*.mir'" should contain ":specialized:"
@@ -47,3 +47,24 @@ Feature: Built-ins
       | {string: number "foo": 1, "bar": 2} | {string: number "bar": 2, "foo": 1} |
       | error("foo")                        | error("foo")                        |
       | \\(x none) none { x }               | <function>                          |
+
+  Scenario: Encode and decode a value
+    Given a file named "main.pen" with:
+      """pen
+      type foo {
+        x number
+        y [string]
+      }
+
+      decodeFoo = \(x any) foo | error {
+        decode(x)
+      }
+
+      main = \(_ context) none {
+        debug(decodeFoo(encode(foo{x: 42, y: [string "bar"]})))
+      }
+      """
+    When I successfully run `pen build`
+    Then I successfully run `./app`
+    And the stderr from "./app" should contain exactly 'foo{x: 42, y: [string "bar"]}'
+    Then I successfully run `check_memory_leak.sh ./app`
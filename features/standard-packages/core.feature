@@ -57,3 +57,40 @@ Feature: Core
       """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Sort a list
+    Given a file named "Foo.pen" with:
+      """pen
+      import Core'List
+
+      f = \() [any] {
+        List'Sort([any "foo", 2, 1])
+      }
+      """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Sort a list with a function
+    Given a file named "Foo.pen" with:
+      """pen
+      import Core'List
+
+      f = \() [any] {
+        List'SortBy(
+          [any "foo", "bar"],
+          \(x any, y any) boolean {
+            if x = x as string {
+              if y = y as string {
+                x > y
+              } else {
+                false
+              }
+            } else {
+              false
+            }
+          },
+        )
+      }
+      """
+    When I run `pen build`
+    Then the exit status should be 0
@@ -59,3 +59,37 @@ Feature: Function
       """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Infer argument and result types of a function
+    Given a file named "Foo.pen" with:
+      """pen
+      type foo {
+        f \(number) number
+      }
+
+      apply = \(f \(number) number, x number) number {
+        f(x)
+      }
+
+      f = \() number {
+        apply(\(x) { x + 1 }, 42)
+      }
+
+      g = \() foo {
+        foo{f: \(x) { x * 2 }}
+      }
+      """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Fail to infer argument types of a function
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \() number {
+        g = \(x) { x }
+
+        g(42)
+      }
+      """
+    When I run `pen build`
+    Then the exit status should not be 0
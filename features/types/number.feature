@@ -28,6 +28,136 @@ Feature: Number
     When I run `pen build`
     Then the exit status should be 0
 
+  Scenario: Use integer division and remainder operators
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \() number {
+        7 // 2 + 7 % 2
+      }
+      """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use bitwise operators
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(x number, y number) number {
+        (x & y | x ^ y) << 1 >> 2
+      }
+      """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Evaluate integer division and remainder operators
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "application",
+        "dependencies": {
+          "Core": "pen:///core",
+          "Os": "pen:///os"
+        }
+      }
+      """
+    And a file named "main.pen" with:
+      """pen
+      import Core'Number
+      import Core'String
+      import Os'File
+
+      main = \(ctx context) none {
+        _ = File'Write(
+          ctx.Os,
+          File'StdOut(),
+          String'Join(
+            [string
+              Number'String(divide(7, 2)),
+              Number'String(remainder(7, 2)),
+              Number'String(divide(0 - 7, 2)),
+              Number'String(remainder(0 - 7, 2)),
+              Number'String(remainder(7.5, 2)),
+            ],
+            " ",
+          ),
+        )
+
+        none
+      }
+
+      divide = \(x number, y number) number {
+        x // y
+      }
+
+      remainder = \(x number, y number) number {
+        x % y
+      }
+      """
+    When I successfully run `pen build`
+    Then I successfully run `./app`
+    And the stdout from "./app" should contain exactly "3 1 -3 -1 1.5"
+
+  Scenario: Evaluate bitwise operators
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "application",
+        "dependencies": {
+          "Core": "pen:///core",
+          "Os": "pen:///os"
+        }
+      }
+      """
+    And a file named "main.pen" with:
+      """pen
+      import Core'Number
+      import Core'String
+      import Os'File
+
+      main = \(ctx context) none {
+        _ = File'Write(
+          ctx.Os,
+          File'StdOut(),
+          String'Join(
+            [string
+              Number'String(and(12, 10)),
+              Number'String(or(12, 10)),
+              Number'String(xor(12, 10)),
+              Number'String(and(0 - 1, 255)),
+              Number'String(leftShift(3, 2)),
+              Number'String(leftShift(1, 65)),
+              Number'String(rightShift(0 - 8, 1)),
+            ],
+            " ",
+          ),
+        )
+
+        none
+      }
+
+      and = \(x number, y number) number {
+        x & y
+      }
+
+      or = \(x number, y number) number {
+        x | y
+      }
+
+      xor = \(x number, y number) number {
+        x ^ y
+      }
+
+      leftShift = \(x number, y number) number {
+        x << y
+      }
+
+      rightShift = \(x number, y number) number {
+        x >> y
+      }
+      """
+    When I successfully run `pen build`
+    Then I successfully run `./app`
+    And the stdout from "./app" should contain exactly "8 14 6 255 12 2 -4"
+
   Scenario: Use equality operators
     Given a file named "Foo.pen" with:
       """pen
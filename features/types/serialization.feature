@@ -0,0 +1,198 @@
+Feature: Serialization
+  Background:
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+
+  Scenario: Encode a record
+    Given a file named "Foo.pen" with:
+      """pen
+      type foo {
+        x number
+        y [string]
+        z {string: boolean}
+      }
+
+      f = \(x foo) any {
+        encode(x)
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Decode a record
+    Given a file named "Foo.pen" with:
+      """pen
+      type foo {
+        x number
+        y [string]
+        z {string: boolean}
+      }
+
+      f = \(x any) foo | error {
+        decode(x)
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Encode and decode a union
+    Given a file named "Foo.pen" with:
+      """pen
+      type foo {
+        x number
+      }
+
+      type bar = foo | string | none
+
+      f = \(x bar) any {
+        encode(x)
+      }
+
+      g = \(x any) bar | error {
+        decode(x)
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Decode a value in a let expression
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(x any) [number] | error {
+        y = x
+
+        decode(y)
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Fail to encode a function
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(x \() none) any {
+        encode(x)
+      }
+      """
+    When I run `pen build`
+    Then the exit status should not be 0
+
+  Scenario: Encode and decode a record at runtime
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "application",
+        "dependencies": {
+          "Os": "pen:///os"
+        }
+      }
+      """
+    And a file named "main.pen" with:
+      """pen
+      import Os'File
+
+      type foo {
+        x string
+      }
+
+      main = \(ctx context) none {
+        print(ctx, decode(encode(foo{x: "hello"})))
+      }
+
+      print = \(ctx context, x foo | error) none {
+        s = if x = x as foo { x.x } else { "error" }
+
+        _ = File'Write(ctx.Os, File'StdOut(), s)
+
+        none
+      }
+      """
+    When I successfully run `pen build`
+    Then I successfully run `./app`
+    And the stdout from "./app" should contain exactly "hello"
+
+  Scenario: Encode and decode a union at runtime
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "application",
+        "dependencies": {
+          "Os": "pen:///os"
+        }
+      }
+      """
+    And a file named "main.pen" with:
+      """pen
+      import Os'File
+
+      type foo {
+        x string
+      }
+
+      type bar = foo | string | none
+
+      main = \(ctx context) none {
+        print(ctx, decode(encode(foo{x: "foo"})))
+        print(ctx, decode(encode("bar")))
+        print(ctx, decode(encode(none)))
+      }
+
+      print = \(ctx context, x bar | error) none {
+        s = if x = x as foo {
+          x.x
+        } else if string {
+          x
+        } else if none {
+          "none"
+        } else {
+          "error"
+        }
+
+        _ = File'Write(ctx.Os, File'StdOut(), s)
+
+        none
+      }
+      """
+    When I successfully run `pen build`
+    Then I successfully run `./app`
+    And the stdout from "./app" should contain exactly "foobarnone"
+
+  Scenario: Fail to decode a value of a different type at runtime
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "application",
+        "dependencies": {
+          "Os": "pen:///os"
+        }
+      }
+      """
+    And a file named "main.pen" with:
+      """pen
+      import Os'File
+
+      type foo {
+        x string
+      }
+
+      main = \(ctx context) none {
+        print(ctx, decode(encode(42)))
+        print(ctx, decode(encode(foo{x: "foo"})))
+      }
+
+      print = \(ctx context, x string | error) none {
+        s = if x = x as string { x } else { "error" }
+
+        _ = File'Write(ctx.Os, File'StdOut(), s)
+
+        none
+      }
+      """
+    When I successfully run `pen build`
+    Then I successfully run `./app`
+    And the stdout from "./app" should contain exactly "errorerror"
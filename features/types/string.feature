@@ -32,6 +32,28 @@ Feature: String
     When I run `pen build`
     Then the exit status should be 0
 
+  Scenario: Use order operators
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \() boolean {
+        "foo" < "bar"
+      }
+
+      g = \() boolean {
+        "foo" <= "bar"
+      }
+
+      h = \() boolean {
+        "foo" > "bar"
+      }
+
+      i = \() boolean {
+        "foo" >= "bar"
+      }
+      """
+    When I run `pen build`
+    Then the exit status should be 0
+
   Scenario: Concatenate strings
     Given a file named "Foo.pen" with:
       """pen
@@ -81,3 +81,23 @@ Feature: Union
       """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Downcast a union type with redundant branches
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(x number | none) number {
+        if x = x as number {
+          x
+        } else if number {
+          1
+        } else if none {
+          0
+        } else {
+          2
+        }
+      }
+      """
+    When I run `pen build`
+    Then the exit status should be 0
+    And the stderr should contain "branch type already covered by previous branches"
+    And the stderr should contain "unreachable else block"
@@ -0,0 +1,83 @@
+Feature: Vector
+  Background:
+    Given a file named "pen.json" with:
+      """json
+      {
+        "type": "library",
+        "dependencies": {}
+      }
+      """
+
+  Scenario: Create an empty vector
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \() [|number|] {
+        [|number|]
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Create a vector with elements
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \() [|number|] {
+        [|number 1, 2, 3|]
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Get an element in a vector
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(xs [|number|]) number {
+        if x = get(xs, 1) as number {
+          x
+        } else {
+          0
+        }
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Update a vector
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(xs [|number|]) [|number|] {
+        slice(set(push(xs, 42), 1, 13), 1, 2)
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Calculate a size of a vector
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(xs [|number|]) number {
+        size(xs)
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Compare vectors
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(xs [|number|], ys [|number|]) boolean {
+        xs == ys
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use a vector as a map key
+    Given a file named "Foo.pen" with:
+      """pen
+      f = \(xs [|number|]) {[|number|]: number} {
+        {[|number|]: number xs: 42}
+      }
+      """
+    When I successfully run `pen build`
+    Then the exit status should be 0
@@ -1,7 +1,11 @@
+pub mod coverage_serializer;
 pub mod dependency_serializer;
 pub mod file_path_resolver;
 pub mod interface_serializer;
+pub mod module_fixer;
 pub mod module_id_calculator;
 pub mod module_test_information_serializer;
 pub mod package_id_calculator;
 pub mod package_test_information_serializer;
+pub mod warning_serializer;
+pub mod warning_suppressor;
@@ -0,0 +1,9 @@
+use std::error::Error;
+
+pub fn serialize(coverage: &test_info::Coverage) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(serde_json::to_vec(&coverage)?)
+}
+
+pub fn deserialize(slice: &[u8]) -> Result<test_info::Coverage, Box<dyn Error>> {
+    Ok(serde_json::from_slice(slice)?)
+}
@@ -3,13 +3,15 @@ use crate::{
     common::module_id_calculator,
     infra::{
         FilePath, FilePathConfiguration, ARCHIVE_DIRECTORY, BUILD_SCRIPT_DIRECTORY,
-        EXTERNAL_PACKAGE_DIRECTORY, OBJECT_DIRECTORY, TEST_DIRECTORY,
+        COVERAGE_DIRECTORY, EXTERNAL_PACKAGE_DIRECTORY, OBJECT_DIRECTORY, TEST_DIRECTORY,
+        VENDOR_DIRECTORY,
     },
     ApplicationConfiguration,
 };
 
 const MAIN_ARCHIVE_BASENAME: &str = "main";
 const TEST_ARCHIVE_SUFFIX: &str = "_test";
+const COVERAGE_REPORT_FILENAME: &str = "lcov.info";
 
 pub fn resolve_object_directory(output_directory: &FilePath) -> FilePath {
     output_directory.join(&FilePath::new([OBJECT_DIRECTORY]))
@@ -66,6 +68,24 @@ pub fn resolve_test_information_file(
     )
 }
 
+pub fn resolve_coverage_file(
+    output_directory: &FilePath,
+    source_file: &FilePath,
+    file_path_configuration: &FilePathConfiguration,
+) -> FilePath {
+    resolve_target_file_basename(output_directory, source_file)
+        .with_extension(file_path_configuration.coverage_file_extension)
+}
+
+pub fn resolve_warning_file(
+    output_directory: &FilePath,
+    source_file: &FilePath,
+    file_path_configuration: &FilePathConfiguration,
+) -> FilePath {
+    resolve_target_file_basename(output_directory, source_file)
+        .with_extension(file_path_configuration.warning_file_extension)
+}
+
 fn resolve_target_file_basename(output_directory: &FilePath, source_file: &FilePath) -> FilePath {
     resolve_object_directory(output_directory).join(&FilePath::new([
         &module_id_calculator::calculate(source_file),
@@ -79,6 +99,16 @@ pub fn resolve_package_directory(output_directory: &FilePath, url: &url::Url) ->
     ]))
 }
 
+pub fn resolve_vendored_package_directory(
+    package_directory: &FilePath,
+    url: &url::Url,
+) -> FilePath {
+    package_directory.join(&FilePath::new(vec![
+        VENDOR_DIRECTORY.into(),
+        package_id_calculator::calculate(url),
+    ]))
+}
+
 pub fn resolve_application_file(
     package_directory: &FilePath,
     application_configuration: &ApplicationConfiguration,
@@ -167,6 +197,14 @@ pub fn resolve_test_executable_file(output_directory: &FilePath) -> FilePath {
     resolve_test_directory(output_directory).join(&FilePath::new(["test"]))
 }
 
+pub fn resolve_coverage_data_directory(output_directory: &FilePath) -> FilePath {
+    resolve_test_directory(output_directory).join(&FilePath::new([COVERAGE_DIRECTORY]))
+}
+
+pub fn resolve_coverage_report_file(output_directory: &FilePath) -> FilePath {
+    output_directory.join(&FilePath::new([COVERAGE_REPORT_FILENAME]))
+}
+
 pub fn resolve_module_path_components(
     package_directory: &FilePath,
     module_file_path: &FilePath,
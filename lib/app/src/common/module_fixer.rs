@@ -0,0 +1,330 @@
+use hir::analysis::AnalysisWarning;
+use position::Position;
+use std::collections::BTreeSet;
+
+// Fix a module by removing unused imports, unqualified names and private
+// definitions reported as warnings and merging duplicate imports.
+//
+// Comments attached to removed definitions are removed together. Imports are
+// sorted later by a formatter.
+pub fn fix(
+    module: &ast::Module,
+    comments: &[ast::Comment],
+    warnings: &[AnalysisWarning],
+) -> (ast::Module, Vec<ast::Comment>) {
+    let positions = warnings
+        .iter()
+        .filter_map(|warning| match warning {
+            AnalysisWarning::UnusedImport(position)
+            | AnalysisWarning::UnusedUnqualifiedName(_, position)
+            | AnalysisWarning::UnusedFunction(_, position)
+            | AnalysisWarning::UnusedType(_, position) => Some(position),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let is_unused = |position: &Position| {
+        positions.iter().any(|other| {
+            other.line_number() == position.line_number()
+                && other.column_number() == position.column_number()
+        })
+    };
+
+    let removed_import_lines = module
+        .imports()
+        .iter()
+        .map(|import| import.position())
+        .filter(|&position| is_unused(position))
+        .map(|position| position.line_number())
+        .collect::<Vec<_>>();
+    let removed_definition_lines = module
+        .type_definitions()
+        .iter()
+        .map(|definition| definition.position())
+        .chain(
+            module
+                .function_definitions()
+                .iter()
+                .map(|definition| definition.position()),
+        )
+        .filter(|&position| is_unused(position))
+        .map(|position| position.line_number())
+        .collect::<Vec<_>>();
+
+    (
+        ast::Module::new(
+            merge_imports(
+                module
+                    .imports()
+                    .iter()
+                    .filter(|import| !is_unused(import.position()))
+                    .map(|import| {
+                        ast::Import::new(
+                            import.module_path().clone(),
+                            import.prefix().map(From::from),
+                            import
+                                .unqualified_names()
+                                .iter()
+                                .filter(|name| !is_unused(name.position()))
+                                .cloned()
+                                .collect(),
+                            import.position().clone(),
+                        )
+                    }),
+            ),
+            module.foreign_imports().to_vec(),
+            module
+                .type_definitions()
+                .iter()
+                .filter(|definition| !is_unused(definition.position()))
+                .cloned()
+                .collect(),
+            module
+                .function_definitions()
+                .iter()
+                .filter(|definition| !is_unused(definition.position()))
+                .cloned()
+                .collect(),
+            module.position().clone(),
+        ),
+        remove_comments(
+            module,
+            comments,
+            &removed_import_lines,
+            &removed_definition_lines,
+        ),
+    )
+}
+
+fn merge_imports(imports: impl IntoIterator<Item = ast::Import>) -> Vec<ast::Import> {
+    let mut merged_imports = Vec::<ast::Import>::new();
+
+    for import in imports {
+        if let Some(other) = merged_imports.iter_mut().find(|other| {
+            other.module_path() == import.module_path() && other.prefix() == import.prefix()
+        }) {
+            let merged_import = ast::Import::new(
+                other.module_path().clone(),
+                other.prefix().map(From::from),
+                other
+                    .unqualified_names()
+                    .iter()
+                    .chain(import.unqualified_names().iter().filter(|name| {
+                        !other
+                            .unqualified_names()
+                            .iter()
+                            .any(|other| other.name() == name.name())
+                    }))
+                    .cloned()
+                    .collect(),
+                other.position().clone(),
+            );
+
+            *other = merged_import;
+        } else {
+            merged_imports.push(import);
+        }
+    }
+
+    merged_imports
+}
+
+// Comments on lines from a removed item up to the next item are removed except
+// ones directly above the next item. Comments directly above removed
+// definitions are also removed.
+fn remove_comments(
+    module: &ast::Module,
+    comments: &[ast::Comment],
+    removed_import_lines: &[usize],
+    removed_definition_lines: &[usize],
+) -> Vec<ast::Comment> {
+    let item_lines = collect_item_lines(module);
+    let comment_lines = comments
+        .iter()
+        .map(|comment| comment.position().line_number())
+        .collect::<BTreeSet<_>>();
+    let mut removed_lines = BTreeSet::new();
+
+    for &line in removed_import_lines.iter().chain(removed_definition_lines) {
+        let next_line = item_lines
+            .range(line + 1..)
+            .next()
+            .copied()
+            .unwrap_or(usize::MAX);
+        let next_comment_lines = if next_line == usize::MAX {
+            BTreeSet::new()
+        } else {
+            (line + 1..next_line)
+                .rev()
+                .take_while(|line| comment_lines.contains(line))
+                .collect()
+        };
+
+        removed_lines.extend(
+            comment_lines
+                .range(line..next_line)
+                .filter(|line| !next_comment_lines.contains(line)),
+        );
+    }
+
+    for &line in removed_definition_lines {
+        removed_lines.extend(
+            (1..line)
+                .rev()
+                .take_while(|line| comment_lines.contains(line)),
+        );
+    }
+
+    comments
+        .iter()
+        .filter(|comment| !removed_lines.contains(&comment.position().line_number()))
+        .cloned()
+        .collect()
+}
+
+fn collect_item_lines(module: &ast::Module) -> BTreeSet<usize> {
+    module
+        .imports()
+        .iter()
+        .map(|import| import.position())
+        .chain(
+            module
+                .foreign_imports()
+                .iter()
+                .map(|import| import.position()),
+        )
+        .chain(
+            module
+                .type_definitions()
+                .iter()
+                .map(|definition| definition.position()),
+        )
+        .chain(
+            module
+                .function_definitions()
+                .iter()
+                .map(|definition| definition.position()),
+        )
+        .map(|position| position.line_number())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fix_source(source: &str, warnings: &[AnalysisWarning]) -> (ast::Module, Vec<String>) {
+        let (module, comments) = fix(
+            &parse::parse(source, "foo.pen").unwrap(),
+            &parse::parse_comments(source, "foo.pen").unwrap(),
+            warnings,
+        );
+
+        (
+            module,
+            comments
+                .iter()
+                .map(|comment| comment.line().into())
+                .collect(),
+        )
+    }
+
+    fn parse_source(source: &str) -> (ast::Module, Vec<String>) {
+        fix_source(source, &[])
+    }
+
+    fn create_position(line_number: usize, column_number: usize) -> Position {
+        Position::new("foo.pen", line_number, column_number, "")
+    }
+
+    #[test]
+    fn fix_nothing() {
+        let source = "import Foo'Bar\nf = \\() none { Bar'x() }";
+
+        assert_eq!(
+            fix_source(source, &[]).0,
+            parse::parse(source, "foo.pen").unwrap()
+        );
+    }
+
+    #[test]
+    fn remove_unused_import() {
+        assert_eq!(
+            fix_source(
+                "import Foo'Bar\nimport Foo'Baz\nf = \\() none { Bar'x() }",
+                &[AnalysisWarning::UnusedImport(create_position(2, 1))]
+            ),
+            parse_source("import Foo'Bar\nf = \\() none { Bar'x() }")
+        );
+    }
+
+    #[test]
+    fn remove_unused_unqualified_name() {
+        assert_eq!(
+            fix_source(
+                "import Foo'Bar { x, y }\nf = \\() none { x() }",
+                &[AnalysisWarning::UnusedUnqualifiedName(
+                    "y".into(),
+                    create_position(1, 21)
+                )]
+            ),
+            parse_source("import Foo'Bar { x }\nf = \\() none { x() }")
+        );
+    }
+
+    #[test]
+    fn merge_duplicate_imports() {
+        assert_eq!(
+            fix_source(
+                "import Foo'Bar { x }\nimport Foo'Baz\nimport Foo'Bar { x, y }\nf = \\() none { Baz'x(x(), y()) }",
+                &[]
+            ),
+            parse_source("import Foo'Bar { x, y }\nimport Foo'Baz\nf = \\() none { Baz'x(x(), y()) }")
+        );
+    }
+
+    #[test]
+    fn merge_no_imports_with_different_prefixes() {
+        let source = "import Foo'Bar\nimport Foo'Bar as Baz\nf = \\() none { Baz'x(Bar'x()) }";
+
+        assert_eq!(fix_source(source, &[]), parse_source(source));
+    }
+
+    #[test]
+    fn remove_unused_function_with_comments() {
+        assert_eq!(
+            fix_source(
+                "# foo\nf = \\() none {\n  # bar\n  none\n}\n\n# baz\nG = \\() none { none }",
+                &[AnalysisWarning::UnusedFunction(
+                    "f".into(),
+                    create_position(2, 1)
+                )]
+            ),
+            parse_source("# baz\nG = \\() none { none }")
+        );
+    }
+
+    #[test]
+    fn remove_unused_type() {
+        assert_eq!(
+            fix_source(
+                "type foo {}\ntype Bar = none",
+                &[AnalysisWarning::UnusedType(
+                    "foo".into(),
+                    create_position(1, 1)
+                )]
+            ),
+            parse_source("type Bar = none")
+        );
+    }
+
+    #[test]
+    fn keep_comment_above_removed_import() {
+        assert_eq!(
+            fix_source(
+                "# foo\nimport Foo'Bar\nF = \\() none { none }",
+                &[AnalysisWarning::UnusedImport(create_position(2, 1))]
+            ),
+            parse_source("# foo\nF = \\() none { none }")
+        );
+    }
+}
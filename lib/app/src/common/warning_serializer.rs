@@ -0,0 +1,10 @@
+use crate::Diagnostic;
+use std::error::Error;
+
+pub fn serialize(diagnostics: &[Diagnostic]) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(serde_json::to_vec(diagnostics)?)
+}
+
+pub fn deserialize(slice: &[u8]) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+    Ok(serde_json::from_slice(slice)?)
+}
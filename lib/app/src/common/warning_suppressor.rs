@@ -0,0 +1,187 @@
+use hir::analysis::AnalysisWarning;
+use std::ops::Range;
+
+const DIRECTIVE_PREFIX: &str = "pen:allow";
+
+// Suppress warnings in function definitions preceded by directive comments of
+// `# pen:allow <lint>...` where lints are warning names or codes.
+//
+// A directive applies to lines from its function definition up to the next
+// top-level item in a module.
+pub fn suppress(
+    module: &ast::Module,
+    comments: &[ast::Comment],
+    warnings: &[AnalysisWarning],
+) -> Vec<AnalysisWarning> {
+    let ranges = collect_ranges(module, comments);
+
+    warnings
+        .iter()
+        .filter(|warning| {
+            !ranges.iter().any(|(range, lints)| {
+                range.contains(&warning.position().line_number())
+                    && lints
+                        .iter()
+                        .any(|lint| lint == warning.name() || lint == warning.code())
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+fn collect_ranges(
+    module: &ast::Module,
+    comments: &[ast::Comment],
+) -> Vec<(Range<usize>, Vec<String>)> {
+    let mut lines = module
+        .imports()
+        .iter()
+        .map(|import| import.position())
+        .chain(
+            module
+                .foreign_imports()
+                .iter()
+                .map(|import| import.position()),
+        )
+        .chain(
+            module
+                .type_definitions()
+                .iter()
+                .map(|definition| definition.position()),
+        )
+        .chain(
+            module
+                .function_definitions()
+                .iter()
+                .map(|definition| definition.position()),
+        )
+        .map(|position| position.line_number())
+        .collect::<Vec<_>>();
+
+    lines.sort();
+
+    module
+        .function_definitions()
+        .iter()
+        .map(|definition| {
+            let line = definition.position().line_number();
+            let previous_line = lines
+                .iter()
+                .rev()
+                .find(|&&other| other < line)
+                .copied()
+                .unwrap_or_default();
+            let next_line = lines
+                .iter()
+                .find(|&&other| other > line)
+                .copied()
+                .unwrap_or(usize::MAX);
+
+            (
+                line..next_line,
+                comments
+                    .iter()
+                    .filter(|comment| {
+                        (previous_line + 1..line).contains(&comment.position().line_number())
+                    })
+                    .flat_map(|comment| parse_directive(comment.line()))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .filter(|(_, lints)| !lints.is_empty())
+        .collect()
+}
+
+fn parse_directive(line: &str) -> Vec<String> {
+    line.trim()
+        .strip_prefix(DIRECTIVE_PREFIX)
+        .map(|lints| {
+            lints
+                .split(|character: char| character == ',' || character.is_whitespace())
+                .filter(|lint| !lint.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::Position;
+
+    fn suppress_source(source: &str, warnings: &[AnalysisWarning]) -> Vec<AnalysisWarning> {
+        suppress(
+            &parse::parse(source, "foo.pen").unwrap(),
+            &parse::parse_comments(source, "foo.pen").unwrap(),
+            warnings,
+        )
+    }
+
+    fn create_warning(line_number: usize) -> AnalysisWarning {
+        AnalysisWarning::UnusedVariable("x".into(), Position::new("foo.pen", line_number, 1, ""))
+    }
+
+    #[test]
+    fn suppress_nothing() {
+        assert_eq!(
+            suppress_source("f = \\(x none) none {\n  none\n}", &[create_warning(1)]),
+            vec![create_warning(1)]
+        );
+    }
+
+    #[test]
+    fn suppress_warning_by_name() {
+        assert_eq!(
+            suppress_source(
+                "# pen:allow unused-variable\nf = \\(x none) none {\n  none\n}",
+                &[create_warning(2)]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn suppress_warning_by_code() {
+        assert_eq!(
+            suppress_source(
+                "# pen:allow W0003\nf = \\(x none) none {\n  none\n}",
+                &[create_warning(3)]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn suppress_warning_with_multiple_lints() {
+        assert_eq!(
+            suppress_source(
+                "# Foo\n# pen:allow shadowed-variable, unused-variable\nf = \\(x none) none {\n  none\n}",
+                &[create_warning(4)]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn suppress_nothing_with_other_lint() {
+        assert_eq!(
+            suppress_source(
+                "# pen:allow unused-function\nf = \\(x none) none {\n  none\n}",
+                &[create_warning(2)]
+            ),
+            vec![create_warning(2)]
+        );
+    }
+
+    #[test]
+    fn suppress_nothing_in_next_function() {
+        assert_eq!(
+            suppress_source(
+                "# pen:allow unused-variable\nf = \\() none {\n  none\n}\n\ng = \\(x none) none {\n  none\n}",
+                &[create_warning(6)]
+            ),
+            vec![create_warning(6)]
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::DiagnosticSeverity;
+use position::Position;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    severity: DiagnosticSeverity,
+    code: Option<String>,
+    message: String,
+    position: Option<Position>,
+    rendered: String,
+}
+
+impl Diagnostic {
+    pub fn new(
+        severity: DiagnosticSeverity,
+        code: Option<&str>,
+        message: impl Into<String>,
+        position: Option<Position>,
+        rendered: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            code: code.map(From::from),
+            message: message.into(),
+            position,
+            rendered: rendered.into(),
+        }
+    }
+
+    pub fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn position(&self) -> Option<&Position> {
+        self.position.as_ref()
+    }
+
+    pub fn rendered(&self) -> &str {
+        &self.rendered
+    }
+}
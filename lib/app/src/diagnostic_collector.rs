@@ -0,0 +1,177 @@
+use crate::{Diagnostic, DiagnosticSeverity};
+use hir::analysis::{AnalysisError, AnalysisWarning, Severity};
+use std::error::Error;
+
+// Collect diagnostics from an error and its sources.
+pub fn collect(error: &(dyn Error + 'static)) -> Vec<Diagnostic> {
+    let mut diagnostics = if let Some(error) = error.downcast_ref::<parse::ParseError>() {
+        vec![Diagnostic::new(
+            DiagnosticSeverity::Error,
+            None,
+            error.message(),
+            Some(error.position().clone()),
+            error.to_string(),
+        )]
+    } else if let Some(error) = error.downcast_ref::<ast_hir::CompileError>() {
+        vec![create_diagnostic(None, error, error.position())]
+    } else if let Some(hir_mir::CompileError::Analysis(error)) =
+        error.downcast_ref::<hir_mir::CompileError>()
+    {
+        collect_analysis_error(error)
+    } else if let Some(error) = error.downcast_ref::<hir_mir::CompileError>() {
+        vec![create_diagnostic(None, error, error.position())]
+    } else if let Some(error) = error.downcast_ref::<AnalysisError>() {
+        collect_analysis_error(error)
+    } else {
+        vec![create_diagnostic(None, error, None)]
+    };
+
+    if let Some(error) = error.source() {
+        diagnostics.extend(collect(error));
+    }
+
+    diagnostics
+}
+
+// Collect diagnostics from warnings.
+pub fn collect_warnings(warnings: &[AnalysisWarning]) -> Vec<Diagnostic> {
+    warnings
+        .iter()
+        .map(|warning| {
+            let rendered = warning.to_string();
+
+            Diagnostic::new(
+                match warning.severity() {
+                    Severity::Warning => DiagnosticSeverity::Warning,
+                    Severity::Information => DiagnosticSeverity::Information,
+                },
+                Some(warning.code()),
+                rendered.lines().next().unwrap_or_default(),
+                Some(warning.position().clone()),
+                rendered.clone(),
+            )
+        })
+        .collect()
+}
+
+fn collect_analysis_error(error: &AnalysisError) -> Vec<Diagnostic> {
+    if let AnalysisError::Multiple(errors) = error {
+        errors.iter().flat_map(collect_analysis_error).collect()
+    } else {
+        vec![create_diagnostic(error.code(), error, error.position())]
+    }
+}
+
+fn create_diagnostic(
+    code: Option<&'static str>,
+    error: &dyn Error,
+    position: Option<&position::Position>,
+) -> Diagnostic {
+    let rendered = error.to_string();
+
+    Diagnostic::new(
+        DiagnosticSeverity::Error,
+        code,
+        rendered.lines().next().unwrap_or_default(),
+        position.cloned(),
+        rendered.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::{test::PositionFake, Position};
+
+    #[test]
+    fn collect_parse_error() {
+        let error = parse::parse("foo", "foo.pen").unwrap_err();
+
+        assert_eq!(
+            collect(&error),
+            vec![Diagnostic::new(
+                DiagnosticSeverity::Error,
+                None,
+                error.message(),
+                Some(error.position().clone()),
+                error.to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_analysis_error() {
+        let error = AnalysisError::UnreachableCode(Position::fake());
+
+        assert_eq!(
+            collect(&hir_mir::CompileError::Analysis(error.clone())),
+            vec![Diagnostic::new(
+                DiagnosticSeverity::Error,
+                Some("E0032"),
+                "unreachable code",
+                Some(Position::fake()),
+                error.to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_multiple_analysis_errors() {
+        assert_eq!(
+            collect(&AnalysisError::Multiple(vec![
+                AnalysisError::UnreachableCode(Position::fake()),
+                AnalysisError::ErrorTypeUndefined,
+            ]))
+            .iter()
+            .map(Diagnostic::code)
+            .collect::<Vec<_>>(),
+            vec![Some("E0032"), Some("E0008")]
+        );
+    }
+
+    #[test]
+    fn collect_unknown_error() {
+        let error = std::io::Error::other("foo\nbar");
+
+        assert_eq!(
+            collect(&error),
+            vec![Diagnostic::new(
+                DiagnosticSeverity::Error,
+                None,
+                "foo",
+                None,
+                "foo\nbar"
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_warning() {
+        let warning = AnalysisWarning::UnusedVariable("x".into(), Position::fake());
+
+        assert_eq!(
+            collect_warnings(&[warning.clone()]),
+            vec![Diagnostic::new(
+                DiagnosticSeverity::Warning,
+                Some("W0003"),
+                "variable \"x\" never used",
+                Some(Position::fake()),
+                warning.to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_information() {
+        assert_eq!(
+            collect_warnings(&[AnalysisWarning::ShadowedVariable(
+                "x".into(),
+                Position::fake()
+            )])
+            .iter()
+            .map(Diagnostic::severity)
+            .collect::<Vec<_>>(),
+            vec![DiagnosticSeverity::Information]
+        );
+    }
+}
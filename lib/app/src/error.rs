@@ -5,13 +5,17 @@ pub enum ApplicationError {
     ArchitectureWordSize(String),
     Build,
     ContextTypeNotFound,
+    CoverageDataMalformed(String),
     ModuleFilesNotFormatted(Vec<String>),
     ModuleNotFound(String),
     NewContextFunctionNotFound,
     PackageDependencyCycle,
     PackageNotFound(String),
+    PackageReferencesConflicted(String, String, String),
+    ReplImportNotSupported,
     SystemPackageNotFound,
     Test,
+    WarningsDenied(usize),
 }
 
 impl Error for ApplicationError {}
@@ -29,6 +33,9 @@ impl Display for ApplicationError {
             Self::ContextTypeNotFound => {
                 write!(formatter, "context type not found")
             }
+            Self::CoverageDataMalformed(line) => {
+                write!(formatter, "malformed coverage data: {line}")
+            }
             Self::ModuleFilesNotFormatted(paths) => {
                 write!(
                     formatter,
@@ -48,10 +55,22 @@ impl Display for ApplicationError {
             Self::PackageNotFound(package) => {
                 write!(formatter, "package {package} not found")
             }
+            Self::PackageReferencesConflicted(url, one, other) => {
+                write!(
+                    formatter,
+                    "conflicting references to package {url}: {one} and {other}"
+                )
+            }
+            Self::ReplImportNotSupported => {
+                write!(formatter, "imports not supported in REPL")
+            }
             Self::SystemPackageNotFound => {
                 write!(formatter, "system package not found")
             }
             Self::Test => write!(formatter, "test failed"),
+            Self::WarningsDenied(count) => {
+                write!(formatter, "{count} warning(s) denied")
+            }
         }
     }
 }
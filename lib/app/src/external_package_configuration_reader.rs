@@ -13,11 +13,14 @@ pub fn read(
     configuration
         .dependencies()
         .iter()
-        .map(|(key, url)| -> Result<_, Box<dyn Error>> {
+        .map(|(key, dependency)| -> Result<_, Box<dyn Error>> {
             Ok((
                 key.clone(),
                 infrastructure.package_configuration_reader.read(
-                    &file_path_resolver::resolve_package_directory(output_directory, url),
+                    &file_path_resolver::resolve_package_directory(
+                        output_directory,
+                        dependency.url(),
+                    ),
                 )?,
             ))
         })
@@ -46,7 +49,8 @@ fn read_dependencies(
     Ok(configuration
         .dependencies()
         .values()
-        .map(|url| -> Result<_, Box<dyn Error>> {
+        .map(|dependency| -> Result<_, Box<dyn Error>> {
+            let url = dependency.url();
             let configuration = infrastructure.package_configuration_reader.read(
                 &file_path_resolver::resolve_package_directory(output_directory, url),
             )?;
@@ -16,8 +16,8 @@ pub fn sort(
     }
 
     for (url, configuration) in external_package_configurations {
-        for dependency_url in configuration.dependencies().values() {
-            graph.add_edge(indices[url], indices[dependency_url], ());
+        for dependency in configuration.dependencies().values() {
+            graph.add_edge(indices[url], indices[dependency.url()], ());
         }
     }
 
@@ -47,8 +47,8 @@ mod tests {
                         PackageConfiguration::new(
                             PackageType::Application,
                             [
-                                ("Foo".into(), url::Url::parse("file:///foo").unwrap()),
-                                ("Baz".into(), url::Url::parse("file:///baz").unwrap())
+                                ("Foo".into(), url::Url::parse("file:///foo").unwrap().into()),
+                                ("Baz".into(), url::Url::parse("file:///baz").unwrap().into())
                             ]
                             .into_iter()
                             .collect(),
@@ -58,7 +58,7 @@ mod tests {
                         url::Url::parse("file:///baz").unwrap(),
                         PackageConfiguration::new(
                             PackageType::Application,
-                            [("Foo".into(), url::Url::parse("file:///foo").unwrap()),]
+                            [("Foo".into(), url::Url::parse("file:///foo").unwrap().into()),]
                                 .into_iter()
                                 .collect(),
                         )
@@ -1,28 +1,45 @@
-use crate::infra::{FilePath, Infrastructure};
+use crate::infra::{FilePath, Infrastructure, VENDOR_DIRECTORY};
 use std::error::Error;
 
 pub fn find(
     infrastructure: &Infrastructure,
     package_directory: &FilePath,
     file_extension: &str,
+) -> Result<Vec<FilePath>, Box<dyn Error>> {
+    find_in_directory(
+        infrastructure,
+        package_directory,
+        file_extension,
+        &package_directory.join(&FilePath::new([VENDOR_DIRECTORY])),
+    )
+}
+
+fn find_in_directory(
+    infrastructure: &Infrastructure,
+    directory: &FilePath,
+    file_extension: &str,
+    vendor_directory: &FilePath,
 ) -> Result<Vec<FilePath>, Box<dyn Error>> {
     let mut source_files = vec![];
 
-    for path in infrastructure
-        .file_system
-        .read_directory(package_directory)?
-    {
+    for path in infrastructure.file_system.read_directory(directory)? {
         if path
-            .relative_to(package_directory)
+            .relative_to(directory)
             .unwrap()
             .components()
             .next()
             .unwrap()
             .starts_with('.')
+            || &path == vendor_directory
         {
             continue;
         } else if infrastructure.file_system.is_directory(&path) {
-            source_files.extend(find(infrastructure, &path, file_extension)?);
+            source_files.extend(find_in_directory(
+                infrastructure,
+                &path,
+                file_extension,
+                vendor_directory,
+            )?);
         } else if path.has_extension(file_extension) {
             source_files.push(path);
         }
@@ -13,6 +13,8 @@ mod module_target;
 mod module_target_source;
 mod package_configuration_reader;
 mod package_configuration_writer;
+mod package_lock_reader;
+mod package_lock_writer;
 mod test_linker;
 mod test_module_target;
 
@@ -31,6 +33,8 @@ pub use module_target::*;
 pub use module_target_source::*;
 pub use package_configuration_reader::*;
 pub use package_configuration_writer::*;
+pub use package_lock_reader::*;
+pub use package_lock_writer::*;
 pub use test_linker::*;
 pub use test_module_target::*;
 
@@ -39,3 +43,5 @@ pub const OBJECT_DIRECTORY: &str = "objects";
 pub const ARCHIVE_DIRECTORY: &str = "archives";
 pub const BUILD_SCRIPT_DIRECTORY: &str = "scripts";
 pub const TEST_DIRECTORY: &str = "test";
+pub const COVERAGE_DIRECTORY: &str = "coverage";
+pub const VENDOR_DIRECTORY: &str = "vendor";
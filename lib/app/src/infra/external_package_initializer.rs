@@ -1,10 +1,24 @@
 use super::file_path::FilePath;
+use crate::{package_configuration::PackageReference, package_lock::LockedPackage};
 use std::error::Error;
 
 pub trait ExternalPackageInitializer {
+    // Initialize a package and return its locked state if it is fetched from a
+    // git repository.
     fn initialize(
         &self,
         url: &url::Url,
+        reference: Option<&PackageReference>,
+        locked_package: Option<&LockedPackage>,
         package_directory: &FilePath,
+        vendored_package_directory: &FilePath,
+    ) -> Result<Option<LockedPackage>, Box<dyn Error>>;
+
+    // Copy an initialized package into a vendor directory.
+    fn vendor(
+        &self,
+        url: &url::Url,
+        package_directory: &FilePath,
+        vendored_package_directory: &FilePath,
     ) -> Result<(), Box<dyn Error>>;
 }
@@ -4,6 +4,8 @@ pub struct FilePathConfiguration {
     pub object_file_extension: &'static str,
     pub interface_file_extension: &'static str,
     pub test_information_file_extension: &'static str,
+    pub coverage_file_extension: &'static str,
+    pub warning_file_extension: &'static str,
     pub archive_file_extension: &'static str,
     pub build_script_file_extension: &'static str,
     pub test_file_extension: &'static str,
@@ -1,7 +1,8 @@
 use super::{
     BuildScriptCompiler, BuildScriptDependencyCompiler, BuildScriptRunner, CommandRunner,
     ExternalPackageInitializer, FilePathConfiguration, FilePathDisplayer, FileSystem,
-    PackageConfigurationReader, PackageConfigurationWriter, TestLinker,
+    PackageConfigurationReader, PackageConfigurationWriter, PackageLockReader, PackageLockWriter,
+    TestLinker,
 };
 use std::rc::Rc;
 
@@ -20,6 +21,8 @@ pub struct Infrastructure {
     pub build_script_runner: Rc<dyn BuildScriptRunner>,
     pub package_configuration_reader: Rc<dyn PackageConfigurationReader>,
     pub package_configuration_writer: Rc<dyn PackageConfigurationWriter>,
+    pub package_lock_reader: Rc<dyn PackageLockReader>,
+    pub package_lock_writer: Rc<dyn PackageLockWriter>,
     pub command_runner: Rc<dyn CommandRunner>,
     pub test_linker: Rc<dyn TestLinker>,
 }
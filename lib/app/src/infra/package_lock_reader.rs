@@ -0,0 +1,7 @@
+use super::file_path::FilePath;
+use crate::package_lock::PackageLock;
+use std::error::Error;
+
+pub trait PackageLockReader {
+    fn read(&self, package_directory: &FilePath) -> Result<PackageLock, Box<dyn Error>>;
+}
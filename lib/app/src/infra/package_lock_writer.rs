@@ -0,0 +1,8 @@
+use super::file_path::FilePath;
+use crate::package_lock::PackageLock;
+use std::error::Error;
+
+pub trait PackageLockWriter {
+    fn write(&self, lock: &PackageLock, package_directory: &FilePath)
+        -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,338 @@
+use crate::{
+    common::{file_path_resolver, interface_serializer, warning_suppressor},
+    diagnostic_collector,
+    infra::{FilePath, Infrastructure},
+    module_dependency_resolver, prelude_interface_file_finder, system_package_finder,
+    ApplicationConfiguration, Diagnostic, PackageType,
+};
+use fnv::FnvHashMap;
+use hir::analysis::{
+    type_collector, type_formatter, variable_reference_collector, AnalysisContext, AnalysisWarning,
+};
+use position::Position;
+use std::{error::Error, slice};
+
+pub struct Document<'a> {
+    pub package_directory: &'a FilePath,
+    pub output_directory: &'a FilePath,
+    pub source_file: &'a FilePath,
+    pub source: &'a str,
+}
+
+pub fn check(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+    Ok(
+        match analyze(
+            infrastructure,
+            document,
+            prelude_package_url,
+            application_configuration,
+        )? {
+            Ok((_, _, warnings)) => diagnostic_collector::collect_warnings(&warnings),
+            Err(diagnostics) => diagnostics,
+        },
+    )
+}
+
+// Collect warnings of a module or diagnostics on errors.
+pub fn collect_warnings(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Result<Vec<AnalysisWarning>, Vec<Diagnostic>>, Box<dyn Error>> {
+    Ok(analyze(
+        infrastructure,
+        document,
+        prelude_package_url,
+        application_configuration,
+    )?
+    .map(|(_, _, warnings)| warnings))
+}
+
+pub fn hover(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    line_number: usize,
+    column_number: usize,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Option<String>, Box<dyn Error>> {
+    Ok(find_reference(
+        infrastructure,
+        document,
+        line_number,
+        column_number,
+        prelude_package_url,
+        application_configuration,
+    )?
+    .map(|(_, type_)| type_formatter::format(&type_)))
+}
+
+pub fn find_definition(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    line_number: usize,
+    column_number: usize,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Option<Position>, Box<dyn Error>> {
+    Ok(find_reference(
+        infrastructure,
+        document,
+        line_number,
+        column_number,
+        prelude_package_url,
+        application_configuration,
+    )?
+    .map(|(position, _)| position))
+}
+
+// Find a byte range of an identifier starting at a column number.
+pub fn find_identifier_range(line: &str, column_number: usize) -> (usize, usize) {
+    let start = column_number.saturating_sub(1).min(line.len());
+    let end = line[start..]
+        .char_indices()
+        .find(|&(_, character)| !is_identifier_character(character))
+        .map(|(index, _)| start + index)
+        .unwrap_or(line.len());
+
+    (start + 1, end + 1)
+}
+
+fn is_identifier_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_' || character == '\''
+}
+
+fn find_reference(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    line_number: usize,
+    column_number: usize,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Option<(Position, hir::types::Type)>, Box<dyn Error>> {
+    let Ok((context, module, _)) = analyze(
+        infrastructure,
+        document,
+        prelude_package_url,
+        application_configuration,
+    )?
+    else {
+        return Ok(None);
+    };
+
+    Ok(variable_reference_collector::collect(&context, &module)
+        .into_iter()
+        .find(|(variable, _, _)| {
+            let position = variable.position();
+            let (start, end) = find_identifier_range(position.line(), position.column_number());
+
+            position.line_number() == line_number && (start..end).contains(&column_number)
+        })
+        .map(|(_, type_, position)| (position, type_)))
+}
+
+fn analyze(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<
+    Result<(AnalysisContext, hir::ir::Module, Vec<AnalysisWarning>), Vec<Diagnostic>>,
+    Box<dyn Error>,
+> {
+    let path = infrastructure
+        .file_path_displayer
+        .display(document.source_file);
+    let (ast_module, comments) = match parse::parse(document.source, &path)
+        .and_then(|module| Ok((module, parse::parse_comments(document.source, &path)?)))
+    {
+        Ok(result) => result,
+        Err(error) => return Ok(Err(diagnostic_collector::collect(&error))),
+    };
+
+    let (module, mut warnings) = match ast_hir::compile(
+        &ast_module,
+        &format!("{}:", document.source_file),
+        &read_module_interfaces(infrastructure, document, &ast_module)?,
+        &read_prelude_interfaces(
+            infrastructure,
+            document,
+            prelude_package_url,
+            application_configuration,
+        )?,
+    ) {
+        Ok(result) => result,
+        Err(error) => return Ok(Err(diagnostic_collector::collect(&error))),
+    };
+
+    let context = AnalysisContext::new(
+        type_collector::collect(&module),
+        type_collector::collect_record_fields(&module),
+    );
+
+    let module = match hir::analysis::analyze(&context, &module) {
+        Ok((module, analysis_warnings)) => {
+            warnings.extend(analysis_warnings);
+            module
+        }
+        Err(error) => return Ok(Err(diagnostic_collector::collect(&error))),
+    };
+
+    // Main functions are used by main function wrappers generated later.
+    if is_main_module(infrastructure, document, application_configuration)? {
+        warnings.retain(|warning| {
+            !matches!(
+                warning,
+                AnalysisWarning::UnusedFunction(name, _)
+                    if name == &application_configuration.main_module.source_main_function_name
+            )
+        });
+    }
+
+    Ok(Ok((
+        context,
+        module,
+        warning_suppressor::suppress(&ast_module, &comments, &warnings),
+    )))
+}
+
+// Interfaces of modules not built yet are skipped so that their imports are
+// reported as diagnostics.
+fn read_module_interfaces(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    module: &ast::Module,
+) -> Result<FnvHashMap<ast::ModulePath, interface::Module>, Box<dyn Error>> {
+    let package_configuration = infrastructure
+        .package_configuration_reader
+        .read(document.package_directory)?;
+    let mut interfaces = FnvHashMap::default();
+
+    for import in module.imports() {
+        let Ok(source_file) = module_dependency_resolver::resolve_source_file(
+            infrastructure,
+            document.package_directory,
+            document.output_directory,
+            &package_configuration,
+            import.module_path(),
+        ) else {
+            continue;
+        };
+        let interface_file = file_path_resolver::resolve_interface_file(
+            document.output_directory,
+            &source_file,
+            &infrastructure.file_path_configuration,
+        );
+
+        if infrastructure.file_system.exists(&interface_file) {
+            interfaces.insert(
+                import.module_path().clone(),
+                interface_serializer::deserialize(
+                    &infrastructure.file_system.read_to_vec(&interface_file)?,
+                )?,
+            );
+        }
+    }
+
+    Ok(interfaces)
+}
+
+fn read_prelude_interfaces(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Vec<interface::Module>, Box<dyn Error>> {
+    let mut files = prelude_interface_file_finder::find(
+        infrastructure,
+        document.output_directory,
+        prelude_package_url,
+    )?;
+
+    if is_main_module(infrastructure, document, application_configuration)? {
+        files.extend(
+            system_package_finder::find(
+                infrastructure,
+                document.package_directory,
+                document.output_directory,
+            )?
+            .values()
+            .map(|url| {
+                file_path_resolver::resolve_interface_file(
+                    document.output_directory,
+                    &file_path_resolver::resolve_source_file(
+                        &file_path_resolver::resolve_package_directory(
+                            document.output_directory,
+                            url,
+                        ),
+                        slice::from_ref(&application_configuration.context_module_basename),
+                        &infrastructure.file_path_configuration,
+                    ),
+                    &infrastructure.file_path_configuration,
+                )
+            }),
+        );
+    }
+
+    files
+        .iter()
+        .filter(|file| infrastructure.file_system.exists(file))
+        .map(|file| {
+            interface_serializer::deserialize(&infrastructure.file_system.read_to_vec(file)?)
+        })
+        .collect()
+}
+
+fn is_main_module(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<bool, Box<dyn Error>> {
+    Ok(infrastructure
+        .package_configuration_reader
+        .read(document.package_directory)?
+        .type_()
+        == PackageType::Application
+        && document.source_file
+            == &file_path_resolver::resolve_source_file(
+                document.package_directory,
+                slice::from_ref(&application_configuration.main_module_basename),
+                &infrastructure.file_path_configuration,
+            ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_identifier_range_at_start() {
+        assert_eq!(find_identifier_range("foo bar", 1), (1, 4));
+    }
+
+    #[test]
+    fn find_identifier_range_in_middle() {
+        assert_eq!(find_identifier_range("f = foo(x)", 5), (5, 8));
+    }
+
+    #[test]
+    fn find_identifier_range_with_qualified_name() {
+        assert_eq!(find_identifier_range("Foo'Bar(x)", 1), (1, 8));
+    }
+
+    #[test]
+    fn find_identifier_range_at_end() {
+        assert_eq!(find_identifier_range("x", 1), (1, 2));
+    }
+
+    #[test]
+    fn find_identifier_range_out_of_line() {
+        assert_eq!(find_identifier_range("x", 3), (2, 2));
+    }
+}
@@ -1,10 +1,14 @@
 mod application_configuration;
 mod common;
+mod diagnostic;
+pub mod diagnostic_collector;
+mod diagnostic_severity;
 mod error;
 mod external_package_configuration_reader;
 mod external_package_topological_sorter;
 mod file_finder;
 pub mod infra;
+pub mod language_server;
 pub mod module_compiler;
 pub mod module_dependency_resolver;
 mod module_finder;
@@ -14,20 +18,36 @@ mod package_build_script_compiler;
 pub mod package_builder;
 mod package_configuration;
 pub mod package_creator;
+pub mod package_dependency_editor;
 pub mod package_documentation_generator;
+pub mod package_fixer;
 pub mod package_format_checker;
 pub mod package_formatter;
 pub mod package_initializer;
+pub mod package_linter;
+mod package_lock;
 mod package_name_formatter;
 mod package_test_builder;
 pub mod package_test_information_compiler;
+pub mod package_updater;
+pub mod package_vendorer;
+pub mod package_warning_collector;
 mod prelude_interface_file_finder;
+pub mod repl;
 mod system_package_finder;
 mod test_configuration;
+mod test_coverage_reporter;
 pub mod test_linker;
 mod test_module_finder;
+mod test_run_configuration;
 pub mod test_runner;
 
 pub use application_configuration::{ApplicationConfiguration, MainModuleConfiguration};
-pub use package_configuration::{PackageConfiguration, PackageType};
+pub use diagnostic::Diagnostic;
+pub use diagnostic_severity::DiagnosticSeverity;
+pub use package_configuration::{
+    PackageConfiguration, PackageDependency, PackageReference, PackageType,
+};
+pub use package_lock::{LockedPackage, PackageLock};
 pub use test_configuration::{TestConfiguration, TestModuleConfiguration};
+pub use test_run_configuration::{TestReportFormat, TestRunConfiguration};
@@ -1,25 +1,36 @@
 mod compile_configuration;
+mod emit_kind;
+mod emitter;
 mod main_module_configuration_qualifier;
 mod prelude_type_configuration_qualifier;
 
 use crate::{
     application_configuration::ApplicationConfiguration,
-    common::{dependency_serializer, interface_serializer, module_test_information_serializer},
+    common::{
+        coverage_serializer, dependency_serializer, interface_serializer,
+        module_test_information_serializer, warning_serializer, warning_suppressor,
+    },
+    diagnostic_collector,
     error::ApplicationError,
     infra::{FilePath, Infrastructure},
     test_configuration::TestModuleConfiguration,
+    Diagnostic,
 };
 pub use compile_configuration::{
     CompileConfiguration, FmmConfiguration, HashConfiguration, HirConfiguration,
     ListTypeConfiguration, MapTypeConfiguration, MapTypeIterationConfiguration, MirConfiguration,
-    NumberTypeConfiguration, StringTypeConfiguration,
+    NumberTypeConfiguration, StringTypeConfiguration, VectorTypeConfiguration,
 };
+pub use emit_kind::EmitKind;
+use emitter::Emitter;
 use fnv::FnvHashMap;
+use hir::analysis::AnalysisWarning;
 use std::{collections::BTreeMap, error::Error, mem::size_of, str::FromStr};
 use target_lexicon::Triple;
 
 const PRELUDE_PREFIX: &str = "prelude:";
 
+#[allow(clippy::too_many_arguments)]
 pub fn compile(
     infrastructure: &Infrastructure,
     source_file: &FilePath,
@@ -28,25 +39,97 @@ pub fn compile(
     interface_file: &FilePath,
     target_triple: Option<&str>,
     compile_configuration: &CompileConfiguration,
+    emit_kinds: &[EmitKind],
 ) -> Result<(), Box<dyn Error>> {
-    let (module, module_interface) = hir_mir::compile(
-        &compile_to_hir(infrastructure, source_file, dependency_file, &[])?,
+    clear_warnings(infrastructure, object_file)?;
+
+    let emitter = Emitter::new(infrastructure, object_file, emit_kinds);
+    let (module, ast_module, mut warnings, external_definitions) =
+        compile_to_hir(infrastructure, source_file, dependency_file, &[], &emitter)?;
+    let (module, module_interface, analysis_warnings) = hir_mir::compile(
+        &module,
         &prelude_type_configuration_qualifier::qualify(&compile_configuration.hir, PRELUDE_PREFIX),
     )?;
+    warnings.extend(analysis_warnings);
 
     compile_mir_module(
         infrastructure,
         &module,
+        &external_definitions,
         object_file,
         target_triple,
         compile_configuration,
+        &emitter,
     )?;
     infrastructure.file_system.write(
         interface_file,
         &interface_serializer::serialize(&module_interface)?,
     )?;
 
-    Ok(())
+    write_warnings(
+        infrastructure,
+        source_file,
+        object_file,
+        &ast_module,
+        &warnings,
+    )
+}
+
+// A coverage file is written next to an object file so that test runs can map
+// counters back to positions in source files.
+#[allow(clippy::too_many_arguments)]
+pub fn compile_with_coverage(
+    infrastructure: &Infrastructure,
+    source_file: &FilePath,
+    dependency_file: &FilePath,
+    object_file: &FilePath,
+    interface_file: &FilePath,
+    target_triple: Option<&str>,
+    compile_configuration: &CompileConfiguration,
+    test_module_configuration: &TestModuleConfiguration,
+    emit_kinds: &[EmitKind],
+) -> Result<(), Box<dyn Error>> {
+    clear_warnings(infrastructure, object_file)?;
+
+    let emitter = Emitter::new(infrastructure, object_file, emit_kinds);
+    let (module, ast_module, mut warnings, external_definitions) =
+        compile_to_hir(infrastructure, source_file, dependency_file, &[], &emitter)?;
+    let (module, module_interface, coverage, analysis_warnings) = hir_mir::compile_with_coverage(
+        &module,
+        &prelude_type_configuration_qualifier::qualify(&compile_configuration.hir, PRELUDE_PREFIX),
+        test_module_configuration,
+    )?;
+    warnings.extend(analysis_warnings);
+
+    compile_mir_module(
+        infrastructure,
+        &module,
+        &external_definitions,
+        object_file,
+        target_triple,
+        compile_configuration,
+        &emitter,
+    )?;
+    infrastructure.file_system.write(
+        interface_file,
+        &interface_serializer::serialize(&module_interface)?,
+    )?;
+    infrastructure.file_system.write(
+        &object_file.with_extension(
+            infrastructure
+                .file_path_configuration
+                .coverage_file_extension,
+        ),
+        &coverage_serializer::serialize(&coverage)?,
+    )?;
+
+    write_warnings(
+        infrastructure,
+        source_file,
+        object_file,
+        &ast_module,
+        &warnings,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -60,6 +143,8 @@ pub fn compile_main(
     compile_configuration: &CompileConfiguration,
     application_configuration: &ApplicationConfiguration,
 ) -> Result<(), Box<dyn Error>> {
+    clear_warnings(infrastructure, object_file)?;
+
     let context_interfaces = context_interface_files
         .iter()
         .map(|(key, file)| {
@@ -70,30 +155,40 @@ pub fn compile_main(
         })
         .collect::<Result<FnvHashMap<_, _>, Box<dyn Error>>>()?;
 
-    compile_mir_module(
+    let (module, ast_module, mut warnings, external_definitions) = compile_to_hir(
         infrastructure,
-        &hir_mir::compile_main(
-            &compile_to_hir(
-                infrastructure,
-                source_file,
-                dependency_file,
-                &context_interfaces.values().cloned().collect::<Vec<_>>(),
-            )?,
-            &prelude_type_configuration_qualifier::qualify(
-                &compile_configuration.hir,
-                PRELUDE_PREFIX,
-            ),
-            &main_module_configuration_qualifier::qualify(
-                &application_configuration.main_module,
-                &context_interfaces,
-            )?,
+        source_file,
+        dependency_file,
+        &context_interfaces.values().cloned().collect::<Vec<_>>(),
+        &Emitter::new(infrastructure, object_file, &[]),
+    )?;
+    let (module, analysis_warnings) = hir_mir::compile_main(
+        &module,
+        &prelude_type_configuration_qualifier::qualify(&compile_configuration.hir, PRELUDE_PREFIX),
+        &main_module_configuration_qualifier::qualify(
+            &application_configuration.main_module,
+            &context_interfaces,
         )?,
+    )?;
+    warnings.extend(analysis_warnings);
+
+    compile_mir_module(
+        infrastructure,
+        &module,
+        &external_definitions,
         object_file,
         target_triple,
         compile_configuration,
+        &Emitter::new(infrastructure, object_file, &[]),
     )?;
 
-    Ok(())
+    write_warnings(
+        infrastructure,
+        source_file,
+        object_file,
+        &ast_module,
+        &warnings,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -107,18 +202,30 @@ pub fn compile_test(
     compile_configuration: &CompileConfiguration,
     test_module_configuration: &TestModuleConfiguration,
 ) -> Result<(), Box<dyn Error>> {
-    let (module, test_information) = hir_mir::compile_test(
-        &compile_to_hir(infrastructure, source_file, dependency_file, &[])?,
+    clear_warnings(infrastructure, object_file)?;
+
+    let (module, ast_module, mut warnings, external_definitions) = compile_to_hir(
+        infrastructure,
+        source_file,
+        dependency_file,
+        &[],
+        &Emitter::new(infrastructure, object_file, &[]),
+    )?;
+    let (module, test_information, analysis_warnings) = hir_mir::compile_test(
+        &module,
         &prelude_type_configuration_qualifier::qualify(&compile_configuration.hir, PRELUDE_PREFIX),
         test_module_configuration,
     )?;
+    warnings.extend(analysis_warnings);
 
     compile_mir_module(
         infrastructure,
         &module,
+        &external_definitions,
         object_file,
         target_triple,
         compile_configuration,
+        &Emitter::new(infrastructure, object_file, &[]),
     )?;
 
     infrastructure.file_system.write(
@@ -126,7 +233,13 @@ pub fn compile_test(
         &module_test_information_serializer::serialize(&test_information)?,
     )?;
 
-    Ok(())
+    write_warnings(
+        infrastructure,
+        source_file,
+        object_file,
+        &ast_module,
+        &warnings,
+    )
 }
 
 fn compile_to_hir(
@@ -134,7 +247,16 @@ fn compile_to_hir(
     source_file: &FilePath,
     dependency_file: &FilePath,
     context_interfaces: &[interface::Module],
-) -> Result<hir::ir::Module, Box<dyn Error>> {
+    emitter: &Emitter,
+) -> Result<
+    (
+        hir::ir::Module,
+        ast::Module,
+        Vec<AnalysisWarning>,
+        Vec<mir::ir::FunctionDefinition>,
+    ),
+    Box<dyn Error>,
+> {
     let (interface_files, prelude_interface_files) = dependency_serializer::deserialize(
         &infrastructure.file_system.read_to_vec(dependency_file)?,
     )?;
@@ -144,31 +266,109 @@ fn compile_to_hir(
         &infrastructure.file_path_displayer.display(source_file),
     )?;
 
-    Ok(ast_hir::compile(
+    emitter.emit(EmitKind::Ast, || {
+        Ok(format::format(
+            &ast_module,
+            &parse::parse_comments(
+                &infrastructure.file_system.read_to_string(source_file)?,
+                &infrastructure.file_path_displayer.display(source_file),
+            )?,
+        )
+        .into_bytes())
+    })?;
+
+    let module_interfaces = ast_module
+        .imports()
+        .iter()
+        .map(|import| {
+            Ok((
+                import.module_path().clone(),
+                interface_serializer::deserialize(
+                    &infrastructure
+                        .file_system
+                        .read_to_vec(&interface_files[import.module_path()].clone())?,
+                )?,
+            ))
+        })
+        .collect::<Result<FnvHashMap<_, _>, Box<dyn Error>>>()?;
+    let prelude_module_interfaces = prelude_interface_files
+        .iter()
+        .map(|file| {
+            interface_serializer::deserialize(&infrastructure.file_system.read_to_vec(file)?)
+        })
+        .chain(context_interfaces.iter().cloned().map(Ok))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (module, warnings) = ast_hir::compile(
         &ast_module,
         &format!("{source_file}:"),
-        &ast_module
-            .imports()
-            .iter()
-            .map(|import| {
-                Ok((
-                    import.module_path().clone(),
-                    interface_serializer::deserialize(
-                        &infrastructure
-                            .file_system
-                            .read_to_vec(&interface_files[import.module_path()].clone())?,
-                    )?,
-                ))
-            })
-            .collect::<Result<_, Box<dyn Error>>>()?,
-        &prelude_interface_files
-            .iter()
-            .map(|file| {
-                interface_serializer::deserialize(&infrastructure.file_system.read_to_vec(file)?)
-            })
-            .chain(context_interfaces.iter().cloned().map(Ok))
-            .collect::<Result<Vec<_>, _>>()?,
-    )?)
+        &module_interfaces,
+        &prelude_module_interfaces,
+    )?;
+
+    emitter.emit(EmitKind::Hir, || {
+        Ok(hir::analysis::module_formatter::format(&module).into_bytes())
+    })?;
+
+    Ok((
+        module,
+        ast_module,
+        warnings,
+        module_interfaces
+            .values()
+            .chain(&prelude_module_interfaces)
+            .flat_map(|interface| interface.function_definitions())
+            .cloned()
+            .collect(),
+    ))
+}
+
+// Warnings are written next to object files so that they are reported even if
+// modules are not recompiled. They are suppressed by directive comments in
+// source files.
+fn write_warnings(
+    infrastructure: &Infrastructure,
+    source_file: &FilePath,
+    object_file: &FilePath,
+    module: &ast::Module,
+    warnings: &[AnalysisWarning],
+) -> Result<(), Box<dyn Error>> {
+    write_warning_file(
+        infrastructure,
+        object_file,
+        &diagnostic_collector::collect_warnings(&warning_suppressor::suppress(
+            module,
+            &parse::parse_comments(
+                &infrastructure.file_system.read_to_string(source_file)?,
+                &infrastructure.file_path_displayer.display(source_file),
+            )?,
+            warnings,
+        )),
+    )
+}
+
+// Warnings of previous compilation are cleared first so that they are not
+// reported after compilation fails.
+fn clear_warnings(
+    infrastructure: &Infrastructure,
+    object_file: &FilePath,
+) -> Result<(), Box<dyn Error>> {
+    write_warning_file(infrastructure, object_file, &[])
+}
+
+fn write_warning_file(
+    infrastructure: &Infrastructure,
+    object_file: &FilePath,
+    diagnostics: &[Diagnostic],
+) -> Result<(), Box<dyn Error>> {
+    infrastructure.file_system.write(
+        &object_file.with_extension(
+            infrastructure
+                .file_path_configuration
+                .warning_file_extension,
+        ),
+        &warning_serializer::serialize(diagnostics)?,
+    )
 }
 
 pub fn compile_prelude(
@@ -179,20 +379,16 @@ pub fn compile_prelude(
     target_triple: Option<&str>,
     compile_configuration: &CompileConfiguration,
 ) -> Result<(), Box<dyn Error>> {
-    let (module, module_interface) = hir_mir::compile_prelude(&ast_hir::compile_prelude(
-        &parse::parse(
-            &infrastructure.file_system.read_to_string(source_file)?,
-            &infrastructure.file_path_displayer.display(source_file),
-        )?,
-        PRELUDE_PREFIX,
-    )?)?;
+    let (module, module_interface) = compile_prelude_to_mir(infrastructure, source_file)?;
 
     compile_mir_module(
         infrastructure,
         &module,
+        &[],
         object_file,
         target_triple,
         compile_configuration,
+        &Emitter::new(infrastructure, object_file, &[]),
     )?;
     infrastructure.file_system.write(
         interface_file,
@@ -202,21 +398,83 @@ pub fn compile_prelude(
     Ok(())
 }
 
+pub(crate) fn compile_prelude_to_mir(
+    infrastructure: &Infrastructure,
+    source_file: &FilePath,
+) -> Result<(mir::ir::Module, interface::Module), Box<dyn Error>> {
+    Ok(hir_mir::compile_prelude(&ast_hir::compile_prelude(
+        &parse::parse(
+            &infrastructure.file_system.read_to_string(source_file)?,
+            &infrastructure.file_path_displayer.display(source_file),
+        )?,
+        PRELUDE_PREFIX,
+    )?)?)
+}
+
+pub(crate) fn qualify_hir_configuration(
+    compile_configuration: &CompileConfiguration,
+) -> HirConfiguration {
+    prelude_type_configuration_qualifier::qualify(&compile_configuration.hir, PRELUDE_PREFIX)
+}
+
 fn compile_mir_module(
     infrastructure: &Infrastructure,
     module: &mir::ir::Module,
+    external_definitions: &[mir::ir::FunctionDefinition],
     object_file: &FilePath,
     target_triple: Option<&str>,
     compile_configuration: &CompileConfiguration,
+    emitter: &Emitter,
 ) -> Result<(), Box<dyn Error>> {
-    let mut module = mir_fmm::compile(module, &compile_configuration.mir)?;
+    let mut mir_modules = vec![];
+    let mut format_mir_module = |name: &str, module: &mir::ir::Module| {
+        if emitter.is_enabled(EmitKind::Mir) {
+            mir_modules.push(format!(
+                "# {name}\n\n{}",
+                mir::analysis::format::format_module(module)
+            ));
+        }
+    };
+
+    // Definitions of functions in other modules are imported only for
+    // optimization.
+    let module = if compile_configuration.mir.optimize {
+        mir::analysis::optimization::external_definition::import(module, external_definitions)
+    } else {
+        module.clone()
+    };
+
+    format_mir_module("input", &module);
+
+    let mut module =
+        mir_fmm::compile_with_passes(&module, &compile_configuration.mir, &mut format_mir_module)?;
+
+    emitter.emit(EmitKind::Mir, || Ok(mir_modules.join("\n").into_bytes()))?;
 
     fmm::analysis::cps::transform(&mut module, fmm::types::void_type())?;
     fmm::analysis::c_calling_convention::transform(&mut module, word_bytes(target_triple)?)?;
     fmm::analysis::validation::validate(&module)?;
 
+    emitter.emit(EmitKind::Fmm, || {
+        Ok(fmm::analysis::format::format_module(&module).into_bytes())
+    })?;
+    emitter.emit(EmitKind::C, || {
+        Ok(fmm_c::compile(&module, None).into_bytes())
+    })?;
+
     let module = fmm_llvm::compile_to_bit_code(&module, &compile_configuration.fmm, target_triple)?;
 
+    emitter.emit(EmitKind::Llvm, || {
+        let context = inkwell::context::Context::create();
+
+        Ok(inkwell::module::Module::parse_bitcode_from_buffer(
+            &inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(&module, "module"),
+            &context,
+        )?
+        .print_to_string()
+        .to_string()
+        .into_bytes())
+    })?;
     infrastructure.file_system.write(object_file, &module)?;
 
     Ok(())
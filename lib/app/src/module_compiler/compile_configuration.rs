@@ -13,3 +13,4 @@ pub type MapTypeConfiguration = hir_mir::MapTypeConfiguration;
 pub type MapTypeIterationConfiguration = hir_mir::MapTypeIterationConfiguration;
 pub type NumberTypeConfiguration = hir_mir::NumberTypeConfiguration;
 pub type StringTypeConfiguration = hir_mir::StringTypeConfiguration;
+pub type VectorTypeConfiguration = hir_mir::VectorTypeConfiguration;
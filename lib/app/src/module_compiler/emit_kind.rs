@@ -0,0 +1,30 @@
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum EmitKind {
+    Ast,
+    Hir,
+    Mir,
+    Fmm,
+    Llvm,
+    C,
+}
+
+impl EmitKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ast => "ast",
+            Self::Hir => "hir",
+            Self::Mir => "mir",
+            Self::Fmm => "fmm",
+            Self::Llvm => "llvm",
+            Self::C => "c",
+        }
+    }
+
+    pub(super) fn file_extension(&self) -> &'static str {
+        match self {
+            // LLVM IR is emitted in a textual format.
+            Self::Llvm => "ll",
+            _ => self.name(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::emit_kind::EmitKind;
+use crate::infra::{FilePath, Infrastructure};
+use std::error::Error;
+
+// An emitter writes intermediate representations of a module next to its
+// object file.
+pub struct Emitter<'a> {
+    infrastructure: &'a Infrastructure,
+    object_file: &'a FilePath,
+    kinds: &'a [EmitKind],
+}
+
+impl<'a> Emitter<'a> {
+    pub fn new(
+        infrastructure: &'a Infrastructure,
+        object_file: &'a FilePath,
+        kinds: &'a [EmitKind],
+    ) -> Self {
+        Self {
+            infrastructure,
+            object_file,
+            kinds,
+        }
+    }
+
+    pub fn is_enabled(&self, kind: EmitKind) -> bool {
+        self.kinds.contains(&kind)
+    }
+
+    pub fn emit(
+        &self,
+        kind: EmitKind,
+        compile: impl FnOnce() -> Result<Vec<u8>, Box<dyn Error>>,
+    ) -> Result<(), Box<dyn Error>> {
+        if self.is_enabled(kind) {
+            self.infrastructure.file_system.write(
+                &self.object_file.with_extension(kind.file_extension()),
+                &compile()?,
+            )?;
+        }
+
+        Ok(())
+    }
+}
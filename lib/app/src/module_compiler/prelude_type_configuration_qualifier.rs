@@ -7,9 +7,11 @@ pub fn qualify(
         map_type: qualify_map_type_configuration(&configuration.map_type, prelude_prefix),
         number_type: qualify_number_type_configuration(&configuration.number_type, prelude_prefix),
         string_type: qualify_string_type_configuration(&configuration.string_type, prelude_prefix),
+        vector_type: qualify_vector_type_configuration(&configuration.vector_type, prelude_prefix),
         spawn_function_name: configuration.spawn_function_name.clone(),
         race_function_name: configuration.race_function_name.clone(),
         debug_function_name: configuration.debug_function_name.clone(),
+        debug_information: configuration.debug_information,
     }
 }
 
@@ -34,6 +36,8 @@ fn qualify_list_type_configuration(
         first_rest_type_name: prelude_prefix.to_owned() + &configuration.first_rest_type_name,
         size_function_name: prelude_prefix.to_owned() + &configuration.size_function_name,
         debug_function_name: prelude_prefix.to_owned() + &configuration.debug_function_name,
+        encode_function_name: prelude_prefix.to_owned() + &configuration.encode_function_name,
+        decode_function_name: prelude_prefix.to_owned() + &configuration.decode_function_name,
     }
 }
 
@@ -42,6 +46,7 @@ fn qualify_string_type_configuration(
     prelude_prefix: &str,
 ) -> hir_mir::StringTypeConfiguration {
     hir_mir::StringTypeConfiguration {
+        compare_function_name: prelude_prefix.to_owned() + &configuration.compare_function_name,
         equal_function_name: prelude_prefix.to_owned() + &configuration.equal_function_name,
     }
 }
@@ -65,6 +70,8 @@ fn qualify_map_type_configuration(
         set_function_name: prelude_prefix.to_owned() + &configuration.set_function_name,
         size_function_name: prelude_prefix.to_owned() + &configuration.size_function_name,
         debug_function_name: prelude_prefix.to_owned() + &configuration.debug_function_name,
+        encode_function_name: prelude_prefix.to_owned() + &configuration.encode_function_name,
+        decode_function_name: prelude_prefix.to_owned() + &configuration.decode_function_name,
         hash: qualify_hash_configuration(&configuration.hash, prelude_prefix),
         iteration: qualify_map_type_iteration_configuration(
             &configuration.iteration,
@@ -73,6 +80,25 @@ fn qualify_map_type_configuration(
     }
 }
 
+fn qualify_vector_type_configuration(
+    configuration: &hir_mir::VectorTypeConfiguration,
+    prelude_prefix: &str,
+) -> hir_mir::VectorTypeConfiguration {
+    hir_mir::VectorTypeConfiguration {
+        empty_function_name: prelude_prefix.to_owned() + &configuration.empty_function_name,
+        equal_function_name: prelude_prefix.to_owned() + &configuration.equal_function_name,
+        maybe_equal_function_name: prelude_prefix.to_owned()
+            + &configuration.maybe_equal_function_name,
+        get_function_name: prelude_prefix.to_owned() + &configuration.get_function_name,
+        push_function_name: prelude_prefix.to_owned() + &configuration.push_function_name,
+        set_function_name: prelude_prefix.to_owned() + &configuration.set_function_name,
+        slice_function_name: prelude_prefix.to_owned() + &configuration.slice_function_name,
+        size_function_name: prelude_prefix.to_owned() + &configuration.size_function_name,
+        debug_function_name: prelude_prefix.to_owned() + &configuration.debug_function_name,
+        vector_type_name: prelude_prefix.to_owned() + &configuration.vector_type_name,
+    }
+}
+
 fn qualify_number_type_configuration(
     configuration: &hir_mir::NumberTypeConfiguration,
     prelude_prefix: &str,
@@ -94,6 +120,8 @@ fn qualify_hash_configuration(
             + &configuration.string_hash_function_name,
         list_hash_function_name: prelude_prefix.to_owned() + &configuration.list_hash_function_name,
         map_hash_function_name: prelude_prefix.to_owned() + &configuration.map_hash_function_name,
+        vector_hash_function_name: prelude_prefix.to_owned()
+            + &configuration.vector_hash_function_name,
     }
 }
 
@@ -2,6 +2,7 @@ use crate::{
     common::{dependency_serializer, file_path_resolver, module_id_calculator},
     error::ApplicationError,
     infra::{FilePath, Infrastructure, OBJECT_DIRECTORY},
+    PackageConfiguration,
 };
 use std::{collections::BTreeMap, error::Error};
 
@@ -27,24 +28,13 @@ pub fn resolve(
     .imports()
     .iter()
     .map(|import| {
-        let source_file = match import.module_path() {
-            ast::ModulePath::Internal(path) => file_path_resolver::resolve_source_file(
-                package_directory,
-                path.components(),
-                &infrastructure.file_path_configuration,
-            ),
-            ast::ModulePath::External(path) => file_path_resolver::resolve_source_file(
-                &file_path_resolver::resolve_package_directory(
-                    output_directory,
-                    package_configuration
-                        .dependencies()
-                        .get(path.package())
-                        .ok_or_else(|| ApplicationError::PackageNotFound(path.package().into()))?,
-                ),
-                path.components(),
-                &infrastructure.file_path_configuration,
-            ),
-        };
+        let source_file = resolve_source_file(
+            infrastructure,
+            package_directory,
+            output_directory,
+            &package_configuration,
+            import.module_path(),
+        )?;
 
         if !infrastructure.file_system.exists(&source_file) {
             return Err(ApplicationError::ModuleNotFound(import.module_path().to_string()).into());
@@ -89,3 +79,31 @@ pub fn resolve(
 
     Ok(())
 }
+
+pub(crate) fn resolve_source_file(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    package_configuration: &PackageConfiguration,
+    module_path: &ast::ModulePath,
+) -> Result<FilePath, ApplicationError> {
+    Ok(match module_path {
+        ast::ModulePath::Internal(path) => file_path_resolver::resolve_source_file(
+            package_directory,
+            path.components(),
+            &infrastructure.file_path_configuration,
+        ),
+        ast::ModulePath::External(path) => file_path_resolver::resolve_source_file(
+            &file_path_resolver::resolve_package_directory(
+                output_directory,
+                package_configuration
+                    .dependencies()
+                    .get(path.package())
+                    .ok_or_else(|| ApplicationError::PackageNotFound(path.package().into()))?
+                    .url(),
+            ),
+            path.components(),
+            &infrastructure.file_path_configuration,
+        ),
+    })
+}
@@ -24,14 +24,58 @@ impl Display for PackageType {
     }
 }
 
+// A git reference of a package
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PackageReference {
+    Branch(String),
+    Commit(String),
+    Tag(String),
+}
+
+impl Display for PackageReference {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Branch(name) => write!(formatter, "branch {name}"),
+            Self::Commit(hash) => write!(formatter, "commit {hash}"),
+            Self::Tag(name) => write!(formatter, "tag {name}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackageDependency {
+    url: url::Url,
+    reference: Option<PackageReference>,
+}
+
+impl PackageDependency {
+    pub fn new(url: url::Url, reference: Option<PackageReference>) -> Self {
+        Self { url, reference }
+    }
+
+    pub fn url(&self) -> &url::Url {
+        &self.url
+    }
+
+    pub fn reference(&self) -> Option<&PackageReference> {
+        self.reference.as_ref()
+    }
+}
+
+impl From<url::Url> for PackageDependency {
+    fn from(url: url::Url) -> Self {
+        Self::new(url, None)
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PackageConfiguration {
     type_: PackageType,
-    dependencies: BTreeMap<String, url::Url>,
+    dependencies: BTreeMap<String, PackageDependency>,
 }
 
 impl PackageConfiguration {
-    pub fn new(type_: PackageType, dependencies: BTreeMap<String, url::Url>) -> Self {
+    pub fn new(type_: PackageType, dependencies: BTreeMap<String, PackageDependency>) -> Self {
         Self {
             type_,
             dependencies,
@@ -42,7 +86,14 @@ impl PackageConfiguration {
         self.type_
     }
 
-    pub fn dependencies(&self) -> &BTreeMap<String, url::Url> {
+    pub fn dependencies(&self) -> &BTreeMap<String, PackageDependency> {
         &self.dependencies
     }
+
+    pub fn set_dependencies(&self, dependencies: BTreeMap<String, PackageDependency>) -> Self {
+        Self {
+            dependencies,
+            ..self.clone()
+        }
+    }
 }
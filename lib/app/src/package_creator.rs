@@ -1,7 +1,7 @@
 use crate::{
     common::file_path_resolver,
     infra::{FilePath, Infrastructure},
-    package_configuration::{PackageConfiguration, PackageType},
+    package_configuration::{PackageConfiguration, PackageDependency, PackageType},
     ApplicationConfiguration,
 };
 use std::{collections::BTreeMap, error::Error};
@@ -17,9 +17,12 @@ pub fn create_application(
     create(
         infrastructure,
         PackageType::Application,
-        &[(system_package_name.into(), system_package_url.clone())]
-            .into_iter()
-            .collect(),
+        &[(
+            system_package_name.into(),
+            system_package_url.clone().into(),
+        )]
+        .into_iter()
+        .collect(),
         &application_configuration.main_module_basename,
         module_content,
         package_directory,
@@ -45,7 +48,7 @@ pub fn create_library(
 fn create(
     infrastructure: &Infrastructure,
     package_type: PackageType,
-    dependencies: &BTreeMap<String, url::Url>,
+    dependencies: &BTreeMap<String, PackageDependency>,
     module_basename: &str,
     module_content: &str,
     package_directory: &FilePath,
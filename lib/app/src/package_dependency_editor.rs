@@ -0,0 +1,45 @@
+use crate::{
+    error::ApplicationError,
+    infra::{FilePath, Infrastructure},
+    package_configuration::PackageDependency,
+};
+use std::error::Error;
+
+pub fn add(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    name: &str,
+    dependency: &PackageDependency,
+) -> Result<(), Box<dyn Error>> {
+    let configuration = infrastructure
+        .package_configuration_reader
+        .read(package_directory)?;
+    let mut dependencies = configuration.dependencies().clone();
+
+    dependencies.insert(name.into(), dependency.clone());
+
+    infrastructure.package_configuration_writer.write(
+        &configuration.set_dependencies(dependencies),
+        package_directory,
+    )
+}
+
+pub fn remove(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    name: &str,
+) -> Result<(), Box<dyn Error>> {
+    let configuration = infrastructure
+        .package_configuration_reader
+        .read(package_directory)?;
+    let mut dependencies = configuration.dependencies().clone();
+
+    if dependencies.remove(name).is_none() {
+        return Err(ApplicationError::PackageNotFound(name.into()).into());
+    }
+
+    infrastructure.package_configuration_writer.write(
+        &configuration.set_dependencies(dependencies),
+        package_directory,
+    )
+}
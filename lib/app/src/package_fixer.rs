@@ -0,0 +1,95 @@
+use crate::{
+    common::module_fixer,
+    infra::{FilePath, Infrastructure},
+    language_server::{self, Document},
+    module_finder, ApplicationConfiguration,
+};
+use std::error::Error;
+
+// Fix modules in a package and return a unified diff of their changes.
+//
+// Interfaces of modules and their dependencies need to be built beforehand.
+// Modules with errors are left unchanged.
+pub fn fix(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+    dry_run: bool,
+) -> Result<String, Box<dyn Error>> {
+    let mut diff = String::new();
+
+    for path in module_finder::find(infrastructure, package_directory)? {
+        let old_source = infrastructure.file_system.read_to_string(&path)?;
+        let source = fix_module(
+            infrastructure,
+            &Document {
+                package_directory,
+                output_directory,
+                source_file: &path,
+                source: &old_source,
+            },
+            prelude_package_url,
+            application_configuration,
+        )?;
+
+        if source == old_source {
+            continue;
+        }
+
+        let path_string = infrastructure.file_path_displayer.display(&path);
+
+        diff.push_str(
+            &similar::TextDiff::from_lines(&old_source, &source)
+                .unified_diff()
+                .header(&format!("a/{path_string}"), &format!("b/{path_string}"))
+                .to_string(),
+        );
+
+        if !dry_run {
+            infrastructure.file_system.write(&path, source.as_bytes())?;
+        }
+    }
+
+    Ok(diff)
+}
+
+// Fixes are applied repeatedly as removal of some definitions can make others
+// unused.
+fn fix_module(
+    infrastructure: &Infrastructure,
+    document: &Document,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<String, Box<dyn Error>> {
+    let path = infrastructure
+        .file_path_displayer
+        .display(document.source_file);
+    let mut source = document.source.to_owned();
+
+    loop {
+        let Ok(warnings) = language_server::collect_warnings(
+            infrastructure,
+            &Document {
+                source: &source,
+                ..*document
+            },
+            prelude_package_url,
+            application_configuration,
+        )?
+        else {
+            return Ok(source);
+        };
+
+        let module = parse::parse(&source, &path)?;
+        let comments = parse::parse_comments(&source, &path)?;
+        let (fixed_module, fixed_comments) = module_fixer::fix(&module, &comments, &warnings);
+
+        if fixed_module == module && fixed_comments == comments {
+            return Ok(source);
+        }
+
+        source = format::format(&fixed_module, &fixed_comments);
+    }
+}
@@ -1,7 +1,10 @@
 mod external_package_initializer;
 
-use crate::infra::{FilePath, Infrastructure};
-use std::error::Error;
+use crate::{
+    infra::{FilePath, Infrastructure},
+    package_lock::PackageLock,
+};
+use std::{collections::BTreeMap, error::Error};
 
 pub fn initialize(
     infrastructure: &Infrastructure,
@@ -10,18 +13,41 @@ pub fn initialize(
     prelude_package_url: &url::Url,
     ffi_package_url: &url::Url,
 ) -> Result<(), Box<dyn Error>> {
+    let lock = infrastructure.package_lock_reader.read(package_directory)?;
+    let mut locked_packages = BTreeMap::new();
+
     external_package_initializer::initialize_prelude(
         infrastructure,
+        package_directory,
         prelude_package_url,
         output_directory,
     )?;
-    external_package_initializer::initialize(infrastructure, ffi_package_url, output_directory)?;
+    external_package_initializer::initialize(
+        infrastructure,
+        package_directory,
+        &ffi_package_url.clone().into(),
+        output_directory,
+        &lock,
+        &mut locked_packages,
+    )?;
 
     external_package_initializer::initialize_dependencies(
         infrastructure,
         package_directory,
+        package_directory,
         output_directory,
+        &lock,
+        &mut locked_packages,
     )?;
 
+    // Unused packages are removed from a lock file here.
+    let locked_packages = PackageLock::new(locked_packages);
+
+    if locked_packages != lock {
+        infrastructure
+            .package_lock_writer
+            .write(&locked_packages, package_directory)?;
+    }
+
     Ok(())
 }
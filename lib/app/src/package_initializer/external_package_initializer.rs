@@ -1,22 +1,35 @@
 use crate::{
     common::file_path_resolver,
+    error::ApplicationError,
     infra::{FilePath, Infrastructure},
     package_build_script_compiler,
+    package_configuration::{PackageDependency, PackageReference},
+    package_lock::{LockedPackage, PackageLock},
 };
-use std::error::Error;
+use std::{collections::BTreeMap, error::Error};
 
 pub fn initialize_dependencies(
     infrastructure: &Infrastructure,
+    main_package_directory: &FilePath,
     package_directory: &FilePath,
     output_directory: &FilePath,
+    lock: &PackageLock,
+    locked_packages: &mut BTreeMap<url::Url, LockedPackage>,
 ) -> Result<(), Box<dyn Error>> {
-    for url in infrastructure
+    for dependency in infrastructure
         .package_configuration_reader
         .read(package_directory)?
         .dependencies()
         .values()
     {
-        initialize(infrastructure, url, output_directory)?;
+        initialize(
+            infrastructure,
+            main_package_directory,
+            dependency,
+            output_directory,
+            lock,
+            locked_packages,
+        )?;
     }
 
     Ok(())
@@ -24,15 +37,46 @@ pub fn initialize_dependencies(
 
 pub fn initialize(
     infrastructure: &Infrastructure,
-    package_url: &url::Url,
+    main_package_directory: &FilePath,
+    dependency: &PackageDependency,
     output_directory: &FilePath,
+    lock: &PackageLock,
+    locked_packages: &mut BTreeMap<url::Url, LockedPackage>,
 ) -> Result<(), Box<dyn Error>> {
+    let package_url = dependency.url();
+
+    // Resolve packages in git repositories only once even if they are shared by
+    // multiple dependents.
+    if let Some(locked_package) = locked_packages.get(package_url) {
+        if locked_package.reference() != dependency.reference() {
+            return Err(ApplicationError::PackageReferencesConflicted(
+                package_url.to_string(),
+                format_reference(locked_package.reference()),
+                format_reference(dependency.reference()),
+            )
+            .into());
+        }
+
+        return Ok(());
+    }
+
     let package_directory =
         file_path_resolver::resolve_package_directory(output_directory, package_url);
 
-    infrastructure
-        .external_package_initializer
-        .initialize(package_url, &package_directory)?;
+    if let Some(locked_package) = infrastructure.external_package_initializer.initialize(
+        package_url,
+        dependency.reference(),
+        lock.packages()
+            .get(package_url)
+            .filter(|package| package.reference() == dependency.reference()),
+        &package_directory,
+        &file_path_resolver::resolve_vendored_package_directory(
+            main_package_directory,
+            package_url,
+        ),
+    )? {
+        locked_packages.insert(package_url.clone(), locked_package);
+    }
 
     package_build_script_compiler::compile_external(
         infrastructure,
@@ -47,8 +91,11 @@ pub fn initialize(
 
     initialize_dependencies(
         infrastructure,
-        &file_path_resolver::resolve_package_directory(output_directory, package_url),
+        main_package_directory,
+        &package_directory,
         output_directory,
+        lock,
+        locked_packages,
     )?;
 
     Ok(())
@@ -56,15 +103,23 @@ pub fn initialize(
 
 pub fn initialize_prelude(
     infrastructure: &Infrastructure,
+    main_package_directory: &FilePath,
     package_url: &url::Url,
     output_directory: &FilePath,
 ) -> Result<(), Box<dyn Error>> {
     let package_directory =
         file_path_resolver::resolve_package_directory(output_directory, package_url);
 
-    infrastructure
-        .external_package_initializer
-        .initialize(package_url, &package_directory)?;
+    infrastructure.external_package_initializer.initialize(
+        package_url,
+        None,
+        None,
+        &package_directory,
+        &file_path_resolver::resolve_vendored_package_directory(
+            main_package_directory,
+            package_url,
+        ),
+    )?;
 
     package_build_script_compiler::compile_prelude(
         infrastructure,
@@ -79,3 +134,9 @@ pub fn initialize_prelude(
 
     Ok(())
 }
+
+fn format_reference(reference: Option<&PackageReference>) -> String {
+    reference
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "default branch".into())
+}
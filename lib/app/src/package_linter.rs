@@ -0,0 +1,32 @@
+use crate::{
+    error::ApplicationError,
+    infra::{FilePath, Infrastructure},
+    package_warning_collector, Diagnostic, DiagnosticSeverity,
+};
+use std::error::Error;
+
+// Lint modules in a package.
+//
+// Modules in a package need to be built beforehand so that their warnings are
+// collected from their last compilation without compiling them again.
+pub fn lint(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+    package_warning_collector::collect(infrastructure, package_directory, output_directory)
+}
+
+// Fail if diagnostics contain any errors or warnings.
+pub fn deny_warnings(diagnostics: &[Diagnostic]) -> Result<(), Box<dyn Error>> {
+    let count = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity() != DiagnosticSeverity::Information)
+        .count();
+
+    if count == 0 {
+        Ok(())
+    } else {
+        Err(ApplicationError::WarningsDenied(count).into())
+    }
+}
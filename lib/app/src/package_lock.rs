@@ -0,0 +1,51 @@
+use crate::package_configuration::PackageReference;
+use std::collections::BTreeMap;
+
+// A package fetched from a git repository and pinned to a commit
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockedPackage {
+    reference: Option<PackageReference>,
+    commit: String,
+    hash: String,
+}
+
+impl LockedPackage {
+    pub fn new(
+        reference: Option<PackageReference>,
+        commit: impl Into<String>,
+        hash: impl Into<String>,
+    ) -> Self {
+        Self {
+            reference,
+            commit: commit.into(),
+            hash: hash.into(),
+        }
+    }
+
+    pub fn reference(&self) -> Option<&PackageReference> {
+        self.reference.as_ref()
+    }
+
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PackageLock {
+    packages: BTreeMap<url::Url, LockedPackage>,
+}
+
+impl PackageLock {
+    pub fn new(packages: BTreeMap<url::Url, LockedPackage>) -> Self {
+        Self { packages }
+    }
+
+    pub fn packages(&self) -> &BTreeMap<url::Url, LockedPackage> {
+        &self.packages
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{
+    error::ApplicationError,
+    infra::{FilePath, Infrastructure},
+    package_initializer,
+    package_lock::PackageLock,
+};
+use std::error::Error;
+
+// Update packages of given names or all packages if no name is given.
+pub fn update(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    names: &[&str],
+    prelude_package_url: &url::Url,
+    ffi_package_url: &url::Url,
+) -> Result<(), Box<dyn Error>> {
+    let configuration = infrastructure
+        .package_configuration_reader
+        .read(package_directory)?;
+    let urls = names
+        .iter()
+        .map(|&name| {
+            Ok(configuration
+                .dependencies()
+                .get(name)
+                .ok_or_else(|| ApplicationError::PackageNotFound(name.into()))?
+                .url())
+        })
+        .collect::<Result<Vec<_>, ApplicationError>>()?;
+
+    infrastructure.package_lock_writer.write(
+        &PackageLock::new(
+            infrastructure
+                .package_lock_reader
+                .read(package_directory)?
+                .packages()
+                .iter()
+                .filter(|(url, _)| !names.is_empty() && !urls.contains(url))
+                .map(|(url, package)| (url.clone(), package.clone()))
+                .collect(),
+        ),
+        package_directory,
+    )?;
+
+    package_initializer::initialize(
+        infrastructure,
+        package_directory,
+        output_directory,
+        prelude_package_url,
+        ffi_package_url,
+    )
+}
@@ -0,0 +1,39 @@
+use crate::{
+    common::file_path_resolver,
+    external_package_configuration_reader,
+    infra::{FilePath, Infrastructure},
+    package_initializer,
+};
+use std::error::Error;
+
+pub fn vendor(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    prelude_package_url: &url::Url,
+    ffi_package_url: &url::Url,
+) -> Result<(), Box<dyn Error>> {
+    package_initializer::initialize(
+        infrastructure,
+        package_directory,
+        output_directory,
+        prelude_package_url,
+        ffi_package_url,
+    )?;
+
+    for url in external_package_configuration_reader::read_all(
+        infrastructure,
+        package_directory,
+        output_directory,
+    )?
+    .keys()
+    {
+        infrastructure.external_package_initializer.vendor(
+            url,
+            &file_path_resolver::resolve_package_directory(output_directory, url),
+            &file_path_resolver::resolve_vendored_package_directory(package_directory, url),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::{
+    common::{file_path_resolver, warning_serializer},
+    infra::{FilePath, Infrastructure},
+    module_finder, test_module_finder, Diagnostic,
+};
+use std::error::Error;
+
+// Collect warnings of modules in a package written by their last compilation.
+//
+// Modules in a package need to be built beforehand.
+pub fn collect(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+    let mut diagnostics = vec![];
+
+    for source_file in module_finder::find(infrastructure, package_directory)?
+        .into_iter()
+        .chain(test_module_finder::find(infrastructure, package_directory)?)
+    {
+        let warning_file = file_path_resolver::resolve_warning_file(
+            output_directory,
+            &source_file,
+            &infrastructure.file_path_configuration,
+        );
+
+        if !infrastructure.file_system.exists(&warning_file) {
+            continue;
+        }
+
+        diagnostics.extend(warning_serializer::deserialize(
+            &infrastructure.file_system.read_to_vec(&warning_file)?,
+        )?);
+    }
+
+    Ok(diagnostics)
+}
@@ -0,0 +1,102 @@
+mod session;
+
+use crate::{
+    common::file_path_resolver,
+    infra::{FilePath, Infrastructure},
+    module_compiler::{self, CompileConfiguration},
+    module_finder,
+};
+pub use session::Session;
+use std::error::Error;
+
+// Start a REPL session with modules in a prelude package compiled in memory.
+pub fn start(
+    infrastructure: &Infrastructure,
+    main_package_directory: &FilePath,
+    output_directory: &FilePath,
+    prelude_package_url: &url::Url,
+    compile_configuration: &CompileConfiguration,
+) -> Result<Session, Box<dyn Error>> {
+    let package_directory =
+        file_path_resolver::resolve_package_directory(output_directory, prelude_package_url);
+
+    infrastructure.external_package_initializer.initialize(
+        prelude_package_url,
+        None,
+        None,
+        &package_directory,
+        &file_path_resolver::resolve_vendored_package_directory(
+            main_package_directory,
+            prelude_package_url,
+        ),
+    )?;
+
+    let (modules, interfaces) = module_finder::find(infrastructure, &package_directory)?
+        .iter()
+        .map(|source_file| module_compiler::compile_prelude_to_mir(infrastructure, source_file))
+        .collect::<Result<(Vec<_>, Vec<_>), _>>()?;
+
+    Ok(Session::new(
+        modules,
+        interfaces,
+        module_compiler::qualify_hir_configuration(compile_configuration),
+    ))
+}
+
+// Check if an input is complete by matching brackets and quotes so that
+// definitions and expressions can span multiple lines.
+pub fn is_input_complete(input: &str) -> bool {
+    let mut depth = 0isize;
+    let mut characters = input.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '"' => loop {
+                match characters.next() {
+                    Some('"') => break,
+                    Some('\\') => {
+                        characters.next();
+                    }
+                    Some(_) => {}
+                    None => return false,
+                }
+            },
+            '#' => {
+                for character in characters.by_ref() {
+                    if character == '\n' {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    depth <= 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_complete_input() {
+        assert!(is_input_complete(""));
+        assert!(is_input_complete("1 + 2"));
+        assert!(is_input_complete("f = \\() none { none }"));
+        assert!(is_input_complete("\"{\""));
+        assert!(is_input_complete("# {\nx"));
+        assert!(is_input_complete(")"));
+    }
+
+    #[test]
+    fn check_incomplete_input() {
+        assert!(!is_input_complete("f = \\() none {"));
+        assert!(!is_input_complete("[none\n"));
+        assert!(!is_input_complete("\"foo"));
+        assert!(!is_input_complete("\"\\\""));
+        assert!(!is_input_complete("(x # )"));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{error::ApplicationError, module_compiler::HirConfiguration};
+use position::Position;
+use std::error::Error;
+
+const INPUT_PATH: &str = "<repl>";
+const MODULE_PREFIX: &str = "repl:";
+const RESULT_FUNCTION_NAME: &str = "__repl_result";
+
+// A session keeps definitions entered so far and evaluates expressions with
+// them.
+pub struct Session {
+    prelude_modules: Vec<mir::ir::Module>,
+    prelude_interfaces: Vec<interface::Module>,
+    compile_configuration: HirConfiguration,
+    type_definitions: Vec<ast::TypeDefinition>,
+    function_definitions: Vec<ast::FunctionDefinition>,
+}
+
+impl Session {
+    pub fn new(
+        prelude_modules: Vec<mir::ir::Module>,
+        prelude_interfaces: Vec<interface::Module>,
+        compile_configuration: HirConfiguration,
+    ) -> Self {
+        Self {
+            prelude_modules,
+            prelude_interfaces,
+            compile_configuration,
+            type_definitions: vec![],
+            function_definitions: vec![],
+        }
+    }
+
+    // Evaluate definitions or an expression in an input. A result of an
+    // expression is formatted in the same way as the `debug` built-in function.
+    //
+    // Definitions replace previous ones of the same names only if a whole
+    // session still type checks.
+    pub fn evaluate(&mut self, input: &str) -> Result<Option<String>, Box<dyn Error>> {
+        let module_error = match parse::parse(input, INPUT_PATH) {
+            Ok(module) => {
+                self.define(&module)?;
+
+                return Ok(None);
+            }
+            Err(error) => error,
+        };
+        let expression = match parse::parse_expression(input, INPUT_PATH) {
+            Ok(expression) => expression,
+            // An error of a parser which reads an input further is reported.
+            Err(error) => {
+                let location = |error: &parse::ParseError| {
+                    (
+                        error.position().line_number(),
+                        error.position().column_number(),
+                    )
+                };
+
+                return Err(if location(&module_error) > location(&error) {
+                    module_error
+                } else {
+                    error
+                }
+                .into());
+            }
+        };
+
+        Ok(Some(self.evaluate_expression(&expression)?))
+    }
+
+    fn define(&mut self, module: &ast::Module) -> Result<(), Box<dyn Error>> {
+        if !module.imports().is_empty() || !module.foreign_imports().is_empty() {
+            return Err(ApplicationError::ReplImportNotSupported.into());
+        }
+
+        let type_definitions = merge_definitions(
+            &self.type_definitions,
+            module.type_definitions(),
+            |definition| definition.name(),
+        );
+        let function_definitions = merge_definitions(
+            &self.function_definitions,
+            module.function_definitions(),
+            |definition| definition.name(),
+        );
+
+        self.compile(&type_definitions, &function_definitions, module.position())?;
+
+        self.type_definitions = type_definitions;
+        self.function_definitions = function_definitions;
+
+        Ok(())
+    }
+
+    fn evaluate_expression(&self, expression: &ast::Expression) -> Result<String, Box<dyn Error>> {
+        let position = expression.position();
+        let (module, name) = self.compile(
+            &self.type_definitions,
+            &self
+                .function_definitions
+                .iter()
+                .cloned()
+                .chain([ast::FunctionDefinition::new(
+                    RESULT_FUNCTION_NAME,
+                    ast::Lambda::new(
+                        vec![],
+                        Some(ast::types::Reference::new("string", position.clone()).into()),
+                        ast::Block::new(
+                            vec![],
+                            ast::Call::new(
+                                ast::Variable::new("_reflect_debug", position.clone()),
+                                vec![expression.clone()],
+                                position.clone(),
+                            ),
+                            position.clone(),
+                        ),
+                        position.clone(),
+                    ),
+                    None,
+                    position.clone(),
+                )])
+                .collect::<Vec<_>>(),
+            position,
+        )?;
+        let interpreter = mir_interpreter::Interpreter::new(
+            &self
+                .prelude_modules
+                .iter()
+                .cloned()
+                .chain([module])
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(String::from_utf8_lossy(
+            interpreter
+                .call(interpreter.function(&name)?, &[])?
+                .to_byte_string()?,
+        )
+        .into())
+    }
+
+    // Compile definitions into a MIR module and return a name of a result
+    // function in it if any.
+    fn compile(
+        &self,
+        type_definitions: &[ast::TypeDefinition],
+        function_definitions: &[ast::FunctionDefinition],
+        position: &Position,
+    ) -> Result<(mir::ir::Module, String), Box<dyn Error>> {
+        let (module, _) = ast_hir::compile(
+            &ast::Module::new(
+                vec![],
+                vec![],
+                type_definitions.to_vec(),
+                function_definitions.to_vec(),
+                position.clone(),
+            ),
+            MODULE_PREFIX,
+            &Default::default(),
+            &self.prelude_interfaces,
+        )?;
+        let name = module
+            .function_definitions()
+            .iter()
+            .find(|definition| definition.original_name() == RESULT_FUNCTION_NAME)
+            .map(|definition| definition.name().to_owned())
+            .unwrap_or_default();
+        let (module, _, _) = hir_mir::compile(&module, &self.compile_configuration)?;
+
+        Ok((module, name))
+    }
+}
+
+fn merge_definitions<T: Clone>(
+    definitions: &[T],
+    new_definitions: &[T],
+    name: impl Fn(&T) -> &str,
+) -> Vec<T> {
+    definitions
+        .iter()
+        .filter(|definition| {
+            !new_definitions
+                .iter()
+                .any(|other| name(other) == name(definition))
+        })
+        .chain(new_definitions)
+        .cloned()
+        .collect()
+}
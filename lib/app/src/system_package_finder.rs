@@ -25,7 +25,7 @@ pub fn find(
         if configuration.type_() == PackageType::System {
             Some((
                 key.clone(),
-                package_configuration.dependencies()[&key].clone(),
+                package_configuration.dependencies()[&key].url().clone(),
             ))
         } else {
             None
@@ -0,0 +1,209 @@
+use crate::{
+    common::{coverage_serializer, file_path_resolver},
+    error::ApplicationError,
+    infra::{FilePath, Infrastructure},
+    module_finder,
+};
+use std::{collections::BTreeMap, error::Error};
+
+type Counts = BTreeMap<String, BTreeMap<usize, u64>>;
+
+pub fn report(
+    infrastructure: &Infrastructure,
+    main_package_directory: &FilePath,
+    output_directory: &FilePath,
+) -> Result<(), Box<dyn Error>> {
+    let counts = read_counts(infrastructure, output_directory)?;
+    let mut report = String::new();
+
+    for source_file in module_finder::find(infrastructure, main_package_directory)? {
+        let coverage_file = file_path_resolver::resolve_coverage_file(
+            output_directory,
+            &source_file,
+            &infrastructure.file_path_configuration,
+        );
+
+        if !infrastructure.file_system.exists(&coverage_file) {
+            continue;
+        }
+
+        let coverage = coverage_serializer::deserialize(
+            &infrastructure.file_system.read_to_vec(&coverage_file)?,
+        )?;
+
+        report += &compile_record(&coverage, counts.get(coverage.path()));
+    }
+
+    infrastructure.file_system.write(
+        &file_path_resolver::resolve_coverage_report_file(output_directory),
+        report.as_bytes(),
+    )?;
+
+    Ok(())
+}
+
+// Read counts dumped by test processes in a format of `<path>\t<index>\t<count>`.
+fn read_counts(
+    infrastructure: &Infrastructure,
+    output_directory: &FilePath,
+) -> Result<Counts, Box<dyn Error>> {
+    let directory = file_path_resolver::resolve_coverage_data_directory(output_directory);
+    let mut counts = Counts::new();
+
+    if !infrastructure.file_system.exists(&directory) {
+        return Ok(counts);
+    }
+
+    for file in infrastructure.file_system.read_directory(&directory)? {
+        for line in infrastructure.file_system.read_to_string(&file)?.lines() {
+            let error = || ApplicationError::CoverageDataMalformed(line.into());
+            let mut fields = line.split('\t');
+            let (Some(path), Some(index), Some(count), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(error().into());
+            };
+
+            *counts
+                .entry(path.into())
+                .or_default()
+                .entry(index.parse().map_err(|_| error())?)
+                .or_default() += count.parse::<u64>().map_err(|_| error())?;
+        }
+    }
+
+    Ok(counts)
+}
+
+fn compile_record(coverage: &test_info::Coverage, counts: Option<&BTreeMap<usize, u64>>) -> String {
+    let mut functions = vec![];
+    let mut branches = vec![];
+    let mut lines = BTreeMap::<usize, u64>::new();
+
+    for (index, counter) in coverage.counters().iter().enumerate() {
+        let line = counter.position().line_number();
+        let count = counts
+            .and_then(|counts| counts.get(&index))
+            .copied()
+            .unwrap_or_default();
+        let line_count = lines.entry(line).or_default();
+
+        *line_count = (*line_count).max(count);
+
+        match counter.kind() {
+            test_info::CounterKind::Function(name) => functions.push((name, line, count)),
+            test_info::CounterKind::Branch { block, branch } => {
+                branches.push((line, block, branch, count))
+            }
+        }
+    }
+
+    let mut record = vec![format!("SF:{}", coverage.path())];
+
+    record.extend(
+        functions
+            .iter()
+            .map(|(name, line, _)| format!("FN:{line},{name}")),
+    );
+    record.extend(
+        functions
+            .iter()
+            .map(|(name, _, count)| format!("FNDA:{count},{name}")),
+    );
+    record.push(format!("FNF:{}", functions.len()));
+    record.push(format!(
+        "FNH:{}",
+        functions.iter().filter(|(_, _, count)| *count > 0).count()
+    ));
+    record.extend(
+        branches
+            .iter()
+            .map(|(line, block, branch, count)| format!("BRDA:{line},{block},{branch},{count}")),
+    );
+    record.push(format!("BRF:{}", branches.len()));
+    record.push(format!(
+        "BRH:{}",
+        branches
+            .iter()
+            .filter(|(_, _, _, count)| *count > 0)
+            .count()
+    ));
+    record.extend(
+        lines
+            .iter()
+            .map(|(line, count)| format!("DA:{line},{count}")),
+    );
+    record.push(format!("LF:{}", lines.len()));
+    record.push(format!(
+        "LH:{}",
+        lines.values().filter(|count| **count > 0).count()
+    ));
+    record.push("end_of_record".into());
+
+    record.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::Position;
+
+    #[test]
+    fn compile_empty_record() {
+        assert_eq!(
+            compile_record(&test_info::Coverage::new("Foo.pen", vec![]), None),
+            "SF:Foo.pen\nFNF:0\nFNH:0\nBRF:0\nBRH:0\nLF:0\nLH:0\nend_of_record\n"
+        );
+    }
+
+    #[test]
+    fn compile_record_with_counts() {
+        assert_eq!(
+            compile_record(
+                &test_info::Coverage::new(
+                    "Foo.pen",
+                    vec![
+                        test_info::Counter::new(
+                            test_info::CounterKind::Function("f".into()),
+                            Position::new("Foo.pen", 1, 1, ""),
+                        ),
+                        test_info::Counter::new(
+                            test_info::CounterKind::Branch {
+                                block: 0,
+                                branch: 0,
+                            },
+                            Position::new("Foo.pen", 3, 1, ""),
+                        ),
+                        test_info::Counter::new(
+                            test_info::CounterKind::Branch {
+                                block: 0,
+                                branch: 1,
+                            },
+                            Position::new("Foo.pen", 5, 1, ""),
+                        ),
+                    ]
+                ),
+                Some(&[(0, 2), (1, 2)].into_iter().collect())
+            ),
+            [
+                "SF:Foo.pen",
+                "FN:1,f",
+                "FNDA:2,f",
+                "FNF:1",
+                "FNH:1",
+                "BRDA:3,0,0,2",
+                "BRDA:5,0,1,0",
+                "BRF:2",
+                "BRH:1",
+                "DA:1,2",
+                "DA:3,2",
+                "DA:5,0",
+                "LF:3",
+                "LH:2",
+                "end_of_record",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TestReportFormat {
+    #[default]
+    Human,
+    Json,
+    Junit,
+}
+
+impl Display for TestReportFormat {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{}",
+            match self {
+                Self::Human => "human",
+                Self::Json => "json",
+                Self::Junit => "junit",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TestRunConfiguration {
+    pub pattern: Option<String>,
+    pub exact: bool,
+    pub list: bool,
+    pub jobs: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub format: TestReportFormat,
+    pub report_file: Option<String>,
+    pub coverage: bool,
+}
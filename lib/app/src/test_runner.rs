@@ -2,7 +2,7 @@ use crate::{
     common::file_path_resolver,
     error::ApplicationError,
     infra::{FilePath, Infrastructure},
-    package_test_builder, ApplicationConfiguration,
+    package_test_builder, test_coverage_reporter, ApplicationConfiguration, TestRunConfiguration,
 };
 use std::error::Error;
 
@@ -13,6 +13,7 @@ pub fn run(
     prelude_package_url: &url::Url,
     ffi_package_url: &url::Url,
     application_configuration: &ApplicationConfiguration,
+    test_run_configuration: &TestRunConfiguration,
 ) -> Result<(), Box<dyn Error>> {
     package_test_builder::build(
         infrastructure,
@@ -23,12 +24,44 @@ pub fn run(
         application_configuration,
     )?;
 
-    infrastructure
+    let result = infrastructure
         .command_runner
-        .run(&file_path_resolver::resolve_test_executable_file(
-            output_directory,
-        ))
-        .map_err(|_| ApplicationError::Test)?;
+        .run(
+            &file_path_resolver::resolve_test_executable_file(output_directory),
+            &compile_arguments(test_run_configuration),
+        )
+        .map_err(|_| ApplicationError::Test);
+
+    // Report coverage even if some tests fail.
+    if test_run_configuration.coverage && !test_run_configuration.list {
+        test_coverage_reporter::report(infrastructure, main_package_directory, output_directory)?;
+    }
+
+    result?;
 
     Ok(())
 }
+
+fn compile_arguments(configuration: &TestRunConfiguration) -> Vec<String> {
+    [
+        configuration.exact.then(|| vec!["--exact".into()]),
+        configuration.list.then(|| vec!["--list".into()]),
+        configuration.coverage.then(|| vec!["--coverage".into()]),
+        configuration
+            .jobs
+            .map(|jobs| vec!["--jobs".into(), jobs.to_string()]),
+        configuration
+            .timeout
+            .map(|timeout| vec!["--timeout".into(), timeout.as_secs_f64().to_string()]),
+        Some(vec!["--format".into(), configuration.format.to_string()]),
+        configuration
+            .report_file
+            .clone()
+            .map(|file| vec!["--report-file".into(), file]),
+        configuration.pattern.clone().map(|pattern| vec![pattern]),
+    ]
+    .into_iter()
+    .flatten()
+    .flatten()
+    .collect()
+}
@@ -20,6 +20,18 @@ pub enum CompileError {
     TooFewBranchesInIf(Position),
 }
 
+impl CompileError {
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::ModuleNotFound(_) => None,
+            Self::NameNotFound(_, position)
+            | Self::ParseFloat { position, .. }
+            | Self::ParseInteger { position, .. }
+            | Self::TooFewBranchesInIf(position) => Some(position),
+        }
+    }
+}
+
 impl Display for CompileError {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
@@ -219,7 +219,7 @@ mod tests {
                             "Foo",
                             ir::Lambda::new(
                                 vec![],
-                                types::None::new(Position::fake()),
+                                Some(types::None::new(Position::fake()).into()),
                                 ir::Variable::new("Bar'Bar", Position::fake()),
                                 Position::fake(),
                             ),
@@ -239,7 +239,8 @@ mod tests {
                                     Position::fake()
                                 ),
                                 Position::fake()
-                            )]
+                            )],
+                            vec![]
                         ),
                         "Bar",
                         Default::default(),
@@ -259,7 +260,7 @@ mod tests {
                         "Foo",
                         ir::Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             ir::Variable::new("RealBar", Position::fake()),
                             Position::fake(),
                         ),
@@ -276,7 +277,7 @@ mod tests {
                     "Foo",
                     ir::Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         ir::Variable::new("Foo", Position::fake()),
                         Position::fake(),
                     ),
@@ -301,7 +302,8 @@ mod tests {
                                     Position::fake()
                                 ),
                                 Position::fake()
-                            )]
+                            )],
+                            vec![]
                         ),
                         "Bar",
                         [("Foo".into(), Position::fake())].into_iter().collect(),
@@ -343,7 +345,7 @@ mod tests {
                             "Foo",
                             ir::Lambda::new(
                                 vec![],
-                                types::Reference::new("Bar'Bar", Position::fake()),
+                                Some(types::Reference::new("Bar'Bar", Position::fake()).into()),
                                 ir::None::new(Position::fake()),
                                 Position::fake(),
                             ),
@@ -360,6 +362,7 @@ mod tests {
                                 Position::fake()
                             )],
                             vec![],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -392,7 +395,7 @@ mod tests {
                         "Foo",
                         ir::Lambda::new(
                             vec![],
-                            types::Reference::new("RealBar", Position::fake()),
+                            Some(types::Reference::new("RealBar", Position::fake()).into()),
                             ir::None::new(Position::fake()),
                             Position::fake(),
                         ),
@@ -417,7 +420,7 @@ mod tests {
                 "Foo",
                 ir::Lambda::new(
                     vec![],
-                    types::Reference::new("Bar'Bar", Position::fake()),
+                    Some(types::Reference::new("Bar'Bar", Position::fake()).into()),
                     ir::None::new(Position::fake()),
                     Position::fake(),
                 ),
@@ -441,6 +444,7 @@ mod tests {
                             )],
                             vec![],
                             vec![],
+                            vec![],
                         ),
                         "Bar",
                         Default::default()
@@ -484,6 +488,7 @@ mod tests {
                             interface::Module::new(
                                 vec![create_type_definition(false)],
                                 vec![],
+                                vec![],
                                 vec![]
                             ),
                             "Foo",
@@ -493,6 +498,7 @@ mod tests {
                             interface::Module::new(
                                 vec![create_type_definition(true)],
                                 vec![],
+                                vec![],
                                 vec![]
                             ),
                             "Bar",
@@ -545,6 +551,7 @@ mod tests {
                                 Position::fake()
                             )],
                             vec![],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -592,7 +599,7 @@ mod tests {
                             "Foo",
                             ir::Lambda::new(
                                 vec![],
-                                types::Reference::new("Bar'Bar", Position::fake()),
+                                Some(types::Reference::new("Bar'Bar", Position::fake()).into()),
                                 ir::None::new(Position::fake()),
                                 Position::fake(),
                             ),
@@ -608,6 +615,7 @@ mod tests {
                                 true,
                                 Position::fake(),
                             )],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -637,7 +645,7 @@ mod tests {
                         "Foo",
                         ir::Lambda::new(
                             vec![],
-                            types::Reference::new("RealBar", Position::fake()),
+                            Some(types::Reference::new("RealBar", Position::fake()).into()),
                             ir::None::new(Position::fake()),
                             Position::fake(),
                         ),
@@ -662,7 +670,7 @@ mod tests {
                 "Foo",
                 ir::Lambda::new(
                     vec![],
-                    types::Reference::new("Bar'Bar", Position::fake()),
+                    Some(types::Reference::new("Bar'Bar", Position::fake()).into()),
                     ir::None::new(Position::fake()),
                     Position::fake(),
                 ),
@@ -684,6 +692,7 @@ mod tests {
                                 false,
                                 Position::fake(),
                             )],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -721,12 +730,22 @@ mod tests {
                     &ir::Module::empty(),
                     &[
                         ImportedModule::new(
-                            interface::Module::new(vec![], vec![create_type_alias(false)], vec![]),
+                            interface::Module::new(
+                                vec![],
+                                vec![create_type_alias(false)],
+                                vec![],
+                                vec![]
+                            ),
                             "Foo",
                             Default::default()
                         ),
                         ImportedModule::new(
-                            interface::Module::new(vec![], vec![create_type_alias(true)], vec![]),
+                            interface::Module::new(
+                                vec![],
+                                vec![create_type_alias(true)],
+                                vec![],
+                                vec![]
+                            ),
                             "Bar",
                             Default::default()
                         )
@@ -776,6 +795,7 @@ mod tests {
                                 Position::fake()
                             )],
                             vec![],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -808,7 +828,7 @@ mod tests {
                             "Foo",
                             ir::Lambda::new(
                                 vec![],
-                                types::None::new(Position::fake()),
+                                Some(types::None::new(Position::fake()).into()),
                                 ir::Variable::new("Bar", Position::fake()),
                                 Position::fake(),
                             ),
@@ -828,7 +848,8 @@ mod tests {
                                     Position::fake()
                                 ),
                                 Position::fake()
-                            )]
+                            )],
+                            vec![]
                         ),
                         "Bar",
                         [("Bar".into(), Position::fake())].into_iter().collect()
@@ -848,7 +869,7 @@ mod tests {
                         "Foo",
                         ir::Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             ir::Variable::new("RealBar", Position::fake()),
                             Position::fake(),
                         ),
@@ -876,7 +897,7 @@ mod tests {
                             "Foo",
                             ir::Lambda::new(
                                 vec![],
-                                types::Reference::new("Bar", Position::fake()),
+                                Some(types::Reference::new("Bar", Position::fake()).into()),
                                 ir::None::new(Position::fake()),
                                 Position::fake(),
                             ),
@@ -893,6 +914,7 @@ mod tests {
                                 Position::fake()
                             )],
                             vec![],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -925,7 +947,7 @@ mod tests {
                         "Foo",
                         ir::Lambda::new(
                             vec![],
-                            types::Reference::new("RealBar", Position::fake()),
+                            Some(types::Reference::new("RealBar", Position::fake()).into()),
                             ir::None::new(Position::fake()),
                             Position::fake(),
                         ),
@@ -953,7 +975,7 @@ mod tests {
                             "Foo",
                             ir::Lambda::new(
                                 vec![],
-                                types::Reference::new("Bar", Position::fake()),
+                                Some(types::Reference::new("Bar", Position::fake()).into()),
                                 ir::None::new(Position::fake()),
                                 Position::fake(),
                             ),
@@ -969,6 +991,7 @@ mod tests {
                                 true,
                                 Position::fake(),
                             )],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -998,7 +1021,7 @@ mod tests {
                         "Foo",
                         ir::Lambda::new(
                             vec![],
-                            types::Reference::new("RealBar", Position::fake()),
+                            Some(types::Reference::new("RealBar", Position::fake()).into()),
                             ir::None::new(Position::fake()),
                             Position::fake(),
                         ),
@@ -0,0 +1,169 @@
+use crate::{module_prefix, name};
+use fnv::FnvHashSet;
+use hir::{
+    analysis::{expression_visitor, type_visitor, AnalysisWarning},
+    ir,
+    types::Type,
+};
+
+// Collect warnings of imports and unqualified names never used in a module.
+//
+// This function expects a module whose imported names are not resolved yet.
+pub fn collect(module: &ir::Module, imports: &[ast::Import]) -> Vec<AnalysisWarning> {
+    let names = collect_names(module);
+    let mut warnings = vec![];
+
+    for import in imports {
+        let prefix = name::qualify(&module_prefix::compile(import), "");
+        let unused_names = import
+            .unqualified_names()
+            .iter()
+            .filter(|name| !names.contains(name.name()))
+            .collect::<Vec<_>>();
+
+        if unused_names.len() == import.unqualified_names().len()
+            && !names.iter().any(|name| name.starts_with(&prefix))
+        {
+            warnings.push(AnalysisWarning::UnusedImport(import.position().clone()));
+        } else {
+            warnings.extend(unused_names.into_iter().map(|name| {
+                AnalysisWarning::UnusedUnqualifiedName(name.name().into(), name.position().clone())
+            }));
+        }
+    }
+
+    warnings
+}
+
+fn collect_names(module: &ir::Module) -> FnvHashSet<String> {
+    let mut names = FnvHashSet::default();
+
+    expression_visitor::visit(module, |expression| {
+        if let ir::Expression::Variable(variable) = expression {
+            names.insert(variable.name().into());
+        }
+    });
+
+    type_visitor::visit(module, |type_| match type_ {
+        Type::Record(record) => {
+            names.insert(record.name().into());
+        }
+        Type::Reference(reference) => {
+            names.insert(reference.name().into());
+        }
+        _ => {}
+    });
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hir::{
+        test::{FunctionDefinitionFake, ModuleFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn create_module(body: impl Into<ir::Expression>) -> ir::Module {
+        ir::Module::empty().set_function_definitions(vec![ir::FunctionDefinition::fake(
+            "f",
+            ir::Lambda::new(
+                vec![],
+                Some(types::None::new(Position::fake()).into()),
+                body,
+                Position::fake(),
+            ),
+            false,
+        )])
+    }
+
+    fn create_import(unqualified_names: &[&str]) -> ast::Import {
+        ast::Import::new(
+            ast::InternalModulePath::new(vec!["Foo".into()]),
+            None,
+            unqualified_names
+                .iter()
+                .map(|&name| ast::UnqualifiedName::new(name, Position::fake()))
+                .collect(),
+            Position::fake(),
+        )
+    }
+
+    #[test]
+    fn collect_nothing() {
+        assert_eq!(
+            collect(
+                &create_module(ir::Variable::new("Foo'Bar", Position::fake())),
+                &[create_import(&[])]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_unused_import() {
+        assert_eq!(
+            collect(
+                &create_module(ir::None::new(Position::fake())),
+                &[create_import(&[])]
+            ),
+            vec![AnalysisWarning::UnusedImport(Position::fake())]
+        );
+    }
+
+    #[test]
+    fn collect_unused_import_with_unqualified_name() {
+        assert_eq!(
+            collect(
+                &create_module(ir::None::new(Position::fake())),
+                &[create_import(&["Bar"])]
+            ),
+            vec![AnalysisWarning::UnusedImport(Position::fake())]
+        );
+    }
+
+    #[test]
+    fn collect_unused_unqualified_name() {
+        assert_eq!(
+            collect(
+                &create_module(ir::Variable::new("Foo'Bar", Position::fake())),
+                &[create_import(&["Baz"])]
+            ),
+            vec![AnalysisWarning::UnusedUnqualifiedName(
+                "Baz".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_used_unqualified_name() {
+        assert_eq!(
+            collect(
+                &create_module(ir::Variable::new("Bar", Position::fake())),
+                &[create_import(&["Bar"])]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_used_type() {
+        assert_eq!(
+            collect(
+                &create_module(ir::Let::new(
+                    Some("x".into()),
+                    Some(types::Reference::new("Foo'Bar", Position::fake()).into()),
+                    ir::None::new(Position::fake()),
+                    ir::Variable::new("x", Position::fake()),
+                    Position::fake(),
+                )),
+                &[create_import(&[])]
+            ),
+            vec![]
+        );
+    }
+}
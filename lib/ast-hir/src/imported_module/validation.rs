@@ -40,7 +40,7 @@ mod tests {
     fn validate_undefined_name() {
         assert_eq!(
             validate(&ImportedModule::new(
-                interface::Module::new(vec![], vec![], vec![]),
+                interface::Module::new(vec![], vec![], vec![], vec![]),
                 "",
                 [("foo".into(), Position::fake())].into_iter().collect()
             )),
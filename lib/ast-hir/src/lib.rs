@@ -1,5 +1,6 @@
 mod error;
 mod import;
+mod import_warning_collector;
 mod imported_module;
 mod module;
 mod module_prefix;
@@ -8,12 +9,12 @@ mod number;
 mod string;
 mod type_;
 
-use error::CompileError;
+pub use error::CompileError;
 use fnv::FnvHashMap;
 use hir::{
     analysis::{
         built_in_type_transformer, built_in_variable_transformer, function_definition_qualifier,
-        type_qualifier,
+        type_qualifier, AnalysisWarning,
     },
     ir,
 };
@@ -24,7 +25,7 @@ pub fn compile(
     prefix: &str,
     module_interfaces: &FnvHashMap<ast::ModulePath, interface::Module>,
     prelude_module_interfaces: &[interface::Module],
-) -> Result<ir::Module, CompileError> {
+) -> Result<(ir::Module, Vec<AnalysisWarning>), CompileError> {
     let imported_modules = module
         .imports()
         .iter()
@@ -48,10 +49,12 @@ pub fn compile(
         imported_module::validation::validate(module)?
     }
 
+    let imports = module.imports();
     let module = module::compile(module)?;
+    let warnings = import_warning_collector::collect(&module, imports);
     let module = import::compile(&module, &imported_modules, prelude_module_interfaces);
 
-    transform_module(&module, prefix)
+    Ok((transform_module(&module, prefix)?, warnings))
 }
 
 pub fn compile_prelude(module: &ast::Module, prefix: &str) -> Result<ir::Module, CompileError> {
@@ -82,7 +85,7 @@ mod tests {
                 &Default::default(),
                 &[],
             ),
-            Ok(ir::Module::empty()),
+            Ok((ir::Module::empty(), vec![])),
         );
     }
 
@@ -94,9 +94,15 @@ fn compile_lambda(lambda: &ast::Lambda) -> Result<ir::Lambda, CompileError> {
         lambda
             .arguments()
             .iter()
-            .map(|argument| ir::Argument::new(argument.name(), type_::compile(argument.type_())))
+            .map(|argument| {
+                ir::Argument::new(
+                    argument.name(),
+                    argument.type_().map(type_::compile),
+                    argument.position().clone(),
+                )
+            })
             .collect(),
-        type_::compile(lambda.result_type()),
+        lambda.result_type().map(type_::compile),
         compile_block(lambda.body())?,
         lambda.position().clone(),
     ))
@@ -148,13 +154,47 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
                     ir::ArithmeticOperation::new(ir::ArithmeticOperator::Divide, lhs, rhs, position)
                         .into()
                 }
+                ast::BinaryOperator::IntegerDivide => ir::ArithmeticOperation::new(
+                    ir::ArithmeticOperator::IntegerDivide,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::Remainder => ir::ArithmeticOperation::new(
+                    ir::ArithmeticOperator::Remainder,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
 
                 ast::BinaryOperator::And => {
-                    ir::BooleanOperation::new(ir::BooleanOperator::And, lhs, rhs, position).into()
+                    ir::BooleanOperation::new(None, ir::BooleanOperator::And, lhs, rhs, position)
+                        .into()
                 }
                 ast::BinaryOperator::Or => {
-                    ir::BooleanOperation::new(ir::BooleanOperator::Or, lhs, rhs, position).into()
+                    ir::BooleanOperation::new(None, ir::BooleanOperator::Or, lhs, rhs, position)
+                        .into()
+                }
+                ast::BinaryOperator::Xor => {
+                    ir::ArithmeticOperation::new(ir::ArithmeticOperator::Xor, lhs, rhs, position)
+                        .into()
                 }
+                ast::BinaryOperator::LeftShift => ir::ArithmeticOperation::new(
+                    ir::ArithmeticOperator::LeftShift,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::RightShift => ir::ArithmeticOperation::new(
+                    ir::ArithmeticOperator::RightShift,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
 
                 ast::BinaryOperator::Equal => ir::EqualityOperation::new(
                     None,
@@ -174,17 +214,27 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
                 .into(),
 
                 ast::BinaryOperator::LessThan => {
-                    ir::OrderOperation::new(ir::OrderOperator::LessThan, lhs, rhs, position).into()
-                }
-                ast::BinaryOperator::LessThanOrEqual => {
-                    ir::OrderOperation::new(ir::OrderOperator::LessThanOrEqual, lhs, rhs, position)
-                        .into()
-                }
-                ast::BinaryOperator::GreaterThan => {
-                    ir::OrderOperation::new(ir::OrderOperator::GreaterThan, lhs, rhs, position)
+                    ir::OrderOperation::new(None, ir::OrderOperator::LessThan, lhs, rhs, position)
                         .into()
                 }
+                ast::BinaryOperator::LessThanOrEqual => ir::OrderOperation::new(
+                    None,
+                    ir::OrderOperator::LessThanOrEqual,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::GreaterThan => ir::OrderOperation::new(
+                    None,
+                    ir::OrderOperator::GreaterThan,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
                 ast::BinaryOperator::GreaterThanOrEqual => ir::OrderOperation::new(
+                    None,
                     ir::OrderOperator::GreaterThanOrEqual,
                     lhs,
                     rhs,
@@ -373,6 +423,16 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
         ast::Expression::Variable(variable) => {
             ir::Variable::new(variable.name(), variable.position().clone()).into()
         }
+        ast::Expression::Vector(vector) => ir::Vector::new(
+            type_::compile(vector.type_()),
+            vector
+                .elements()
+                .iter()
+                .map(compile_expression)
+                .collect::<Result<_, _>>()?,
+            vector.position().clone(),
+        )
+        .into(),
     })
 }
 
@@ -438,7 +498,7 @@ mod tests {
                     "Foo3",
                     ast::Lambda::new(
                         vec![],
-                        ast::types::Reference::new("none", Position::fake()),
+                        Some(ast::types::Reference::new("none", Position::fake()).into()),
                         ast::Block::new(
                             vec![],
                             ast::Variable::new("none", Position::fake()),
@@ -474,7 +534,7 @@ mod tests {
                     "Foo3",
                     ir::Lambda::new(
                         vec![],
-                        types::Reference::new("none", Position::fake()),
+                        Some(types::Reference::new("none", Position::fake()).into()),
                         ir::Variable::new("none", Position::fake()),
                         Position::fake(),
                     ),
@@ -496,7 +556,7 @@ mod tests {
                     "f",
                     ast::Lambda::new(
                         vec![],
-                        ast::types::Reference::new("none", Position::fake()),
+                        Some(ast::types::Reference::new("none", Position::fake()).into()),
                         ast::Block::new(
                             vec![],
                             ast::ListComprehension::new(
@@ -533,7 +593,7 @@ mod tests {
                     "f",
                     ir::Lambda::new(
                         vec![],
-                        types::Reference::new("none", Position::fake()),
+                        Some(types::Reference::new("none", Position::fake()).into()),
                         ir::ListComprehension::new(
                             types::Reference::new("number", Position::fake()),
                             ir::Variable::new("x", Position::fake()),
@@ -580,7 +640,7 @@ mod tests {
                     "f",
                     ast::Lambda::new(
                         vec![],
-                        ast::types::Reference::new("none", Position::fake()),
+                        Some(ast::types::Reference::new("none", Position::fake()).into()),
                         ast::Block::new(
                             vec![],
                             ast::ListComprehension::new(
@@ -609,7 +669,7 @@ mod tests {
                     "f",
                     ir::Lambda::new(
                         vec![],
-                        types::Reference::new("none", Position::fake()),
+                        Some(types::Reference::new("none", Position::fake()).into()),
                         ir::ListComprehension::new(
                             types::Reference::new("number", Position::fake()),
                             ir::Variable::new("x", Position::fake()),
@@ -29,5 +29,8 @@ pub fn compile(type_: &ast::types::Type) -> Type {
             union.position().clone(),
         )
         .into(),
+        ast::types::Type::Vector(vector) => {
+            types::Vector::new(compile(vector.element()), vector.position().clone()).into()
+        }
     }
 }
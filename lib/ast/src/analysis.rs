@@ -26,15 +26,20 @@ pub fn is_module_path_public(path: &ModulePath) -> bool {
 pub fn operator_priority(operator: BinaryOperator) -> usize {
     match operator {
         BinaryOperator::Or => 1,
-        BinaryOperator::And => 2,
+        BinaryOperator::Xor => 2,
+        BinaryOperator::And => 3,
         BinaryOperator::Equal
         | BinaryOperator::NotEqual
         | BinaryOperator::LessThan
         | BinaryOperator::LessThanOrEqual
         | BinaryOperator::GreaterThan
-        | BinaryOperator::GreaterThanOrEqual => 3,
-        BinaryOperator::Add | BinaryOperator::Subtract => 4,
-        BinaryOperator::Multiply | BinaryOperator::Divide => 5,
+        | BinaryOperator::GreaterThanOrEqual => 4,
+        BinaryOperator::LeftShift | BinaryOperator::RightShift => 5,
+        BinaryOperator::Add | BinaryOperator::Subtract => 6,
+        BinaryOperator::Multiply
+        | BinaryOperator::Divide
+        | BinaryOperator::IntegerDivide
+        | BinaryOperator::Remainder => 7,
     }
 }
 
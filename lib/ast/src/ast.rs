@@ -41,6 +41,7 @@ mod unary_operation;
 mod unary_operator;
 mod unqualified_name;
 mod variable;
+mod vector;
 
 pub use argument::*;
 pub use binary_operation::*;
@@ -85,5 +86,6 @@ pub use unary_operation::*;
 pub use unary_operator::*;
 pub use unqualified_name::*;
 pub use variable::*;
+pub use vector::*;
 
 pub const IDENTIFIER_SEPARATOR: &str = "'";
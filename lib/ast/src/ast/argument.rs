@@ -4,15 +4,15 @@ use position::Position;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Argument {
     name: String,
-    type_: Type,
+    type_: Option<Type>,
     position: Position,
 }
 
 impl Argument {
-    pub fn new(name: impl Into<String>, type_: impl Into<Type>, position: Position) -> Self {
+    pub fn new(name: impl Into<String>, type_: Option<Type>, position: Position) -> Self {
         Self {
             name: name.into(),
-            type_: type_.into(),
+            type_,
             position,
         }
     }
@@ -21,8 +21,8 @@ impl Argument {
         &self.name
     }
 
-    pub fn type_(&self) -> &Type {
-        &self.type_
+    pub fn type_(&self) -> Option<&Type> {
+        self.type_.as_ref()
     }
 
     pub fn position(&self) -> &Position {
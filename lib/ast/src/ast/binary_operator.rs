@@ -4,9 +4,14 @@ pub enum BinaryOperator {
     Subtract,
     Multiply,
     Divide,
+    IntegerDivide,
+    Remainder,
 
     And,
     Or,
+    Xor,
+    LeftShift,
+    RightShift,
 
     Equal,
     NotEqual,
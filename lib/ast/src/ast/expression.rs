@@ -1,6 +1,6 @@
 use super::{
     BinaryOperation, ByteString, Call, If, IfList, IfMap, IfType, Lambda, List, ListComprehension,
-    Map, Number, Record, RecordDeconstruction, UnaryOperation, Variable,
+    Map, Number, Record, RecordDeconstruction, UnaryOperation, Variable, Vector,
 };
 use position::Position;
 
@@ -22,6 +22,7 @@ pub enum Expression {
     String(ByteString),
     UnaryOperation(UnaryOperation),
     Variable(Variable),
+    Vector(Vector),
 }
 
 impl Expression {
@@ -43,6 +44,7 @@ impl Expression {
             Self::String(string) => string.position(),
             Self::UnaryOperation(operation) => operation.position(),
             Self::Variable(variable) => variable.position(),
+            Self::Vector(vector) => vector.position(),
         }
     }
 }
@@ -142,3 +144,9 @@ impl From<Variable> for Expression {
         Self::Variable(variable)
     }
 }
+
+impl From<Vector> for Expression {
+    fn from(vector: Vector) -> Self {
+        Self::Vector(vector)
+    }
+}
@@ -5,7 +5,7 @@ use position::Position;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Lambda {
     arguments: Vec<Argument>,
-    result_type: Type,
+    result_type: Option<Type>,
     body: Block,
     position: Position,
 }
@@ -13,13 +13,13 @@ pub struct Lambda {
 impl Lambda {
     pub fn new(
         arguments: Vec<Argument>,
-        result_type: impl Into<Type>,
+        result_type: Option<Type>,
         body: impl Into<Block>,
         position: Position,
     ) -> Self {
         Self {
             arguments,
-            result_type: result_type.into(),
+            result_type,
             body: body.into(),
             position,
         }
@@ -29,8 +29,8 @@ impl Lambda {
         &self.arguments
     }
 
-    pub fn result_type(&self) -> &Type {
-        &self.result_type
+    pub fn result_type(&self) -> Option<&Type> {
+        self.result_type.as_ref()
     }
 
     pub fn body(&self) -> &Block {
@@ -0,0 +1,32 @@
+use super::expression::Expression;
+use crate::types::Type;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vector {
+    type_: Type,
+    elements: Vec<Expression>,
+    position: Position,
+}
+
+impl Vector {
+    pub fn new(type_: impl Into<Type>, elements: Vec<Expression>, position: Position) -> Self {
+        Self {
+            type_: type_.into(),
+            elements,
+            position,
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn elements(&self) -> &[Expression] {
+        &self.elements
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -6,6 +6,7 @@ mod record_field;
 mod reference;
 mod type_;
 mod union;
+mod vector;
 
 pub use function::*;
 pub use list::*;
@@ -15,3 +16,4 @@ pub use record_field::*;
 pub use reference::*;
 pub use type_::*;
 pub use union::*;
+pub use vector::*;
@@ -1,5 +1,6 @@
 use super::{
     function::Function, list::List, map::Map, record::Record, reference::Reference, union::Union,
+    vector::Vector,
 };
 use position::Position;
 
@@ -11,6 +12,7 @@ pub enum Type {
     Record(Record),
     Reference(Reference),
     Union(Union),
+    Vector(Vector),
 }
 
 impl Type {
@@ -22,6 +24,7 @@ impl Type {
             Self::Record(record) => record.position(),
             Self::Reference(reference) => reference.position(),
             Self::Union(union) => union.position(),
+            Self::Vector(vector) => vector.position(),
         }
     }
 }
@@ -61,3 +64,9 @@ impl From<Union> for Type {
         Self::Union(union)
     }
 }
+
+impl From<Vector> for Type {
+    fn from(vector: Vector) -> Self {
+        Self::Vector(vector)
+    }
+}
@@ -303,6 +303,11 @@ fn compile_type<'a>(context: &Context<'a>, type_: &'a Type) -> Document<'a> {
         ]),
         Type::Record(record) => record.name().into(),
         Type::Reference(reference) => reference.name().into(),
+        Type::Vector(vector) => builder.sequence([
+            "[|".into(),
+            compile_type(context, vector.element()),
+            "|]".into(),
+        ]),
         Type::Union(_) => {
             let types = collect_union_types(type_);
 
@@ -358,7 +363,7 @@ fn compile_lambda<'a>(context: &mut Context<'a>, lambda: &'a Lambda) -> Document
 fn compile_signature<'a>(
     context: &mut Context<'a>,
     arguments: &'a [Argument],
-    result_type: &'a Type,
+    result_type: Option<&'a Type>,
     position: &Position,
 ) -> Document<'a> {
     let builder = context.builder();
@@ -372,8 +377,7 @@ fn compile_signature<'a>(
                 compile_line_comment(context, argument.position(), |context| {
                     builder.sequence([
                         argument.name().into(),
-                        " ".into(),
-                        compile_type(context, argument.type_()),
+                        compile_type_annotation(context, argument.type_()),
                     ])
                 })
             })
@@ -390,11 +394,21 @@ fn compile_signature<'a>(
                 separator,
             ]))
         },
-        ") ".into(),
-        compile_type(context, result_type),
+        ")".into(),
+        compile_type_annotation(context, result_type),
     ])
 }
 
+fn compile_type_annotation<'a>(context: &Context<'a>, type_: Option<&'a Type>) -> Document<'a> {
+    if let Some(type_) = type_ {
+        context
+            .builder()
+            .sequence([" ".into(), compile_type(context, type_)])
+    } else {
+        empty()
+    }
+}
+
 fn are_arguments_flat(arguments: &[Argument], position: &Position) -> bool {
     arguments.is_empty()
         || Some(position.line_number())
@@ -665,6 +679,7 @@ fn compile_expression<'a>(context: &mut Context<'a>, expression: &'a Expression)
             }
         }
         Expression::Variable(variable) => variable.name().into(),
+        Expression::Vector(vector) => compile_vector(context, vector),
     }
 }
 
@@ -770,6 +785,43 @@ fn compile_list<'a>(context: &mut Context<'a>, list: &'a List) -> Document<'a> {
     ])
 }
 
+fn compile_vector<'a>(context: &mut Context<'a>, vector: &'a Vector) -> Document<'a> {
+    let builder = context.builder();
+    let separator = Document::from(",");
+    let elements = builder.sequence(
+        vector
+            .elements()
+            .iter()
+            .map(|element| {
+                builder.sequence([
+                    line(),
+                    compile_line_comment(context, element.position(), |context| {
+                        compile_expression(context, element)
+                    }),
+                ])
+            })
+            .intersperse(separator.clone()),
+    );
+
+    builder.sequence([
+        "[|".into(),
+        compile_type(context, vector.type_()),
+        if vector.elements().is_empty()
+            || Some(vector.position().line_number())
+                == vector
+                    .elements()
+                    .first()
+                    .map(|element| element.position().line_number())
+                && !is_broken(&elements)
+        {
+            builder.flatten(elements)
+        } else {
+            builder.r#break(builder.sequence([builder.indent(elements), separator, line()]))
+        },
+        "|]".into(),
+    ])
+}
+
 fn compile_map<'a>(context: &mut Context<'a>, map: &'a Map) -> Document<'a> {
     let builder = context.builder();
     let type_ = builder.sequence([
@@ -863,6 +915,7 @@ fn compile_operand<'a>(
 fn compile_binary_operator(operator: BinaryOperator) -> Document<'static> {
     match operator {
         BinaryOperator::Or => "|",
+        BinaryOperator::Xor => "^",
         BinaryOperator::And => "&",
         BinaryOperator::Equal => "==",
         BinaryOperator::NotEqual => "!=",
@@ -870,10 +923,14 @@ fn compile_binary_operator(operator: BinaryOperator) -> Document<'static> {
         BinaryOperator::LessThanOrEqual => "<=",
         BinaryOperator::GreaterThan => ">",
         BinaryOperator::GreaterThanOrEqual => ">=",
+        BinaryOperator::LeftShift => "<<",
+        BinaryOperator::RightShift => ">>",
         BinaryOperator::Add => "+",
         BinaryOperator::Subtract => "-",
         BinaryOperator::Multiply => "*",
         BinaryOperator::Divide => "/",
+        BinaryOperator::IntegerDivide => "//",
+        BinaryOperator::Remainder => "%",
     }
     .into()
 }
@@ -1423,6 +1480,20 @@ mod tests {
             );
         }
 
+        #[test]
+        fn format_vector_type() {
+            assert_eq!(
+                format_type(
+                    &types::Vector::new(
+                        types::Reference::new("none", Position::fake()),
+                        Position::fake()
+                    )
+                    .into()
+                ),
+                "[|none|]"
+            );
+        }
+
         #[test]
         fn format_function_multi_line_union_type() {
             assert_eq!(
@@ -1473,7 +1544,7 @@ mod tests {
                         "foo",
                         Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -1496,7 +1567,7 @@ mod tests {
                 "foo",
                 Lambda::new(
                     vec![],
-                    types::Reference::new("none", Position::fake()),
+                    Some(types::Reference::new("none", Position::fake()).into()),
                     Block::new(
                         vec![],
                         Variable::new("none", Position::fake()),
@@ -1538,10 +1609,10 @@ mod tests {
                         Lambda::new(
                             vec![Argument::new(
                                 "x",
-                                types::Reference::new("none", Position::fake()),
+                                Some(types::Reference::new("none", Position::fake()).into()),
                                 Position::fake()
                             )],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -1569,7 +1640,7 @@ mod tests {
                         "foo",
                         Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![Statement::new(
                                     None,
@@ -1608,16 +1679,19 @@ mod tests {
                         "foo",
                         Lambda::new(
                             vec![],
-                            types::Function::new(
-                                vec![],
-                                types::Reference::new("none", Position::fake()),
-                                Position::fake()
+                            Some(
+                                types::Function::new(
+                                    vec![],
+                                    types::Reference::new("none", Position::fake()),
+                                    Position::fake()
+                                )
+                                .into()
                             ),
                             Block::new(
                                 vec![],
                                 Lambda::new(
                                     vec![],
-                                    types::Reference::new("none", Position::fake()),
+                                    Some(types::Reference::new("none", Position::fake()).into()),
                                     Block::new(
                                         vec![],
                                         Variable::new("none", Position::fake()),
@@ -1649,7 +1723,7 @@ mod tests {
                         "foo",
                         Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -1677,7 +1751,7 @@ mod tests {
                         "foo",
                         Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -1876,7 +1950,7 @@ mod tests {
                         "foo",
                         Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![Statement::new(
                                     None,
@@ -2620,7 +2694,7 @@ mod tests {
                     format(
                         &Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -2634,13 +2708,36 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn format_without_type_annotations() {
+                assert_eq!(
+                    format(
+                        &Lambda::new(
+                            vec![
+                                Argument::new("x", None, Position::fake()),
+                                Argument::new("y", None, Position::fake())
+                            ],
+                            None,
+                            Block::new(
+                                vec![],
+                                Variable::new("x", Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    "\\(x, y) { x }"
+                );
+            }
+
             #[test]
             fn format_multi_line_body() {
                 assert_eq!(
                     format(
                         &Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![Statement::new(
                                     Some("x".into()),
@@ -2672,7 +2769,7 @@ mod tests {
                     format(
                         &Lambda::new(
                             vec![],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", line_position(2)),
@@ -2700,10 +2797,10 @@ mod tests {
                         &Lambda::new(
                             vec![Argument::new(
                                 "x",
-                                types::Reference::new("none", Position::fake()),
+                                Some(types::Reference::new("none", Position::fake()).into()),
                                 line_position(2),
                             )],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -2734,16 +2831,16 @@ mod tests {
                             vec![
                                 Argument::new(
                                     "x",
-                                    types::Reference::new("none", Position::fake()),
+                                    Some(types::Reference::new("none", Position::fake()).into()),
                                     line_position(2)
                                 ),
                                 Argument::new(
                                     "y",
-                                    types::Reference::new("none", Position::fake()),
+                                    Some(types::Reference::new("none", Position::fake()).into()),
                                     Position::fake()
                                 )
                             ],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -2774,10 +2871,10 @@ mod tests {
                         &Lambda::new(
                             vec![Argument::new(
                                 "x",
-                                types::Reference::new("none", Position::fake()),
+                                Some(types::Reference::new("none", Position::fake()).into()),
                                 line_position(2)
                             )],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -2808,10 +2905,10 @@ mod tests {
                         &Lambda::new(
                             vec![Argument::new(
                                 "x",
-                                types::Reference::new("none", Position::fake()),
+                                Some(types::Reference::new("none", Position::fake()).into()),
                                 line_position(3),
                             )],
-                            types::Reference::new("none", Position::fake()),
+                            Some(types::Reference::new("none", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Variable::new("none", Position::fake()),
@@ -2973,6 +3070,32 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn format_nested_bitwise_operations_with_priority() {
+                assert_eq!(
+                    format(
+                        &BinaryOperation::new(
+                            BinaryOperator::LeftShift,
+                            BinaryOperation::new(
+                                BinaryOperator::Xor,
+                                Variable::new("x", Position::fake()),
+                                Variable::new("y", Position::fake()),
+                                Position::fake()
+                            ),
+                            BinaryOperation::new(
+                                BinaryOperator::Remainder,
+                                Variable::new("x", Position::fake()),
+                                Variable::new("y", Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    "(x ^ y) << x % y"
+                );
+            }
+
             #[test]
             fn format_nested_operations_with_priority() {
                 assert_eq!(
@@ -3307,6 +3430,66 @@ mod tests {
             }
         }
 
+        mod vector {
+            use super::*;
+            use pretty_assertions::assert_eq;
+
+            #[test]
+            fn format_empty() {
+                assert_eq!(
+                    format(
+                        &Vector::new(
+                            types::Reference::new("none", Position::fake()),
+                            vec![],
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    "[|none|]"
+                );
+            }
+
+            #[test]
+            fn format_two_elements() {
+                assert_eq!(
+                    format(
+                        &Vector::new(
+                            types::Reference::new("none", Position::fake()),
+                            vec![
+                                Variable::new("none", Position::fake()).into(),
+                                Variable::new("none", Position::fake()).into()
+                            ],
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    "[|none none, none|]"
+                );
+            }
+
+            #[test]
+            fn format_multi_line() {
+                assert_eq!(
+                    format(
+                        &Vector::new(
+                            types::Reference::new("none", Position::fake()),
+                            vec![Variable::new("none", line_position(2)).into()],
+                            line_position(1)
+                        )
+                        .into()
+                    ),
+                    indoc!(
+                        "
+                        [|none
+                          none,
+                        |]
+                        "
+                    )
+                    .trim()
+                );
+            }
+        }
+
         mod map {
             use super::*;
             use pretty_assertions::assert_eq;
@@ -4029,7 +4212,7 @@ mod tests {
                             "foo",
                             Lambda::new(
                                 vec![],
-                                types::Reference::new("none", Position::fake()),
+                                Some(types::Reference::new("none", Position::fake()).into()),
                                 Block::new(
                                     vec![],
                                     Variable::new("none", Position::fake()),
@@ -4,11 +4,11 @@ use crate::{
     runtime_function_declaration::{
         LOCAL_DEBUG_FUNCTION_NAME, LOCAL_RACE_FUNCTION_NAME, LOCAL_SPAWN_FUNCTION_NAME,
     },
-    transformation::map_context,
+    transformation::{collection_type, map_context, serialization},
     type_, type_information, CompileError,
 };
 use hir::{
-    analysis::{type_canonicalizer, AnalysisError},
+    analysis::{type_canonicalizer, type_difference_calculator, AnalysisError},
     ir::*,
     types,
     types::Type,
@@ -51,6 +51,40 @@ pub fn compile(
             vec![type_information::debug::compile_call(arguments[0].clone())],
         )
         .into(),
+        BuiltInFunctionName::Decode => {
+            let any_type = Type::from(types::Any::new(position.clone()));
+            let type_ = type_difference_calculator::calculate(
+                function_type.result(),
+                &types::Error::new(position.clone()).into(),
+                context.types(),
+            )?
+            .ok_or_else(|| {
+                AnalysisError::TypeNotSerializable(position.clone(), function_type.result().clone())
+            })?;
+
+            expression::compile(
+                context,
+                &downcast::compile(
+                    context,
+                    &any_type,
+                    function_type.result(),
+                    &Call::new(
+                        Some(
+                            types::Function::new(
+                                vec![any_type.clone()],
+                                any_type.clone(),
+                                position.clone(),
+                            )
+                            .into(),
+                        ),
+                        serialization::function::transform_decode(context, &type_)?,
+                        call.arguments().to_vec(),
+                        position.clone(),
+                    )
+                    .into(),
+                )?,
+            )?
+        }
         BuiltInFunctionName::Delete => {
             let map_type =
                 type_canonicalizer::canonicalize_map(function_type.result(), context.types())?
@@ -92,7 +126,48 @@ pub fn compile(
             )
             .into()
         }
+        BuiltInFunctionName::Encode => mir::ir::Call::new(
+            mir::types::Function::new(vec![mir::types::Type::Variant], mir::types::Type::Variant),
+            expression::compile(
+                context,
+                &serialization::function::transform_encode(context, &function_type.arguments()[0])?,
+            )?,
+            vec![compile_any(context, call, &function_type, 0)?],
+        )
+        .into(),
         BuiltInFunctionName::Error => error_type::compile_error(arguments[0].clone()),
+        BuiltInFunctionName::Get => {
+            let any_type = Type::from(types::Any::new(position.clone()));
+
+            expression::compile(
+                context,
+                &downcast::compile(
+                    context,
+                    &any_type,
+                    function_type.result(),
+                    &Call::new(
+                        Some(
+                            types::Function::new(
+                                vec![
+                                    collection_type::transform_vector(context, position)?,
+                                    types::Number::new(position.clone()).into(),
+                                ],
+                                any_type.clone(),
+                                position.clone(),
+                            )
+                            .into(),
+                        ),
+                        Variable::new(
+                            &context.configuration()?.vector_type.get_function_name,
+                            position.clone(),
+                        ),
+                        call.arguments().to_vec(),
+                        position.clone(),
+                    )
+                    .into(),
+                )?,
+            )?
+        }
         BuiltInFunctionName::Keys => {
             let argument_type = &function_type.arguments()[0];
             let argument = &call.arguments()[0];
@@ -116,6 +191,22 @@ pub fn compile(
                 position,
             )?
         }
+        BuiltInFunctionName::Push => {
+            let mir_vector_type = type_::compile_vector(context)?;
+
+            mir::ir::Call::new(
+                mir::types::Function::new(
+                    vec![mir_vector_type.clone().into(), mir::types::Type::Variant],
+                    mir_vector_type,
+                ),
+                mir::ir::Variable::new(&context.configuration()?.vector_type.push_function_name),
+                vec![
+                    arguments[0].clone(),
+                    compile_any(context, call, &function_type, 1)?,
+                ],
+            )
+            .into()
+        }
         BuiltInFunctionName::Race => {
             const ELEMENT_NAME: &str = "$element";
 
@@ -167,6 +258,27 @@ pub fn compile(
         BuiltInFunctionName::ReflectEqual => {
             type_information::equal::compile_call(arguments[0].clone(), arguments[1].clone())
         }
+        BuiltInFunctionName::Set => {
+            let mir_vector_type = type_::compile_vector(context)?;
+
+            mir::ir::Call::new(
+                mir::types::Function::new(
+                    vec![
+                        mir_vector_type.clone().into(),
+                        mir::types::Type::Number,
+                        mir::types::Type::Variant,
+                    ],
+                    mir_vector_type,
+                ),
+                mir::ir::Variable::new(&context.configuration()?.vector_type.set_function_name),
+                vec![
+                    arguments[0].clone(),
+                    arguments[1].clone(),
+                    compile_any(context, call, &function_type, 2)?,
+                ],
+            )
+            .into()
+        }
         BuiltInFunctionName::Size => mir::ir::Call::new(
             type_::compile_function(context, &function_type)?,
             match &function_type.arguments()[0] {
@@ -176,11 +288,31 @@ pub fn compile(
                 Type::Map(_) => {
                     mir::ir::Variable::new(&context.configuration()?.map_type.size_function_name)
                 }
+                Type::Vector(_) => {
+                    mir::ir::Variable::new(&context.configuration()?.vector_type.size_function_name)
+                }
                 _ => unreachable!(),
             },
             arguments,
         )
         .into(),
+        BuiltInFunctionName::Slice => {
+            let mir_vector_type = type_::compile_vector(context)?;
+
+            mir::ir::Call::new(
+                mir::types::Function::new(
+                    vec![
+                        mir_vector_type.clone().into(),
+                        mir::types::Type::Number,
+                        mir::types::Type::Number,
+                    ],
+                    mir_vector_type,
+                ),
+                mir::ir::Variable::new(&context.configuration()?.vector_type.slice_function_name),
+                arguments,
+            )
+            .into()
+        }
         BuiltInFunctionName::Source => error_type::compile_source(arguments[0].clone()),
         BuiltInFunctionName::Spawn => {
             const ANY_THUNK_NAME: &str = "$any_thunk";
@@ -286,6 +418,26 @@ pub fn compile(
     })
 }
 
+fn compile_any(
+    context: &Context,
+    call: &Call,
+    function_type: &types::Function,
+    index: usize,
+) -> Result<mir::ir::Expression, CompileError> {
+    let position = call.position();
+
+    expression::compile(
+        context,
+        &TypeCoercion::new(
+            function_type.arguments()[index].clone(),
+            types::Any::new(position.clone()),
+            call.arguments()[index].clone(),
+            position.clone(),
+        )
+        .into(),
+    )
+}
+
 fn compile_map_iteration(
     context: &Context,
     argument: &Expression,
@@ -602,7 +754,7 @@ mod tests {
                     BuiltInFunction::new(BuiltInFunctionName::Spawn, Position::fake()),
                     vec![Lambda::new(
                         vec![],
-                        types::Number::new(Position::fake()),
+                        Some(types::Number::new(Position::fake()).into()),
                         Number::new(42.0, Position::fake()),
                         Position::fake()
                     )
@@ -688,7 +840,7 @@ mod tests {
                     BuiltInFunction::new(BuiltInFunctionName::Spawn, Position::fake()),
                     vec![Lambda::new(
                         vec![],
-                        types::Any::new(Position::fake()),
+                        Some(types::Any::new(Position::fake()).into()),
                         Variable::new("x", Position::fake()),
                         Position::fake()
                     )
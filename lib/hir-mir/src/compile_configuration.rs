@@ -1,6 +1,7 @@
 use super::{
     list_type_configuration::ListTypeConfiguration, map_type_configuration::MapTypeConfiguration,
     string_type_configuration::StringTypeConfiguration,
+    vector_type_configuration::VectorTypeConfiguration,
 };
 #[cfg(test)]
 use super::{
@@ -8,6 +9,7 @@ use super::{
     map_type_configuration::MAP_TYPE_CONFIGURATION,
     number_type_configuration::NUMBER_TYPE_CONFIGURATION,
     string_type_configuration::STRING_TYPE_CONFIGURATION,
+    vector_type_configuration::VECTOR_TYPE_CONFIGURATION,
 };
 use crate::number_type_configuration::NumberTypeConfiguration;
 #[cfg(test)]
@@ -19,9 +21,11 @@ pub struct CompileConfiguration {
     pub map_type: MapTypeConfiguration,
     pub number_type: NumberTypeConfiguration,
     pub string_type: StringTypeConfiguration,
+    pub vector_type: VectorTypeConfiguration,
     pub debug_function_name: String,
     pub race_function_name: String,
     pub spawn_function_name: String,
+    pub debug_information: bool,
 }
 
 #[cfg(test)]
@@ -31,7 +35,9 @@ pub static COMPILE_CONFIGURATION: LazyLock<CompileConfiguration> =
         map_type: MAP_TYPE_CONFIGURATION.clone(),
         number_type: NUMBER_TYPE_CONFIGURATION.clone(),
         string_type: STRING_TYPE_CONFIGURATION.clone(),
+        vector_type: VECTOR_TYPE_CONFIGURATION.clone(),
         debug_function_name: "debug".into(),
         race_function_name: "race".into(),
         spawn_function_name: "spawn".into(),
+        debug_information: false,
     });
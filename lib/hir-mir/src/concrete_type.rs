@@ -31,6 +31,11 @@ pub fn compile(
                 vec![expression],
             )
             .into(),
+            Type::Vector(vector_type) => mir::ir::Record::new(
+                type_::compile_concrete_vector(vector_type, context.types())?,
+                vec![expression],
+            )
+            .into(),
             Type::Any(_) | Type::Reference(_) | Type::Union(_) => unreachable!(),
         },
     )
@@ -5,6 +5,7 @@ use hir::{
     ir::Module,
     types::{self, Type},
 };
+use position::Position;
 
 #[derive(Debug)]
 pub struct Context {
@@ -53,4 +54,12 @@ impl Context {
             .as_ref()
             .ok_or(CompileError::CompileConfigurationNotProvided)
     }
+
+    // Source positions are kept in MIR only when debug information is enabled.
+    pub fn debug_position(&self, position: &Position) -> Option<Position> {
+        self.configuration
+            .as_ref()
+            .filter(|configuration| configuration.debug_information)
+            .map(|_| position.clone())
+    }
 }
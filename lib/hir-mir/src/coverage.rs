@@ -0,0 +1,440 @@
+use super::test_module_configuration::TestModuleConfiguration;
+use hir::{ir::*, types};
+use position::Position;
+
+const COUNTER_FUNCTION_NAME: &str = "$coverage";
+
+struct Counters {
+    path: String,
+    counters: Vec<test_info::Counter>,
+    block_count: usize,
+}
+
+pub fn instrument(
+    module: &Module,
+    configuration: &TestModuleConfiguration,
+) -> (Module, test_info::Coverage) {
+    let position = module.position();
+    let mut counters = Counters {
+        path: position.path().into(),
+        counters: vec![],
+        block_count: 0,
+    };
+
+    let module = Module::new(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        module
+            .foreign_declarations()
+            .iter()
+            .cloned()
+            .chain([ForeignDeclaration::new(
+                COUNTER_FUNCTION_NAME,
+                &configuration.coverage_function_name,
+                CallingConvention::C,
+                types::Function::new(
+                    vec![
+                        types::ByteString::new(position.clone()).into(),
+                        types::Number::new(position.clone()).into(),
+                    ],
+                    types::None::new(position.clone()),
+                    position.clone(),
+                ),
+                position.clone(),
+            )])
+            .collect(),
+        module.function_declarations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| instrument_function_definition(definition, &mut counters))
+            .collect(),
+        position.clone(),
+    );
+
+    (
+        module,
+        test_info::Coverage::new(counters.path, counters.counters),
+    )
+}
+
+fn instrument_function_definition(
+    definition: &FunctionDefinition,
+    counters: &mut Counters,
+) -> FunctionDefinition {
+    let lambda = definition.lambda();
+    let body = count(
+        test_info::CounterKind::Function(definition.original_name().into()),
+        definition.position(),
+        lambda.body(),
+        counters,
+    );
+
+    FunctionDefinition::new(
+        definition.name(),
+        definition.original_name(),
+        Lambda::new(
+            lambda.arguments().to_vec(),
+            lambda.result_type().cloned(),
+            body,
+            lambda.position().clone(),
+        ),
+        definition.foreign_definition_configuration().cloned(),
+        definition.is_public(),
+        definition.position().clone(),
+    )
+}
+
+fn instrument_lambda(lambda: &Lambda, counters: &mut Counters) -> Lambda {
+    Lambda::new(
+        lambda.arguments().to_vec(),
+        lambda.result_type().cloned(),
+        instrument_expression(lambda.body(), counters),
+        lambda.position().clone(),
+    )
+}
+
+fn instrument_expression(expression: &Expression, counters: &mut Counters) -> Expression {
+    match expression {
+        Expression::Call(call) => Call::new(
+            call.function_type().cloned(),
+            instrument_expression(call.function(), counters),
+            call.arguments()
+                .iter()
+                .map(|argument| instrument_expression(argument, counters))
+                .collect(),
+            call.position().clone(),
+        )
+        .into(),
+        Expression::If(if_) => {
+            let condition = instrument_expression(if_.condition(), counters);
+            let block = allocate_block(counters);
+
+            If::new(
+                condition,
+                instrument_branch(if_.then(), block, 0, counters),
+                instrument_branch(if_.else_(), block, 1, counters),
+                if_.position().clone(),
+            )
+            .into()
+        }
+        Expression::IfList(if_) => {
+            let list = instrument_expression(if_.list(), counters);
+            let block = allocate_block(counters);
+
+            IfList::new(
+                if_.type_().cloned(),
+                list,
+                if_.first_name(),
+                if_.rest_name(),
+                instrument_branch(if_.then(), block, 0, counters),
+                instrument_branch(if_.else_(), block, 1, counters),
+                if_.position().clone(),
+            )
+            .into()
+        }
+        Expression::IfMap(if_) => {
+            let map = instrument_expression(if_.map(), counters);
+            let key = instrument_expression(if_.key(), counters);
+            let block = allocate_block(counters);
+
+            IfMap::new(
+                if_.key_type().cloned(),
+                if_.value_type().cloned(),
+                if_.name(),
+                map,
+                key,
+                instrument_branch(if_.then(), block, 0, counters),
+                instrument_branch(if_.else_(), block, 1, counters),
+                if_.position().clone(),
+            )
+            .into()
+        }
+        Expression::IfType(if_) => {
+            let argument = instrument_expression(if_.argument(), counters);
+            let block = allocate_block(counters);
+            let branches = if_
+                .branches()
+                .iter()
+                .enumerate()
+                .map(|(index, branch)| {
+                    IfTypeBranch::new(
+                        branch.type_().clone(),
+                        instrument_branch(branch.expression(), block, index, counters),
+                    )
+                })
+                .collect();
+            let else_ = if_.else_().map(|branch| {
+                ElseBranch::new(
+                    branch.type_().cloned(),
+                    instrument_branch(branch.expression(), block, if_.branches().len(), counters),
+                    branch.position().clone(),
+                )
+            });
+
+            IfType::new(
+                if_.name(),
+                argument,
+                branches,
+                else_,
+                if_.position().clone(),
+            )
+            .into()
+        }
+        Expression::Lambda(lambda) => instrument_lambda(lambda, counters).into(),
+        Expression::Let(let_) => Let::new(
+            let_.name().map(String::from),
+            let_.type_().cloned(),
+            instrument_expression(let_.bound_expression(), counters),
+            instrument_expression(let_.expression(), counters),
+            let_.position().clone(),
+        )
+        .into(),
+        Expression::List(list) => List::new(
+            list.type_().clone(),
+            list.elements()
+                .iter()
+                .map(|element| match element {
+                    ListElement::Multiple(element) => {
+                        ListElement::Multiple(instrument_expression(element, counters))
+                    }
+                    ListElement::Single(element) => {
+                        ListElement::Single(instrument_expression(element, counters))
+                    }
+                })
+                .collect(),
+            list.position().clone(),
+        )
+        .into(),
+        Expression::ListComprehension(comprehension) => {
+            let branches = comprehension
+                .branches()
+                .iter()
+                .map(|branch| {
+                    ListComprehensionBranch::new(
+                        branch.names().to_vec(),
+                        branch
+                            .iteratees()
+                            .iter()
+                            .map(|iteratee| {
+                                ListComprehensionIteratee::new(
+                                    iteratee.type_().cloned(),
+                                    instrument_expression(iteratee.expression(), counters),
+                                )
+                            })
+                            .collect(),
+                        branch
+                            .condition()
+                            .map(|expression| instrument_expression(expression, counters)),
+                        branch.position().clone(),
+                    )
+                })
+                .collect();
+
+            ListComprehension::new(
+                comprehension.type_().clone(),
+                instrument_expression(comprehension.element(), counters),
+                branches,
+                comprehension.position().clone(),
+            )
+            .into()
+        }
+        Expression::Map(map) => Map::new(
+            map.key_type().clone(),
+            map.value_type().clone(),
+            map.elements()
+                .iter()
+                .map(|element| match element {
+                    MapElement::Single(entry) => MapElement::Single(MapEntry::new(
+                        instrument_expression(entry.key(), counters),
+                        instrument_expression(entry.value(), counters),
+                        entry.position().clone(),
+                    )),
+                    MapElement::Multiple(map) => {
+                        MapElement::Multiple(instrument_expression(map, counters))
+                    }
+                })
+                .collect(),
+            map.position().clone(),
+        )
+        .into(),
+        Expression::Operation(operation) => instrument_operation(operation, counters).into(),
+        Expression::RecordConstruction(construction) => RecordConstruction::new(
+            construction.type_().clone(),
+            construction
+                .fields()
+                .iter()
+                .map(|field| {
+                    RecordField::new(
+                        field.name(),
+                        instrument_expression(field.expression(), counters),
+                        field.position().clone(),
+                    )
+                })
+                .collect(),
+            construction.position().clone(),
+        )
+        .into(),
+        Expression::RecordDeconstruction(deconstruction) => RecordDeconstruction::new(
+            deconstruction.type_().cloned(),
+            instrument_expression(deconstruction.record(), counters),
+            deconstruction.field_name(),
+            deconstruction.position().clone(),
+        )
+        .into(),
+        Expression::RecordUpdate(update) => RecordUpdate::new(
+            update.type_().clone(),
+            instrument_expression(update.record(), counters),
+            update
+                .fields()
+                .iter()
+                .map(|field| {
+                    RecordField::new(
+                        field.name(),
+                        instrument_expression(field.expression(), counters),
+                        field.position().clone(),
+                    )
+                })
+                .collect(),
+            update.position().clone(),
+        )
+        .into(),
+        Expression::Thunk(thunk) => Thunk::new(
+            thunk.type_().cloned(),
+            instrument_expression(thunk.expression(), counters),
+            thunk.position().clone(),
+        )
+        .into(),
+        Expression::TypeCoercion(coercion) => TypeCoercion::new(
+            coercion.from().clone(),
+            coercion.to().clone(),
+            instrument_expression(coercion.argument(), counters),
+            coercion.position().clone(),
+        )
+        .into(),
+        Expression::Vector(vector) => Vector::new(
+            vector.type_().clone(),
+            vector
+                .elements()
+                .iter()
+                .map(|element| instrument_expression(element, counters))
+                .collect(),
+            vector.position().clone(),
+        )
+        .into(),
+        Expression::Boolean(_)
+        | Expression::BuiltInFunction(_)
+        | Expression::String(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::Variable(_) => expression.clone(),
+    }
+}
+
+fn instrument_operation(operation: &Operation, counters: &mut Counters) -> Operation {
+    match operation {
+        Operation::Addition(operation) => AdditionOperation::new(
+            operation.type_().cloned(),
+            instrument_expression(operation.lhs(), counters),
+            instrument_expression(operation.rhs(), counters),
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Arithmetic(operation) => ArithmeticOperation::new(
+            operation.operator(),
+            instrument_expression(operation.lhs(), counters),
+            instrument_expression(operation.rhs(), counters),
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Boolean(operation) => BooleanOperation::new(
+            operation.type_().cloned(),
+            operation.operator(),
+            instrument_expression(operation.lhs(), counters),
+            instrument_expression(operation.rhs(), counters),
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Equality(operation) => EqualityOperation::new(
+            operation.type_().cloned(),
+            operation.operator(),
+            instrument_expression(operation.lhs(), counters),
+            instrument_expression(operation.rhs(), counters),
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Not(operation) => NotOperation::new(
+            instrument_expression(operation.expression(), counters),
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Order(operation) => OrderOperation::new(
+            operation.type_().cloned(),
+            operation.operator(),
+            instrument_expression(operation.lhs(), counters),
+            instrument_expression(operation.rhs(), counters),
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Try(operation) => TryOperation::new(
+            operation.type_().cloned(),
+            instrument_expression(operation.expression(), counters),
+            operation.position().clone(),
+        )
+        .into(),
+    }
+}
+
+fn allocate_block(counters: &mut Counters) -> usize {
+    let block = counters.block_count;
+
+    counters.block_count += 1;
+
+    block
+}
+
+fn instrument_branch(
+    expression: &Expression,
+    block: usize,
+    branch: usize,
+    counters: &mut Counters,
+) -> Expression {
+    count(
+        test_info::CounterKind::Branch { block, branch },
+        expression.position(),
+        expression,
+        counters,
+    )
+}
+
+fn count(
+    kind: test_info::CounterKind,
+    position: &Position,
+    expression: &Expression,
+    counters: &mut Counters,
+) -> Expression {
+    let index = counters.counters.len();
+
+    counters
+        .counters
+        .push(test_info::Counter::new(kind, position.clone()));
+
+    let expression = instrument_expression(expression, counters);
+
+    Let::new(
+        None,
+        None,
+        Call::new(
+            None,
+            Variable::new(COUNTER_FUNCTION_NAME, position.clone()),
+            vec![
+                ByteString::new(counters.path.as_str(), position.clone()).into(),
+                Number::new(index as f64, position.clone()).into(),
+            ],
+            position.clone(),
+        ),
+        expression,
+        position.clone(),
+    )
+    .into()
+}
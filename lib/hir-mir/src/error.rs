@@ -19,6 +19,19 @@ pub enum CompileError {
     NewContextFunctionNotFound(Position),
 }
 
+impl CompileError {
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::Analysis(error) => error.position(),
+            Self::CompileConfigurationNotProvided | Self::MirTypeCheck(_) => None,
+            Self::InvalidRecordEqualOperation(position)
+            | Self::MainFunctionNotFound(position)
+            | Self::NewContextFunctionNotFound(position) => Some(position),
+            Self::InvalidVariantType(type_) => Some(type_.position()),
+        }
+    }
+}
+
 impl Display for CompileError {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
@@ -3,7 +3,7 @@ use super::{
     context::Context,
     transformation::{
         boolean_operation, equal_operation, if_list, if_map, list_literal, map_literal,
-        not_equal_operation,
+        not_equal_operation, vector_literal,
     },
     type_, CompileError,
 };
@@ -11,8 +11,8 @@ use crate::{concrete_type, list_comprehension};
 use fnv::FnvHashMap;
 use hir::{
     analysis::{
-        record_field_resolver, type_canonicalizer, type_equality_checker,
-        union_type_member_calculator, AnalysisError,
+        name_suggester, record_field_resolver, type_canonicalizer, type_equality_checker,
+        type_extractor, union_type_member_calculator, AnalysisError,
     },
     ir::*,
     types::{self, Type},
@@ -37,7 +37,7 @@ pub fn compile(
                     .function_type()
                     .ok_or_else(|| AnalysisError::TypeNotInferred(call.position().clone()))?;
 
-                mir::ir::Call::new(
+                mir::ir::Call::with_position(
                     type_::compile(context, type_)?
                         .into_function()
                         .ok_or_else(|| {
@@ -51,6 +51,7 @@ pub fn compile(
                         .iter()
                         .map(compile)
                         .collect::<Result<_, _>>()?,
+                    context.debug_position(call.position()),
                 )
                 .into()
             }
@@ -252,11 +253,23 @@ pub fn compile(
                         .into()
                     }
                 }
+                Type::Vector(vector_type) => {
+                    if to.is_vector() {
+                        argument
+                    } else {
+                        mir::ir::Variant::new(
+                            type_::compile_concrete_vector(vector_type, context.types())?,
+                            concrete_type::compile(context, argument, &from)?,
+                        )
+                        .into()
+                    }
+                }
                 Type::Any(_) | Type::Union(_) => argument,
                 Type::Reference(_) => unreachable!(),
             }
         }
         Expression::Variable(variable) => mir::ir::Variable::new(variable.name()).into(),
+        Expression::Vector(vector) => compile(&vector_literal::transform(context, vector)?)?,
     })
 }
 
@@ -266,22 +279,26 @@ fn compile_lambda(
 ) -> Result<mir::ir::Expression, CompileError> {
     const CLOSURE_NAME: &str = "$closure";
 
+    let type_ = type_extractor::extract_from_lambda(lambda)?;
+
     Ok(mir::ir::LetRecursive::new(
         mir::ir::FunctionDefinition::new(
             CLOSURE_NAME,
             lambda
                 .arguments()
                 .iter()
-                .map(|argument| -> Result<_, CompileError> {
+                .zip(type_.arguments())
+                .map(|(argument, type_)| -> Result<_, CompileError> {
                     Ok(mir::ir::Argument::new(
                         argument.name(),
-                        type_::compile(context, argument.type_())?,
+                        type_::compile(context, type_)?,
                     ))
                 })
                 .collect::<Result<_, _>>()?,
-            type_::compile(context, lambda.result_type())?,
+            type_::compile(context, type_.result())?,
             compile(context, lambda.body())?,
-        ),
+        )
+        .set_position(context.debug_position(lambda.position())),
         mir::ir::Variable::new(CLOSURE_NAME),
     )
     .into())
@@ -316,6 +333,9 @@ fn compile_alternative(
             map_type,
             context.types(),
         )?)?,
+        Type::Vector(vector_type) => compile_generic_type_alternative(
+            &type_::compile_concrete_vector(vector_type, context.types())?,
+        )?,
         _ => mir::ir::Alternative::new(
             union_type_member_calculator::calculate(&type_, context.types())?
                 .iter()
@@ -378,12 +398,36 @@ fn compile_operation(
                 ArithmeticOperator::Subtract => mir::ir::ArithmeticOperator::Subtract,
                 ArithmeticOperator::Multiply => mir::ir::ArithmeticOperator::Multiply,
                 ArithmeticOperator::Divide => mir::ir::ArithmeticOperator::Divide,
+                ArithmeticOperator::IntegerDivide => mir::ir::ArithmeticOperator::IntegerDivide,
+                ArithmeticOperator::Remainder => mir::ir::ArithmeticOperator::Remainder,
+                ArithmeticOperator::Xor => mir::ir::ArithmeticOperator::Xor,
+                ArithmeticOperator::LeftShift => mir::ir::ArithmeticOperator::LeftShift,
+                ArithmeticOperator::RightShift => mir::ir::ArithmeticOperator::RightShift,
             },
             compile(operation.lhs())?,
             compile(operation.rhs())?,
         )
         .into(),
-        Operation::Boolean(operation) => compile(&boolean_operation::transform(operation))?,
+        Operation::Boolean(operation) => match type_canonicalizer::canonicalize(
+            operation
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?,
+            context.types(),
+        )? {
+            Type::Boolean(_) => compile(&boolean_operation::transform(operation))?,
+            Type::Number(_) => mir::ir::ArithmeticOperation::new(
+                match operation.operator() {
+                    BooleanOperator::And => mir::ir::ArithmeticOperator::And,
+                    BooleanOperator::Or => mir::ir::ArithmeticOperator::Or,
+                },
+                compile(operation.lhs())?,
+                compile(operation.rhs())?,
+            )
+            .into(),
+            type_ => {
+                return Err(AnalysisError::InvalidBooleanOperand(type_.position().clone()).into());
+            }
+        },
         Operation::Equality(operation) => match operation.operator() {
             EqualityOperator::Equal => {
                 match type_canonicalizer::canonicalize(
@@ -417,19 +461,48 @@ fn compile_operation(
         Operation::Not(operation) => {
             mir::ir::If::new(compile(operation.expression())?, false, true).into()
         }
-        Operation::Order(operation) => mir::ir::ComparisonOperation::new(
-            match operation.operator() {
+        Operation::Order(operation) => {
+            let operator = match operation.operator() {
                 OrderOperator::LessThan => mir::ir::ComparisonOperator::LessThan,
                 OrderOperator::LessThanOrEqual => mir::ir::ComparisonOperator::LessThanOrEqual,
                 OrderOperator::GreaterThan => mir::ir::ComparisonOperator::GreaterThan,
                 OrderOperator::GreaterThanOrEqual => {
                     mir::ir::ComparisonOperator::GreaterThanOrEqual
                 }
-            },
-            compile(operation.lhs())?,
-            compile(operation.rhs())?,
-        )
-        .into(),
+            };
+
+            match type_canonicalizer::canonicalize(
+                operation
+                    .type_()
+                    .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?,
+                context.types(),
+            )? {
+                Type::Number(_) => mir::ir::ComparisonOperation::new(
+                    operator,
+                    compile(operation.lhs())?,
+                    compile(operation.rhs())?,
+                )
+                .into(),
+                Type::String(_) => mir::ir::ComparisonOperation::new(
+                    operator,
+                    mir::ir::Call::new(
+                        mir::types::Function::new(
+                            vec![mir::types::Type::ByteString, mir::types::Type::ByteString],
+                            mir::types::Type::Number,
+                        ),
+                        mir::ir::Variable::new(
+                            &context.configuration()?.string_type.compare_function_name,
+                        ),
+                        vec![compile(operation.lhs())?, compile(operation.rhs())?],
+                    ),
+                    0.0,
+                )
+                .into(),
+                type_ => {
+                    return Err(AnalysisError::InvalidOrderOperand(type_.position().clone()).into());
+                }
+            }
+        }
         Operation::Try(operation) => {
             const SUCCESS_NAME: &str = "$success";
             const ERROR_NAME: &str = "$error";
@@ -481,7 +554,15 @@ fn compile_record_fields(
                     field_types
                         .iter()
                         .find(|field_type| field_type.name() == field.name())
-                        .ok_or_else(|| AnalysisError::RecordFieldUnknown(field.position().clone()))?
+                        .ok_or_else(|| {
+                            AnalysisError::RecordFieldUnknown(
+                                field.position().clone(),
+                                name_suggester::suggest(
+                                    field.name(),
+                                    field_types.iter().map(|field_type| field_type.name()),
+                                ),
+                            )
+                        })?
                         .type_(),
                 )?,
                 compile(context, field.expression())?,
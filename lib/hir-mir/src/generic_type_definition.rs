@@ -40,6 +40,13 @@ fn compile_type_definition(
             )
             .into()]),
         )),
+        Type::Vector(vector_type) => Some(mir::ir::TypeDefinition::new(
+            type_::compile_concrete_vector_name(vector_type, context.types())?,
+            mir::types::RecordBody::new(vec![mir::types::Record::new(
+                &context.configuration()?.vector_type.vector_type_name,
+            )
+            .into()]),
+        )),
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
@@ -74,8 +81,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "foo",
                     Lambda::new(
-                        vec![Argument::new("x", list_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         Call::new(
                             Some(
                                 types::Function::new(
@@ -125,8 +136,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "foo",
                     Lambda::new(
-                        vec![Argument::new("x", function_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(function_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         TypeCoercion::new(
                             function_type.clone(),
                             union_type,
@@ -166,8 +181,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "foo",
                     Lambda::new(
-                        vec![Argument::new("x", list_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         TypeCoercion::new(
                             list_type.clone(),
                             union_type,
@@ -201,8 +220,12 @@ mod tests {
         let definition = FunctionDefinition::fake(
             "foo",
             Lambda::new(
-                vec![Argument::new("x", list_type.clone())],
-                types::None::new(Position::fake()),
+                vec![Argument::new(
+                    "x",
+                    Some(list_type.clone().into()),
+                    Position::fake(),
+                )],
+                Some(types::None::new(Position::fake()).into()),
                 TypeCoercion::new(
                     list_type.clone(),
                     union_type,
@@ -240,8 +263,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "foo",
                     Lambda::new(
-                        vec![Argument::new("x", list_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "x",
                             Variable::new("x", Position::fake()),
@@ -283,8 +310,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "foo",
                     Lambda::new(
-                        vec![Argument::new("x", union_type)],
-                        types::Boolean::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.into()),
+                            Position::fake()
+                        )],
+                        Some(types::Boolean::new(Position::fake()).into()),
                         EqualityOperation::new(
                             Some(list_type.clone().into()),
                             EqualityOperator::Equal,
@@ -323,8 +354,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "foo",
                     Lambda::new(
-                        vec![Argument::new("x", union_type)],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         TryOperation::new(
                             Some(list_type.clone().into()),
                             Variable::new("x", Position::fake()),
@@ -361,8 +396,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "foo",
                     Lambda::new(
-                        vec![Argument::new("x", list_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         List::new(
                             union_type,
                             vec![ListElement::Single(
@@ -402,7 +441,7 @@ mod tests {
                     "foo",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         ListComprehension::new(
                             types::None::new(Position::fake()),
                             None::new(Position::fake()),
@@ -452,7 +491,7 @@ mod tests {
                     "foo",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         ListComprehension::new(
                             union_type,
                             None::new(Position::fake()),
@@ -498,7 +537,7 @@ mod tests {
                     "foo",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         IfList::new(
                             Some(list_type.clone().into()),
                             List::new(list_type.element().clone(), vec![], Position::fake()),
@@ -537,7 +576,7 @@ mod tests {
                     "foo",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         IfMap::new(
                             Some(map_type.key().clone()),
                             Some(map_type.value().clone()),
@@ -590,7 +629,7 @@ mod tests {
                     "foo",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Map::new(
                             key_type.clone(),
                             value_type.clone(),
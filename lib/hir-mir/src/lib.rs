@@ -2,6 +2,7 @@ mod built_in_call;
 mod compile_configuration;
 mod concrete_type;
 mod context;
+mod coverage;
 mod downcast;
 mod error;
 mod error_type;
@@ -23,11 +24,12 @@ mod transformation;
 mod type_;
 mod type_information;
 mod variant_type_collection;
+mod vector_type_configuration;
 
 pub use compile_configuration::CompileConfiguration;
 use context::Context;
 pub use error::CompileError;
-use hir::ir::*;
+use hir::{analysis::AnalysisWarning, ir::*};
 pub use list_type_configuration::ListTypeConfiguration;
 pub use main_module_configuration::*;
 pub use map_type_configuration::{
@@ -38,66 +40,91 @@ pub use string_type_configuration::StringTypeConfiguration;
 pub use test_module_configuration::TestModuleConfiguration;
 use transformation::{
     equal_operation, hash_calculation, map_context, record_equal_function, record_hash_function,
+    record_serialization_function, serialization,
 };
+pub use vector_type_configuration::VectorTypeConfiguration;
 
 pub fn compile_main(
     module: &Module,
     compile_configuration: &CompileConfiguration,
     main_module_configuration: &MainModuleConfiguration,
-) -> Result<mir::ir::Module, CompileError> {
+) -> Result<(mir::ir::Module, Vec<AnalysisWarning>), CompileError> {
     let module = main_function::compile(module, main_module_configuration)?;
-    let (module, _) = compile_module(&module, Some(compile_configuration))?;
+    let (module, _, warnings) = compile_module(&module, Some(compile_configuration))?;
 
-    Ok(module)
+    Ok((module, warnings))
 }
 
 pub fn compile(
     module: &Module,
     configuration: &CompileConfiguration,
-) -> Result<(mir::ir::Module, interface::Module), CompileError> {
+) -> Result<(mir::ir::Module, interface::Module, Vec<AnalysisWarning>), CompileError> {
     compile_module(module, Some(configuration))
 }
 
 pub fn compile_prelude(
     module: &Module,
 ) -> Result<(mir::ir::Module, interface::Module), CompileError> {
-    compile_module(module, None)
+    let (module, interface, _) = compile_module(module, None)?;
+
+    Ok((module, interface))
 }
 
 pub fn compile_test(
     module: &Module,
     compile_configuration: &CompileConfiguration,
     test_module_configuration: &TestModuleConfiguration,
-) -> Result<(mir::ir::Module, test_info::Module), CompileError> {
+) -> Result<(mir::ir::Module, test_info::Module, Vec<AnalysisWarning>), CompileError> {
     let (module, test_information) = test_function::compile(module, test_module_configuration)?;
-    let (module, _) = compile_module(&module, Some(compile_configuration))?;
+    let (module, _, warnings) = compile_module(&module, Some(compile_configuration))?;
+
+    Ok((module, test_information, warnings))
+}
 
-    Ok((module, test_information))
+pub fn compile_with_coverage(
+    module: &Module,
+    compile_configuration: &CompileConfiguration,
+    test_module_configuration: &TestModuleConfiguration,
+) -> Result<
+    (
+        mir::ir::Module,
+        interface::Module,
+        test_info::Coverage,
+        Vec<AnalysisWarning>,
+    ),
+    CompileError,
+> {
+    let (module, coverage) = coverage::instrument(module, test_module_configuration);
+    let (module, interface, warnings) = compile_module(&module, Some(compile_configuration))?;
+
+    Ok((module, interface, coverage, warnings))
 }
 
 fn compile_module(
     module: &Module,
     configuration: Option<&CompileConfiguration>,
-) -> Result<(mir::ir::Module, interface::Module), CompileError> {
+) -> Result<(mir::ir::Module, interface::Module, Vec<AnalysisWarning>), CompileError> {
     let context = Context::new(module, configuration.cloned());
 
-    let module = hir::analysis::analyze(context.analysis(), module)?;
+    let (module, warnings) = hir::analysis::analyze(context.analysis(), module)?;
 
-    Ok((
-        {
-            let module = record_equal_function::transform(&context, &module)?;
-            let module = record_hash_function::transform(&context, &module)?;
-            let module = map_context::module::transform(&context, &module)?;
-            let module = equal_operation::module::transform(&context, &module)?;
-            let module = hash_calculation::module::transform(&context, &module)?;
-            let module = module::compile(&context, &module)?;
-
-            mir::analysis::type_check::check(&module)?;
-
-            module
-        },
-        module_interface::compile(&module)?,
-    ))
+    let mir_module = {
+        let module = record_equal_function::transform(&context, &module)?;
+        let module = record_hash_function::transform(&context, &module)?;
+        let module = record_serialization_function::transform(&context, &module)?;
+        let module = serialization::module::transform(&context, &module)?;
+        let module = map_context::module::transform(&context, &module)?;
+        let module = equal_operation::module::transform(&context, &module)?;
+        let module = hash_calculation::module::transform(&context, &module)?;
+        let module = module::compile(&context, &module)?;
+
+        mir::analysis::type_check::check(&module)?;
+
+        module
+    };
+    let interface = module_interface::compile(&module, &mir_module)?;
+
+    Ok((mir_module, interface, warnings))
 }
 
 #[cfg(test)]
@@ -113,7 +140,7 @@ mod tests {
 
     fn compile_module(
         module: &Module,
-    ) -> Result<(mir::ir::Module, interface::Module), CompileError> {
+    ) -> Result<(mir::ir::Module, interface::Module, Vec<AnalysisWarning>), CompileError> {
         let debug_function_type = types::Function::new(
             vec![types::Any::new(Position::fake()).into()],
             types::ByteString::new(Position::fake()),
@@ -147,6 +174,14 @@ mod tests {
         let map_iterator_type = Type::from(types::Record::fake(
             &COMPILE_CONFIGURATION.map_type.iteration.iterator_type_name,
         ));
+        let vector_type = Type::from(types::Record::fake(
+            &COMPILE_CONFIGURATION.vector_type.vector_type_name,
+        ));
+        let serialization_function_type = Type::from(types::Function::new(
+            vec![types::Any::new(Position::fake()).into()],
+            types::Any::new(Position::fake()),
+            Position::fake(),
+        ));
         let maybe_equal_function_type = Type::from(types::Function::new(
             vec![
                 types::Any::new(Position::fake()).into(),
@@ -189,6 +224,13 @@ mod tests {
                                 false,
                                 true,
                             ),
+                            TypeDefinition::fake(
+                                &COMPILE_CONFIGURATION.map_type.empty_type_name,
+                                vec![],
+                                false,
+                                false,
+                                true,
+                            ),
                             TypeDefinition::fake(
                                 &COMPILE_CONFIGURATION.map_type.iteration.iterator_type_name,
                                 vec![],
@@ -203,6 +245,13 @@ mod tests {
                                 false,
                                 true,
                             ),
+                            TypeDefinition::fake(
+                                &COMPILE_CONFIGURATION.vector_type.vector_type_name,
+                                vec![],
+                                false,
+                                false,
+                                true,
+                            ),
                         ])
                         .collect(),
                 )
@@ -242,11 +291,33 @@ mod tests {
                                 ),
                                 Position::fake(),
                             ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.list_type.decode_function_name,
+                                types::Function::new(
+                                    vec![serialization_function_type.clone(), list_type.clone()],
+                                    types::Union::new(
+                                        list_type.clone(),
+                                        types::Error::new(Position::fake()),
+                                        Position::fake(),
+                                    ),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
                             FunctionDeclaration::new(
                                 &COMPILE_CONFIGURATION.list_type.empty_function_name,
                                 types::Function::new(vec![], list_type.clone(), Position::fake()),
                                 Position::fake(),
                             ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.list_type.encode_function_name,
+                                types::Function::new(
+                                    vec![serialization_function_type.clone(), list_type.clone()],
+                                    list_type.clone(),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
                             FunctionDeclaration::new(
                                 &COMPILE_CONFIGURATION.list_type.deconstruct_function_name,
                                 types::Function::new(
@@ -349,7 +420,7 @@ mod tests {
                                     vec![
                                         equal_function_type.clone().into(),
                                         hash_function_type.clone().into(),
-                                        equal_function_type.into(),
+                                        equal_function_type.clone().into(),
                                         hash_function_type.clone().into(),
                                     ],
                                     map_context_type.clone(),
@@ -364,13 +435,30 @@ mod tests {
                                         types::ByteString::new(Position::fake()).into(),
                                         types::ByteString::new(Position::fake()).into(),
                                         map_type.clone(),
-                                        debug_function_type.into(),
+                                        debug_function_type.clone().into(),
                                     ],
                                     types::ByteString::new(Position::fake()),
                                     Position::fake(),
                                 ),
                                 Position::fake(),
                             ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.map_type.decode_function_name,
+                                types::Function::new(
+                                    vec![
+                                        map_context_type.clone(),
+                                        serialization_function_type.clone(),
+                                        map_type.clone(),
+                                    ],
+                                    types::Union::new(
+                                        map_type.clone(),
+                                        types::Error::new(Position::fake()),
+                                        Position::fake(),
+                                    ),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
                             FunctionDeclaration::new(
                                 &COMPILE_CONFIGURATION.map_type.delete_function_name,
                                 types::Function::new(
@@ -389,6 +477,19 @@ mod tests {
                                 types::Function::new(vec![], map_type.clone(), Position::fake()),
                                 Position::fake(),
                             ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.map_type.encode_function_name,
+                                types::Function::new(
+                                    vec![
+                                        map_context_type.clone(),
+                                        serialization_function_type,
+                                        map_type.clone(),
+                                    ],
+                                    map_type.clone(),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
                             FunctionDeclaration::new(
                                 &COMPILE_CONFIGURATION.map_type.hash.combine_function_name,
                                 types::Function::new(
@@ -404,7 +505,7 @@ mod tests {
                             FunctionDeclaration::new(
                                 &COMPILE_CONFIGURATION.map_type.hash.list_hash_function_name,
                                 types::Function::new(
-                                    vec![hash_function_type.into(), list_type],
+                                    vec![hash_function_type.clone().into(), list_type],
                                     types::Number::new(Position::fake()),
                                     Position::fake(),
                                 ),
@@ -485,7 +586,7 @@ mod tests {
                                 &COMPILE_CONFIGURATION.map_type.maybe_equal_function_name,
                                 types::Function::new(
                                     vec![
-                                        maybe_equal_function_type,
+                                        maybe_equal_function_type.clone(),
                                         map_type.clone(),
                                         map_type.clone(),
                                     ],
@@ -498,6 +599,19 @@ mod tests {
                                 ),
                                 Position::fake(),
                             ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.map_type.get_function_name,
+                                types::Function::new(
+                                    vec![
+                                        map_context_type.clone(),
+                                        map_type.clone(),
+                                        types::Any::new(Position::fake()).into(),
+                                    ],
+                                    types::Any::new(Position::fake()),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
                             FunctionDeclaration::new(
                                 &COMPILE_CONFIGURATION.map_type.set_function_name,
                                 types::Function::new(
@@ -521,6 +635,18 @@ mod tests {
                                 ),
                                 Position::fake(),
                             ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.string_type.compare_function_name,
+                                types::Function::new(
+                                    vec![
+                                        types::ByteString::new(Position::fake()).into(),
+                                        types::ByteString::new(Position::fake()).into(),
+                                    ],
+                                    types::Number::new(Position::fake()),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
                             FunctionDeclaration::new(
                                 &COMPILE_CONFIGURATION.string_type.equal_function_name,
                                 types::Function::new(
@@ -533,6 +659,125 @@ mod tests {
                                 ),
                                 Position::fake(),
                             ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.debug_function_name,
+                                types::Function::new(
+                                    vec![
+                                        types::ByteString::new(Position::fake()).into(),
+                                        vector_type.clone(),
+                                        debug_function_type.into(),
+                                    ],
+                                    types::ByteString::new(Position::fake()),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.empty_function_name,
+                                types::Function::new(vec![], vector_type.clone(), Position::fake()),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.equal_function_name,
+                                types::Function::new(
+                                    vec![
+                                        equal_function_type.into(),
+                                        vector_type.clone(),
+                                        vector_type.clone(),
+                                    ],
+                                    types::Boolean::new(Position::fake()),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.get_function_name,
+                                types::Function::new(
+                                    vec![
+                                        vector_type.clone(),
+                                        types::Number::new(Position::fake()).into(),
+                                    ],
+                                    types::Any::new(Position::fake()),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION
+                                    .map_type
+                                    .hash
+                                    .vector_hash_function_name,
+                                types::Function::new(
+                                    vec![hash_function_type.into(), vector_type.clone()],
+                                    types::Number::new(Position::fake()),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.maybe_equal_function_name,
+                                types::Function::new(
+                                    vec![
+                                        maybe_equal_function_type,
+                                        vector_type.clone(),
+                                        vector_type.clone(),
+                                    ],
+                                    types::Union::new(
+                                        types::Boolean::new(Position::fake()),
+                                        types::None::new(Position::fake()),
+                                        Position::fake(),
+                                    ),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.push_function_name,
+                                types::Function::new(
+                                    vec![
+                                        vector_type.clone(),
+                                        types::Any::new(Position::fake()).into(),
+                                    ],
+                                    vector_type.clone(),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.set_function_name,
+                                types::Function::new(
+                                    vec![
+                                        vector_type.clone(),
+                                        types::Number::new(Position::fake()).into(),
+                                        types::Any::new(Position::fake()).into(),
+                                    ],
+                                    vector_type.clone(),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.size_function_name,
+                                types::Function::new(
+                                    vec![vector_type.clone()],
+                                    types::Number::new(Position::fake()),
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            FunctionDeclaration::new(
+                                &COMPILE_CONFIGURATION.vector_type.slice_function_name,
+                                types::Function::new(
+                                    vec![
+                                        vector_type.clone(),
+                                        types::Number::new(Position::fake()).into(),
+                                        types::Number::new(Position::fake()).into(),
+                                    ],
+                                    vector_type,
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
                         ])
                         .collect(),
                 ),
@@ -552,7 +797,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::Number::new(Position::fake()),
+                    Some(types::Number::new(Position::fake()).into()),
                     AdditionOperation::new(
                         Some(types::Number::new(Position::fake()).into()),
                         Number::new(1.0, Position::fake()),
@@ -574,7 +819,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::ByteString::new(Position::fake()),
+                    Some(types::ByteString::new(Position::fake()).into()),
                     AdditionOperation::new(
                         Some(types::ByteString::new(Position::fake()).into()),
                         ByteString::new("foo", Position::fake()),
@@ -590,14 +835,20 @@ mod tests {
     }
 
     #[test]
-    fn compile_boolean() {
+    fn compile_order_operation_with_numbers() {
         compile_module(
             &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                 "x",
                 Lambda::new(
                     vec![],
-                    types::Boolean::new(Position::fake()),
-                    Boolean::new(false, Position::fake()),
+                    Some(types::Boolean::new(Position::fake()).into()),
+                    OrderOperation::new(
+                        None,
+                        OrderOperator::LessThan,
+                        Number::new(1.0, Position::fake()),
+                        Number::new(2.0, Position::fake()),
+                        Position::fake(),
+                    ),
                     Position::fake(),
                 ),
                 false,
@@ -607,14 +858,20 @@ mod tests {
     }
 
     #[test]
-    fn compile_none() {
+    fn compile_order_operation_with_strings() {
         compile_module(
             &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
-                    None::new(Position::fake()),
+                    Some(types::Boolean::new(Position::fake()).into()),
+                    OrderOperation::new(
+                        None,
+                        OrderOperator::LessThan,
+                        ByteString::new("foo", Position::fake()),
+                        ByteString::new("bar", Position::fake()),
+                        Position::fake(),
+                    ),
                     Position::fake(),
                 ),
                 false,
@@ -624,14 +881,50 @@ mod tests {
     }
 
     #[test]
-    fn compile_number() {
+    fn compile_arithmetic_operations() {
+        for operator in [
+            ArithmeticOperator::IntegerDivide,
+            ArithmeticOperator::Remainder,
+            ArithmeticOperator::Xor,
+            ArithmeticOperator::LeftShift,
+            ArithmeticOperator::RightShift,
+        ] {
+            compile_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        Some(types::Number::new(Position::fake()).into()),
+                        ArithmeticOperation::new(
+                            operator,
+                            Number::new(1.0, Position::fake()),
+                            Number::new(2.0, Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn compile_boolean_operation_with_numbers() {
         compile_module(
             &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                 "x",
                 Lambda::new(
                     vec![],
-                    types::Number::new(Position::fake()),
-                    Number::new(42.0, Position::fake()),
+                    Some(types::Number::new(Position::fake()).into()),
+                    BooleanOperation::new(
+                        None,
+                        BooleanOperator::And,
+                        Number::new(1.0, Position::fake()),
+                        Number::new(2.0, Position::fake()),
+                        Position::fake(),
+                    ),
                     Position::fake(),
                 ),
                 false,
@@ -641,14 +934,14 @@ mod tests {
     }
 
     #[test]
-    fn compile_string() {
+    fn compile_boolean() {
         compile_module(
             &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                 "x",
                 Lambda::new(
                     vec![],
-                    types::ByteString::new(Position::fake()),
-                    ByteString::new("foo", Position::fake()),
+                    Some(types::Boolean::new(Position::fake()).into()),
+                    Boolean::new(false, Position::fake()),
                     Position::fake(),
                 ),
                 false,
@@ -658,23 +951,74 @@ mod tests {
     }
 
     #[test]
-    fn compile_duplicate_function_names() {
-        let definition = FunctionDefinition::fake(
-            "x",
-            Lambda::new(
-                vec![],
-                types::None::new(Position::fake()),
-                None::new(Position::fake()),
-                Position::fake(),
-            ),
-            false,
-        );
-
-        assert_eq!(
-            compile_module(
-                &Module::empty().set_function_definitions(vec![definition.clone(), definition])
-            ),
-            Err(AnalysisError::DuplicateFunctionNames(Position::fake(), Position::fake()).into())
+    fn compile_none() {
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![],
+                    Some(types::None::new(Position::fake()).into()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn compile_number() {
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![],
+                    Some(types::Number::new(Position::fake()).into()),
+                    Number::new(42.0, Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn compile_string() {
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![],
+                    Some(types::ByteString::new(Position::fake()).into()),
+                    ByteString::new("foo", Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn compile_duplicate_function_names() {
+        let definition = FunctionDefinition::fake(
+            "x",
+            Lambda::new(
+                vec![],
+                Some(types::None::new(Position::fake()).into()),
+                None::new(Position::fake()),
+                Position::fake(),
+            ),
+            false,
+        );
+
+        assert_eq!(
+            compile_module(
+                &Module::empty().set_function_definitions(vec![definition.clone(), definition])
+            ),
+            Err(AnalysisError::DuplicateFunctionNames(Position::fake(), Position::fake()).into())
         );
     }
 
@@ -687,13 +1031,14 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Union::new(
+                            Some(types::Union::new(
                                 types::None::new(Position::fake()),
                                 types::Error::new(Position::fake(),),
                                 Position::fake(),
-                            ),
+                            ).into()),
+                            Position::fake(),
                         )],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         TryOperation::new(
                             None,
                             Variable::new("x", Position::fake()),
@@ -715,7 +1060,7 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    types::Any::new(Position::fake()),
+                    Some(types::Any::new(Position::fake()).into()),
                     Variable::new("f", Position::fake()),
                     Position::fake(),
                 ),
@@ -730,29 +1075,35 @@ mod tests {
 
         #[test]
         fn compile_empty_list() {
-            compile_module(&Module::empty().set_function_definitions(vec![
-                FunctionDefinition::fake(
+            compile_module(&Module::empty().set_function_definitions(
+                vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
                         vec![],
-                        types::List::new(types::None::new(Position::fake()), Position::fake()),
+                        Some(
+                            types::List::new(types::None::new(Position::fake()), Position::fake())
+                                .into(),
+                        ),
                         List::new(types::None::new(Position::fake()), vec![], Position::fake()),
                         Position::fake(),
                     ),
                     false,
-                ),
-            ]))
+                )],
+            ))
             .unwrap();
         }
 
         #[test]
         fn compile_list_with_element() {
-            compile_module(&Module::empty().set_function_definitions(vec![
-                FunctionDefinition::fake(
+            compile_module(&Module::empty().set_function_definitions(
+                vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
                         vec![],
-                        types::List::new(types::None::new(Position::fake()), Position::fake()),
+                        Some(
+                            types::List::new(types::None::new(Position::fake()), Position::fake())
+                                .into(),
+                        ),
                         List::new(
                             types::None::new(Position::fake()),
                             vec![ListElement::Single(None::new(Position::fake()).into())],
@@ -761,19 +1112,22 @@ mod tests {
                         Position::fake(),
                     ),
                     false,
-                ),
-            ]))
+                )],
+            ))
             .unwrap();
         }
 
         #[test]
         fn compile_list_with_elements() {
-            compile_module(&Module::empty().set_function_definitions(vec![
-                FunctionDefinition::fake(
+            compile_module(&Module::empty().set_function_definitions(
+                vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
                         vec![],
-                        types::List::new(types::None::new(Position::fake()), Position::fake()),
+                        Some(
+                            types::List::new(types::None::new(Position::fake()), Position::fake())
+                                .into(),
+                        ),
                         List::new(
                             types::None::new(Position::fake()),
                             vec![
@@ -785,8 +1139,8 @@ mod tests {
                         Position::fake(),
                     ),
                     false,
-                ),
-            ]))
+                )],
+            ))
             .unwrap();
         }
 
@@ -798,8 +1152,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", list_type.clone())],
-                        list_type,
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(list_type.into()),
                         List::new(
                             types::None::new(Position::fake()),
                             vec![ListElement::Multiple(
@@ -827,8 +1185,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            list_type,
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(list_type.into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 Call::new(
@@ -878,7 +1240,7 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                output_list_type.clone(),
+                                Some(output_list_type.clone().into()),
                                 ListComprehension::new(
                                     output_list_type.element().clone(),
                                     ListComprehension::new(
@@ -937,8 +1299,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", input_list_type)],
-                            output_list_type,
+                            vec![Argument::new(
+                                "xs",
+                                Some(input_list_type.into()),
+                                Position::fake(),
+                            )],
+                            Some(output_list_type.into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 None::new(Position::fake()),
@@ -970,8 +1336,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", list_type.clone())],
-                            list_type,
+                            vec![Argument::new(
+                                "xs",
+                                Some(list_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(list_type.into()),
                             ListComprehension::new(
                                 types::Number::new(Position::fake()),
                                 Call::new(
@@ -1020,10 +1390,18 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![
-                                Argument::new("xs", list_type.clone()),
-                                Argument::new("ys", list_type.clone()),
+                                Argument::new(
+                                    "xs",
+                                    Some(list_type.clone().into()),
+                                    Position::fake(),
+                                ),
+                                Argument::new(
+                                    "ys",
+                                    Some(list_type.clone().into()),
+                                    Position::fake(),
+                                ),
                             ],
-                            list_type,
+                            Some(list_type.into()),
                             ListComprehension::new(
                                 types::Number::new(Position::fake()),
                                 AdditionOperation::new(
@@ -1082,8 +1460,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", nested_list_type)],
-                            list_type,
+                            vec![Argument::new(
+                                "xs",
+                                Some(nested_list_type.into()),
+                                Position::fake(),
+                            )],
+                            Some(list_type.into()),
                             ListComprehension::new(
                                 types::Number::new(Position::fake()),
                                 Call::new(
@@ -1136,8 +1518,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", list_type.clone())],
-                            list_type.clone(),
+                            vec![Argument::new(
+                                "xs",
+                                Some(list_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 list_type.element().clone(),
                                 Call::new(
@@ -1179,10 +1565,18 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![
-                                Argument::new("xs", iteratee_list_types[0].clone()),
-                                Argument::new("ys", iteratee_list_types[1].clone()),
+                                Argument::new(
+                                    "xs",
+                                    Some(iteratee_list_types[0].clone().into()),
+                                    Position::fake(),
+                                ),
+                                Argument::new(
+                                    "ys",
+                                    Some(iteratee_list_types[1].clone().into()),
+                                    Position::fake(),
+                                ),
                             ],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 list_type.element().clone(),
                                 None::new(Position::fake()),
@@ -1229,7 +1623,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        map_type.clone(),
+                        Some(map_type.clone().into()),
                         Map::new(
                             map_type.key().clone(),
                             map_type.value().clone(),
@@ -1257,7 +1651,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        map_type.clone(),
+                        Some(map_type.clone().into()),
                         Map::new(
                             map_type.key().clone(),
                             map_type.value().clone(),
@@ -1290,7 +1684,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        map_type.clone(),
+                        Some(map_type.clone().into()),
                         Map::new(
                             map_type.key().clone(),
                             map_type.value().clone(),
@@ -1330,8 +1724,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", map_type.clone())],
-                        map_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(map_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(map_type.clone().into()),
                         Map::new(
                             map_type.key().clone(),
                             map_type.value().clone(),
@@ -1360,8 +1758,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", map_type.clone())],
-                        map_type,
+                        vec![Argument::new(
+                            "x",
+                            Some(map_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(map_type.into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Delete, Position::fake()),
@@ -1395,8 +1797,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", map_type.clone())],
-                        map_type,
+                        vec![Argument::new(
+                            "x",
+                            Some(map_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(map_type.into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Delete, Position::fake()),
@@ -1427,7 +1833,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        map_type.clone(),
+                        Some(map_type.clone().into()),
                         Map::new(
                             map_type.key().clone(),
                             map_type.value().clone(),
@@ -1443,6 +1849,417 @@ mod tests {
         }
     }
 
+    mod serialization {
+        use super::*;
+
+        fn compile_encode(type_: impl Into<Type>) -> Result<(), CompileError> {
+            let type_ = type_.into();
+
+            compile_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", Some(type_), Position::fake())],
+                        Some(types::Any::new(Position::fake()).into()),
+                        Call::new(
+                            None,
+                            BuiltInFunction::new(BuiltInFunctionName::Encode, Position::fake()),
+                            vec![Variable::new("x", Position::fake()).into()],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))?;
+
+            Ok(())
+        }
+
+        fn compile_decode(type_: impl Into<Type>) -> Result<(), CompileError> {
+            compile_module(&Module::empty().set_function_definitions(
+                vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(
+                            types::Union::new(
+                                type_,
+                                types::Error::new(Position::fake()),
+                                Position::fake(),
+                            )
+                            .into(),
+                        ),
+                        Call::new(
+                            None,
+                            BuiltInFunction::new(BuiltInFunctionName::Decode, Position::fake()),
+                            vec![Variable::new("x", Position::fake()).into()],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )],
+            ))?;
+
+            Ok(())
+        }
+
+        fn list_type() -> types::List {
+            types::List::new(types::Number::new(Position::fake()), Position::fake())
+        }
+
+        fn map_type() -> types::Map {
+            types::Map::new(
+                types::ByteString::new(Position::fake()),
+                types::Number::new(Position::fake()),
+                Position::fake(),
+            )
+        }
+
+        fn union_type() -> types::Union {
+            types::Union::new(
+                types::Number::new(Position::fake()),
+                types::None::new(Position::fake()),
+                Position::fake(),
+            )
+        }
+
+        #[test]
+        fn encode_number() {
+            compile_encode(types::Number::new(Position::fake())).unwrap();
+        }
+
+        #[test]
+        fn encode_list() {
+            compile_encode(list_type()).unwrap();
+        }
+
+        #[test]
+        fn encode_map() {
+            compile_encode(map_type()).unwrap();
+        }
+
+        #[test]
+        fn encode_union() {
+            compile_encode(union_type()).unwrap();
+        }
+
+        #[test]
+        fn decode_number() {
+            compile_decode(types::Number::new(Position::fake())).unwrap();
+        }
+
+        #[test]
+        fn decode_list() {
+            compile_decode(list_type()).unwrap();
+        }
+
+        #[test]
+        fn decode_map() {
+            compile_decode(map_type()).unwrap();
+        }
+
+        #[test]
+        fn decode_union() {
+            compile_decode(union_type()).unwrap();
+        }
+
+        #[test]
+        fn encode_and_decode_record() {
+            let record_type = types::Record::fake("r");
+
+            compile_module(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "r",
+                        vec![
+                            types::RecordField::new("x", types::Number::new(Position::fake())),
+                            types::RecordField::new("y", list_type()),
+                        ],
+                        false,
+                        false,
+                        false,
+                    )])
+                    .set_function_definitions(vec![
+                        FunctionDefinition::fake(
+                            "f",
+                            Lambda::new(
+                                vec![Argument::new(
+                                    "x",
+                                    Some(record_type.clone().into()),
+                                    Position::fake(),
+                                )],
+                                Some(types::Any::new(Position::fake()).into()),
+                                Call::new(
+                                    None,
+                                    BuiltInFunction::new(
+                                        BuiltInFunctionName::Encode,
+                                        Position::fake(),
+                                    ),
+                                    vec![Variable::new("x", Position::fake()).into()],
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            false,
+                        ),
+                        FunctionDefinition::fake(
+                            "g",
+                            Lambda::new(
+                                vec![Argument::new(
+                                    "x",
+                                    Some(types::Any::new(Position::fake()).into()),
+                                    Position::fake(),
+                                )],
+                                Some(
+                                    types::Union::new(
+                                        record_type,
+                                        types::Error::new(Position::fake()),
+                                        Position::fake(),
+                                    )
+                                    .into(),
+                                ),
+                                Call::new(
+                                    None,
+                                    BuiltInFunction::new(
+                                        BuiltInFunctionName::Decode,
+                                        Position::fake(),
+                                    ),
+                                    vec![Variable::new("x", Position::fake()).into()],
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            false,
+                        ),
+                    ]),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn fail_to_encode_function() {
+            assert!(matches!(
+                compile_encode(types::Function::new(
+                    vec![],
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                )),
+                Err(CompileError::Analysis(AnalysisError::TypeNotSerializable(
+                    _,
+                    _
+                )))
+            ));
+        }
+    }
+
+    mod vector {
+        use super::*;
+
+        fn vector_type() -> types::Vector {
+            types::Vector::new(types::Number::new(Position::fake()), Position::fake())
+        }
+
+        fn compile_function(
+            arguments: Vec<Argument>,
+            result_type: impl Into<Type>,
+            body: impl Into<Expression>,
+        ) -> Result<(mir::ir::Module, interface::Module, Vec<AnalysisWarning>), CompileError>
+        {
+            compile_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(arguments, Some(result_type.into()), body, Position::fake()),
+                    false,
+                ),
+            ]))
+        }
+
+        fn call_built_in(name: BuiltInFunctionName, arguments: Vec<Expression>) -> Call {
+            Call::new(
+                None,
+                BuiltInFunction::new(name, Position::fake()),
+                arguments,
+                Position::fake(),
+            )
+        }
+
+        #[test]
+        fn compile_empty_vector() {
+            compile_function(
+                vec![],
+                vector_type(),
+                Vector::new(
+                    types::Number::new(Position::fake()),
+                    vec![],
+                    Position::fake(),
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_vector_with_elements() {
+            compile_function(
+                vec![],
+                vector_type(),
+                Vector::new(
+                    types::Number::new(Position::fake()),
+                    vec![
+                        Number::new(1.0, Position::fake()).into(),
+                        Number::new(2.0, Position::fake()).into(),
+                    ],
+                    Position::fake(),
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_get() {
+            compile_function(
+                vec![Argument::new(
+                    "x",
+                    Some(vector_type().into()),
+                    Position::fake(),
+                )],
+                types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                call_built_in(
+                    BuiltInFunctionName::Get,
+                    vec![
+                        Variable::new("x", Position::fake()).into(),
+                        Number::new(1.0, Position::fake()).into(),
+                    ],
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_push() {
+            compile_function(
+                vec![Argument::new(
+                    "x",
+                    Some(vector_type().into()),
+                    Position::fake(),
+                )],
+                vector_type(),
+                call_built_in(
+                    BuiltInFunctionName::Push,
+                    vec![
+                        Variable::new("x", Position::fake()).into(),
+                        Number::new(42.0, Position::fake()).into(),
+                    ],
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_set() {
+            compile_function(
+                vec![Argument::new(
+                    "x",
+                    Some(vector_type().into()),
+                    Position::fake(),
+                )],
+                vector_type(),
+                call_built_in(
+                    BuiltInFunctionName::Set,
+                    vec![
+                        Variable::new("x", Position::fake()).into(),
+                        Number::new(1.0, Position::fake()).into(),
+                        Number::new(42.0, Position::fake()).into(),
+                    ],
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_slice() {
+            compile_function(
+                vec![Argument::new(
+                    "x",
+                    Some(vector_type().into()),
+                    Position::fake(),
+                )],
+                vector_type(),
+                call_built_in(
+                    BuiltInFunctionName::Slice,
+                    vec![
+                        Variable::new("x", Position::fake()).into(),
+                        Number::new(1.0, Position::fake()).into(),
+                        Number::new(2.0, Position::fake()).into(),
+                    ],
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_size() {
+            compile_function(
+                vec![Argument::new(
+                    "x",
+                    Some(vector_type().into()),
+                    Position::fake(),
+                )],
+                types::Number::new(Position::fake()),
+                call_built_in(
+                    BuiltInFunctionName::Size,
+                    vec![Variable::new("x", Position::fake()).into()],
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_equal_operation() {
+            compile_function(
+                vec![
+                    Argument::new("x", Some(vector_type().into()), Position::fake()),
+                    Argument::new("y", Some(vector_type().into()), Position::fake()),
+                ],
+                types::Boolean::new(Position::fake()),
+                EqualityOperation::new(
+                    None,
+                    EqualityOperator::Equal,
+                    Variable::new("x", Position::fake()),
+                    Variable::new("y", Position::fake()),
+                    Position::fake(),
+                ),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn compile_debug() {
+            compile_function(
+                vec![Argument::new(
+                    "x",
+                    Some(vector_type().into()),
+                    Position::fake(),
+                )],
+                types::None::new(Position::fake()),
+                call_built_in(
+                    BuiltInFunctionName::Debug,
+                    vec![Variable::new("x", Position::fake()).into()],
+                ),
+            )
+            .unwrap();
+        }
+    }
+
     mod record {
         use super::*;
 
@@ -1466,7 +2283,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            reference_type.clone(),
+                            Some(reference_type.clone().into()),
                             RecordConstruction::new(
                                 reference_type,
                                 vec![RecordField::new(
@@ -1503,8 +2320,12 @@ mod tests {
                     .set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("r", reference_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "r",
+                                Some(reference_type.into()),
+                                Position::fake(),
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             RecordDeconstruction::new(
                                 None,
                                 Variable::new("r", Position::fake()),
@@ -1530,7 +2351,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Debug, Position::fake()),
@@ -1566,8 +2387,12 @@ mod tests {
                     .set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", types::Record::fake("r"))],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(types::Record::fake("r").into()),
+                                Position::fake(),
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Debug, Position::fake()),
@@ -1590,9 +2415,16 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::List::new(types::None::new(Position::fake()), Position::fake()),
+                            Some(
+                                types::List::new(
+                                    types::None::new(Position::fake()),
+                                    Position::fake(),
+                                )
+                                .into(),
+                            ),
+                            Position::fake(),
                         )],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Debug, Position::fake()),
@@ -1615,13 +2447,14 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Map::new(
+                            Some(types::Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
+                            Position::fake(),
                         )],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Debug, Position::fake()),
@@ -1638,12 +2471,15 @@ mod tests {
 
         #[test]
         fn compile_race() {
-            compile_module(&Module::empty().set_function_definitions(vec![
-                FunctionDefinition::fake(
+            compile_module(&Module::empty().set_function_definitions(
+                vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
                         vec![],
-                        types::List::new(types::None::new(Position::fake()), Position::fake()),
+                        Some(
+                            types::List::new(types::None::new(Position::fake()), Position::fake())
+                                .into(),
+                        ),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Race, Position::fake()),
@@ -1654,14 +2490,15 @@ mod tests {
                                 ),
                                 vec![],
                                 Position::fake(),
-                            ).into()],
+                            )
+                            .into()],
                             Position::fake(),
                         ),
                         Position::fake(),
                     ),
                     false,
-                ),
-            ]))
+                )],
+            ))
             .unwrap();
         }
 
@@ -1678,7 +2515,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::List::new(map_type.key().clone(), Position::fake()),
+                        Some(types::List::new(map_type.key().clone(), Position::fake()).into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Keys, Position::fake()),
@@ -1711,7 +2548,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::List::new(map_type.value().clone(), Position::fake()),
+                        Some(types::List::new(map_type.value().clone(), Position::fake()).into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(BuiltInFunctionName::Values, Position::fake()),
@@ -1742,7 +2579,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::ByteString::new(Position::fake()),
+                        Some(types::ByteString::new(Position::fake()).into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(
@@ -1767,11 +2604,11 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::Union::new(
+                        Some(types::Union::new(
                             types::Boolean::new(Position::fake()),
                             types::None::new(Position::fake()),
                             Position::fake(),
-                        ),
+                        ).into()),
                         Call::new(
                             None,
                             BuiltInFunction::new(
@@ -1792,4 +2629,121 @@ mod tests {
             .unwrap();
         }
     }
+
+    mod coverage {
+        use super::*;
+
+        fn instrument(module: &Module) -> (Module, test_info::Coverage) {
+            crate::coverage::instrument(
+                module,
+                &TestModuleConfiguration {
+                    test_function_prefix: "test_".into(),
+                    coverage_function_name: "coverage".into(),
+                },
+            )
+        }
+
+        #[test]
+        fn compile_function() {
+            let (module, coverage) = instrument(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]));
+
+            compile_module(&module).unwrap();
+
+            assert!(matches!(
+                coverage.counters(),
+                [counter] if matches!(
+                    counter.kind(),
+                    test_info::CounterKind::Function(_)
+                )
+            ));
+        }
+
+        #[test]
+        fn compile_if() {
+            let (module, coverage) = instrument(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        If::new(
+                            Boolean::new(true, Position::fake()),
+                            None::new(Position::fake()),
+                            None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]));
+
+            compile_module(&module).unwrap();
+
+            assert!(matches!(
+                coverage.counters(),
+                [_, then, else_] if matches!(
+                    (then.kind(), else_.kind()),
+                    (
+                        test_info::CounterKind::Branch { block: 0, branch: 0 },
+                        test_info::CounterKind::Branch { block: 0, branch: 1 },
+                    )
+                )
+            ));
+        }
+
+        #[test]
+        fn compile_if_type() {
+            let union_type = types::Union::new(
+                types::Number::new(Position::fake()),
+                types::None::new(Position::fake()),
+                Position::fake(),
+            );
+
+            let (module, coverage) = instrument(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
+                        IfType::new(
+                            "y",
+                            Variable::new("x", Position::fake()),
+                            vec![IfTypeBranch::new(
+                                types::Number::new(Position::fake()),
+                                None::new(Position::fake()),
+                            )],
+                            Some(ElseBranch::new(
+                                None,
+                                None::new(Position::fake()),
+                                Position::fake(),
+                            )),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]));
+
+            compile_module(&module).unwrap();
+
+            assert_eq!(coverage.counters().len(), 3);
+        }
+    }
 }
@@ -17,6 +17,8 @@ pub static LIST_TYPE_CONFIGURATION: LazyLock<ListTypeConfiguration> =
         first_rest_type_name: "firstRest".into(),
         size_function_name: "listSize".into(),
         debug_function_name: "debugList".into(),
+        encode_function_name: "encodeList".into(),
+        decode_function_name: "decodeList".into(),
     });
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -34,4 +36,6 @@ pub struct ListTypeConfiguration {
     pub first_rest_type_name: String,
     pub size_function_name: String,
     pub debug_function_name: String,
+    pub encode_function_name: String,
+    pub decode_function_name: String,
 }
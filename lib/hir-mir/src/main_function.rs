@@ -84,7 +84,7 @@ pub fn compile(
                 &main_module_configuration.object_main_function_name,
                 Lambda::new(
                     vec![],
-                    function_type.result().clone(),
+                    Some(function_type.result().clone()),
                     Call::new(
                         None,
                         Variable::new(main_function_definition.name(), position.clone()),
@@ -17,6 +17,8 @@ pub static MAP_TYPE_CONFIGURATION: LazyLock<MapTypeConfiguration> =
         set_function_name: "setMap".into(),
         size_function_name: "mapSize".into(),
         debug_function_name: "debugMap".into(),
+        encode_function_name: "encodeMap".into(),
+        decode_function_name: "decodeMap".into(),
         hash: HASH_CONFIGURATION.clone(),
         iteration: MAP_TYPE_ITERATION_CONFIGURATION.clone(),
     });
@@ -28,6 +30,7 @@ pub static HASH_CONFIGURATION: LazyLock<HashConfiguration> = LazyLock::new(|| Ha
     string_hash_function_name: "hashString".into(),
     list_hash_function_name: "hashList".into(),
     map_hash_function_name: "hashMap".into(),
+    vector_hash_function_name: "hashVector".into(),
 });
 
 #[cfg(test)]
@@ -55,6 +58,8 @@ pub struct MapTypeConfiguration {
     pub set_function_name: String,
     pub size_function_name: String,
     pub debug_function_name: String,
+    pub encode_function_name: String,
+    pub decode_function_name: String,
     pub hash: HashConfiguration,
     pub iteration: MapTypeIterationConfiguration,
 }
@@ -66,6 +71,7 @@ pub struct HashConfiguration {
     pub string_hash_function_name: String,
     pub list_hash_function_name: String,
     pub map_hash_function_name: String,
+    pub vector_hash_function_name: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
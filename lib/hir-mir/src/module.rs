@@ -1,6 +1,9 @@
 use super::{context::Context, expression, generic_type_definition, type_, CompileError};
 use crate::{error_type, runtime_function_declaration, type_information};
-use hir::{analysis::AnalysisError, ir::*};
+use hir::{
+    analysis::{type_extractor, AnalysisError},
+    ir::*,
+};
 
 pub fn compile(context: &Context, module: &Module) -> Result<mir::ir::Module, CompileError> {
     let (type_information_function_declarations, type_information_function_definitions) =
@@ -121,8 +124,9 @@ fn compile_function_definition(
     context: &Context,
     definition: &FunctionDefinition,
 ) -> Result<mir::ir::GlobalFunctionDefinition, CompileError> {
+    let type_ = type_extractor::extract_from_lambda(definition.lambda())?;
     let body = expression::compile(context, definition.lambda().body())?;
-    let result_type = type_::compile(context, definition.lambda().result_type())?;
+    let result_type = type_::compile(context, type_.result())?;
 
     Ok(mir::ir::GlobalFunctionDefinition::new(
         if definition.lambda().arguments().is_empty() {
@@ -134,17 +138,19 @@ fn compile_function_definition(
                     .lambda()
                     .arguments()
                     .iter()
-                    .map(|argument| -> Result<_, CompileError> {
+                    .zip(type_.arguments())
+                    .map(|(argument, type_)| -> Result<_, CompileError> {
                         Ok(mir::ir::Argument::new(
                             argument.name(),
-                            type_::compile(context, argument.type_())?,
+                            type_::compile(context, type_)?,
                         ))
                     })
                     .collect::<Result<_, _>>()?,
                 result_type,
                 body,
             )
-        },
+        }
+        .set_position(context.debug_position(definition.position())),
         definition.is_public(),
     ))
 }
@@ -152,7 +158,7 @@ fn compile_function_definition(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::compile_configuration::COMPILE_CONFIGURATION;
+    use crate::{compile_configuration::COMPILE_CONFIGURATION, CompileConfiguration};
     use hir::{test::ModuleFake, types};
     use mir::test::{GlobalFunctionDefinitionFake, ModuleFake as _};
     use position::{test::PositionFake, Position};
@@ -168,8 +174,12 @@ mod tests {
             "foo",
             "bar",
             Lambda::new(
-                vec![Argument::new("x", types::None::new(Position::fake()))],
-                types::None::new(Position::fake()),
+                vec![Argument::new(
+                    "x",
+                    Some(types::None::new(Position::fake()).into()),
+                    Position::fake(),
+                )],
+                Some(types::None::new(Position::fake()).into()),
                 None::new(Position::fake()),
                 Position::fake(),
             ),
@@ -218,8 +228,12 @@ mod tests {
             "foo",
             "bar",
             Lambda::new(
-                vec![Argument::new("x", types::None::new(Position::fake()))],
-                types::None::new(Position::fake()),
+                vec![Argument::new(
+                    "x",
+                    Some(types::None::new(Position::fake()).into()),
+                    Position::fake(),
+                )],
+                Some(types::None::new(Position::fake()).into()),
                 None::new(Position::fake()),
                 Position::fake(),
             ),
@@ -261,4 +275,85 @@ mod tests {
                 ))
         );
     }
+
+    #[test]
+    fn compile_function_definition_with_debug_information() {
+        let none_type = types::None::new(Position::fake());
+        let definition = FunctionDefinition::new(
+            "foo",
+            "foo",
+            Lambda::new(
+                vec![Argument::new(
+                    "x",
+                    Some(none_type.clone().into()),
+                    Position::fake(),
+                )],
+                Some(none_type.clone().into()),
+                Call::new(
+                    Some(
+                        types::Function::new(
+                            vec![none_type.clone().into()],
+                            none_type,
+                            Position::fake(),
+                        )
+                        .into(),
+                    ),
+                    Variable::new("foo", Position::fake()),
+                    vec![Variable::new("x", Position::fake()).into()],
+                    Position::new("foo.pen", 2, 3, ""),
+                ),
+                Position::fake(),
+            ),
+            None,
+            false,
+            Position::new("foo.pen", 1, 1, ""),
+        );
+        let module = Module::empty().set_function_definitions(vec![definition.clone()]);
+        let context = Context::new(
+            &module,
+            CompileConfiguration {
+                debug_information: true,
+                ..COMPILE_CONFIGURATION.clone()
+            }
+            .into(),
+        );
+
+        let definition = compile_function_definition(&context, &definition).unwrap();
+        let position = definition.definition().position().unwrap();
+
+        assert_eq!((position.path(), position.line_number()), ("foo.pen", 1));
+
+        let mir::ir::Expression::Call(call) = definition.definition().body() else {
+            unreachable!()
+        };
+        let position = call.position().unwrap();
+
+        assert_eq!((position.path(), position.line_number()), ("foo.pen", 2));
+    }
+
+    #[test]
+    fn compile_function_definition_without_debug_information() {
+        let definition = FunctionDefinition::new(
+            "foo",
+            "foo",
+            Lambda::new(
+                vec![],
+                Some(types::None::new(Position::fake()).into()),
+                None::new(Position::fake()),
+                Position::fake(),
+            ),
+            None,
+            false,
+            Position::fake(),
+        );
+        let module = Module::empty().set_function_definitions(vec![definition.clone()]);
+
+        assert_eq!(
+            compile_function_definition(&create_context(&module), &definition)
+                .unwrap()
+                .definition()
+                .position(),
+            None
+        );
+    }
 }
@@ -1,7 +1,10 @@
 use super::error::CompileError;
 use hir::{analysis::type_extractor, ir};
 
-pub fn compile(module: &ir::Module) -> Result<interface::Module, CompileError> {
+pub fn compile(
+    module: &ir::Module,
+    mir_module: &mir::ir::Module,
+) -> Result<interface::Module, CompileError> {
     Ok(interface::Module::new(
         module
             .type_definitions()
@@ -33,19 +36,17 @@ pub fn compile(module: &ir::Module) -> Result<interface::Module, CompileError> {
         module
             .function_definitions()
             .iter()
-            .filter_map(|definition| {
-                if definition.is_public() {
-                    Some(interface::FunctionDeclaration::new(
-                        definition.name(),
-                        definition.original_name(),
-                        type_extractor::extract_from_lambda(definition.lambda()),
-                        definition.position().clone(),
-                    ))
-                } else {
-                    None
-                }
+            .filter(|definition| definition.is_public())
+            .map(|definition| {
+                Ok(interface::FunctionDeclaration::new(
+                    definition.name(),
+                    definition.original_name(),
+                    type_extractor::extract_from_lambda(definition.lambda())?,
+                    definition.position().clone(),
+                ))
             })
-            .collect(),
+            .collect::<Result<_, CompileError>>()?,
+        mir::analysis::optimization::external_definition::export(mir_module),
     ))
 }
 
@@ -56,32 +57,59 @@ mod tests {
         test::{FunctionDefinitionFake, ModuleFake},
         types,
     };
+    use mir::test::ModuleFake as _;
     use position::{test::PositionFake, Position};
 
     #[test]
     fn compile_empty_module() {
         assert_eq!(
-            compile(&ir::Module::empty()),
-            Ok(interface::Module::new(vec![], vec![], vec![]))
+            compile(&ir::Module::empty(), &mir::ir::Module::empty()),
+            Ok(interface::Module::new(vec![], vec![], vec![], vec![]))
         );
     }
 
     #[test]
     fn compile_without_private_declaration() {
         assert_eq!(
-            compile(&ir::Module::empty().set_function_definitions(vec![
-                ir::FunctionDefinition::fake(
+            compile(
+                &ir::Module::empty().set_function_definitions(vec![ir::FunctionDefinition::fake(
                     "foo",
                     ir::Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         ir::None::new(Position::fake()),
                         Position::fake(),
                     ),
                     false,
-                )
-            ])),
-            Ok(interface::Module::new(vec![], vec![], vec![]))
+                )]),
+                &mir::ir::Module::empty()
+            ),
+            Ok(interface::Module::new(vec![], vec![], vec![], vec![]))
+        );
+    }
+
+    #[test]
+    fn compile_function_definition() {
+        let definition = mir::ir::FunctionDefinition::new(
+            "foo",
+            vec![mir::ir::Argument::new("x", mir::types::Type::Variant)],
+            mir::types::Type::Variant,
+            mir::ir::Variable::new("x"),
+        );
+
+        assert_eq!(
+            compile(
+                &ir::Module::empty(),
+                &mir::ir::Module::empty().set_global_function_definitions(vec![
+                    mir::ir::GlobalFunctionDefinition::new(definition.clone(), true)
+                ])
+            ),
+            Ok(interface::Module::new(
+                vec![],
+                vec![],
+                vec![],
+                vec![definition]
+            ))
         );
     }
 }
@@ -4,10 +4,12 @@ use std::sync::LazyLock;
 #[cfg(test)]
 pub static STRING_TYPE_CONFIGURATION: LazyLock<StringTypeConfiguration> =
     LazyLock::new(|| StringTypeConfiguration {
+        compare_function_name: "_compareStrings".into(),
         equal_function_name: "_equalStrings".into(),
     });
 
 #[derive(Clone, Debug)]
 pub struct StringTypeConfiguration {
+    pub compare_function_name: String,
     pub equal_function_name: String,
 }
@@ -43,7 +43,7 @@ pub fn compile(
                                 compile_foreign_name(definition.name(), configuration),
                                 Lambda::new(
                                     vec![],
-                                    types::ByteString::new(position.clone()),
+                                    Some(types::ByteString::new(position.clone()).into()),
                                     IfType::new(
                                         "$result",
                                         Call::new(
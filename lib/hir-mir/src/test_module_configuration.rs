@@ -1,3 +1,4 @@
 pub struct TestModuleConfiguration {
     pub test_function_prefix: String,
+    pub coverage_function_name: String,
 }
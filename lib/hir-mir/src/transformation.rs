@@ -1,5 +1,5 @@
 pub mod boolean_operation;
-mod collection_type;
+pub mod collection_type;
 pub mod equal_operation;
 pub mod hash_calculation;
 pub mod if_list;
@@ -10,4 +10,7 @@ pub mod map_literal;
 pub mod not_equal_operation;
 pub mod record_equal_function;
 pub mod record_hash_function;
+pub mod record_serialization_function;
 mod record_type_information;
+pub mod serialization;
+pub mod vector_literal;
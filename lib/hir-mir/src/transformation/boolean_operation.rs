@@ -31,6 +31,7 @@ mod tests {
     fn transform_and_operation() {
         assert_eq!(
             transform(&BooleanOperation::new(
+                None,
                 BooleanOperator::And,
                 Boolean::new(true, Position::fake()),
                 Boolean::new(true, Position::fake()),
@@ -50,6 +51,7 @@ mod tests {
     fn transform_or_operation() {
         assert_eq!(
             transform(&BooleanOperation::new(
+                None,
                 BooleanOperator::Or,
                 Boolean::new(false, Position::fake()),
                 Boolean::new(false, Position::fake()),
@@ -26,6 +26,14 @@ pub fn transform_map(context: &Context, position: &Position) -> Result<Type, Com
     .into())
 }
 
+pub fn transform_vector(context: &Context, position: &Position) -> Result<Type, CompileError> {
+    Ok(types::Reference::new(
+        &context.configuration()?.vector_type.vector_type_name,
+        position.clone(),
+    )
+    .into())
+}
+
 pub fn transform_map_context(context: &Context, position: &Position) -> Result<Type, CompileError> {
     Ok(types::Reference::new(
         &context.configuration()?.map_type.context_type_name,
@@ -47,6 +55,9 @@ pub fn collect_comparable_parameter_types(
         Type::Map(map_type) => {
             types.extend([map_type.key(), map_type.value()]);
         }
+        Type::Vector(vector_type) => {
+            types.insert(vector_type.element());
+        }
         _ => {}
     });
 
@@ -66,6 +77,9 @@ pub fn collect_comparable_parameter_types(
         Expression::Map(map) => {
             types.extend([map.key_type(), map.value_type()]);
         }
+        Expression::Vector(vector) => {
+            types.insert(vector.type_());
+        }
         _ => {}
     });
 
@@ -41,10 +41,18 @@ fn compile_function_definition(
         &name,
         Lambda::new(
             vec![
-                Argument::new(LHS_NAME, types::Any::new(position.clone())),
-                Argument::new(RHS_NAME, types::Any::new(position.clone())),
+                Argument::new(
+                    LHS_NAME,
+                    Some(types::Any::new(position.clone()).into()),
+                    position.clone(),
+                ),
+                Argument::new(
+                    RHS_NAME,
+                    Some(types::Any::new(position.clone()).into()),
+                    position.clone(),
+                ),
             ],
-            types::Boolean::new(position.clone()),
+            Some(types::Boolean::new(position.clone()).into()),
             IfType::new(
                 LHS_NAME,
                 Variable::new(LHS_NAME, position.clone()),
@@ -151,6 +151,35 @@ fn transform_canonical(
             position.clone(),
         )
         .into(),
+        Type::Vector(vector_type) => {
+            let any_vector_type = collection_type::transform_vector(context, position)?;
+
+            Call::new(
+                Some(
+                    types::Function::new(
+                        vec![
+                            compile_any_function_type(position).into(),
+                            any_vector_type.clone(),
+                            any_vector_type,
+                        ],
+                        types::Boolean::new(position.clone()),
+                        position.clone(),
+                    )
+                    .into(),
+                ),
+                Variable::new(
+                    &context.configuration()?.vector_type.equal_function_name,
+                    position.clone(),
+                ),
+                vec![
+                    function::transform(context, vector_type.element())?,
+                    lhs.clone(),
+                    rhs.clone(),
+                ],
+                position.clone(),
+            )
+            .into()
+        }
         Type::Union(_) => {
             let member_types = union_type_member_calculator::calculate(type_, context.types())?;
 
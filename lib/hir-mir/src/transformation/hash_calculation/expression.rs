@@ -104,6 +104,29 @@ pub fn transform(
             type_,
             position,
         ),
+        Type::Vector(vector_type) => Call::new(
+            Some(
+                types::Function::new(
+                    vec![
+                        compile_any_function_type(position).into(),
+                        collection_type::transform_vector(context, position)?,
+                    ],
+                    types::Number::new(position.clone()),
+                    position.clone(),
+                )
+                .into(),
+            ),
+            Variable::new(
+                &configuration.map_type.hash.vector_hash_function_name,
+                position.clone(),
+            ),
+            vec![
+                function::transform(context, vector_type.element())?,
+                value.clone(),
+            ],
+            position.clone(),
+        )
+        .into(),
         Type::Union(_) => {
             const VALUE_NAME: &str = "$x";
             let member_types = union_type_member_calculator::calculate(type_, context.types())?;
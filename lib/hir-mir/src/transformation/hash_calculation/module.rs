@@ -45,9 +45,10 @@ fn compile_function_definition(
         Lambda::new(
             vec![Argument::new(
                 ARGUMENT_NAME,
-                types::Any::new(position.clone()),
+                Some(types::Any::new(position.clone()).into()),
+                position.clone(),
             )],
-            types::Number::new(position.clone()),
+            Some(types::Number::new(position.clone()).into()),
             IfType::new(
                 ARGUMENT_NAME,
                 Variable::new(ARGUMENT_NAME, position.clone()),
@@ -69,7 +69,7 @@ pub fn transform(context: &Context, if_: &IfList) -> Result<Expression, CompileE
                                 first,
                                 Lambda::new(
                                     vec![],
-                                    element_type.clone(),
+                                    Some(element_type.clone()),
                                     downcast::compile(
                                         context,
                                         &any_type,
@@ -50,7 +50,7 @@ fn transform_map_context_function_definition(
         &name,
         Lambda::new(
             vec![],
-            context_type.clone(),
+            Some(context_type.clone()),
             Call::new(
                 Some(compile_context_function_type(&context_type, position).into()),
                 Variable::new(
@@ -118,10 +118,18 @@ fn compile_context_function_type(context_type: &Type, position: &Position) -> ty
 fn compile_fake_equal_function(position: &Position) -> Lambda {
     Lambda::new(
         vec![
-            Argument::new("$value1", types::Any::new(position.clone())),
-            Argument::new("$value2", types::Any::new(position.clone())),
+            Argument::new(
+                "$value1",
+                Some(types::Any::new(position.clone()).into()),
+                position.clone(),
+            ),
+            Argument::new(
+                "$value2",
+                Some(types::Any::new(position.clone()).into()),
+                position.clone(),
+            ),
         ],
-        types::Boolean::new(position.clone()),
+        Some(types::Boolean::new(position.clone()).into()),
         Boolean::new(false, position.clone()),
         position.clone(),
     )
@@ -129,8 +137,12 @@ fn compile_fake_equal_function(position: &Position) -> Lambda {
 
 fn compile_fake_hash_function(position: &Position) -> Lambda {
     Lambda::new(
-        vec![Argument::new("$value", types::Any::new(position.clone()))],
-        types::Number::new(position.clone()),
+        vec![Argument::new(
+            "$value",
+            Some(types::Any::new(position.clone()).into()),
+            position.clone(),
+        )],
+        Some(types::Number::new(position.clone()).into()),
         Number::new(0.0, position.clone()),
         position.clone(),
     )
@@ -194,7 +206,10 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    collection_type::transform_map_context(&context, &Position::fake()).unwrap(),
+                    Some(
+                        collection_type::transform_map_context(&context, &Position::fake())
+                            .unwrap()
+                    ),
                     Map::new(
                         types::None::new(Position::fake()),
                         types::None::new(Position::fake()),
@@ -218,7 +233,10 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    collection_type::transform_map_context(&context, &Position::fake()).unwrap(),
+                    Some(
+                        collection_type::transform_map_context(&context, &Position::fake())
+                            .unwrap()
+                    ),
                     Map::new(
                         types::None::new(Position::fake()),
                         types::Function::new(
@@ -252,7 +270,10 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    collection_type::transform_map_context(&context, &Position::fake()).unwrap(),
+                    Some(
+                        collection_type::transform_map_context(&context, &Position::fake())
+                            .unwrap(),
+                    ),
                     Map::new(
                         types::None::new(Position::fake()),
                         types::None::new(Position::fake()),
@@ -267,7 +288,10 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    collection_type::transform_map_context(&context, &Position::fake()).unwrap(),
+                    Some(
+                        collection_type::transform_map_context(&context, &Position::fake())
+                            .unwrap(),
+                    ),
                     Map::new(
                         types::Reference::new("foo", Position::fake()),
                         types::Reference::new("bar", Position::fake()),
@@ -95,10 +95,10 @@ fn compile_function_definition(type_definition: &TypeDefinition) -> FunctionDefi
         &function_name,
         Lambda::new(
             vec![
-                Argument::new(LHS_NAME, record_type.clone()),
-                Argument::new(RHS_NAME, record_type.clone()),
+                Argument::new(LHS_NAME, Some(record_type.clone().into()), position.clone()),
+                Argument::new(RHS_NAME, Some(record_type.clone().into()), position.clone()),
             ],
-            types::Boolean::new(position.clone()),
+            Some(types::Boolean::new(position.clone()).into()),
             type_definition.fields().iter().rev().fold(
                 Boolean::new(true, position.clone()).into(),
                 |expression: Expression, field| {
@@ -175,10 +175,18 @@ mod tests {
                     "foo.$equal",
                     Lambda::new(
                         vec![
-                            Argument::new(LHS_NAME, record_type.clone()),
-                            Argument::new(RHS_NAME, record_type.clone()),
+                            Argument::new(
+                                LHS_NAME,
+                                Some(record_type.clone().into()),
+                                Position::fake()
+                            ),
+                            Argument::new(
+                                RHS_NAME,
+                                Some(record_type.clone().into()),
+                                Position::fake()
+                            ),
                         ],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         If::new(
                             EqualityOperation::new(
                                 Some(types::None::new(Position::fake()).into()),
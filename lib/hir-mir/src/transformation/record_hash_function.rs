@@ -113,8 +113,12 @@ fn compile_function_definition(
         &function_name,
         &function_name,
         Lambda::new(
-            vec![Argument::new(RECORD_NAME, record_type.clone())],
-            hash_type.clone(),
+            vec![Argument::new(
+                RECORD_NAME,
+                Some(record_type.clone().into()),
+                position.clone(),
+            )],
+            Some(hash_type.clone()),
             type_definition.fields().iter().rev().try_fold(
                 Expression::from(compile_identity_hash(type_definition)),
                 |expression, field| -> Result<_, CompileError> {
@@ -225,8 +229,12 @@ mod tests {
                     "foo.$hash",
                     "foo.$hash",
                     Lambda::new(
-                        vec![Argument::new(RECORD_NAME, record_type),],
-                        hash_type(),
+                        vec![Argument::new(
+                            RECORD_NAME,
+                            Some(record_type.into()),
+                            Position::fake()
+                        ),],
+                        Some(hash_type()),
                         Call::new(
                             Some(combine_hash_function_type()),
                             Variable::new(
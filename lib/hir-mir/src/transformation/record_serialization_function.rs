@@ -0,0 +1,313 @@
+use crate::{
+    context::Context,
+    transformation::{record_type_information, serialization},
+    CompileError,
+};
+use hir::{analysis::type_serializability_checker, ir::*, types};
+
+const RECORD_NAME: &str = "$record";
+const TREE_NAME: &str = "$tree";
+
+pub fn transform(context: &Context, module: &Module) -> Result<Module, CompileError> {
+    // Skip the prelude modules as they do not serialize any values.
+    if context.configuration().is_err() {
+        return Ok(module.clone());
+    }
+
+    let (external_type_definitions, internal_type_definitions) = module
+        .type_definitions()
+        .iter()
+        .map(|definition| {
+            Ok(
+                if type_serializability_checker::check(
+                    &types::Record::new(
+                        definition.name(),
+                        definition.original_name(),
+                        definition.position().clone(),
+                    )
+                    .into(),
+                    context.types(),
+                    context.records(),
+                )? {
+                    Some(definition)
+                } else {
+                    None
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, CompileError>>()?
+        .into_iter()
+        .flatten()
+        .partition::<Vec<_>, _>(|definition| definition.is_external());
+
+    Ok(Module::new(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module
+            .function_declarations()
+            .iter()
+            .cloned()
+            .chain(
+                external_type_definitions
+                    .iter()
+                    .copied()
+                    .flat_map(compile_function_declarations),
+            )
+            .collect(),
+        module
+            .function_definitions()
+            .iter()
+            .cloned()
+            .chain(
+                internal_type_definitions
+                    .iter()
+                    .map(|definition| {
+                        Ok([
+                            compile_encode_function_definition(context, definition)?,
+                            compile_decode_function_definition(context, definition)?,
+                        ])
+                    })
+                    .collect::<Result<Vec<_>, CompileError>>()?
+                    .into_iter()
+                    .flatten(),
+            )
+            .collect(),
+        module.position().clone(),
+    ))
+}
+
+fn compile_function_declarations(type_definition: &TypeDefinition) -> [FunctionDeclaration; 2] {
+    let position = type_definition.position();
+    let record_type = compile_record_type(type_definition);
+
+    [
+        FunctionDeclaration::new(
+            record_type_information::compile_encode_function_name(&record_type),
+            compile_encode_function_type(&record_type),
+            position.clone(),
+        ),
+        FunctionDeclaration::new(
+            record_type_information::compile_decode_function_name(&record_type),
+            compile_decode_function_type(&record_type),
+            position.clone(),
+        ),
+    ]
+}
+
+fn compile_encode_function_definition(
+    context: &Context,
+    type_definition: &TypeDefinition,
+) -> Result<FunctionDefinition, CompileError> {
+    let position = type_definition.position();
+    let record_type = compile_record_type(type_definition);
+    let tree_type = types::Map::new(
+        types::ByteString::new(position.clone()),
+        types::Any::new(position.clone()),
+        position.clone(),
+    );
+    let function_name = record_type_information::compile_encode_function_name(&record_type);
+
+    Ok(FunctionDefinition::new(
+        &function_name,
+        &function_name,
+        Lambda::new(
+            vec![Argument::new(
+                RECORD_NAME,
+                Some(record_type.clone().into()),
+                position.clone(),
+            )],
+            Some(types::Any::new(position.clone()).into()),
+            TypeCoercion::new(
+                tree_type.clone(),
+                types::Any::new(position.clone()),
+                Map::new(
+                    tree_type.key().clone(),
+                    tree_type.value().clone(),
+                    type_definition
+                        .fields()
+                        .iter()
+                        .map(|field| {
+                            Ok(MapEntry::new(
+                                ByteString::new(field.name(), position.clone()),
+                                serialization::expression::transform_encode(
+                                    context,
+                                    &RecordDeconstruction::new(
+                                        Some(record_type.clone().into()),
+                                        Variable::new(RECORD_NAME, position.clone()),
+                                        field.name(),
+                                        position.clone(),
+                                    )
+                                    .into(),
+                                    field.type_(),
+                                    position,
+                                )?,
+                                position.clone(),
+                            )
+                            .into())
+                        })
+                        .collect::<Result<_, CompileError>>()?,
+                    position.clone(),
+                ),
+                position.clone(),
+            ),
+            position.clone(),
+        ),
+        None,
+        true,
+        position.clone(),
+    ))
+}
+
+fn compile_decode_function_definition(
+    context: &Context,
+    type_definition: &TypeDefinition,
+) -> Result<FunctionDefinition, CompileError> {
+    let position = type_definition.position();
+    let record_type = compile_record_type(type_definition);
+    let result_type = types::Union::new(
+        record_type.clone(),
+        types::Error::new(position.clone()),
+        position.clone(),
+    );
+    let error = TypeCoercion::new(
+        types::Error::new(position.clone()),
+        result_type.clone(),
+        serialization::expression::compile_error_call(&record_type.clone().into(), position),
+        position.clone(),
+    );
+    let function_name = record_type_information::compile_decode_function_name(&record_type);
+
+    Ok(FunctionDefinition::new(
+        &function_name,
+        &function_name,
+        Lambda::new(
+            vec![Argument::new(
+                TREE_NAME,
+                Some(types::Any::new(position.clone()).into()),
+                position.clone(),
+            )],
+            Some(result_type.clone().into()),
+            IfType::new(
+                TREE_NAME,
+                Variable::new(TREE_NAME, position.clone()),
+                vec![IfTypeBranch::new(
+                    types::Map::new(
+                        types::ByteString::new(position.clone()),
+                        types::Any::new(position.clone()),
+                        position.clone(),
+                    ),
+                    type_definition.fields().iter().rev().try_fold(
+                        TypeCoercion::new(
+                            record_type.clone(),
+                            result_type.clone(),
+                            RecordConstruction::new(
+                                record_type.clone(),
+                                type_definition
+                                    .fields()
+                                    .iter()
+                                    .map(|field| {
+                                        RecordField::new(
+                                            field.name(),
+                                            Variable::new(
+                                                compile_field_name(field),
+                                                position.clone(),
+                                            ),
+                                            position.clone(),
+                                        )
+                                    })
+                                    .collect(),
+                                position.clone(),
+                            ),
+                            position.clone(),
+                        )
+                        .into(),
+                        |expression: Expression, field| -> Result<_, CompileError> {
+                            let name = compile_field_name(field);
+
+                            Ok(IfMap::new(
+                                Some(types::ByteString::new(position.clone()).into()),
+                                Some(types::Any::new(position.clone()).into()),
+                                &name,
+                                Variable::new(TREE_NAME, position.clone()),
+                                ByteString::new(field.name(), position.clone()),
+                                IfType::new(
+                                    &name,
+                                    serialization::expression::transform_decode(
+                                        context,
+                                        &Variable::new(&name, position.clone()).into(),
+                                        field.type_(),
+                                        position,
+                                    )?,
+                                    vec![
+                                        IfTypeBranch::new(
+                                            types::Error::new(position.clone()),
+                                            TypeCoercion::new(
+                                                types::Error::new(position.clone()),
+                                                result_type.clone(),
+                                                Variable::new(&name, position.clone()),
+                                                position.clone(),
+                                            ),
+                                        ),
+                                        IfTypeBranch::new(field.type_().clone(), expression),
+                                    ],
+                                    None,
+                                    position.clone(),
+                                ),
+                                error.clone(),
+                                position.clone(),
+                            )
+                            .into())
+                        },
+                    )?,
+                )],
+                Some(ElseBranch::new(
+                    Some(types::Any::new(position.clone()).into()),
+                    error.clone(),
+                    position.clone(),
+                )),
+                position.clone(),
+            ),
+            position.clone(),
+        ),
+        None,
+        true,
+        position.clone(),
+    ))
+}
+
+fn compile_record_type(type_definition: &TypeDefinition) -> types::Record {
+    types::Record::new(
+        type_definition.name(),
+        type_definition.original_name(),
+        type_definition.position().clone(),
+    )
+}
+
+fn compile_field_name(field: &types::RecordField) -> String {
+    format!("$field:{}", field.name())
+}
+
+fn compile_encode_function_type(record_type: &types::Record) -> types::Function {
+    let position = record_type.position();
+
+    types::Function::new(
+        vec![record_type.clone().into()],
+        types::Any::new(position.clone()),
+        position.clone(),
+    )
+}
+
+fn compile_decode_function_type(record_type: &types::Record) -> types::Function {
+    let position = record_type.position();
+
+    types::Function::new(
+        vec![types::Any::new(position.clone()).into()],
+        types::Union::new(
+            record_type.clone(),
+            types::Error::new(position.clone()),
+            position.clone(),
+        ),
+        position.clone(),
+    )
+}
@@ -7,3 +7,11 @@ pub fn compile_equal_function_name(record_type: &types::Record) -> String {
 pub fn compile_hash_function_name(record_type: &types::Record) -> String {
     format!("{}.$hash", record_type.name())
 }
+
+pub fn compile_encode_function_name(record_type: &types::Record) -> String {
+    format!("{}.$encode", record_type.name())
+}
+
+pub fn compile_decode_function_name(record_type: &types::Record) -> String {
+    format!("{}.$decode", record_type.name())
+}
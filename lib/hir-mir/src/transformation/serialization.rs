@@ -0,0 +1,3 @@
+pub mod expression;
+pub mod function;
+pub mod module;
@@ -0,0 +1,530 @@
+use super::function;
+use crate::{
+    context::Context,
+    transformation::{collection_type, map_context, record_type_information},
+    CompileError,
+};
+use hir::{
+    analysis::{type_formatter, type_resolver, union_type_member_calculator, AnalysisError},
+    ir::*,
+    types::{self, Type},
+};
+use position::Position;
+
+const VALUE_NAME: &str = "$x";
+const TAG_NAME: &str = "$type";
+const CONTENT_NAME: &str = "$value";
+const TAG_KEY: &str = "type";
+const CONTENT_KEY: &str = "value";
+
+// Encode a value into a tree of booleans, none, numbers, strings, lists, and
+// maps typed as `any`.
+pub fn transform_encode(
+    context: &Context,
+    value: &Expression,
+    type_: &Type,
+    position: &Position,
+) -> Result<Expression, CompileError> {
+    let configuration = context.configuration()?;
+    let any_type = Type::from(types::Any::new(position.clone()));
+
+    Ok(match type_ {
+        Type::Boolean(_) | Type::None(_) | Type::Number(_) | Type::String(_) => {
+            TypeCoercion::new(type_.clone(), any_type, value.clone(), position.clone()).into()
+        }
+        Type::List(list_type) => {
+            let any_list_type = collection_type::transform_list(context, position)?;
+
+            TypeCoercion::new(
+                compile_tree_list_type(position),
+                any_type,
+                Call::new(
+                    Some(
+                        types::Function::new(
+                            vec![
+                                compile_function_type(position).into(),
+                                any_list_type.clone(),
+                            ],
+                            any_list_type,
+                            position.clone(),
+                        )
+                        .into(),
+                    ),
+                    Variable::new(
+                        &configuration.list_type.encode_function_name,
+                        position.clone(),
+                    ),
+                    vec![
+                        function::transform_encode(context, list_type.element())?,
+                        value.clone(),
+                    ],
+                    position.clone(),
+                ),
+                position.clone(),
+            )
+            .into()
+        }
+        Type::Map(map_type) => {
+            let tree_type = compile_tree_map_type(position);
+            let any_map_type = collection_type::transform_map(context, position)?;
+
+            TypeCoercion::new(
+                tree_type.clone(),
+                any_type,
+                Call::new(
+                    Some(
+                        types::Function::new(
+                            vec![
+                                collection_type::transform_map_context(context, position)?,
+                                compile_function_type(position).into(),
+                                any_map_type.clone(),
+                            ],
+                            any_map_type,
+                            position.clone(),
+                        )
+                        .into(),
+                    ),
+                    Variable::new(
+                        &configuration.map_type.encode_function_name,
+                        position.clone(),
+                    ),
+                    vec![
+                        map_context::expression::transform(context, &tree_type)?,
+                        function::transform_encode(context, map_type.value())?,
+                        value.clone(),
+                    ],
+                    position.clone(),
+                ),
+                position.clone(),
+            )
+            .into()
+        }
+        Type::Record(record_type) => Call::new(
+            Some(types::Function::new(vec![type_.clone()], any_type, position.clone()).into()),
+            Variable::new(
+                record_type_information::compile_encode_function_name(record_type),
+                position.clone(),
+            ),
+            vec![value.clone()],
+            position.clone(),
+        )
+        .into(),
+        Type::Reference(reference) => transform_encode(
+            context,
+            value,
+            &type_resolver::resolve(reference, context.types())?,
+            position,
+        )?,
+        Type::Union(_) => IfType::new(
+            VALUE_NAME,
+            value.clone(),
+            union_type_member_calculator::calculate(type_, context.types())?
+                .iter()
+                .map(|type_| {
+                    Ok(IfTypeBranch::new(
+                        type_.clone(),
+                        compile_tagged_tree(
+                            type_,
+                            transform_encode(
+                                context,
+                                &Variable::new(VALUE_NAME, position.clone()).into(),
+                                type_,
+                                position,
+                            )?,
+                            position,
+                        ),
+                    ))
+                })
+                .collect::<Result<_, CompileError>>()?,
+            None,
+            position.clone(),
+        )
+        .into(),
+        Type::Any(_) | Type::Error(_) | Type::Function(_) | Type::Vector(_) => {
+            return Err(AnalysisError::TypeNotSerializable(position.clone(), type_.clone()).into())
+        }
+    })
+}
+
+// Decode a tree typed as `any` into a value of a given type or an error. The
+// result is typed as `any`.
+pub fn transform_decode(
+    context: &Context,
+    value: &Expression,
+    type_: &Type,
+    position: &Position,
+) -> Result<Expression, CompileError> {
+    let configuration = context.configuration()?;
+    let any_type = Type::from(types::Any::new(position.clone()));
+
+    Ok(match type_ {
+        Type::Boolean(_) | Type::None(_) | Type::Number(_) | Type::String(_) => {
+            compile_tree_downcast(
+                value,
+                type_.clone(),
+                TypeCoercion::new(
+                    type_.clone(),
+                    any_type,
+                    Variable::new(VALUE_NAME, position.clone()),
+                    position.clone(),
+                ),
+                compile_error(type_, position),
+                position,
+            )
+        }
+        Type::List(list_type) => {
+            let any_list_type = collection_type::transform_list(context, position)?;
+
+            compile_tree_downcast(
+                value,
+                compile_tree_list_type(position),
+                compile_collection_result(
+                    type_,
+                    &any_list_type,
+                    Call::new(
+                        Some(
+                            types::Function::new(
+                                vec![
+                                    compile_function_type(position).into(),
+                                    any_list_type.clone(),
+                                ],
+                                types::Union::new(
+                                    any_list_type.clone(),
+                                    types::Error::new(position.clone()),
+                                    position.clone(),
+                                ),
+                                position.clone(),
+                            )
+                            .into(),
+                        ),
+                        Variable::new(
+                            &configuration.list_type.decode_function_name,
+                            position.clone(),
+                        ),
+                        vec![
+                            function::transform_decode(context, list_type.element())?,
+                            Variable::new(VALUE_NAME, position.clone()).into(),
+                        ],
+                        position.clone(),
+                    ),
+                    position,
+                ),
+                compile_error(type_, position),
+                position,
+            )
+        }
+        Type::Map(map_type) => {
+            let any_map_type = collection_type::transform_map(context, position)?;
+
+            compile_tree_downcast(
+                value,
+                compile_tree_map_type(position),
+                compile_collection_result(
+                    type_,
+                    &any_map_type,
+                    Call::new(
+                        Some(
+                            types::Function::new(
+                                vec![
+                                    collection_type::transform_map_context(context, position)?,
+                                    compile_function_type(position).into(),
+                                    any_map_type.clone(),
+                                ],
+                                types::Union::new(
+                                    any_map_type.clone(),
+                                    types::Error::new(position.clone()),
+                                    position.clone(),
+                                ),
+                                position.clone(),
+                            )
+                            .into(),
+                        ),
+                        Variable::new(
+                            &configuration.map_type.decode_function_name,
+                            position.clone(),
+                        ),
+                        vec![
+                            map_context::expression::transform(context, map_type)?,
+                            function::transform_decode(context, map_type.value())?,
+                            Variable::new(VALUE_NAME, position.clone()).into(),
+                        ],
+                        position.clone(),
+                    ),
+                    position,
+                ),
+                compile_error(type_, position),
+                position,
+            )
+        }
+        Type::Record(record_type) => {
+            let result_type = types::Union::new(
+                type_.clone(),
+                types::Error::new(position.clone()),
+                position.clone(),
+            );
+
+            TypeCoercion::new(
+                result_type.clone(),
+                any_type.clone(),
+                Call::new(
+                    Some(
+                        types::Function::new(vec![any_type], result_type, position.clone()).into(),
+                    ),
+                    Variable::new(
+                        record_type_information::compile_decode_function_name(record_type),
+                        position.clone(),
+                    ),
+                    vec![value.clone()],
+                    position.clone(),
+                ),
+                position.clone(),
+            )
+            .into()
+        }
+        Type::Reference(reference) => transform_decode(
+            context,
+            value,
+            &type_resolver::resolve(reference, context.types())?,
+            position,
+        )?,
+        Type::Union(_) => {
+            let string_type = Type::from(types::ByteString::new(position.clone()));
+            let error = compile_error(type_, position);
+
+            compile_tree_downcast(
+                value,
+                compile_tree_map_type(position),
+                compile_tree_map_element(
+                    TAG_NAME,
+                    TAG_KEY,
+                    compile_tree_map_element(
+                        CONTENT_NAME,
+                        CONTENT_KEY,
+                        IfType::new(
+                            TAG_NAME,
+                            Variable::new(TAG_NAME, position.clone()),
+                            vec![IfTypeBranch::new(
+                                string_type.clone(),
+                                union_type_member_calculator::calculate(type_, context.types())?
+                                    .iter()
+                                    .rev()
+                                    .try_fold(error.clone(), |expression, type_| {
+                                        Ok::<_, CompileError>(
+                                            If::new(
+                                                EqualityOperation::new(
+                                                    Some(string_type.clone()),
+                                                    EqualityOperator::Equal,
+                                                    Variable::new(TAG_NAME, position.clone()),
+                                                    ByteString::new(
+                                                        type_formatter::format(type_),
+                                                        position.clone(),
+                                                    ),
+                                                    position.clone(),
+                                                ),
+                                                transform_decode(
+                                                    context,
+                                                    &Variable::new(CONTENT_NAME, position.clone())
+                                                        .into(),
+                                                    type_,
+                                                    position,
+                                                )?,
+                                                expression,
+                                                position.clone(),
+                                            )
+                                            .into(),
+                                        )
+                                    })?,
+                            )],
+                            Some(ElseBranch::new(
+                                Some(types::Any::new(position.clone()).into()),
+                                error.clone(),
+                                position.clone(),
+                            )),
+                            position.clone(),
+                        ),
+                        &error,
+                        position,
+                    ),
+                    &error,
+                    position,
+                ),
+                error.clone(),
+                position,
+            )
+        }
+        Type::Any(_) | Type::Error(_) | Type::Function(_) | Type::Vector(_) => {
+            return Err(AnalysisError::TypeNotSerializable(position.clone(), type_.clone()).into())
+        }
+    })
+}
+
+fn compile_tagged_tree(type_: &Type, tree: Expression, position: &Position) -> Expression {
+    let tree_type = compile_tree_map_type(position);
+
+    TypeCoercion::new(
+        tree_type.clone(),
+        types::Any::new(position.clone()),
+        Map::new(
+            tree_type.key().clone(),
+            tree_type.value().clone(),
+            vec![
+                MapEntry::new(
+                    ByteString::new(TAG_KEY, position.clone()),
+                    TypeCoercion::new(
+                        types::ByteString::new(position.clone()),
+                        types::Any::new(position.clone()),
+                        ByteString::new(type_formatter::format(type_), position.clone()),
+                        position.clone(),
+                    ),
+                    position.clone(),
+                )
+                .into(),
+                MapEntry::new(
+                    ByteString::new(CONTENT_KEY, position.clone()),
+                    tree,
+                    position.clone(),
+                )
+                .into(),
+            ],
+            position.clone(),
+        ),
+        position.clone(),
+    )
+    .into()
+}
+
+// Downcast a tree and evaluate an expression with it bound to `$x`.
+fn compile_tree_downcast(
+    value: &Expression,
+    tree_type: impl Into<Type>,
+    expression: impl Into<Expression>,
+    error: Expression,
+    position: &Position,
+) -> Expression {
+    IfType::new(
+        VALUE_NAME,
+        value.clone(),
+        vec![IfTypeBranch::new(tree_type.into(), expression)],
+        Some(ElseBranch::new(
+            Some(types::Any::new(position.clone()).into()),
+            error,
+            position.clone(),
+        )),
+        position.clone(),
+    )
+    .into()
+}
+
+fn compile_tree_map_element(
+    name: &str,
+    key: &str,
+    then: impl Into<Expression>,
+    else_: &Expression,
+    position: &Position,
+) -> Expression {
+    IfMap::new(
+        Some(types::ByteString::new(position.clone()).into()),
+        Some(types::Any::new(position.clone()).into()),
+        name,
+        Variable::new(VALUE_NAME, position.clone()),
+        ByteString::new(key, position.clone()),
+        then,
+        else_.clone(),
+        position.clone(),
+    )
+    .into()
+}
+
+// Convert a result of a collection decode function in the prelude into a
+// collection or an error typed as `any`.
+fn compile_collection_result(
+    type_: &Type,
+    any_collection_type: &Type,
+    call: Call,
+    position: &Position,
+) -> Expression {
+    const RESULT_NAME: &str = "$result";
+
+    IfType::new(
+        RESULT_NAME,
+        call,
+        vec![
+            IfTypeBranch::new(
+                types::Error::new(position.clone()),
+                TypeCoercion::new(
+                    types::Error::new(position.clone()),
+                    types::Any::new(position.clone()),
+                    Variable::new(RESULT_NAME, position.clone()),
+                    position.clone(),
+                ),
+            ),
+            IfTypeBranch::new(
+                any_collection_type.clone(),
+                TypeCoercion::new(
+                    type_.clone(),
+                    types::Any::new(position.clone()),
+                    Variable::new(RESULT_NAME, position.clone()),
+                    position.clone(),
+                ),
+            ),
+        ],
+        None,
+        position.clone(),
+    )
+    .into()
+}
+
+pub fn compile_error(type_: &Type, position: &Position) -> Expression {
+    TypeCoercion::new(
+        types::Error::new(position.clone()),
+        types::Any::new(position.clone()),
+        compile_error_call(type_, position),
+        position.clone(),
+    )
+    .into()
+}
+
+pub fn compile_error_call(type_: &Type, position: &Position) -> Call {
+    Call::new(
+        Some(
+            types::Function::new(
+                vec![types::Any::new(position.clone()).into()],
+                types::Error::new(position.clone()),
+                position.clone(),
+            )
+            .into(),
+        ),
+        BuiltInFunction::new(BuiltInFunctionName::Error, position.clone()),
+        vec![TypeCoercion::new(
+            types::ByteString::new(position.clone()),
+            types::Any::new(position.clone()),
+            ByteString::new(
+                format!("failed to decode {}", type_formatter::format(type_)),
+                position.clone(),
+            ),
+            position.clone(),
+        )
+        .into()],
+        position.clone(),
+    )
+}
+
+fn compile_tree_list_type(position: &Position) -> types::List {
+    types::List::new(types::Any::new(position.clone()), position.clone())
+}
+
+fn compile_tree_map_type(position: &Position) -> types::Map {
+    types::Map::new(
+        types::ByteString::new(position.clone()),
+        types::Any::new(position.clone()),
+        position.clone(),
+    )
+}
+
+fn compile_function_type(position: &Position) -> types::Function {
+    types::Function::new(
+        vec![types::Any::new(position.clone()).into()],
+        types::Any::new(position.clone()),
+        position.clone(),
+    )
+}
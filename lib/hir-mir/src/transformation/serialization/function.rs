@@ -0,0 +1,39 @@
+use crate::{context::Context, error::CompileError};
+use fnv::FnvHashMap;
+use hir::{analysis::type_id_calculator, ir::*, types::Type};
+
+pub fn transform_encode(context: &Context, type_: &Type) -> Result<Expression, CompileError> {
+    Ok(Variable::new(
+        transform_encode_name(type_, context.types())?,
+        type_.position().clone(),
+    )
+    .into())
+}
+
+pub fn transform_decode(context: &Context, type_: &Type) -> Result<Expression, CompileError> {
+    Ok(Variable::new(
+        transform_decode_name(type_, context.types())?,
+        type_.position().clone(),
+    )
+    .into())
+}
+
+pub fn transform_encode_name(
+    type_: &Type,
+    types: &FnvHashMap<String, Type>,
+) -> Result<String, CompileError> {
+    Ok(format!(
+        "hir:encode:{}",
+        type_id_calculator::calculate(type_, types)?
+    ))
+}
+
+pub fn transform_decode_name(
+    type_: &Type,
+    types: &FnvHashMap<String, Type>,
+) -> Result<String, CompileError> {
+    Ok(format!(
+        "hir:decode:{}",
+        type_id_calculator::calculate(type_, types)?
+    ))
+}
@@ -0,0 +1,214 @@
+use super::{expression, function};
+use crate::{context::Context, CompileError};
+use fnv::FnvHashSet;
+use hir::{
+    analysis::{
+        expression_visitor, type_canonicalizer, type_difference_calculator,
+        type_serializability_checker, union_type_member_calculator, AnalysisError,
+    },
+    ir::*,
+    types::{self, Type},
+};
+
+const ARGUMENT_NAME: &str = "$x";
+
+pub fn transform(context: &Context, module: &Module) -> Result<Module, CompileError> {
+    // Skip the prelude modules as they do not serialize any values.
+    if context.configuration().is_err() {
+        return Ok(module.clone());
+    }
+
+    Ok(Module::new(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.function_declarations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .cloned()
+            .chain(
+                collect_types(context, module)?
+                    .into_iter()
+                    .map(|type_| {
+                        Ok([
+                            compile_encode_function_definition(context, &type_)?,
+                            compile_decode_function_definition(context, &type_)?,
+                        ])
+                    })
+                    .collect::<Result<Vec<_>, CompileError>>()?
+                    .into_iter()
+                    .flatten(),
+            )
+            .collect(),
+        module.position().clone(),
+    ))
+}
+
+fn compile_encode_function_definition(
+    context: &Context,
+    type_: &Type,
+) -> Result<FunctionDefinition, CompileError> {
+    let position = type_.position();
+    let name = function::transform_encode_name(type_, context.types())?;
+
+    Ok(FunctionDefinition::new(
+        &name,
+        &name,
+        Lambda::new(
+            vec![Argument::new(
+                ARGUMENT_NAME,
+                Some(types::Any::new(position.clone()).into()),
+                position.clone(),
+            )],
+            Some(types::Any::new(position.clone()).into()),
+            IfType::new(
+                ARGUMENT_NAME,
+                Variable::new(ARGUMENT_NAME, position.clone()),
+                vec![IfTypeBranch::new(
+                    type_.clone(),
+                    expression::transform_encode(
+                        context,
+                        &Variable::new(ARGUMENT_NAME, position.clone()).into(),
+                        type_,
+                        position,
+                    )?,
+                )],
+                None,
+                position.clone(),
+            ),
+            position.clone(),
+        ),
+        None,
+        false,
+        position.clone(),
+    ))
+}
+
+fn compile_decode_function_definition(
+    context: &Context,
+    type_: &Type,
+) -> Result<FunctionDefinition, CompileError> {
+    let position = type_.position();
+    let name = function::transform_decode_name(type_, context.types())?;
+
+    Ok(FunctionDefinition::new(
+        &name,
+        &name,
+        Lambda::new(
+            vec![Argument::new(
+                ARGUMENT_NAME,
+                Some(types::Any::new(position.clone()).into()),
+                position.clone(),
+            )],
+            Some(types::Any::new(position.clone()).into()),
+            expression::transform_decode(
+                context,
+                &Variable::new(ARGUMENT_NAME, position.clone()).into(),
+                type_,
+                position,
+            )?,
+            position.clone(),
+        ),
+        None,
+        false,
+        position.clone(),
+    ))
+}
+
+fn collect_types(context: &Context, module: &Module) -> Result<FnvHashSet<Type>, CompileError> {
+    let mut function_types = vec![];
+
+    expression_visitor::visit(module, |expression| {
+        if let Expression::Call(call) = expression {
+            if let (Expression::BuiltInFunction(function), Some(function_type)) =
+                (call.function(), call.function_type())
+            {
+                if matches!(
+                    function.name(),
+                    BuiltInFunctionName::Decode | BuiltInFunctionName::Encode
+                ) {
+                    function_types.push((function.name(), function_type.clone()));
+                }
+            }
+        }
+    });
+
+    let mut root_types = vec![];
+
+    for (name, function_type) in function_types {
+        let function_type =
+            type_canonicalizer::canonicalize_function(&function_type, context.types())?
+                .ok_or_else(|| {
+                    AnalysisError::FunctionExpected(
+                        function_type.position().clone(),
+                        function_type.clone(),
+                    )
+                })?;
+
+        if name == BuiltInFunctionName::Encode {
+            root_types.extend(function_type.arguments().iter().cloned());
+        } else {
+            root_types.extend(type_difference_calculator::calculate(
+                function_type.result(),
+                &types::Error::new(function_type.position().clone()).into(),
+                context.types(),
+            )?);
+        }
+    }
+
+    for definition in module.type_definitions() {
+        if !definition.is_external()
+            && type_serializability_checker::check(
+                &types::Record::new(
+                    definition.name(),
+                    definition.original_name(),
+                    definition.position().clone(),
+                )
+                .into(),
+                context.types(),
+                context.records(),
+            )?
+        {
+            root_types.extend(
+                definition
+                    .fields()
+                    .iter()
+                    .map(|field| field.type_().clone()),
+            );
+        }
+    }
+
+    let mut types = FnvHashSet::default();
+
+    for type_ in &root_types {
+        collect_type(context, type_, &mut types)?;
+    }
+
+    Ok(types)
+}
+
+fn collect_type(
+    context: &Context,
+    type_: &Type,
+    types: &mut FnvHashSet<Type>,
+) -> Result<(), CompileError> {
+    let type_ = type_canonicalizer::canonicalize(type_, context.types())?;
+
+    if !types.insert(type_.clone()) {
+        return Ok(());
+    }
+
+    match &type_ {
+        Type::List(list_type) => collect_type(context, list_type.element(), types)?,
+        Type::Map(map_type) => collect_type(context, map_type.value(), types)?,
+        Type::Union(_) => {
+            for type_ in union_type_member_calculator::calculate(&type_, context.types())? {
+                collect_type(context, &type_, types)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use super::collection_type;
+use crate::{context::Context, CompileError};
+use hir::{ir::*, types};
+
+pub fn transform(context: &Context, vector: &Vector) -> Result<Expression, CompileError> {
+    let position = vector.position();
+    let configuration = &context.configuration()?.vector_type;
+    let any_vector_type = collection_type::transform_vector(context, position)?;
+
+    Ok(vector.elements().iter().fold(
+        Call::new(
+            Some(types::Function::new(vec![], any_vector_type.clone(), position.clone()).into()),
+            Variable::new(&configuration.empty_function_name, position.clone()),
+            vec![],
+            position.clone(),
+        )
+        .into(),
+        |vector_expression, element| {
+            Call::new(
+                Some(
+                    types::Function::new(
+                        vec![
+                            any_vector_type.clone(),
+                            types::Any::new(position.clone()).into(),
+                        ],
+                        any_vector_type.clone(),
+                        position.clone(),
+                    )
+                    .into(),
+                ),
+                Variable::new(&configuration.push_function_name, position.clone()),
+                vec![
+                    vector_expression,
+                    TypeCoercion::new(
+                        vector.type_().clone(),
+                        types::Any::new(position.clone()),
+                        element.clone(),
+                        position.clone(),
+                    )
+                    .into(),
+                ],
+                position.clone(),
+            )
+            .into()
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_type_configuration::VECTOR_TYPE_CONFIGURATION;
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn get_vector_type() -> types::Reference {
+        types::Reference::new(
+            VECTOR_TYPE_CONFIGURATION.vector_type_name.clone(),
+            Position::fake(),
+        )
+    }
+
+    fn get_empty_vector() -> Call {
+        Call::new(
+            Some(types::Function::new(vec![], get_vector_type(), Position::fake()).into()),
+            Variable::new(
+                &VECTOR_TYPE_CONFIGURATION.empty_function_name,
+                Position::fake(),
+            ),
+            vec![],
+            Position::fake(),
+        )
+    }
+
+    #[test]
+    fn transform_empty_vector() {
+        assert_eq!(
+            transform(
+                &Context::dummy(Default::default(), Default::default()),
+                &Vector::new(types::None::new(Position::fake()), vec![], Position::fake()),
+            ),
+            Ok(get_empty_vector().into())
+        );
+    }
+
+    #[test]
+    fn transform_vector_with_element() {
+        let vector_type = get_vector_type();
+
+        assert_eq!(
+            transform(
+                &Context::dummy(Default::default(), Default::default()),
+                &Vector::new(
+                    types::None::new(Position::fake()),
+                    vec![None::new(Position::fake()).into()],
+                    Position::fake()
+                ),
+            ),
+            Ok(Call::new(
+                Some(
+                    types::Function::new(
+                        vec![
+                            vector_type.clone().into(),
+                            types::Any::new(Position::fake()).into(),
+                        ],
+                        vector_type,
+                        Position::fake(),
+                    )
+                    .into()
+                ),
+                Variable::new(
+                    &VECTOR_TYPE_CONFIGURATION.push_function_name,
+                    Position::fake()
+                ),
+                vec![
+                    get_empty_vector().into(),
+                    TypeCoercion::new(
+                        types::None::new(Position::fake()),
+                        types::Any::new(Position::fake()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    )
+                    .into(),
+                ],
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+}
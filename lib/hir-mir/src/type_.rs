@@ -18,6 +18,7 @@ pub fn compile(context: &Context, type_: &Type) -> Result<mir::types::Type, Comp
             Type::Number(_) => mir::types::Type::Number,
             Type::Record(record) => compile_record(&record).into(),
             Type::String(_) => mir::types::Type::ByteString,
+            Type::Vector(_) => compile_vector(context)?.into(),
             Type::Any(_) | Type::Union(_) => mir::types::Type::Variant,
             Type::Reference(_) => unreachable!(),
         },
@@ -34,6 +35,7 @@ pub fn compile_concrete(context: &Context, type_: &Type) -> Result<mir::types::T
             Type::Function(type_) => compile_concrete_function(type_, context.types())?.into(),
             Type::List(type_) => compile_concrete_list(type_, context.types())?.into(),
             Type::Map(type_) => compile_concrete_map(type_, context.types())?.into(),
+            Type::Vector(type_) => compile_concrete_vector(type_, context.types())?.into(),
             Type::Boolean(_)
             | Type::Error(_)
             | Type::None(_)
@@ -141,6 +143,31 @@ pub fn compile_concrete_map_name(
     ))
 }
 
+pub fn compile_vector(context: &Context) -> Result<mir::types::Record, CompileError> {
+    Ok(mir::types::Record::new(
+        &context.configuration()?.vector_type.vector_type_name,
+    ))
+}
+
+pub fn compile_concrete_vector(
+    vector: &types::Vector,
+    types: &FnvHashMap<String, Type>,
+) -> Result<mir::types::Record, CompileError> {
+    Ok(mir::types::Record::new(compile_concrete_vector_name(
+        vector, types,
+    )?))
+}
+
+pub fn compile_concrete_vector_name(
+    vector: &types::Vector,
+    types: &FnvHashMap<String, Type>,
+) -> Result<String, CompileError> {
+    Ok(format!(
+        "hir:vector:{}",
+        type_id_calculator::calculate(vector.element(), types)?
+    ))
+}
+
 pub fn compile_race_function(context: &Context) -> Result<mir::types::Function, CompileError> {
     let list_type = compile_list(context)?;
 
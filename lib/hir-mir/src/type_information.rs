@@ -382,7 +382,7 @@ mod tests {
             "f",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 List::new(types::Any::new(Position::fake()), vec![], Position::fake()),
                 Position::fake(),
             ),
@@ -402,7 +402,7 @@ mod tests {
             "f",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 List::new(
                     types::Union::new(
                         types::Number::new(Position::fake()),
@@ -432,7 +432,7 @@ mod tests {
             "f",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 List::new(function_type.clone(), vec![], Position::fake()),
                 Position::fake(),
             ),
@@ -467,7 +467,7 @@ mod tests {
             "f",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 List::new(list_type.clone(), vec![], Position::fake()),
                 Position::fake(),
             ),
@@ -501,7 +501,7 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     List::new(
                         types::List::new(types::None::new(Position::fake()), Position::fake()),
                         vec![],
@@ -515,7 +515,7 @@ mod tests {
                 "g",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     List::new(
                         types::List::new(types::Number::new(Position::fake()), Position::fake()),
                         vec![],
@@ -548,7 +548,7 @@ mod tests {
             "f",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 List::new(map_type.clone(), vec![], Position::fake()),
                 Position::fake(),
             ),
@@ -591,7 +591,7 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     List::new(types::Record::fake("r"), vec![], Position::fake()),
                     Position::fake(),
                 ),
@@ -635,7 +635,7 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     List::new(types::Record::fake("r"), vec![], Position::fake()),
                     Position::fake(),
                 ),
@@ -682,7 +682,7 @@ mod tests {
                 "f",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     List::new(types::Record::fake("r"), vec![], Position::fake()),
                     Position::fake(),
                 ),
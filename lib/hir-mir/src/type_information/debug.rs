@@ -182,6 +182,25 @@ pub(super) fn compile_function_definition(
             ])
             .into(),
         )?,
+        Type::Vector(vector_type) => compile_function_definition(
+            mir::ir::Call::new(
+                mir::types::Function::new(
+                    vec![
+                        mir::types::Type::ByteString,
+                        type_::compile_vector(context)?.into(),
+                        compile_function_type().into(),
+                    ],
+                    mir::types::Type::ByteString,
+                ),
+                mir::ir::Variable::new(&context.configuration()?.vector_type.debug_function_name),
+                vec![
+                    mir::ir::ByteString::new(type_formatter::format(vector_type.element())).into(),
+                    utility::compile_unboxed_concrete(context, argument, type_)?,
+                    compile_element_function(),
+                ],
+            )
+            .into(),
+        )?,
         Type::Any(_) | Type::Reference(_) | Type::Union(_) => {
             return Err(CompileError::InvalidVariantType(type_.clone()))
         }
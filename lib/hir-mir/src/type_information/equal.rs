@@ -189,6 +189,34 @@ pub(super) fn compile_function_definition(
             )
             .into(),
         )?,
+        Type::Vector(_) => {
+            let vector_type = mir::types::Type::from(type_::compile_vector(context)?);
+
+            compile_function_definition(
+                mir::ir::Call::new(
+                    mir::types::Function::new(
+                        vec![
+                            compile_function_type().into(),
+                            vector_type.clone(),
+                            vector_type,
+                        ],
+                        mir::types::Type::Variant,
+                    ),
+                    mir::ir::Variable::new(
+                        &context
+                            .configuration()?
+                            .vector_type
+                            .maybe_equal_function_name,
+                    ),
+                    vec![
+                        compile_element_function(),
+                        utility::compile_unboxed_concrete(context, lhs, type_)?,
+                        utility::compile_unboxed_concrete(context, rhs, type_)?,
+                    ],
+                )
+                .into(),
+            )?
+        }
         Type::Any(_) | Type::Reference(_) | Type::Union(_) => {
             return Err(CompileError::InvalidVariantType(type_.clone()))
         }
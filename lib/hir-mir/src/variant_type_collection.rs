@@ -19,13 +19,31 @@ pub fn collect(context: &Context, module: &Module) -> Result<FnvHashSet<Type>, A
     expression_visitor::visit(module, |expression| match expression {
         Expression::Call(call) => {
             if let Expression::BuiltInFunction(function) = call.function() {
-                if function.name() == BuiltInFunctionName::Race {
-                    let position = call.position();
+                match (function.name(), call.function_type()) {
+                    (BuiltInFunctionName::Race, _) => {
+                        let position = call.position();
 
-                    lower_types.insert(
-                        types::List::new(types::Any::new(position.clone()), position.clone())
-                            .into(),
-                    );
+                        lower_types.insert(
+                            types::List::new(types::Any::new(position.clone()), position.clone())
+                                .into(),
+                        );
+                    }
+                    (
+                        BuiltInFunctionName::Decode | BuiltInFunctionName::Get,
+                        Some(Type::Function(function_type)),
+                    ) => {
+                        lower_types.insert(function_type.result().clone());
+                    }
+                    (BuiltInFunctionName::Encode, Some(Type::Function(function_type))) => {
+                        lower_types.extend(function_type.arguments().first().cloned());
+                    }
+                    (
+                        BuiltInFunctionName::Push | BuiltInFunctionName::Set,
+                        Some(Type::Function(function_type)),
+                    ) => {
+                        lower_types.extend(function_type.arguments().last().cloned());
+                    }
+                    _ => {}
                 }
             }
         }
@@ -63,6 +81,9 @@ pub fn collect(context: &Context, module: &Module) -> Result<FnvHashSet<Type>, A
             lower_types.insert(map.key_type().clone());
             lower_types.insert(map.value_type().clone());
         }
+        Expression::Vector(vector) => {
+            lower_types.insert(vector.type_().clone());
+        }
         Expression::TypeCoercion(coercion) => {
             lower_types.insert(coercion.from().clone());
         }
@@ -124,7 +145,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         EqualityOperation::new(
                             Some(
                                 types::Union::new(
@@ -156,18 +177,21 @@ mod tests {
     #[test]
     fn collect_from_list() {
         assert_eq!(
-            collect_module(&Module::empty().set_function_definitions(vec![
-                FunctionDefinition::fake(
+            collect_module(&Module::empty().set_function_definitions(
+                vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
                         vec![],
-                        types::List::new(types::None::new(Position::fake()), Position::fake()),
+                        Some(
+                            types::List::new(types::None::new(Position::fake()), Position::fake())
+                                .into()
+                        ),
                         List::new(types::None::new(Position::fake()), vec![], Position::fake(),),
                         Position::fake(),
                     ),
                     false,
-                )
-            ])),
+                )]
+            )),
             [types::None::new(Position::fake()).into()]
                 .into_iter()
                 .collect()
@@ -182,11 +206,11 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Map::new(
+                        Some(types::Map::new(
                             types::None::new(Position::fake()),
                             types::Number::new(Position::fake()),
                             Position::fake()
-                        ),
+                        ).into()),
                         Map::new(
                             types::None::new(Position::fake()),
                             types::Number::new(Position::fake()),
@@ -217,27 +241,31 @@ mod tests {
         assert_eq!(
             collect_module(&Module::empty().set_function_definitions(
                 vec![FunctionDefinition::fake(
-                        "x",
-                        Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            list_type.element().clone(),
-                            Call::new(
-                                Some(
-                                    types::Function::new(
-                                        vec![list_type.clone().into()],
-                                        list_type.element().clone(),
-                                        Position::fake(),
-                                    )
-                                    .into(),
-                                ),
-                                BuiltInFunction::new(BuiltInFunctionName::Race, Position::fake()),
-                                vec![Variable::new("x", Position::fake()).into()],
-                                Position::fake(),
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(list_type.element().clone()),
+                        Call::new(
+                            Some(
+                                types::Function::new(
+                                    vec![list_type.clone().into()],
+                                    list_type.element().clone(),
+                                    Position::fake(),
+                                )
+                                .into(),
                             ),
+                            BuiltInFunction::new(BuiltInFunctionName::Race, Position::fake()),
+                            vec![Variable::new("x", Position::fake()).into()],
                             Position::fake(),
                         ),
-                        false,
-                    )]
+                        Position::fake(),
+                    ),
+                    false,
+                )]
             )),
             [types::List::new(types::Any::new(Position::fake()), Position::fake()).into()]
                 .into_iter()
@@ -257,8 +285,8 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", list_type)],
-                        types::Boolean::new(Position::fake()),
+                        vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                        Some(types::Boolean::new(Position::fake()).into()),
                         TryOperation::new(
                             Some(types::None::new(Position::fake()).into()),
                             None::new(Position::fake()),
@@ -283,7 +311,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Any::new(Position::fake()),
+                        Some(types::Any::new(Position::fake()).into()),
                         TypeCoercion::new(
                             types::None::new(Position::fake()),
                             types::Any::new(Position::fake()),
@@ -336,8 +364,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", input_list_type.clone())],
-                            output_list_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(input_list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(output_list_type.clone().into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 None::new(Position::fake(),),
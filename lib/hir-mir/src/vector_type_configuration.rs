@@ -0,0 +1,31 @@
+#[cfg(test)]
+use std::sync::LazyLock;
+
+#[cfg(test)]
+pub static VECTOR_TYPE_CONFIGURATION: LazyLock<VectorTypeConfiguration> =
+    LazyLock::new(|| VectorTypeConfiguration {
+        empty_function_name: "emptyVector".into(),
+        equal_function_name: "equalVectors".into(),
+        maybe_equal_function_name: "maybeEqualVectors".into(),
+        get_function_name: "getVector".into(),
+        push_function_name: "pushVector".into(),
+        set_function_name: "setVector".into(),
+        slice_function_name: "sliceVector".into(),
+        size_function_name: "vectorSize".into(),
+        debug_function_name: "debugVector".into(),
+        vector_type_name: "anyVector".into(),
+    });
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VectorTypeConfiguration {
+    pub empty_function_name: String,
+    pub equal_function_name: String,
+    pub maybe_equal_function_name: String,
+    pub get_function_name: String,
+    pub push_function_name: String,
+    pub set_function_name: String,
+    pub slice_function_name: String,
+    pub size_function_name: String,
+    pub debug_function_name: String,
+    pub vector_type_name: String,
+}
@@ -1,16 +1,22 @@
 pub mod built_in_type_transformer;
 pub mod built_in_variable_transformer;
+pub mod comparison_warning_collector;
 mod context;
+pub mod definition_warning_collector;
 pub mod duplicate_function_name_validator;
 pub mod duplicate_type_name_validator;
 mod error;
 pub mod expression_visitor;
 pub mod function_definition_qualifier;
+pub mod if_type_warning_collector;
 pub mod impossible_type_validator;
 mod module_environment;
+pub mod module_formatter;
+pub mod name_suggester;
 pub mod record_field_resolver;
 pub mod record_field_validator;
 pub mod recursive_type_alias_validator;
+mod severity;
 pub mod try_operation_validator;
 pub mod type_canonicalizer;
 pub mod type_checker;
@@ -26,36 +32,401 @@ pub mod type_id_calculator;
 pub mod type_inferrer;
 pub mod type_qualifier;
 pub mod type_resolver;
+pub mod type_serializability_checker;
 pub mod type_subsumption_checker;
 pub mod type_transformer;
 pub mod type_visitor;
 pub mod union_type_creator;
 pub mod union_type_member_calculator;
 pub mod unused_error_validator;
+pub mod variable_reference_collector;
 pub mod variable_renamer;
 pub mod variable_transformer;
+pub mod variable_warning_collector;
+mod warning;
 
-use crate::ir::Module;
+use crate::{
+    ir::{FunctionDefinition, Module},
+    types::Type,
+};
 pub use context::AnalysisContext;
 pub use error::AnalysisError;
+use fnv::FnvHashSet;
+pub use severity::Severity;
+pub use warning::AnalysisWarning;
+
+const MAX_ERROR_COUNT: usize = 50;
 
 // Validate a module and replace subtyping with type coercion there.
-pub fn analyze(context: &AnalysisContext, module: &Module) -> Result<Module, AnalysisError> {
-    duplicate_function_name_validator::validate(module)?;
-    duplicate_type_name_validator::validate(module)?;
-    type_existence_validator::validate(module)?;
-    recursive_type_alias_validator::validate(module)?;
+//
+// Function definitions are analyzed independently against a module
+// environment built once and errors in them are reported together ordered by
+// their positions. Warnings are returned only when no error is found.
+pub fn analyze(
+    context: &AnalysisContext,
+    module: &Module,
+) -> Result<(Module, Vec<AnalysisWarning>), AnalysisError> {
+    AnalysisError::collect(
+        [
+            duplicate_function_name_validator::validate(module),
+            duplicate_type_name_validator::validate(module),
+            type_existence_validator::validate(module),
+            recursive_type_alias_validator::validate(module),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect(),
+        MAX_ERROR_COUNT,
+    )?;
     impossible_type_validator::validate(context, module)?;
 
-    let module = type_inferrer::infer(context, module)?;
-    type_checker::check_types(context, &module)?;
+    let environment = module_environment::create(module)?;
+    let global_names = environment.keys().cloned().collect::<Vec<_>>();
+    let variables = plist::FlailMap::new(environment);
+    let open_records = record_field_validator::collect_open_records(module);
+    let mut definitions = vec![];
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    for definition in module.function_definitions() {
+        match analyze_function_definition(
+            context,
+            definition,
+            &variables,
+            &global_names,
+            &open_records,
+        ) {
+            Ok((definition, definition_warnings)) => {
+                definitions.push(definition);
+                warnings.extend(definition_warnings);
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+
+    AnalysisError::collect(errors, MAX_ERROR_COUNT)?;
+
+    warnings.extend(definition_warning_collector::collect(module));
+
+    Ok((
+        Module::new(
+            module.type_definitions().to_vec(),
+            module.type_aliases().to_vec(),
+            module.foreign_declarations().to_vec(),
+            module.function_declarations().to_vec(),
+            definitions,
+            module.position().clone(),
+        ),
+        warnings,
+    ))
+}
+
+fn analyze_function_definition(
+    context: &AnalysisContext,
+    definition: &FunctionDefinition,
+    variables: &plist::FlailMap<String, Type>,
+    global_names: &[String],
+    open_records: &FnvHashSet<&str>,
+) -> Result<(FunctionDefinition, Vec<AnalysisWarning>), AnalysisError> {
+    let definition = type_inferrer::infer_function_definition(context, definition, variables)
+        .map_err(|error| suggest(global_names, definition, error))?;
+    type_checker::check_function_definition(context, &definition, variables)
+        .map_err(|error| suggest(global_names, &definition, error))?;
+
+    try_operation_validator::validate_function_definition(context, &definition)?;
+    record_field_validator::validate_function_definition(context, &definition, open_records)?;
+    unused_error_validator::validate_function_definition(context, &definition)?;
+
+    let warnings = if_type_warning_collector::collect_function_definition(context, &definition)?
+        .into_iter()
+        .chain(variable_warning_collector::collect_function_definition(
+            &definition,
+        ))
+        .chain(comparison_warning_collector::collect_function_definition(
+            context,
+            &definition,
+        )?)
+        .collect();
+
+    let definition = type_coercer::coerce_function_definition(context, &definition, variables)?;
+    type_checker::check_function_definition(context, &definition, variables)?;
+
+    Ok((definition, warnings))
+}
+
+fn suggest(
+    global_names: &[String],
+    definition: &FunctionDefinition,
+    error: AnalysisError,
+) -> AnalysisError {
+    match error {
+        AnalysisError::VariableNotFound(variable, None) => {
+            let suggestion = name_suggester::suggest_variable(
+                global_names.iter().map(String::as_str),
+                definition,
+                &variable,
+            );
+
+            AnalysisError::VariableNotFound(variable, suggestion)
+        }
+        error => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::*,
+        test::{FunctionDefinitionFake, ModuleFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn analyze_module(module: &Module) -> Result<Module, AnalysisError> {
+        analyze(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_record_fields(module),
+            ),
+            module,
+        )
+        .map(|(module, _)| module)
+    }
+
+    fn create_invalid_definition(name: &str, line_number: usize) -> FunctionDefinition {
+        FunctionDefinition::fake(
+            name,
+            Lambda::new(
+                vec![],
+                Some(types::None::new(Position::fake()).into()),
+                Variable::new("x", Position::new("foo.pen", line_number, 1, "x")),
+                Position::fake(),
+            ),
+            false,
+        )
+    }
+
+    fn collect_line_numbers(error: &AnalysisError) -> Vec<usize> {
+        match error {
+            AnalysisError::Multiple(errors) => errors
+                .iter()
+                .map(|error| error.position().unwrap().line_number())
+                .collect(),
+            _ => vec![error.position().unwrap().line_number()],
+        }
+    }
+
+    #[test]
+    fn analyze_empty_module() {
+        assert_eq!(analyze_module(&Module::empty()), Ok(Module::empty()));
+    }
+
+    #[test]
+    fn fail_to_analyze_function_definition_without_type_annotations() {
+        assert_eq!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", None, Position::fake())],
+                        None,
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Err(AnalysisError::TypeNotInferred(Position::fake()))
+        );
+    }
+
+    #[test]
+    fn analyze_multiple_definitions() {
+        let definition = FunctionDefinition::fake(
+            "f",
+            Lambda::new(
+                vec![],
+                Some(types::None::new(Position::fake()).into()),
+                None::new(Position::fake()),
+                Position::fake(),
+            ),
+            false,
+        );
+        let module = Module::empty().set_function_definitions(vec![
+            definition.clone(),
+            FunctionDefinition::fake("g", definition.lambda().clone(), false),
+        ]);
+
+        assert_eq!(analyze_module(&module), Ok(module));
+    }
+
+    #[test]
+    fn report_warning_in_definition() {
+        let union_type = types::Union::new(
+            types::Number::new(Position::fake()),
+            types::None::new(Position::fake()),
+            Position::fake(),
+        );
+        let module = Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+            "f",
+            Lambda::new(
+                vec![Argument::new(
+                    "x",
+                    Some(union_type.into()),
+                    Position::fake(),
+                )],
+                Some(types::None::new(Position::fake()).into()),
+                IfType::new(
+                    "y",
+                    Variable::new("x", Position::fake()),
+                    vec![
+                        IfTypeBranch::new(
+                            types::Number::new(Position::fake()),
+                            None::new(Position::fake()),
+                        ),
+                        IfTypeBranch::new(
+                            types::None::new(Position::fake()),
+                            None::new(Position::fake()),
+                        ),
+                    ],
+                    Some(ElseBranch::new(
+                        None,
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    )),
+                    Position::fake(),
+                ),
+                Position::fake(),
+            ),
+            true,
+        )]);
+
+        assert_eq!(
+            analyze(
+                &AnalysisContext::new(
+                    type_collector::collect(&module),
+                    type_collector::collect_record_fields(&module),
+                ),
+                &module,
+            )
+            .map(|(_, warnings)| warnings),
+            Ok(vec![
+                AnalysisWarning::UnreachableElseBlock(Position::fake()),
+                AnalysisWarning::UnusedVariable("y".into(), Position::fake()),
+            ])
+        );
+    }
+
+    #[test]
+    fn report_error_in_definition() {
+        assert_eq!(
+            analyze_module(
+                &Module::empty().set_function_definitions(vec![create_invalid_definition("f", 1)])
+            ),
+            Err(AnalysisError::VariableNotFound(
+                Variable::new("x", Position::fake()),
+                None
+            ))
+        );
+    }
+
+    #[test]
+    fn suggest_variable_name() {
+        assert_eq!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "foo",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                FunctionDefinition::fake(
+                    "bar",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        Call::new(
+                            None,
+                            Variable::new("fooo", Position::fake()),
+                            vec![],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ])),
+            Err(AnalysisError::VariableNotFound(
+                Variable::new("fooo", Position::fake()),
+                Some("foo".into())
+            ))
+        );
+    }
+
+    #[test]
+    fn report_errors_in_definitions_in_order() {
+        let error = analyze_module(&Module::empty().set_function_definitions(vec![
+            create_invalid_definition("f", 3),
+            create_invalid_definition("g", 1),
+            create_invalid_definition("h", 2),
+        ]))
+        .unwrap_err();
+
+        assert!(matches!(error, AnalysisError::Multiple(_)));
+        assert_eq!(collect_line_numbers(&error), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn report_errors_in_module_validation() {
+        let create_definition = |name: &str, line_number| {
+            FunctionDefinition::fake(
+                name,
+                Lambda::new(
+                    vec![],
+                    Some(
+                        types::Reference::new(
+                            "foo",
+                            Position::new("foo.pen", line_number, 1, "foo"),
+                        )
+                        .into(),
+                    ),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            )
+        };
+
+        let error =
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                create_definition("f", 2),
+                create_definition("g", 1),
+            ]))
+            .unwrap_err();
 
-    try_operation_validator::validate(context, &module)?;
-    record_field_validator::validate(context, &module)?;
-    unused_error_validator::validate(context, &module)?;
+        assert_eq!(collect_line_numbers(&error), vec![1, 2]);
+    }
 
-    let module = type_coercer::coerce_types(context, &module)?;
-    type_checker::check_types(context, &module)?;
+    #[test]
+    fn limit_error_count() {
+        let error = analyze_module(
+            &Module::empty().set_function_definitions(
+                (0..2 * MAX_ERROR_COUNT)
+                    .map(|index| create_invalid_definition(&format!("f{index}"), index + 1))
+                    .collect(),
+            ),
+        )
+        .unwrap_err();
 
-    Ok(module)
+        assert_eq!(
+            collect_line_numbers(&error),
+            (1..=MAX_ERROR_COUNT).collect::<Vec<_>>()
+        );
+    }
 }
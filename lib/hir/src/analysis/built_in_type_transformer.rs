@@ -62,7 +62,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Reference::new("none", Position::fake()),
+                        Some(types::Reference::new("none", Position::fake()).into()),
                         None::new(Position::fake()),
                         Position::fake(),
                     ),
@@ -73,7 +73,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     None::new(Position::fake()),
                     Position::fake(),
                 ),
@@ -91,9 +91,10 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "y",
-                            types::Reference::new("none", Position::fake())
+                            Some(types::Reference::new("none", Position::fake()).into()),
+                            Position::fake(),
                         )],
-                        types::Reference::new("foo", Position::fake()),
+                        Some(types::Reference::new("foo", Position::fake()).into()),
                         None::new(Position::fake()),
                         Position::fake(),
                     ),
@@ -103,8 +104,12 @@ mod tests {
             Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                 "x",
                 Lambda::new(
-                    vec![Argument::new("y", types::None::new(Position::fake()))],
-                    types::Reference::new("foo", Position::fake()),
+                    vec![Argument::new(
+                        "y",
+                        Some(types::None::new(Position::fake()).into()),
+                        Position::fake(),
+                    )],
+                    Some(types::Reference::new("foo", Position::fake()).into()),
                     None::new(Position::fake()),
                     Position::fake(),
                 ),
@@ -7,14 +7,20 @@ pub fn transform(module: &Module) -> Module {
 
         match variable.name() {
             "debug" => BuiltInFunction::new(BuiltInFunctionName::Debug, position.clone()).into(),
+            "decode" => BuiltInFunction::new(BuiltInFunctionName::Decode, position.clone()).into(),
             "delete" => BuiltInFunction::new(BuiltInFunctionName::Delete, position.clone()).into(),
+            "encode" => BuiltInFunction::new(BuiltInFunctionName::Encode, position.clone()).into(),
             "error" => BuiltInFunction::new(BuiltInFunctionName::Error, position.clone()).into(),
             "false" => Boolean::new(false, position.clone()).into(),
+            "get" => BuiltInFunction::new(BuiltInFunctionName::Get, position.clone()).into(),
             "go" => BuiltInFunction::new(BuiltInFunctionName::Spawn, position.clone()).into(),
             "keys" => BuiltInFunction::new(BuiltInFunctionName::Keys, position.clone()).into(),
             "none" => None::new(position.clone()).into(),
+            "push" => BuiltInFunction::new(BuiltInFunctionName::Push, position.clone()).into(),
             "race" => BuiltInFunction::new(BuiltInFunctionName::Race, position.clone()).into(),
+            "set" => BuiltInFunction::new(BuiltInFunctionName::Set, position.clone()).into(),
             "size" => BuiltInFunction::new(BuiltInFunctionName::Size, position.clone()).into(),
+            "slice" => BuiltInFunction::new(BuiltInFunctionName::Slice, position.clone()).into(),
             "source" => BuiltInFunction::new(BuiltInFunctionName::Source, position.clone()).into(),
             "true" => Boolean::new(true, position.clone()).into(),
             "values" => BuiltInFunction::new(BuiltInFunctionName::Values, position.clone()).into(),
@@ -52,7 +58,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Variable::new("none", Position::fake()),
                         Position::fake(),
                     ),
@@ -63,7 +69,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     None::new(Position::fake()),
                     Position::fake(),
                 ),
@@ -0,0 +1,313 @@
+use super::{context::AnalysisContext, AnalysisError, AnalysisWarning};
+use crate::{
+    analysis::{expression_visitor, type_canonicalizer, union_type_member_calculator},
+    ir::*,
+    types::Type,
+};
+
+// Collect warnings of comparisons whose results are known at compile time.
+//
+// This function expects a module whose types are all inferred already.
+pub fn collect(
+    context: &AnalysisContext,
+    module: &Module,
+) -> Result<Vec<AnalysisWarning>, AnalysisError> {
+    let mut warnings = vec![];
+
+    for definition in module.function_definitions() {
+        warnings.extend(collect_function_definition(context, definition)?);
+    }
+
+    Ok(warnings)
+}
+
+pub fn collect_function_definition(
+    context: &AnalysisContext,
+    definition: &FunctionDefinition,
+) -> Result<Vec<AnalysisWarning>, AnalysisError> {
+    let mut warnings = vec![];
+
+    for operation in collect_operations(definition) {
+        let (result, position) = match &operation {
+            Operation::Equality(operation) => {
+                (evaluate_equality(context, operation)?, operation.position())
+            }
+            Operation::Order(operation) => {
+                (evaluate_order(context, operation)?, operation.position())
+            }
+            _ => continue,
+        };
+
+        match result {
+            Some(true) => warnings.push(AnalysisWarning::AlwaysTrueComparison(position.clone())),
+            Some(false) => warnings.push(AnalysisWarning::AlwaysFalseComparison(position.clone())),
+            None => {}
+        }
+    }
+
+    Ok(warnings)
+}
+
+fn evaluate_equality(
+    context: &AnalysisContext,
+    operation: &EqualityOperation,
+) -> Result<Option<bool>, AnalysisError> {
+    let equal = match (operation.lhs(), operation.rhs()) {
+        (Expression::Boolean(lhs), Expression::Boolean(rhs)) => Some(lhs.value() == rhs.value()),
+        (Expression::None(_), Expression::None(_)) => Some(true),
+        (Expression::Number(lhs), Expression::Number(rhs)) => Some(lhs.value() == rhs.value()),
+        (Expression::String(lhs), Expression::String(rhs)) => Some(lhs.value() == rhs.value()),
+        // Values are not always equal to themselves if they can be NaN.
+        (Expression::Variable(lhs), Expression::Variable(rhs))
+            if lhs.name() == rhs.name()
+                && is_reflexive(
+                    context,
+                    operation.type_().ok_or_else(|| {
+                        AnalysisError::TypeNotInferred(operation.position().clone())
+                    })?,
+                )? =>
+        {
+            Some(true)
+        }
+        _ => None,
+    };
+
+    Ok(equal.map(|equal| match operation.operator() {
+        EqualityOperator::Equal => equal,
+        EqualityOperator::NotEqual => !equal,
+    }))
+}
+
+fn evaluate_order(
+    context: &AnalysisContext,
+    operation: &OrderOperation,
+) -> Result<Option<bool>, AnalysisError> {
+    Ok(match (operation.lhs(), operation.rhs()) {
+        (Expression::Number(lhs), Expression::Number(rhs)) => {
+            Some(compare(operation.operator(), lhs.value(), rhs.value()))
+        }
+        (Expression::String(lhs), Expression::String(rhs)) => {
+            Some(compare(operation.operator(), lhs.value(), rhs.value()))
+        }
+        (Expression::Variable(lhs), Expression::Variable(rhs)) if lhs.name() == rhs.name() => {
+            match operation.operator() {
+                OrderOperator::LessThan | OrderOperator::GreaterThan => Some(false),
+                OrderOperator::LessThanOrEqual | OrderOperator::GreaterThanOrEqual => matches!(
+                    type_canonicalizer::canonicalize(
+                        operation.type_().ok_or_else(|| {
+                            AnalysisError::TypeNotInferred(operation.position().clone())
+                        })?,
+                        context.types(),
+                    )?,
+                    Type::String(_)
+                )
+                .then_some(true),
+            }
+        }
+        _ => None,
+    })
+}
+
+fn compare<T: PartialOrd>(operator: OrderOperator, lhs: T, rhs: T) -> bool {
+    match operator {
+        OrderOperator::LessThan => lhs < rhs,
+        OrderOperator::LessThanOrEqual => lhs <= rhs,
+        OrderOperator::GreaterThan => lhs > rhs,
+        OrderOperator::GreaterThanOrEqual => lhs >= rhs,
+    }
+}
+
+fn is_reflexive(context: &AnalysisContext, type_: &Type) -> Result<bool, AnalysisError> {
+    Ok(
+        union_type_member_calculator::calculate(type_, context.types())?
+            .iter()
+            .all(|type_| matches!(type_, Type::Boolean(_) | Type::String(_) | Type::None(_))),
+    )
+}
+
+fn collect_operations(definition: &FunctionDefinition) -> Vec<Operation> {
+    let mut operations = vec![];
+
+    expression_visitor::visit_function_definition(definition, &mut |expression| {
+        if let Expression::Operation(operation @ (Operation::Equality(_) | Operation::Order(_))) =
+            expression
+        {
+            operations.push(operation.clone())
+        }
+    });
+
+    operations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::type_collector,
+        test::{FunctionDefinitionFake, ModuleFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn collect_module(module: &Module) -> Result<Vec<AnalysisWarning>, AnalysisError> {
+        collect(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_record_fields(module),
+            ),
+            module,
+        )
+    }
+
+    fn collect_expression(
+        argument_type: impl Into<Type>,
+        expression: impl Into<Expression>,
+    ) -> Result<Vec<AnalysisWarning>, AnalysisError> {
+        collect_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![Argument::new(
+                        "x",
+                        Some(argument_type.into()),
+                        Position::fake(),
+                    )],
+                    Some(types::Boolean::new(Position::fake()).into()),
+                    expression,
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )
+    }
+
+    #[test]
+    fn collect_nothing() {
+        assert_eq!(
+            collect_expression(
+                types::Number::new(Position::fake()),
+                EqualityOperation::new(
+                    Some(types::Number::new(Position::fake()).into()),
+                    EqualityOperator::Equal,
+                    Variable::new("x", Position::fake()),
+                    Number::new(42.0, Position::fake()),
+                    Position::fake(),
+                ),
+            ),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn collect_equal_numbers() {
+        assert_eq!(
+            collect_expression(
+                types::Number::new(Position::fake()),
+                EqualityOperation::new(
+                    Some(types::Number::new(Position::fake()).into()),
+                    EqualityOperator::Equal,
+                    Number::new(42.0, Position::fake()),
+                    Number::new(42.0, Position::fake()),
+                    Position::fake(),
+                ),
+            ),
+            Ok(vec![
+                AnalysisWarning::AlwaysTrueComparison(Position::fake())
+            ])
+        );
+    }
+
+    #[test]
+    fn collect_not_equal_strings() {
+        assert_eq!(
+            collect_expression(
+                types::Number::new(Position::fake()),
+                EqualityOperation::new(
+                    Some(types::ByteString::new(Position::fake()).into()),
+                    EqualityOperator::NotEqual,
+                    ByteString::new("foo", Position::fake()),
+                    ByteString::new("foo", Position::fake()),
+                    Position::fake(),
+                ),
+            ),
+            Ok(vec![AnalysisWarning::AlwaysFalseComparison(
+                Position::fake()
+            )])
+        );
+    }
+
+    #[test]
+    fn collect_equal_variables() {
+        assert_eq!(
+            collect_expression(
+                types::ByteString::new(Position::fake()),
+                EqualityOperation::new(
+                    Some(types::ByteString::new(Position::fake()).into()),
+                    EqualityOperator::Equal,
+                    Variable::new("x", Position::fake()),
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                ),
+            ),
+            Ok(vec![
+                AnalysisWarning::AlwaysTrueComparison(Position::fake())
+            ])
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_equal_number_variables() {
+        assert_eq!(
+            collect_expression(
+                types::Number::new(Position::fake()),
+                EqualityOperation::new(
+                    Some(types::Number::new(Position::fake()).into()),
+                    EqualityOperator::Equal,
+                    Variable::new("x", Position::fake()),
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                ),
+            ),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn collect_ordered_numbers() {
+        assert_eq!(
+            collect_expression(
+                types::Number::new(Position::fake()),
+                OrderOperation::new(
+                    Some(types::Number::new(Position::fake()).into()),
+                    OrderOperator::LessThan,
+                    Number::new(2.0, Position::fake()),
+                    Number::new(1.0, Position::fake()),
+                    Position::fake(),
+                ),
+            ),
+            Ok(vec![AnalysisWarning::AlwaysFalseComparison(
+                Position::fake()
+            )])
+        );
+    }
+
+    #[test]
+    fn collect_ordered_variables() {
+        assert_eq!(
+            collect_expression(
+                types::Number::new(Position::fake()),
+                OrderOperation::new(
+                    Some(types::Number::new(Position::fake()).into()),
+                    OrderOperator::GreaterThan,
+                    Variable::new("x", Position::fake()),
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                ),
+            ),
+            Ok(vec![AnalysisWarning::AlwaysFalseComparison(
+                Position::fake()
+            )])
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use super::AnalysisWarning;
+use crate::{
+    analysis::{expression_visitor, type_visitor},
+    ir::*,
+    types::Type,
+};
+use fnv::FnvHashSet;
+
+// Collect warnings of private function and type definitions never used in a
+// module.
+//
+// Recursive references of functions to themselves are not counted as uses.
+// Foreign functions are considered to be used by other languages.
+pub fn collect(module: &Module) -> Vec<AnalysisWarning> {
+    let mut function_names = FnvHashSet::default();
+
+    for definition in module.function_definitions() {
+        function_names.extend(
+            collect_variable_names(module, definition)
+                .into_iter()
+                .filter(|name| name != definition.name()),
+        );
+    }
+
+    let type_names = collect_type_names(module);
+
+    module
+        .type_definitions()
+        .iter()
+        .filter(|definition| {
+            !definition.is_public()
+                && !definition.is_external()
+                && !type_names.contains(definition.name())
+        })
+        .map(|definition| {
+            AnalysisWarning::UnusedType(
+                definition.original_name().into(),
+                definition.position().clone(),
+            )
+        })
+        .chain(
+            module
+                .type_aliases()
+                .iter()
+                .filter(|alias| {
+                    !alias.is_public() && !alias.is_external() && !type_names.contains(alias.name())
+                })
+                .map(|alias| {
+                    AnalysisWarning::UnusedType(
+                        alias.original_name().into(),
+                        alias.position().clone(),
+                    )
+                }),
+        )
+        .chain(
+            module
+                .function_definitions()
+                .iter()
+                .filter(|definition| {
+                    !definition.is_public()
+                        && definition.foreign_definition_configuration().is_none()
+                        && !function_names.contains(definition.name())
+                })
+                .map(|definition| {
+                    AnalysisWarning::UnusedFunction(
+                        definition.original_name().into(),
+                        definition.position().clone(),
+                    )
+                }),
+        )
+        .collect()
+}
+
+fn collect_variable_names(module: &Module, definition: &FunctionDefinition) -> Vec<String> {
+    let mut names = vec![];
+
+    expression_visitor::visit(
+        &Module::new(
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![definition.clone()],
+            module.position().clone(),
+        ),
+        |expression| {
+            if let Expression::Variable(variable) = expression {
+                names.push(variable.name().into());
+            }
+        },
+    );
+
+    names
+}
+
+fn collect_type_names(module: &Module) -> FnvHashSet<String> {
+    let mut names = FnvHashSet::default();
+
+    type_visitor::visit(module, |type_| match type_ {
+        Type::Record(record) => {
+            names.insert(record.name().into());
+        }
+        Type::Reference(reference) => {
+            names.insert(reference.name().into());
+        }
+        _ => {}
+    });
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::{FunctionDefinitionFake, ModuleFake, TypeAliasFake, TypeDefinitionFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn create_function_definition(
+        name: &str,
+        body: impl Into<Expression>,
+        public: bool,
+    ) -> FunctionDefinition {
+        FunctionDefinition::new(
+            name,
+            name,
+            Lambda::new(
+                vec![],
+                Some(types::None::new(Position::fake()).into()),
+                body,
+                Position::fake(),
+            ),
+            None,
+            public,
+            Position::fake(),
+        )
+    }
+
+    #[test]
+    fn collect_nothing() {
+        assert_eq!(collect(&Module::empty()), vec![]);
+    }
+
+    #[test]
+    fn collect_unused_function() {
+        assert_eq!(
+            collect(
+                &Module::empty().set_function_definitions(vec![create_function_definition(
+                    "f",
+                    None::new(Position::fake()),
+                    false
+                )])
+            ),
+            vec![AnalysisWarning::UnusedFunction(
+                "f".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_recursive_function() {
+        assert_eq!(
+            collect(
+                &Module::empty().set_function_definitions(vec![create_function_definition(
+                    "f",
+                    Variable::new("f", Position::fake()),
+                    false
+                )])
+            ),
+            vec![AnalysisWarning::UnusedFunction(
+                "f".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_used_function() {
+        assert_eq!(
+            collect(&Module::empty().set_function_definitions(vec![
+                create_function_definition("f", None::new(Position::fake()), false),
+                create_function_definition("g", Variable::new("f", Position::fake()), true),
+            ])),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_foreign_function() {
+        assert_eq!(
+            collect(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+                    "f",
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    Some(ForeignDefinitionConfiguration::new(CallingConvention::C)),
+                    false,
+                    Position::fake(),
+                )])
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_unused_types() {
+        assert_eq!(
+            collect(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "a",
+                        vec![],
+                        false,
+                        false,
+                        false
+                    )])
+                    .set_type_aliases(vec![TypeAlias::new(
+                        "b",
+                        "b",
+                        types::None::new(Position::fake()),
+                        false,
+                        false,
+                        Position::fake()
+                    )])
+            ),
+            vec![
+                AnalysisWarning::UnusedType("a".into(), Position::fake()),
+                AnalysisWarning::UnusedType("b".into(), Position::fake())
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_used_type() {
+        assert_eq!(
+            collect(
+                &Module::empty()
+                    .set_type_aliases(vec![TypeAlias::fake(
+                        "a",
+                        types::None::new(Position::fake()),
+                        false,
+                        false
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Reference::new("a", Position::fake()).into()),
+                            None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        true,
+                    )])
+            ),
+            vec![]
+        );
+    }
+}
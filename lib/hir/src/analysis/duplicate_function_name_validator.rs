@@ -1,23 +1,24 @@
-use super::AnalysisError;
+use super::{AnalysisError, MAX_ERROR_COUNT};
 use crate::ir::*;
 use fnv::FnvHashMap;
 use position::Position;
 
 pub fn validate(module: &Module) -> Result<(), AnalysisError> {
     let mut definitions = FnvHashMap::<&str, &Position>::default();
+    let mut errors = vec![];
 
     for definition in module.function_definitions() {
         if let Some(&position) = definitions.get(definition.name()) {
-            return Err(AnalysisError::DuplicateFunctionNames(
+            errors.push(AnalysisError::DuplicateFunctionNames(
                 position.clone(),
                 definition.position().clone(),
             ));
+        } else {
+            definitions.insert(definition.name(), definition.position());
         }
-
-        definitions.insert(definition.name(), definition.position());
     }
 
-    Ok(())
+    AnalysisError::collect(errors, MAX_ERROR_COUNT)
 }
 
 #[cfg(test)]
@@ -35,7 +36,7 @@ mod tests {
             "x",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 None::new(Position::fake()),
                 Position::fake(),
             ),
@@ -1,10 +1,11 @@
-use super::AnalysisError;
+use super::{AnalysisError, MAX_ERROR_COUNT};
 use crate::ir::*;
 use fnv::FnvHashMap;
 use position::Position;
 
 pub fn validate(module: &Module) -> Result<(), AnalysisError> {
     let mut definitions = FnvHashMap::<&str, &Position>::default();
+    let mut errors = vec![];
 
     for (name, position) in module
         .type_definitions()
@@ -17,17 +18,17 @@ pub fn validate(module: &Module) -> Result<(), AnalysisError> {
                 .map(|alias| (alias.name(), alias.position())),
         )
     {
-        if let Some(&position) = definitions.get(name) {
-            return Err(AnalysisError::DuplicateTypeNames(
-                position.clone(),
+        if let Some(&first_position) = definitions.get(name) {
+            errors.push(AnalysisError::DuplicateTypeNames(
+                first_position.clone(),
                 position.clone(),
             ));
+        } else {
+            definitions.insert(name, position);
         }
-
-        definitions.insert(name, position);
     }
 
-    Ok(())
+    AnalysisError::collect(errors, MAX_ERROR_COUNT)
 }
 
 #[cfg(test)]
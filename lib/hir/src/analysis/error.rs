@@ -22,37 +22,170 @@ pub enum AnalysisError {
     FunctionExpected(Position, Type),
     ImpossibleRecord(Position),
     InvalidAdditionOperand(Position),
+    InvalidBooleanOperand(Position),
+    InvalidOrderOperand(Position),
     InvalidTryOperation(Position),
     KeyNameNotDefined(Position),
     ListComprehensionIterateeCount(Position),
     ListExpected(Position, Type),
     MapExpected(Position, Type),
     MissingElseBlock(Position),
+    Multiple(Vec<AnalysisError>),
     RecordExpected(Position, Type),
     RecordFieldNotFound(String, Position),
     RecordFieldPrivate(Position),
-    RecordFieldUnknown(Position),
+    RecordFieldUnknown(Position, Option<String>),
     RecordNotFound(Record),
     RecursiveTypeAlias(Position),
     SpawnedFunctionArguments(Position),
     TryOperationInList(Position),
-    TypeNotFound(Reference),
+    TypeNotFound(Reference, Option<String>),
     TypeNotInferred(Position),
     TypeNotComparable(Position, Type),
+    TypeNotSerializable(Position, Type),
     TypesNotMatched {
         found: (Position, Type),
         expected: (Position, Type),
     },
     UnionExpected(Position, Type),
-    UnknownRecordField(Position),
+    UnknownRecordField(Position, Option<String>),
     UnreachableCode(Position),
     UnusedErrorValue(Position),
     ValueNameNotDefined(Position),
-    VariableNotFound(Variable),
+    VariableNotFound(Variable, Option<String>),
     VariantExpected(Position, Type),
+    VectorExpected(Position, Type),
 }
 
 impl AnalysisError {
+    // Error codes are stable and never reused for other errors.
+    pub fn code(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::AnyTypeBranch(_) => "E0001",
+            Self::ArgumentCount(_) => "E0002",
+            Self::BuiltInFunctionNotCalled(_) => "E0003",
+            Self::CollectionExpected(_, _) => "E0004",
+            Self::DuplicateFunctionNames(_, _) => "E0005",
+            Self::DuplicateTypeNames(_, _) => "E0006",
+            Self::ElementNameNotDefined(_) => "E0007",
+            Self::ErrorTypeUndefined => "E0008",
+            Self::FunctionExpected(_, _) => "E0009",
+            Self::ImpossibleRecord(_) => "E0010",
+            Self::InvalidAdditionOperand(_) => "E0011",
+            Self::InvalidTryOperation(_) => "E0012",
+            Self::KeyNameNotDefined(_) => "E0013",
+            Self::ListComprehensionIterateeCount(_) => "E0014",
+            Self::ListExpected(_, _) => "E0015",
+            Self::MapExpected(_, _) => "E0016",
+            Self::MissingElseBlock(_) => "E0017",
+            Self::RecordExpected(_, _) => "E0018",
+            Self::RecordFieldNotFound(_, _) => "E0019",
+            Self::RecordFieldPrivate(_) => "E0020",
+            Self::RecordFieldUnknown(_, _) => "E0021",
+            Self::RecordNotFound(_) => "E0022",
+            Self::RecursiveTypeAlias(_) => "E0023",
+            Self::SpawnedFunctionArguments(_) => "E0024",
+            Self::TryOperationInList(_) => "E0025",
+            Self::TypeNotFound(_, _) => "E0026",
+            Self::TypeNotInferred(_) => "E0027",
+            Self::TypeNotComparable(_, _) => "E0028",
+            Self::TypesNotMatched { .. } => "E0029",
+            Self::UnionExpected(_, _) => "E0030",
+            Self::UnknownRecordField(_, _) => "E0031",
+            Self::UnreachableCode(_) => "E0032",
+            Self::UnusedErrorValue(_) => "E0033",
+            Self::ValueNameNotDefined(_) => "E0034",
+            Self::VariableNotFound(_, _) => "E0035",
+            Self::VariantExpected(_, _) => "E0036",
+            Self::VectorExpected(_, _) => "E0037",
+            Self::TypeNotSerializable(_, _) => "E0038",
+            Self::InvalidOrderOperand(_) => "E0039",
+            Self::InvalidBooleanOperand(_) => "E0040",
+            Self::Multiple(_) => return None,
+        })
+    }
+
+    pub fn position(&self) -> Option<&Position> {
+        Some(match self {
+            Self::AnyTypeBranch(position)
+            | Self::ArgumentCount(position)
+            | Self::BuiltInFunctionNotCalled(position)
+            | Self::CollectionExpected(position, _)
+            | Self::DuplicateFunctionNames(position, _)
+            | Self::DuplicateTypeNames(position, _)
+            | Self::ElementNameNotDefined(position)
+            | Self::FunctionExpected(position, _)
+            | Self::ImpossibleRecord(position)
+            | Self::InvalidAdditionOperand(position)
+            | Self::InvalidBooleanOperand(position)
+            | Self::InvalidOrderOperand(position)
+            | Self::InvalidTryOperation(position)
+            | Self::KeyNameNotDefined(position)
+            | Self::ListComprehensionIterateeCount(position)
+            | Self::ListExpected(position, _)
+            | Self::MapExpected(position, _)
+            | Self::MissingElseBlock(position)
+            | Self::RecordExpected(position, _)
+            | Self::RecordFieldNotFound(_, position)
+            | Self::RecordFieldPrivate(position)
+            | Self::RecordFieldUnknown(position, _)
+            | Self::RecursiveTypeAlias(position)
+            | Self::SpawnedFunctionArguments(position)
+            | Self::TryOperationInList(position)
+            | Self::TypeNotInferred(position)
+            | Self::TypeNotComparable(position, _)
+            | Self::TypeNotSerializable(position, _)
+            | Self::TypesNotMatched {
+                found: (position, _),
+                ..
+            }
+            | Self::UnionExpected(position, _)
+            | Self::UnknownRecordField(position, _)
+            | Self::UnreachableCode(position)
+            | Self::UnusedErrorValue(position)
+            | Self::ValueNameNotDefined(position)
+            | Self::VariantExpected(position, _)
+            | Self::VectorExpected(position, _) => position,
+            Self::RecordNotFound(record) => record.position(),
+            Self::TypeNotFound(reference, _) => reference.position(),
+            Self::VariableNotFound(variable, _) => variable.position(),
+            Self::ErrorTypeUndefined => return None,
+            Self::Multiple(errors) => return errors.first().and_then(Self::position),
+        })
+    }
+
+    // Merge errors ordered by their positions into one.
+    pub(crate) fn from_errors(errors: Vec<Self>, limit: usize) -> Self {
+        let mut errors = errors
+            .into_iter()
+            .flat_map(|error| match error {
+                Self::Multiple(errors) => errors,
+                error => vec![error],
+            })
+            .collect::<Vec<_>>();
+
+        errors.sort_by(|one, other| match (one.position(), other.position()) {
+            (Some(one), Some(other)) => (one.path(), one.line_number(), one.column_number())
+                .cmp(&(other.path(), other.line_number(), other.column_number())),
+            (one, other) => other.is_some().cmp(&one.is_some()),
+        });
+        errors.truncate(limit);
+
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            Self::Multiple(errors)
+        }
+    }
+
+    pub(crate) fn collect(errors: Vec<Self>, limit: usize) -> Result<(), Self> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Self::from_errors(errors, limit))
+        }
+    }
+
     fn format_type(type_: &Type) -> String {
         format!("`{}`", type_formatter::format(type_))
     }
@@ -61,8 +194,12 @@ impl AnalysisError {
         position::format_message(position, &format!("found {}", Self::format_type(type_)))
     }
 
-    fn format_expected_type_message(position: &Position, type_: &Type) -> String {
-        position::format_message(position, &format!("expected {}", Self::format_type(type_)))
+    fn format_suggestion(position: &Position, suggestion: Option<&str>) -> String {
+        if let Some(suggestion) = suggestion {
+            position::format_message(position, &format!("did you mean `{suggestion}`?"))
+        } else {
+            position.to_string()
+        }
     }
 }
 
@@ -90,7 +227,7 @@ impl Display for AnalysisError {
             Self::CollectionExpected(position, type_) => {
                 write!(
                     formatter,
-                    "list or map expected\n{}",
+                    "list, map, or vector expected\n{}",
                     Self::format_found_type_message(position, type_)
                 )
             }
@@ -125,6 +262,18 @@ impl Display for AnalysisError {
                     "addition operands must be numbers or strings\n{position}"
                 )
             }
+            Self::InvalidBooleanOperand(position) => {
+                write!(
+                    formatter,
+                    "boolean operands must be booleans or numbers\n{position}"
+                )
+            }
+            Self::InvalidOrderOperand(position) => {
+                write!(
+                    formatter,
+                    "order operands must be numbers or strings\n{position}"
+                )
+            }
             Self::InvalidTryOperation(position) => {
                 write!(
                     formatter,
@@ -160,6 +309,17 @@ impl Display for AnalysisError {
                     "missing else block in if-type expression\n{position}"
                 )
             }
+            Self::Multiple(errors) => {
+                for (index, error) in errors.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, "\n\n")?;
+                    }
+
+                    write!(formatter, "{error}")?;
+                }
+
+                Ok(())
+            }
             Self::RecordExpected(position, type_) => {
                 write!(
                     formatter,
@@ -173,8 +333,12 @@ impl Display for AnalysisError {
             Self::RecordFieldPrivate(position) => {
                 write!(formatter, "private record field\n{position}")
             }
-            Self::RecordFieldUnknown(position) => {
-                write!(formatter, "unknown record field\n{position}")
+            Self::RecordFieldUnknown(position, suggestion) => {
+                write!(
+                    formatter,
+                    "unknown record field\n{}",
+                    Self::format_suggestion(position, suggestion.as_deref())
+                )
             }
             Self::RecordNotFound(record) => write!(
                 formatter,
@@ -212,11 +376,26 @@ impl Display for AnalysisError {
                     )
                 )
             }
-            Self::TypeNotFound(reference) => write!(
+            Self::TypeNotSerializable(position, type_) => {
+                write!(
+                    formatter,
+                    "type not serializable\n{}",
+                    position::format_message(
+                        position,
+                        &format!(
+                            "{} might include function, {}, {}, or vector types, or map types with non-string keys",
+                            Self::format_type(type_),
+                            Self::format_type(&types::Error::new(position.clone()).into()),
+                            Self::format_type(&types::Any::new(position.clone()).into()),
+                        ),
+                    )
+                )
+            }
+            Self::TypeNotFound(reference, suggestion) => write!(
                 formatter,
                 "type \"{}\" not found\n{}",
                 reference.name(),
-                reference.position()
+                Self::format_suggestion(reference.position(), suggestion.as_deref())
             ),
             Self::TypeNotInferred(position) => {
                 write!(formatter, "type not inferred\n{position}")
@@ -226,9 +405,17 @@ impl Display for AnalysisError {
                 expected: (expected_position, expected_type),
             } => write!(
                 formatter,
-                "types not matched\n{}\n{}",
-                Self::format_found_type_message(found_position, found_type),
-                Self::format_expected_type_message(expected_position, expected_type),
+                "types not matched\n{}",
+                position::format_labels(&[
+                    (
+                        found_position,
+                        &format!("found {}", Self::format_type(found_type))
+                    ),
+                    (
+                        expected_position,
+                        &format!("expected {}", Self::format_type(expected_type))
+                    ),
+                ]),
             ),
             Self::UnionExpected(position, type_) => {
                 write!(
@@ -237,8 +424,12 @@ impl Display for AnalysisError {
                     Self::format_found_type_message(position, type_)
                 )
             }
-            Self::UnknownRecordField(position) => {
-                write!(formatter, "unknown record field\n{position}")
+            Self::UnknownRecordField(position, suggestion) => {
+                write!(
+                    formatter,
+                    "unknown record field\n{}",
+                    Self::format_suggestion(position, suggestion.as_deref())
+                )
             }
             Self::UnreachableCode(position) => {
                 write!(formatter, "unreachable code\n{position}")
@@ -249,11 +440,11 @@ impl Display for AnalysisError {
             Self::ValueNameNotDefined(position) => {
                 write!(formatter, "value name not defined\n{position}")
             }
-            Self::VariableNotFound(variable) => write!(
+            Self::VariableNotFound(variable, suggestion) => write!(
                 formatter,
                 "variable \"{}\" not found\n{}",
                 variable.name(),
-                variable.position()
+                Self::format_suggestion(variable.position(), suggestion.as_deref())
             ),
             Self::VariantExpected(position, type_) => {
                 write!(
@@ -262,6 +453,13 @@ impl Display for AnalysisError {
                     Self::format_found_type_message(position, type_)
                 )
             }
+            Self::VectorExpected(position, type_) => {
+                write!(
+                    formatter,
+                    "vector expected\n{}",
+                    Self::format_found_type_message(position, type_)
+                )
+            }
         }
     }
 }
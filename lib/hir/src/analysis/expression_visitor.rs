@@ -2,11 +2,11 @@ use crate::ir::*;
 
 pub fn visit<'a>(module: &'a Module, mut visit: impl FnMut(&'a Expression)) {
     for definition in module.function_definitions() {
-        visit_definition(definition, &mut visit);
+        visit_function_definition(definition, &mut visit);
     }
 }
 
-fn visit_definition<'a>(
+pub fn visit_function_definition<'a>(
     definition: &'a FunctionDefinition,
     visit: &mut impl FnMut(&'a Expression),
 ) {
@@ -135,6 +135,11 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a E
             }
         }
         Expression::Thunk(thunk) => visit_expression(thunk.expression()),
+        Expression::Vector(vector) => {
+            for element in vector.elements() {
+                visit_expression(element);
+            }
+        }
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::None(_)
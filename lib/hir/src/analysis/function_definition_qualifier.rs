@@ -76,7 +76,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         None::new(Position::fake()),
                         Position::fake()
                     ),
@@ -88,7 +88,7 @@ mod tests {
                 "foo.x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     None::new(Position::fake()),
                     Position::fake()
                 ),
@@ -105,7 +105,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Variable::new("x", Position::fake()),
                         Position::fake()
                     ),
@@ -117,7 +117,7 @@ mod tests {
                 "foo.x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     Variable::new("foo.x", Position::fake()),
                     Position::fake()
                 ),
@@ -133,8 +133,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", types::None::new(Position::fake()))],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::None::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         Variable::new("x", Position::fake()),
                         Position::fake()
                     ),
@@ -145,8 +149,12 @@ mod tests {
             Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                 "foo.x",
                 Lambda::new(
-                    vec![Argument::new("x", types::None::new(Position::fake()))],
-                    types::None::new(Position::fake()),
+                    vec![Argument::new(
+                        "x",
+                        Some(types::None::new(Position::fake()).into()),
+                        Position::fake(),
+                    )],
+                    Some(types::None::new(Position::fake()).into()),
                     Variable::new("x", Position::fake()),
                     Position::fake()
                 ),
@@ -163,7 +171,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             Some("x".into()),
                             None,
@@ -181,7 +189,7 @@ mod tests {
                 "foo.x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     Let::new(
                         Some("x".into()),
                         None,
@@ -0,0 +1,390 @@
+use super::{context::AnalysisContext, AnalysisError, AnalysisWarning};
+use crate::{
+    analysis::{
+        expression_visitor, type_difference_calculator, type_subsumption_checker,
+        union_type_creator,
+    },
+    ir::*,
+};
+
+// Collect warnings of redundant branches and unreachable else blocks in
+// if-type expressions.
+//
+// This function expects a module whose types are all inferred already.
+pub fn collect(
+    context: &AnalysisContext,
+    module: &Module,
+) -> Result<Vec<AnalysisWarning>, AnalysisError> {
+    let mut warnings = vec![];
+
+    for definition in module.function_definitions() {
+        warnings.extend(collect_function_definition(context, definition)?);
+    }
+
+    Ok(warnings)
+}
+
+pub fn collect_function_definition(
+    context: &AnalysisContext,
+    definition: &FunctionDefinition,
+) -> Result<Vec<AnalysisWarning>, AnalysisError> {
+    let mut warnings = vec![];
+
+    for if_ in collect_if_types(definition) {
+        for (index, branch) in if_.branches().iter().enumerate() {
+            if let Some(covered_type) = union_type_creator::create(
+                &if_.branches()[..index]
+                    .iter()
+                    .map(|branch| branch.type_().clone())
+                    .collect::<Vec<_>>(),
+                if_.position(),
+            ) {
+                if type_subsumption_checker::check(branch.type_(), &covered_type, context.types())?
+                {
+                    warnings.push(AnalysisWarning::RedundantIfTypeBranch(
+                        branch.type_().position().clone(),
+                    ));
+                }
+            }
+        }
+
+        if let Some(branch) = if_.else_() {
+            if type_difference_calculator::calculate(
+                branch
+                    .type_()
+                    .ok_or_else(|| AnalysisError::TypeNotInferred(branch.position().clone()))?,
+                &union_type_creator::create(
+                    &if_.branches()
+                        .iter()
+                        .map(|branch| branch.type_().clone())
+                        .collect::<Vec<_>>(),
+                    if_.position(),
+                )
+                .unwrap(),
+                context.types(),
+            )?
+            .is_none()
+            {
+                warnings.push(AnalysisWarning::UnreachableElseBlock(
+                    branch.position().clone(),
+                ));
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+fn collect_if_types(definition: &FunctionDefinition) -> Vec<IfType> {
+    let mut if_types = vec![];
+
+    expression_visitor::visit_function_definition(definition, &mut |expression| {
+        if let Expression::IfType(if_) = expression {
+            if_types.push(if_.clone())
+        }
+    });
+
+    if_types
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::type_collector,
+        test::{FunctionDefinitionFake, ModuleFake},
+        types::{self, Type},
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn collect_module(module: &Module) -> Result<Vec<AnalysisWarning>, AnalysisError> {
+        collect(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_record_fields(module),
+            ),
+            module,
+        )
+    }
+
+    fn create_module(
+        argument_type: impl Into<Type>,
+        branches: Vec<IfTypeBranch>,
+        else_: Option<ElseBranch>,
+    ) -> Module {
+        Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+            "f",
+            Lambda::new(
+                vec![Argument::new(
+                    "x",
+                    Some(argument_type.into()),
+                    Position::fake(),
+                )],
+                Some(types::None::new(Position::fake()).into()),
+                IfType::new(
+                    "y",
+                    Variable::new("x", Position::fake()),
+                    branches,
+                    else_,
+                    Position::fake(),
+                ),
+                Position::fake(),
+            ),
+            false,
+        )])
+    }
+
+    #[test]
+    fn collect_nothing() {
+        assert_eq!(
+            collect_module(&create_module(
+                types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                vec![IfTypeBranch::new(
+                    types::Number::new(Position::fake()),
+                    None::new(Position::fake()),
+                )],
+                Some(ElseBranch::new(
+                    Some(types::None::new(Position::fake()).into()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                )),
+            )),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn collect_redundant_branch() {
+        assert_eq!(
+            collect_module(&create_module(
+                types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                vec![
+                    IfTypeBranch::new(
+                        types::Number::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(
+                        types::Number::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                ],
+                Some(ElseBranch::new(
+                    Some(types::None::new(Position::fake()).into()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                )),
+            )),
+            Ok(vec![AnalysisWarning::RedundantIfTypeBranch(
+                Position::fake()
+            )])
+        );
+    }
+
+    #[test]
+    fn collect_redundant_branch_covered_by_multiple_branches() {
+        let union_type = types::Union::new(
+            types::Number::new(Position::fake()),
+            types::None::new(Position::fake()),
+            Position::fake(),
+        );
+
+        assert_eq!(
+            collect_module(&create_module(
+                types::Union::new(
+                    union_type.clone(),
+                    types::ByteString::new(Position::fake()),
+                    Position::fake(),
+                ),
+                vec![
+                    IfTypeBranch::new(
+                        types::Number::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(
+                        types::None::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(union_type, None::new(Position::fake())),
+                ],
+                Some(ElseBranch::new(
+                    Some(types::ByteString::new(Position::fake()).into()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                )),
+            )),
+            Ok(vec![AnalysisWarning::RedundantIfTypeBranch(
+                Position::fake()
+            )])
+        );
+    }
+
+    #[test]
+    fn collect_redundant_branch_after_any_type() {
+        assert_eq!(
+            collect_module(&create_module(
+                types::Any::new(Position::fake()),
+                vec![
+                    IfTypeBranch::new(
+                        types::Any::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(
+                        types::Number::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                ],
+                None,
+            )),
+            Ok(vec![AnalysisWarning::RedundantIfTypeBranch(
+                Position::fake()
+            )])
+        );
+    }
+
+    #[test]
+    fn collect_redundant_branch_of_list_after_any_type() {
+        assert_eq!(
+            collect_module(&create_module(
+                types::Any::new(Position::fake()),
+                vec![
+                    IfTypeBranch::new(
+                        types::Union::new(
+                            types::Any::new(Position::fake()),
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(
+                        types::List::new(types::Number::new(Position::fake()), Position::fake(),),
+                        None::new(Position::fake()),
+                    ),
+                ],
+                None,
+            )),
+            Ok(vec![AnalysisWarning::RedundantIfTypeBranch(
+                Position::fake()
+            )])
+        );
+    }
+
+    #[test]
+    fn collect_redundant_branch_of_list_after_same_list_type() {
+        let list_type = types::List::new(types::Any::new(Position::fake()), Position::fake());
+
+        assert_eq!(
+            collect_module(&create_module(
+                types::Any::new(Position::fake()),
+                vec![
+                    IfTypeBranch::new(list_type.clone(), None::new(Position::fake())),
+                    IfTypeBranch::new(
+                        types::Union::new(
+                            list_type,
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(
+                        types::List::new(types::Any::new(Position::fake()), Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                ],
+                None,
+            )),
+            Ok(vec![AnalysisWarning::RedundantIfTypeBranch(
+                Position::fake()
+            )])
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_partially_covered_branch() {
+        assert_eq!(
+            collect_module(&create_module(
+                types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                vec![
+                    IfTypeBranch::new(
+                        types::Number::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(
+                        types::Union::new(
+                            types::Number::new(Position::fake()),
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        None::new(Position::fake()),
+                    ),
+                ],
+                None,
+            )),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn collect_unreachable_else_block() {
+        let union_type = types::Union::new(
+            types::Number::new(Position::fake()),
+            types::None::new(Position::fake()),
+            Position::fake(),
+        );
+
+        assert_eq!(
+            collect_module(&create_module(
+                union_type.clone(),
+                vec![
+                    IfTypeBranch::new(
+                        types::Number::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                    IfTypeBranch::new(
+                        types::None::new(Position::fake()),
+                        None::new(Position::fake()),
+                    ),
+                ],
+                Some(ElseBranch::new(
+                    Some(union_type.into()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                )),
+            )),
+            Ok(vec![
+                AnalysisWarning::UnreachableElseBlock(Position::fake())
+            ])
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_else_block_of_any_type() {
+        assert_eq!(
+            collect_module(&create_module(
+                types::Any::new(Position::fake()),
+                vec![IfTypeBranch::new(
+                    types::Number::new(Position::fake()),
+                    None::new(Position::fake()),
+                )],
+                Some(ElseBranch::new(
+                    Some(types::Any::new(Position::fake()).into()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                )),
+            )),
+            Ok(vec![])
+        );
+    }
+}
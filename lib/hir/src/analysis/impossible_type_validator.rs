@@ -1,14 +1,17 @@
-use super::{record_field_resolver, type_resolver, AnalysisContext};
+use super::{record_field_resolver, type_resolver, AnalysisContext, MAX_ERROR_COUNT};
 use crate::{analysis::AnalysisError, ir::*, types::Type};
 use fnv::FnvHashSet;
 use std::convert::identity;
 
 pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
-    for definition in module.type_definitions() {
-        validate_type_definition(context, definition)?;
-    }
-
-    Ok(())
+    AnalysisError::collect(
+        module
+            .type_definitions()
+            .iter()
+            .filter_map(|definition| validate_type_definition(context, definition).err())
+            .collect(),
+        MAX_ERROR_COUNT,
+    )
 }
 
 fn validate_type_definition(
@@ -68,7 +71,8 @@ fn is_type_recursive(
         | Type::Map(_)
         | Type::None(_)
         | Type::Number(_)
-        | Type::String(_) => false,
+        | Type::String(_)
+        | Type::Vector(_) => false,
     })
 }
 
@@ -282,7 +286,11 @@ mod tests {
 
         assert!(matches!(
             validate_module(&module),
-            Err(AnalysisError::ImpossibleRecord(_))
+            Err(AnalysisError::Multiple(errors))
+                if matches!(
+                    errors.as_slice(),
+                    [AnalysisError::ImpossibleRecord(_), AnalysisError::ImpossibleRecord(_)]
+                )
         ));
     }
 
@@ -1,9 +1,9 @@
-use super::type_extractor;
+use super::{type_extractor, AnalysisError};
 use crate::{ir::*, types::Type};
 use std::collections::HashMap;
 
 // TODO Use FnvHashMap for deterministic build.
-pub fn create(module: &Module) -> HashMap<String, Type> {
+pub fn create(module: &Module) -> Result<HashMap<String, Type>, AnalysisError> {
     module
         .function_declarations()
         .iter()
@@ -19,11 +19,12 @@ pub fn create(module: &Module) -> HashMap<String, Type> {
                 .iter()
                 .map(|declaration| (declaration.name().into(), declaration.type_().clone())),
         )
+        .map(Ok)
         .chain(module.function_definitions().iter().map(|definition| {
-            (
+            Ok((
                 definition.name().into(),
-                type_extractor::extract_from_lambda(definition.lambda()).into(),
-            )
+                type_extractor::extract_from_lambda(definition.lambda())?.into(),
+            ))
         }))
         .collect()
 }
@@ -49,7 +50,7 @@ mod tests {
                     Position::fake()
                 )])
             ),
-            [("foo".into(), type_.into())].into_iter().collect()
+            Ok([("foo".into(), type_.into())].into_iter().collect())
         );
     }
 }
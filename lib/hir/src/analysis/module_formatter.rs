@@ -0,0 +1,483 @@
+use super::type_formatter;
+use crate::{ir::*, types::Type};
+
+const INDENT: &str = "  ";
+
+pub fn format(module: &Module) -> String {
+    module
+        .type_definitions()
+        .iter()
+        .map(format_type_definition)
+        .chain(module.type_aliases().iter().map(format_type_alias))
+        .chain(
+            module
+                .foreign_declarations()
+                .iter()
+                .map(format_foreign_declaration),
+        )
+        .chain(
+            module
+                .function_declarations()
+                .iter()
+                .map(format_function_declaration),
+        )
+        .chain(
+            module
+                .function_definitions()
+                .iter()
+                .map(format_function_definition),
+        )
+        .map(|string| string + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_type_definition(definition: &TypeDefinition) -> String {
+    format!(
+        "{}type {} {{\n{}}}",
+        format_visibility(definition.is_public(), definition.is_external()),
+        definition.name(),
+        definition
+            .fields()
+            .iter()
+            .map(|field| format!(
+                "{INDENT}{} {}\n",
+                field.name(),
+                type_formatter::format(field.type_())
+            ))
+            .collect::<String>()
+    )
+}
+
+fn format_type_alias(alias: &TypeAlias) -> String {
+    format!(
+        "{}type {} = {}",
+        format_visibility(alias.is_public(), alias.is_external()),
+        alias.name(),
+        type_formatter::format(alias.type_())
+    )
+}
+
+fn format_visibility(public: bool, external: bool) -> String {
+    [public.then_some("public "), external.then_some("external ")]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn format_foreign_declaration(declaration: &ForeignDeclaration) -> String {
+    format!(
+        "import foreign {} {} {} = {}",
+        format_calling_convention(declaration.calling_convention()),
+        declaration.foreign_name(),
+        declaration.name(),
+        type_formatter::format(declaration.type_())
+    )
+}
+
+fn format_function_declaration(declaration: &FunctionDeclaration) -> String {
+    format!(
+        "declare {} = {}",
+        declaration.name(),
+        type_formatter::format(&declaration.type_().clone().into())
+    )
+}
+
+fn format_function_definition(definition: &FunctionDefinition) -> String {
+    format!(
+        "{}{}{} = {}",
+        if definition.is_public() {
+            "public "
+        } else {
+            ""
+        },
+        definition
+            .foreign_definition_configuration()
+            .map(|configuration| format!(
+                "foreign {} ",
+                format_calling_convention(configuration.calling_convention())
+            ))
+            .unwrap_or_default(),
+        definition.name(),
+        format_lambda(definition.lambda())
+    )
+}
+
+fn format_calling_convention(calling_convention: CallingConvention) -> &'static str {
+    match calling_convention {
+        CallingConvention::C => "\"c\"",
+        CallingConvention::Native => "\"native\"",
+    }
+}
+
+fn format_lambda(lambda: &Lambda) -> String {
+    format!(
+        "\\({}){} {}",
+        lambda
+            .arguments()
+            .iter()
+            .map(|argument| format!(
+                "{}{}",
+                argument.name(),
+                format_type_annotation(argument.type_())
+            ))
+            .collect::<Vec<_>>()
+            .join(", "),
+        format_type_annotation(lambda.result_type()),
+        format_block(lambda.body())
+    )
+}
+
+fn format_type_annotation(type_: Option<&Type>) -> String {
+    type_
+        .map(|type_| " ".to_owned() + &type_formatter::format(type_))
+        .unwrap_or_default()
+}
+
+fn format_block(expression: &Expression) -> String {
+    format!("{{\n{}\n}}", indent(&format_expression(expression)))
+}
+
+fn format_expression(expression: &Expression) -> String {
+    match expression {
+        Expression::Boolean(boolean) => boolean.value().to_string(),
+        Expression::BuiltInFunction(function) => format_built_in_function(function.name()).into(),
+        Expression::Call(call) => format!(
+            "{}({})",
+            format_expression(call.function()),
+            format_expressions(call.arguments())
+        ),
+        Expression::If(if_) => format!(
+            "if {} {} else {}",
+            format_expression(if_.condition()),
+            format_block(if_.then()),
+            format_block(if_.else_())
+        ),
+        Expression::IfList(if_) => format!(
+            "if [{}, ...{}] = {} {} else {}",
+            if_.first_name(),
+            if_.rest_name(),
+            format_expression(if_.list()),
+            format_block(if_.then()),
+            format_block(if_.else_())
+        ),
+        Expression::IfMap(if_) => format!(
+            "if {} = {}[{}] {} else {}",
+            if_.name(),
+            format_expression(if_.map()),
+            format_expression(if_.key()),
+            format_block(if_.then()),
+            format_block(if_.else_())
+        ),
+        Expression::IfType(if_) => format!(
+            "if {} = {} as {}{}",
+            if_.name(),
+            format_expression(if_.argument()),
+            if_.branches()
+                .iter()
+                .map(|branch| format!(
+                    "{} {}",
+                    type_formatter::format(branch.type_()),
+                    format_block(branch.expression())
+                ))
+                .collect::<Vec<_>>()
+                .join(" else if "),
+            if_.else_()
+                .map(|branch| format!(" else {}", format_block(branch.expression())))
+                .unwrap_or_default()
+        ),
+        Expression::Lambda(lambda) => format_lambda(lambda),
+        Expression::Let(let_) => format!(
+            "{}{} = {}\n{}",
+            let_.name().unwrap_or("_"),
+            format_optional_type(let_.type_()),
+            format_expression(let_.bound_expression()),
+            format_expression(let_.expression())
+        ),
+        Expression::List(list) => format!(
+            "[{} {}]",
+            type_formatter::format(list.type_()),
+            list.elements()
+                .iter()
+                .map(|element| match element {
+                    ListElement::Multiple(expression) => {
+                        format!("...{}", format_expression(expression))
+                    }
+                    ListElement::Single(expression) => format_expression(expression),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Expression::ListComprehension(comprehension) => format!(
+            "[{} {} {}]",
+            type_formatter::format(comprehension.type_()),
+            format_expression(comprehension.element()),
+            comprehension
+                .branches()
+                .iter()
+                .map(|branch| format!(
+                    "for {} in {}{}",
+                    branch.names().join(", "),
+                    branch
+                        .iteratees()
+                        .iter()
+                        .map(|iteratee| format_expression(iteratee.expression()))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    branch
+                        .condition()
+                        .map(|condition| format!(" if {}", format_expression(condition)))
+                        .unwrap_or_default()
+                ))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+        Expression::Map(map) => format!(
+            "{{{}: {} {}}}",
+            type_formatter::format(map.key_type()),
+            type_formatter::format(map.value_type()),
+            map.elements()
+                .iter()
+                .map(|element| match element {
+                    MapElement::Multiple(expression) => {
+                        format!("...{}", format_expression(expression))
+                    }
+                    MapElement::Single(entry) => format!(
+                        "{}: {}",
+                        format_expression(entry.key()),
+                        format_expression(entry.value())
+                    ),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Expression::None(_) => "none".into(),
+        Expression::Number(number) => number.value().to_string(),
+        Expression::Operation(operation) => format_operation(operation),
+        Expression::RecordConstruction(construction) => format!(
+            "{}{{{}}}",
+            type_formatter::format(construction.type_()),
+            format_record_fields(construction.fields())
+        ),
+        Expression::RecordDeconstruction(deconstruction) => format!(
+            "{}.{}",
+            format_expression(deconstruction.record()),
+            deconstruction.field_name()
+        ),
+        Expression::RecordUpdate(update) => format!(
+            "{}{{...{}, {}}}",
+            type_formatter::format(update.type_()),
+            format_expression(update.record()),
+            format_record_fields(update.fields())
+        ),
+        Expression::String(string) => format!("{:?}", String::from_utf8_lossy(string.value())),
+        Expression::Thunk(thunk) => format!("thunk {}", format_block(thunk.expression())),
+        Expression::TypeCoercion(coercion) => format!(
+            "({} as {} -> {})",
+            format_expression(coercion.argument()),
+            type_formatter::format(coercion.from()),
+            type_formatter::format(coercion.to())
+        ),
+        Expression::Variable(variable) => variable.name().into(),
+        Expression::Vector(vector) => format!(
+            "[|{} {}|]",
+            type_formatter::format(vector.type_()),
+            format_expressions(vector.elements())
+        ),
+    }
+}
+
+fn format_expressions(expressions: &[Expression]) -> String {
+    expressions
+        .iter()
+        .map(format_expression)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_record_fields(fields: &[RecordField]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}: {}",
+                field.name(),
+                format_expression(field.expression())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_optional_type(type_: Option<&Type>) -> String {
+    type_
+        .map(|type_| format!(" {}", type_formatter::format(type_)))
+        .unwrap_or_default()
+}
+
+fn format_operation(operation: &Operation) -> String {
+    let (lhs, operator, rhs) = match operation {
+        Operation::Addition(operation) => (operation.lhs(), "+", operation.rhs()),
+        Operation::Arithmetic(operation) => (
+            operation.lhs(),
+            match operation.operator() {
+                ArithmeticOperator::Subtract => "-",
+                ArithmeticOperator::Multiply => "*",
+                ArithmeticOperator::Divide => "/",
+                ArithmeticOperator::IntegerDivide => "//",
+                ArithmeticOperator::Remainder => "%",
+                ArithmeticOperator::Xor => "^",
+                ArithmeticOperator::LeftShift => "<<",
+                ArithmeticOperator::RightShift => ">>",
+            },
+            operation.rhs(),
+        ),
+        Operation::Boolean(operation) => (
+            operation.lhs(),
+            match operation.operator() {
+                BooleanOperator::And => "&",
+                BooleanOperator::Or => "|",
+            },
+            operation.rhs(),
+        ),
+        Operation::Equality(operation) => (
+            operation.lhs(),
+            match operation.operator() {
+                EqualityOperator::Equal => "==",
+                EqualityOperator::NotEqual => "!=",
+            },
+            operation.rhs(),
+        ),
+        Operation::Not(operation) => {
+            return format!("!{}", format_expression(operation.expression()))
+        }
+        Operation::Order(operation) => (
+            operation.lhs(),
+            match operation.operator() {
+                OrderOperator::LessThan => "<",
+                OrderOperator::LessThanOrEqual => "<=",
+                OrderOperator::GreaterThan => ">",
+                OrderOperator::GreaterThanOrEqual => ">=",
+            },
+            operation.rhs(),
+        ),
+        Operation::Try(operation) => {
+            return format!("{}?", format_expression(operation.expression()))
+        }
+    };
+
+    format!(
+        "({} {} {})",
+        format_expression(lhs),
+        operator,
+        format_expression(rhs)
+    )
+}
+
+fn format_built_in_function(name: BuiltInFunctionName) -> &'static str {
+    match name {
+        BuiltInFunctionName::Debug => "debug",
+        BuiltInFunctionName::Decode => "decode",
+        BuiltInFunctionName::Delete => "delete",
+        BuiltInFunctionName::Encode => "encode",
+        BuiltInFunctionName::Error => "error",
+        BuiltInFunctionName::Get => "get",
+        BuiltInFunctionName::Keys => "keys",
+        BuiltInFunctionName::Push => "push",
+        BuiltInFunctionName::Race => "race",
+        BuiltInFunctionName::ReflectDebug => "reflectDebug",
+        BuiltInFunctionName::ReflectEqual => "reflectEqual",
+        BuiltInFunctionName::Set => "set",
+        BuiltInFunctionName::Size => "size",
+        BuiltInFunctionName::Slice => "slice",
+        BuiltInFunctionName::Source => "source",
+        BuiltInFunctionName::Spawn => "go",
+        BuiltInFunctionName::Values => "values",
+    }
+}
+
+fn indent(string: &str) -> String {
+    string
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                line.into()
+            } else {
+                INDENT.to_owned() + line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::{FunctionDefinitionFake, ModuleFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_empty_module() {
+        assert_eq!(format(&Module::empty()), "");
+    }
+
+    #[test]
+    fn format_function_definition() {
+        assert_eq!(
+            format(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Number::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::Number::new(Position::fake()).into()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            ),
+            "f = \\(x number) number {\n  x\n}\n"
+        );
+    }
+
+    #[test]
+    fn format_if() {
+        assert_eq!(
+            format_expression(
+                &If::new(
+                    Boolean::new(true, Position::fake()),
+                    Number::new(1.0, Position::fake()),
+                    Number::new(2.0, Position::fake()),
+                    Position::fake(),
+                )
+                .into()
+            ),
+            "if true {\n  1\n} else {\n  2\n}"
+        );
+    }
+
+    #[test]
+    fn format_let() {
+        assert_eq!(
+            format_expression(
+                &Let::new(
+                    Some("x".into()),
+                    Some(types::Number::new(Position::fake()).into()),
+                    Number::new(1.0, Position::fake()),
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                )
+                .into()
+            ),
+            "x number = 1\nx"
+        );
+    }
+}
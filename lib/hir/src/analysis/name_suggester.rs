@@ -0,0 +1,388 @@
+use crate::ir::*;
+
+// Suggest the most similar name among candidates by edit distance.
+pub fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    let limit = name.chars().count() / 3;
+
+    candidates
+        .into_iter()
+        .filter(|&candidate| candidate != name)
+        .map(|candidate| (calculate_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= limit)
+        .min()
+        .map(|(_, candidate)| candidate.into())
+}
+
+// Suggest a variable name among global names and local ones in scope at a
+// variable in a function definition.
+pub fn suggest_variable<'a>(
+    global_names: impl IntoIterator<Item = &'a str>,
+    definition: &'a FunctionDefinition,
+    variable: &Variable,
+) -> Option<String> {
+    let mut names = vec![];
+
+    suggest(
+        variable.name(),
+        global_names.into_iter().chain(
+            find_local_names_in_lambda(definition.lambda(), variable, &mut names)
+                .unwrap_or_default(),
+        ),
+    )
+}
+
+fn find_local_names_in_lambda<'a>(
+    lambda: &'a Lambda,
+    variable: &Variable,
+    names: &mut Vec<&'a str>,
+) -> Option<Vec<&'a str>> {
+    find_local_names(
+        lambda.body(),
+        variable,
+        names,
+        lambda.arguments().iter().map(|argument| argument.name()),
+    )
+}
+
+// Find names in scope at a variable with names bound additionally.
+fn find_local_names<'a>(
+    expression: &'a Expression,
+    variable: &Variable,
+    names: &mut Vec<&'a str>,
+    bound_names: impl IntoIterator<Item = &'a str>,
+) -> Option<Vec<&'a str>> {
+    let length = names.len();
+
+    names.extend(bound_names);
+
+    let found = find_local_names_in_expression(expression, variable, names);
+
+    names.truncate(length);
+
+    found
+}
+
+fn find_local_names_in_expression<'a>(
+    expression: &'a Expression,
+    variable: &Variable,
+    names: &mut Vec<&'a str>,
+) -> Option<Vec<&'a str>> {
+    let find = |expression, names: &mut _| find_local_names(expression, variable, names, []);
+
+    match expression {
+        Expression::Call(call) => find(call.function(), names).or_else(|| {
+            call.arguments()
+                .iter()
+                .find_map(|argument| find(argument, names))
+        }),
+        Expression::If(if_) => find(if_.condition(), names)
+            .or_else(|| find(if_.then(), names))
+            .or_else(|| find(if_.else_(), names)),
+        Expression::IfList(if_) => find(if_.list(), names)
+            .or_else(|| {
+                find_local_names(
+                    if_.then(),
+                    variable,
+                    names,
+                    [if_.first_name(), if_.rest_name()],
+                )
+            })
+            .or_else(|| find(if_.else_(), names)),
+        Expression::IfMap(if_) => find(if_.map(), names)
+            .or_else(|| find(if_.key(), names))
+            .or_else(|| find_local_names(if_.then(), variable, names, [if_.name()]))
+            .or_else(|| find(if_.else_(), names)),
+        Expression::IfType(if_) => find(if_.argument(), names).or_else(|| {
+            if_.branches()
+                .iter()
+                .map(|branch| branch.expression())
+                .chain(if_.else_().map(|branch| branch.expression()))
+                .find_map(|expression| find_local_names(expression, variable, names, [if_.name()]))
+        }),
+        Expression::Lambda(lambda) => find_local_names_in_lambda(lambda, variable, names),
+        Expression::Let(let_) => find(let_.bound_expression(), names)
+            .or_else(|| find_local_names(let_.expression(), variable, names, let_.name())),
+        Expression::List(list) => list.elements().iter().find_map(|element| match element {
+            ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                find(expression, names)
+            }
+        }),
+        Expression::ListComprehension(comprehension) => {
+            let length = names.len();
+            let mut found = None;
+
+            for branch in comprehension.branches() {
+                for (name, iteratee) in branch.names().iter().zip(branch.iteratees()) {
+                    found = found.or_else(|| find(iteratee.expression(), names));
+                    names.push(name);
+                }
+
+                if let Some(condition) = branch.condition() {
+                    found = found.or_else(|| find(condition, names));
+                }
+            }
+
+            let found = found.or_else(|| find(comprehension.element(), names));
+
+            names.truncate(length);
+
+            found
+        }
+        Expression::Map(map) => map.elements().iter().find_map(|element| match element {
+            MapElement::Single(entry) => {
+                find(entry.key(), names).or_else(|| find(entry.value(), names))
+            }
+            MapElement::Multiple(expression) => find(expression, names),
+        }),
+        Expression::Operation(operation) => match operation {
+            Operation::Addition(operation) => {
+                find(operation.lhs(), names).or_else(|| find(operation.rhs(), names))
+            }
+            Operation::Arithmetic(operation) => {
+                find(operation.lhs(), names).or_else(|| find(operation.rhs(), names))
+            }
+            Operation::Boolean(operation) => {
+                find(operation.lhs(), names).or_else(|| find(operation.rhs(), names))
+            }
+            Operation::Equality(operation) => {
+                find(operation.lhs(), names).or_else(|| find(operation.rhs(), names))
+            }
+            Operation::Not(operation) => find(operation.expression(), names),
+            Operation::Order(operation) => {
+                find(operation.lhs(), names).or_else(|| find(operation.rhs(), names))
+            }
+            Operation::Try(operation) => find(operation.expression(), names),
+        },
+        Expression::RecordConstruction(construction) => construction
+            .fields()
+            .iter()
+            .find_map(|field| find(field.expression(), names)),
+        Expression::RecordDeconstruction(deconstruction) => find(deconstruction.record(), names),
+        Expression::RecordUpdate(update) => find(update.record(), names).or_else(|| {
+            update
+                .fields()
+                .iter()
+                .find_map(|field| find(field.expression(), names))
+        }),
+        Expression::Thunk(thunk) => find(thunk.expression(), names),
+        Expression::TypeCoercion(coercion) => find(coercion.argument(), names),
+        Expression::Variable(other) => (other.name() == variable.name()
+            && is_same_position(other, variable))
+        .then(|| names.clone()),
+        Expression::Vector(vector) => vector
+            .elements()
+            .iter()
+            .find_map(|element| find(element, names)),
+        Expression::Boolean(_)
+        | Expression::BuiltInFunction(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::String(_) => None,
+    }
+}
+
+// Positions are always equal to each other by their `PartialEq`
+// implementation.
+fn is_same_position(one: &Variable, other: &Variable) -> bool {
+    let one = one.position();
+    let other = other.position();
+
+    (one.path(), one.line_number(), one.column_number())
+        == (other.path(), other.line_number(), other.column_number())
+}
+
+// Calculate an edit distance with transpositions of adjacent characters.
+fn calculate_distance(one: &str, other: &str) -> usize {
+    let one = one.chars().collect::<Vec<_>>();
+    let other = other.chars().collect::<Vec<_>>();
+    let mut distances = vec![vec![0; other.len() + 1]; one.len() + 1];
+
+    for (index, row) in distances.iter_mut().enumerate() {
+        row[0] = index;
+    }
+
+    for (index, distance) in distances[0].iter_mut().enumerate() {
+        *distance = index;
+    }
+
+    for index in 1..=one.len() {
+        for other_index in 1..=other.len() {
+            let cost = usize::from(one[index - 1] != other[other_index - 1]);
+
+            distances[index][other_index] = (distances[index - 1][other_index] + 1)
+                .min(distances[index][other_index - 1] + 1)
+                .min(distances[index - 1][other_index - 1] + cost);
+
+            if index > 1
+                && other_index > 1
+                && one[index - 1] == other[other_index - 2]
+                && one[index - 2] == other[other_index - 1]
+            {
+                distances[index][other_index] =
+                    distances[index][other_index].min(distances[index - 2][other_index - 2] + 1);
+            }
+        }
+    }
+
+    distances[one.len()][other.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::FunctionDefinitionFake, types};
+    use position::{test::PositionFake, Position};
+
+    #[test]
+    fn calculate_distances() {
+        assert_eq!(calculate_distance("", ""), 0);
+        assert_eq!(calculate_distance("foo", "foo"), 0);
+        assert_eq!(calculate_distance("foo", "fo"), 1);
+        assert_eq!(calculate_distance("foo", "bar"), 3);
+        assert_eq!(calculate_distance("kitten", "sitting"), 3);
+        assert_eq!(calculate_distance("value", "valeu"), 1);
+    }
+
+    #[test]
+    fn suggest_similar_name() {
+        assert_eq!(suggest("foo", ["bar", "fob"]), Some("fob".into()));
+    }
+
+    #[test]
+    fn suggest_nearest_name() {
+        assert_eq!(
+            suggest("counter", ["counters", "count", "ounter"]),
+            Some("counters".into())
+        );
+    }
+
+    #[test]
+    fn suggest_no_name() {
+        assert_eq!(suggest("foo", ["bar", "baz"]), None);
+        assert_eq!(suggest("foo", ["foo"]), None);
+        assert_eq!(suggest("foo", []), None);
+        assert_eq!(suggest("x", ["y"]), None);
+    }
+
+    fn suggest_variable_in_definition(
+        global_names: &[&str],
+        definition: &FunctionDefinition,
+        variable: &Variable,
+    ) -> Option<String> {
+        suggest_variable(global_names.iter().copied(), definition, variable)
+    }
+
+    #[test]
+    fn suggest_variable_from_argument() {
+        let variable = Variable::new("valeu", Position::fake());
+
+        assert_eq!(
+            suggest_variable_in_definition(
+                &[],
+                &FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "value",
+                            Some(types::None::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
+                        variable.clone(),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                &variable,
+            ),
+            Some("value".into())
+        );
+    }
+
+    #[test]
+    fn suggest_variable_from_let() {
+        let variable = Variable::new("valeu", Position::fake());
+
+        assert_eq!(
+            suggest_variable_in_definition(
+                &[],
+                &FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        Let::new(
+                            Some("value".into()),
+                            None,
+                            None::new(Position::fake()),
+                            variable.clone(),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                &variable,
+            ),
+            Some("value".into())
+        );
+    }
+
+    #[test]
+    fn suggest_variable_from_global_names() {
+        let variable = Variable::new("fob", Position::fake());
+
+        assert_eq!(
+            suggest_variable_in_definition(
+                &["foo"],
+                &FunctionDefinition::fake(
+                    "bar",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        variable.clone(),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                &variable,
+            ),
+            Some("foo".into())
+        );
+    }
+
+    #[test]
+    fn suggest_no_variable_out_of_scope() {
+        let variable = Variable::new("valeu", Position::new("foo.pen", 2, 1, "valeu"));
+
+        assert_eq!(
+            suggest_variable_in_definition(
+                &[],
+                &FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        Let::new(
+                            None,
+                            None,
+                            Let::new(
+                                Some("value".into()),
+                                None,
+                                None::new(Position::fake()),
+                                Variable::new("value", Position::new("foo.pen", 1, 1, "value")),
+                                Position::fake(),
+                            ),
+                            variable.clone(),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                &variable,
+            ),
+            None
+        );
+    }
+}
@@ -6,9 +6,21 @@ use crate::{
 use fnv::FnvHashSet;
 
 pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
-    let open_records = collect_open_records(module.type_definitions());
+    let open_records = collect_open_records(module);
 
-    for expression in collect_expressions(module) {
+    for definition in module.function_definitions() {
+        validate_function_definition(context, definition, &open_records)?;
+    }
+
+    Ok(())
+}
+
+pub fn validate_function_definition(
+    context: &AnalysisContext,
+    definition: &FunctionDefinition,
+    open_records: &FnvHashSet<&str>,
+) -> Result<(), AnalysisError> {
+    for expression in collect_expressions(definition) {
         match expression {
             Expression::RecordConstruction(construction) => {
                 let record_type =
@@ -69,10 +81,10 @@ pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), Analys
     Ok(())
 }
 
-fn collect_expressions(module: &Module) -> Vec<Expression> {
+fn collect_expressions(definition: &FunctionDefinition) -> Vec<Expression> {
     let mut expressions = vec![];
 
-    expression_visitor::visit(module, |expression: &Expression| match expression {
+    expression_visitor::visit_function_definition(definition, &mut |expression| match expression {
         Expression::RecordConstruction(_)
         | Expression::RecordDeconstruction(_)
         | Expression::RecordUpdate(_) => expressions.push(expression.clone()),
@@ -82,8 +94,9 @@ fn collect_expressions(module: &Module) -> Vec<Expression> {
     expressions
 }
 
-fn collect_open_records(type_definitions: &[TypeDefinition]) -> FnvHashSet<&str> {
-    type_definitions
+pub fn collect_open_records(module: &Module) -> FnvHashSet<&str> {
+    module
+        .type_definitions()
         .iter()
         .filter(|definition| !definition.is_external() || definition.is_open())
         .map(|definition| definition.name())
@@ -130,7 +143,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        record_type.clone(),
+                        Some(record_type.clone().into()),
                         RecordConstruction::new(
                             record_type,
                             vec![RecordField::new(
@@ -169,7 +182,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        record_type.clone(),
+                        Some(record_type.clone().into()),
                         RecordConstruction::new(
                             record_type,
                             vec![RecordField::new(
@@ -206,8 +219,12 @@ mod tests {
                 .set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", record_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(record_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         RecordDeconstruction::new(
                             Some(record_type.into()),
                             Variable::new("x", Position::fake()),
@@ -242,8 +259,12 @@ mod tests {
                 .set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", record_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(record_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         RecordDeconstruction::new(
                             Some(record_type.into()),
                             Variable::new("x", Position::fake()),
@@ -277,8 +298,12 @@ mod tests {
                 .set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", record_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(record_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         RecordUpdate::new(
                             record_type,
                             Variable::new("x", Position::fake()),
@@ -317,8 +342,12 @@ mod tests {
                 .set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", record_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(record_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         RecordUpdate::new(
                             record_type,
                             Variable::new("x", Position::fake()),
@@ -361,7 +390,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        record_type.clone(),
+                        Some(record_type.clone().into()),
                         RecordConstruction::new(
                             record_type,
                             vec![RecordField::new(
@@ -389,8 +418,12 @@ mod tests {
                 .set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("r", record_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "r",
+                            Some(record_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         RecordDeconstruction::new(
                             Some(record_type.into()),
                             Variable::new("r", Position::fake()),
@@ -42,6 +42,7 @@ fn collect_references(type_: &Type) -> FnvHashSet<&str> {
             .into_iter()
             .chain(collect_references(union.rhs()))
             .collect(),
+        Type::Vector(vector) => collect_references(vector.element()),
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
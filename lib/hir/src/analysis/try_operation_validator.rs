@@ -3,14 +3,21 @@ use crate::{analysis::type_subsumption_checker, ir::*, types, types::Type};
 
 pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
     for definition in module.function_definitions() {
-        validate_lambda(context, definition.lambda())?;
+        validate_function_definition(context, definition)?;
     }
 
     Ok(())
 }
 
+pub fn validate_function_definition(
+    context: &AnalysisContext,
+    definition: &FunctionDefinition,
+) -> Result<(), AnalysisError> {
+    validate_lambda(context, definition.lambda())
+}
+
 fn validate_lambda(context: &AnalysisContext, lambda: &Lambda) -> Result<(), AnalysisError> {
-    validate_expression(context, lambda.body(), Some(lambda.result_type()))
+    validate_expression(context, lambda.body(), lambda.result_type())
 }
 
 fn validate_expression(
@@ -169,6 +176,11 @@ fn validate_expression(
                 ),
             )?;
         }
+        Expression::Vector(vector) => {
+            for element in vector.elements() {
+                validate(element)?;
+            }
+        }
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::None(_)
@@ -224,13 +236,17 @@ mod tests {
                         Lambda::new(
                             vec![Argument::new(
                                 "x",
-                                types::Union::new(
-                                    types::None::new(Position::fake()),
-                                    types::Reference::new(ERROR_TYPE_NAME, Position::fake(),),
-                                    Position::fake(),
+                                Some(
+                                    types::Union::new(
+                                        types::None::new(Position::fake()),
+                                        types::Reference::new(ERROR_TYPE_NAME, Position::fake(),),
+                                        Position::fake(),
+                                    )
+                                    .into()
                                 ),
+                                Position::fake(),
                             )],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             TryOperation::new(
                                 None,
                                 Variable::new("x", Position::fake()),
@@ -258,12 +274,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", union_type.clone())],
-                        types::Function::new(
+                        vec![Argument::new("x", Some(union_type.clone().into()), Position::fake())],
+                        Some(types::Function::new(
                             vec![],
                             types::None::new(Position::fake()),
                             Position::fake()
-                        ),
+                        ).into()),
                         Thunk::new(
                             Some(union_type.into()),
                             TryOperation::new(
@@ -291,17 +307,18 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Union::new(
+                            Some(types::Union::new(
                                 types::None::new(Position::fake()),
                                 types::Error::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
+                            Position::fake(),
                         )],
-                        types::Function::new(
+                        Some(types::Function::new(
                             vec![],
                             types::None::new(Position::fake()),
                             Position::fake()
-                        ),
+                        ).into()),
                         Thunk::new(
                             Some(types::None::new(Position::fake()).into()),
                             TryOperation::new(
@@ -329,17 +346,18 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Union::new(
+                            Some(types::Union::new(
                                 types::None::new(Position::fake()),
                                 types::Error::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
+                            Position::fake(),
                         )],
-                        types::Function::new(
+                        Some(types::Function::new(
                             vec![],
                             types::None::new(Position::fake()),
                             Position::fake()
-                        ),
+                        ).into()),
                         List::new(
                             types::None::new(Position::fake()),
                             vec![ListElement::Single(
@@ -373,11 +391,11 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Function::new(
+                            Some(types::Function::new(
                                 vec![],
                                 types::None::new(Position::fake()),
                                 Position::fake()
-                            ),
+                            ).into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 TryOperation::new(
@@ -413,11 +431,11 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Function::new(
+                            Some(types::Function::new(
                                 vec![],
                                 types::None::new(Position::fake()),
                                 Position::fake()
-                            ),
+                            ).into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 None::new(Position::fake()),
@@ -453,11 +471,11 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Function::new(
+                            Some(types::Function::new(
                                 vec![],
                                 types::None::new(Position::fake()),
                                 Position::fake()
-                            ),
+                            ).into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 None::new(Position::fake()),
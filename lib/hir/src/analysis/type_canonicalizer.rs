@@ -28,6 +28,11 @@ pub fn canonicalize(type_: &Type, types: &FnvHashMap<String, Type>) -> Result<Ty
         )
         .into(),
         Type::Union(union) => canonicalize_union(union, types)?,
+        Type::Vector(vector) => Vector::new(
+            canonicalize(vector.element(), types)?,
+            vector.position().clone(),
+        )
+        .into(),
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
@@ -62,6 +67,13 @@ pub fn canonicalize_map(
     Ok(canonicalize(type_, types)?.into_map())
 }
 
+pub fn canonicalize_vector(
+    type_: &Type,
+    types: &FnvHashMap<String, Type>,
+) -> Result<Option<Vector>, AnalysisError> {
+    Ok(canonicalize(type_, types)?.into_vector())
+}
+
 pub fn canonicalize_record(
     type_: &Type,
     types: &FnvHashMap<String, Type>,
@@ -117,7 +129,8 @@ fn collect_types_in_place(
         | Type::Map(_)
         | Type::None(_)
         | Type::Number(_)
-        | Type::String(_) => {
+        | Type::String(_)
+        | Type::Vector(_) => {
             collected_types.insert(canonicalize(type_, types)?);
         }
         Type::Reference(reference) => {
@@ -1,7 +1,8 @@
 use super::{context::AnalysisContext, module_environment, AnalysisError};
 use crate::{
     analysis::{
-        record_field_resolver, type_canonicalizer, type_equality_checker, type_extractor,
+        name_suggester, record_field_resolver, type_canonicalizer, type_difference_calculator,
+        type_equality_checker, type_extractor, type_serializability_checker,
         type_subsumption_checker, union_type_creator,
     },
     ir::*,
@@ -11,20 +12,32 @@ use fnv::{FnvHashMap, FnvHashSet};
 use position::Position;
 
 pub fn check_types(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
-    let variables = plist::FlailMap::new(module_environment::create(module));
+    let variables = plist::FlailMap::new(module_environment::create(module)?);
 
     for definition in module.function_definitions() {
-        check_lambda(context, definition.lambda(), &variables)?;
+        check_function_definition(context, definition, &variables)?;
     }
 
     Ok(())
 }
 
+pub fn check_function_definition(
+    context: &AnalysisContext,
+    definition: &FunctionDefinition,
+    variables: &plist::FlailMap<String, Type>,
+) -> Result<(), AnalysisError> {
+    check_lambda(context, definition.lambda(), variables)?;
+
+    Ok(())
+}
+
 fn check_lambda(
     context: &AnalysisContext,
     lambda: &Lambda,
     variables: &plist::FlailMap<String, Type>,
 ) -> Result<types::Function, AnalysisError> {
+    let type_ = type_extractor::extract_from_lambda(lambda)?;
+
     check_subsumption(
         &check_expression(
             context,
@@ -33,16 +46,17 @@ fn check_lambda(
                 lambda
                     .arguments()
                     .iter()
-                    .map(|argument| (argument.name().into(), argument.type_().clone())),
+                    .zip(type_.arguments())
+                    .map(|(argument, type_)| (argument.name().into(), type_.clone())),
             ),
         )?,
-        lambda.result_type(),
+        type_.result(),
         lambda.body().position(),
-        lambda.result_type().position(),
+        type_.result().position(),
         context.types(),
     )?;
 
-    Ok(type_extractor::extract_from_lambda(lambda))
+    Ok(type_)
 }
 
 fn check_expression(
@@ -232,7 +246,7 @@ fn check_expression(
                             .clone(),
                     ),
                 )?;
-            } else if !type_equality_checker::check(
+            } else if type_difference_calculator::calculate(
                 &argument_type,
                 &union_type_creator::create(
                     &if_.branches()
@@ -243,7 +257,9 @@ fn check_expression(
                 )
                 .unwrap(),
                 context.types(),
-            )? {
+            )?
+            .is_some()
+            {
                 return Err(AnalysisError::MissingElseBlock(if_.position().clone()));
             }
 
@@ -309,6 +325,18 @@ fn check_expression(
 
             types::List::new(list.type_().clone(), list.position().clone()).into()
         }
+        Expression::Vector(vector) => {
+            for element in vector.elements() {
+                check_subsumption(
+                    &check_expression(element, variables)?,
+                    vector.type_(),
+                    element.position(),
+                    vector.type_().position(),
+                )?;
+            }
+
+            types::Vector::new(vector.type_().clone(), vector.position().clone()).into()
+        }
         Expression::ListComprehension(comprehension) => {
             let position = comprehension.position();
             let mut variables = variables.clone();
@@ -436,7 +464,15 @@ fn check_expression(
                 let field_type = field_types
                     .iter()
                     .find(|field_type| field_type.name() == field.name())
-                    .ok_or_else(|| AnalysisError::UnknownRecordField(field.position().clone()))?
+                    .ok_or_else(|| {
+                        AnalysisError::UnknownRecordField(
+                            field.position().clone(),
+                            name_suggester::suggest(
+                                field.name(),
+                                field_types.iter().map(|field_type| field_type.name()),
+                            ),
+                        )
+                    })?
                     .type_();
 
                 check_subsumption(
@@ -487,7 +523,13 @@ fn check_expression(
                 .iter()
                 .find(|field_type| field_type.name() == deconstruction.field_name())
                 .ok_or_else(|| {
-                    AnalysisError::UnknownRecordField(deconstruction.position().clone())
+                    AnalysisError::UnknownRecordField(
+                        deconstruction.position().clone(),
+                        name_suggester::suggest(
+                            deconstruction.field_name(),
+                            field_types.iter().map(|field_type| field_type.name()),
+                        ),
+                    )
                 })?
                 .type_()
                 .clone()
@@ -511,7 +553,15 @@ fn check_expression(
                 let field_type = field_types
                     .iter()
                     .find(|field_type| field_type.name() == field.name())
-                    .ok_or_else(|| AnalysisError::UnknownRecordField(field.position().clone()))?
+                    .ok_or_else(|| {
+                        AnalysisError::UnknownRecordField(
+                            field.position().clone(),
+                            name_suggester::suggest(
+                                field.name(),
+                                field_types.iter().map(|field_type| field_type.name()),
+                            ),
+                        )
+                    })?
                     .type_();
 
                 check_subsumption(
@@ -562,7 +612,7 @@ fn check_expression(
         }
         Expression::Variable(variable) => variables
             .get(variable.name())
-            .ok_or_else(|| AnalysisError::VariableNotFound(variable.clone()))?
+            .ok_or_else(|| AnalysisError::VariableNotFound(variable.clone(), None))?
             .clone(),
     })
 }
@@ -576,6 +626,23 @@ fn check_built_in_call(
     let position = call.position();
 
     match function.name() {
+        BuiltInFunctionName::Decode => {
+            let [_] = call.arguments() else {
+                return Err(AnalysisError::ArgumentCount(position.clone()));
+            };
+            let type_ = type_difference_calculator::calculate(
+                function_type.result(),
+                &types::Error::new(position.clone()).into(),
+                context.types(),
+            )?
+            .ok_or_else(|| {
+                AnalysisError::TypeNotSerializable(position.clone(), function_type.result().clone())
+            })?;
+
+            if !type_serializability_checker::check(&type_, context.types(), context.records())? {
+                return Err(AnalysisError::TypeNotSerializable(position.clone(), type_));
+            }
+        }
         BuiltInFunctionName::Delete => {
             let [map_type, key_type] = function_type.arguments() else {
                 return Err(AnalysisError::ArgumentCount(position.clone()));
@@ -605,6 +672,40 @@ fn check_built_in_call(
                 context.types(),
             )?;
         }
+        BuiltInFunctionName::Encode => {
+            let ([argument], [argument_type]) = (call.arguments(), function_type.arguments())
+            else {
+                return Err(AnalysisError::ArgumentCount(position.clone()));
+            };
+
+            if !type_serializability_checker::check(
+                argument_type,
+                context.types(),
+                context.records(),
+            )? {
+                return Err(AnalysisError::TypeNotSerializable(
+                    argument.position().clone(),
+                    argument_type.clone(),
+                ));
+            }
+        }
+        BuiltInFunctionName::Get
+        | BuiltInFunctionName::Push
+        | BuiltInFunctionName::Set
+        | BuiltInFunctionName::Slice => {
+            let (Some(argument), Some(argument_type)) =
+                (call.arguments().first(), function_type.arguments().first())
+            else {
+                return Err(AnalysisError::ArgumentCount(position.clone()));
+            };
+
+            if type_canonicalizer::canonicalize_vector(argument_type, context.types())?.is_none() {
+                return Err(AnalysisError::VectorExpected(
+                    argument.position().clone(),
+                    argument_type.clone(),
+                ));
+            }
+        }
         BuiltInFunctionName::Race => {
             let ([argument], [argument_type]) = (call.arguments(), function_type.arguments())
             else {
@@ -630,7 +731,10 @@ fn check_built_in_call(
         }
         BuiltInFunctionName::Size => {
             if let ([argument], [argument_type]) = (call.arguments(), function_type.arguments()) {
-                if !matches!(argument_type, Type::List(_) | Type::Map(_)) {
+                if !matches!(
+                    argument_type,
+                    Type::List(_) | Type::Map(_) | Type::Vector(_)
+                ) {
                     return Err(AnalysisError::CollectionExpected(
                         argument.position().clone(),
                         argument_type.clone(),
@@ -751,22 +855,38 @@ fn check_operation(
             number_type
         }
         Operation::Boolean(operation) => {
-            let boolean_type = types::Boolean::new(operation.position().clone()).into();
+            let type_ = operation
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+
+            if !type_equality_checker::check(
+                type_,
+                &types::Boolean::new(position.clone()).into(),
+                context.types(),
+            )? && !type_equality_checker::check(
+                type_,
+                &types::Number::new(position.clone()).into(),
+                context.types(),
+            )? {
+                return Err(AnalysisError::InvalidBooleanOperand(
+                    type_.position().clone(),
+                ));
+            }
 
             check_subsumption(
                 &check_expression(operation.lhs())?,
-                &boolean_type,
+                type_,
                 operation.lhs().position(),
                 position,
             )?;
             check_subsumption(
                 &check_expression(operation.rhs())?,
-                &boolean_type,
+                type_,
                 operation.rhs().position(),
                 position,
             )?;
 
-            boolean_type
+            type_.clone()
         }
         Operation::Equality(operation) => {
             let operand_type = operation
@@ -815,17 +935,31 @@ fn check_operation(
             boolean_type
         }
         Operation::Order(operation) => {
-            let number_type = types::Number::new(operation.position().clone()).into();
+            let type_ = operation
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+
+            if !type_equality_checker::check(
+                type_,
+                &types::Number::new(position.clone()).into(),
+                context.types(),
+            )? && !type_equality_checker::check(
+                type_,
+                &types::ByteString::new(position.clone()).into(),
+                context.types(),
+            )? {
+                return Err(AnalysisError::InvalidOrderOperand(type_.position().clone()));
+            }
 
             check_subsumption(
                 &check_expression(operation.lhs())?,
-                &number_type,
+                type_,
                 operation.lhs().position(),
                 position,
             )?;
             check_subsumption(
                 &check_expression(operation.rhs())?,
-                &number_type,
+                type_,
                 operation.rhs().position(),
                 position,
             )?;
@@ -917,7 +1051,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         None::new(Position::fake()),
                         Position::fake(),
                     ),
@@ -941,7 +1075,7 @@ mod tests {
                             "x",
                             Lambda::new(
                                 vec![],
-                                types::None::new(Position::fake()),
+                                Some(types::None::new(Position::fake()).into()),
                                 Call::new(
                                     Some(function_type.clone().into()),
                                     Variable::new("y", Position::fake()),
@@ -984,7 +1118,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -1012,11 +1146,11 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Function::new(
+                        Some(types::Function::new(
                             vec![],
                             types::None::new(Position::fake()),
                             Position::fake(),
-                        ),
+                        ).into()),
                         Thunk::new(
                             Some(types::None::new(Position::fake()).into()),
                             None::new(Position::fake()),
@@ -1042,10 +1176,13 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Union::new(
-                            types::Number::new(Position::fake()),
-                            types::None::new(Position::fake()),
-                            Position::fake(),
+                        Some(
+                            types::Union::new(
+                                types::Number::new(Position::fake()),
+                                types::None::new(Position::fake()),
+                                Position::fake(),
+                            )
+                            .into(),
                         ),
                         None::new(Position::fake()),
                         Position::fake(),
@@ -1063,7 +1200,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Number::new(Position::fake()),
+                            Some(types::Number::new(Position::fake()).into()),
                             None::new(Position::fake()),
                             Position::fake(),
                         ),
@@ -1092,7 +1229,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             Some("x".into()),
                             Some(types::None::new(Position::fake()).into()),
@@ -1116,7 +1253,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             Let::new(
                                 Some("x".into()),
                                 Some(types::None::new(Position::fake()).into()),
@@ -1151,7 +1288,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::Number::new(Position::fake()),
+                        Some(types::Number::new(Position::fake()).into()),
                         If::new(
                             Boolean::new(true, Position::fake()),
                             Number::new(0.0, Position::fake()),
@@ -1173,10 +1310,13 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::Union::new(
-                            types::Number::new(Position::fake()),
-                            types::None::new(Position::fake()),
-                            Position::fake(),
+                        Some(
+                            types::Union::new(
+                                types::Number::new(Position::fake()),
+                                types::None::new(Position::fake()),
+                                Position::fake(),
+                            )
+                            .into(),
                         ),
                         If::new(
                             Boolean::new(true, Position::fake()),
@@ -1200,7 +1340,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::Number::new(Position::fake()),
+                            Some(types::Number::new(Position::fake()).into()),
                             If::new(
                                 Boolean::new(true, Position::fake()),
                                 NotOperation::new(None::new(Position::fake()), Position::fake()),
@@ -1230,7 +1370,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::Number::new(Position::fake()),
+                            Some(types::Number::new(Position::fake()).into()),
                             If::new(
                                 Boolean::new(true, Position::fake()),
                                 Number::new(0.0, Position::fake()),
@@ -1268,8 +1408,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", union_type)],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1300,8 +1444,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", types::Any::new(Position::fake()))],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1330,11 +1478,18 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", types::Any::new(Position::fake()))],
-                        types::Union::new(
-                            types::Number::new(Position::fake()),
-                            types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
                             Position::fake(),
+                        )],
+                        Some(
+                            types::Union::new(
+                                types::Number::new(Position::fake()),
+                                types::None::new(Position::fake()),
+                                Position::fake(),
+                            )
+                            .into(),
                         ),
                         IfType::new(
                             "y",
@@ -1364,8 +1519,12 @@ mod tests {
                 &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", types::Any::new(Position::fake()))],
-                        types::Any::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::Any::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1396,7 +1555,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             None::new(Position::fake()),
@@ -1424,13 +1583,14 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Union::new(
+                            Some(types::Union::new(
                                 types::Number::new(Position::fake()),
                                 types::None::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
+                            Position::fake(),
                         )],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1458,13 +1618,14 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Union::new(
+                            Some(types::Union::new(
                                 types::Number::new(Position::fake()),
                                 types::None::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
+                            Position::fake(),
                         )],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1490,8 +1651,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", types::Any::new(Position::fake()))],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1527,8 +1692,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", union_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1562,7 +1731,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -1586,90 +1755,102 @@ mod tests {
 
         #[test]
         fn check_call_with_arguments() {
-            check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
-                        "f",
-                        Lambda::new(
-                            vec![Argument::new("x", types::None::new(Position::fake()))],
-                            types::None::new(Position::fake()),
-                            Call::new(
-                                Some(
-                                    types::Function::new(
-                                        vec![types::None::new(Position::fake()).into()],
-                                        types::None::new(Position::fake()),
-                                        Position::fake(),
-                                    )
-                                    .into(),
-                                ),
-                                Variable::new("f", Position::fake()),
-                                vec![None::new(Position::fake()).into()],
-                                Position::fake(),
+            check_module(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::None::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
+                        Call::new(
+                            Some(
+                                types::Function::new(
+                                    vec![types::None::new(Position::fake()).into()],
+                                    types::None::new(Position::fake()),
+                                    Position::fake(),
+                                )
+                                .into(),
                             ),
+                            Variable::new("f", Position::fake()),
+                            vec![None::new(Position::fake()).into()],
                             Position::fake(),
                         ),
-                        false,
+                        Position::fake(),
                     ),
-                ]))
+                    false,
+                )]),
+            )
             .unwrap()
         }
 
         #[test]
         #[should_panic]
         fn fail_to_check_call_with_wrong_argument_type() {
-            check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
-                        "f",
-                        Lambda::new(
-                            vec![Argument::new("x", types::None::new(Position::fake()))],
-                            types::None::new(Position::fake()),
-                            Call::new(
-                                Some(
-                                    types::Function::new(
-                                        vec![types::None::new(Position::fake()).into()],
-                                        types::None::new(Position::fake()),
-                                        Position::fake(),
-                                    )
-                                    .into(),
-                                ),
-                                Variable::new("f", Position::fake()),
-                                vec![Number::new(42.0, Position::fake()).into()],
-                                Position::fake(),
+            check_module(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::None::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
+                        Call::new(
+                            Some(
+                                types::Function::new(
+                                    vec![types::None::new(Position::fake()).into()],
+                                    types::None::new(Position::fake()),
+                                    Position::fake(),
+                                )
+                                .into(),
                             ),
+                            Variable::new("f", Position::fake()),
+                            vec![Number::new(42.0, Position::fake()).into()],
                             Position::fake(),
                         ),
-                        false,
+                        Position::fake(),
                     ),
-                ]))
+                    false,
+                )]),
+            )
             .unwrap()
         }
 
         #[test]
         #[should_panic]
         fn fail_to_check_call_with_wrong_argument_count() {
-            check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
-                        "f",
-                        Lambda::new(
-                            vec![Argument::new("x", types::None::new(Position::fake()))],
-                            types::None::new(Position::fake()),
-                            Call::new(
-                                Some(
-                                    types::Function::new(
-                                        vec![types::None::new(Position::fake()).into()],
-                                        types::None::new(Position::fake()),
-                                        Position::fake(),
-                                    )
-                                    .into(),
-                                ),
-                                Variable::new("f", Position::fake()),
-                                vec![],
-                                Position::fake(),
-                            ),
-                            Position::fake(),
-                        ),
-                        false,
+            check_module(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::None::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
+                        Call::new(
+                            Some(
+                                types::Function::new(
+                                    vec![types::None::new(Position::fake()).into()],
+                                    types::None::new(Position::fake()),
+                                    Position::fake(),
+                                )
+                                .into(),
+                            ),
+                            Variable::new("f", Position::fake()),
+                            vec![],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
                     ),
-                ]))
+                    false,
+                )]),
+            )
             .unwrap()
         }
     }
@@ -1685,7 +1866,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Number::new(Position::fake()),
+                        Some(types::Number::new(Position::fake()).into()),
                         AdditionOperation::new(
                             Some(types::Number::new(Position::fake()).into()),
                             Number::new(1.0, Position::fake()),
@@ -1707,7 +1888,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::ByteString::new(Position::fake()),
+                        Some(types::ByteString::new(Position::fake()).into()),
                         AdditionOperation::new(
                             Some(types::ByteString::new(Position::fake()).into()),
                             ByteString::new("", Position::fake()),
@@ -1730,7 +1911,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             AdditionOperation::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 None::new(Position::fake()),
@@ -1753,7 +1934,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Number::new(Position::fake()),
+                        Some(types::Number::new(Position::fake()).into()),
                         ArithmeticOperation::new(
                             ArithmeticOperator::Subtract,
                             Number::new(0.0, Position::fake()),
@@ -1775,8 +1956,9 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         BooleanOperation::new(
+                            Some(types::Boolean::new(Position::fake()).into()),
                             BooleanOperator::And,
                             Boolean::new(true, Position::fake()),
                             Boolean::new(true, Position::fake()),
@@ -1798,8 +1980,9 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Boolean::new(Position::fake()),
+                            Some(types::Boolean::new(Position::fake()).into()),
                             BooleanOperation::new(
+                                Some(types::Boolean::new(Position::fake()).into()),
                                 BooleanOperator::And,
                                 Number::new(42.0, Position::fake()),
                                 Boolean::new(true, Position::fake()),
@@ -1823,6 +2006,54 @@ mod tests {
             );
         }
 
+        #[test]
+        fn check_boolean_operation_with_numbers() {
+            check_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        Some(types::Number::new(Position::fake()).into()),
+                        BooleanOperation::new(
+                            Some(types::Number::new(Position::fake()).into()),
+                            BooleanOperator::Or,
+                            Number::new(1.0, Position::fake()),
+                            Number::new(2.0, Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+
+        #[test]
+        fn fail_to_check_boolean_operation_with_strings() {
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            Some(types::ByteString::new(Position::fake()).into()),
+                            BooleanOperation::new(
+                                Some(types::ByteString::new(Position::fake()).into()),
+                                BooleanOperator::And,
+                                ByteString::new("foo", Position::fake()),
+                                ByteString::new("bar", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ]),),
+                Err(AnalysisError::InvalidBooleanOperand(Position::fake()))
+            );
+        }
+
         #[test]
         fn check_equality_operation() {
             check_module(&Module::empty().set_function_definitions(vec![
@@ -1830,7 +2061,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         EqualityOperation::new(
                             Some(types::Number::new(Position::fake()).into()),
                             EqualityOperator::Equal,
@@ -1853,7 +2084,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Boolean::new(Position::fake()),
+                            Some(types::Boolean::new(Position::fake()).into()),
                             EqualityOperation::new(
                                 Some(
                                     types::Union::new(
@@ -1884,7 +2115,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Boolean::new(Position::fake()),
+                            Some(types::Boolean::new(Position::fake()).into()),
                             EqualityOperation::new(
                                 Some(
                                     types::Union::new(
@@ -1921,7 +2152,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         NotOperation::new(Boolean::new(true, Position::fake()), Position::fake()),
                         Position::fake(),
                     ),
@@ -1938,8 +2169,9 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         OrderOperation::new(
+                            Some(types::Number::new(Position::fake()).into()),
                             OrderOperator::LessThan,
                             Number::new(0.0, Position::fake()),
                             Number::new(0.0, Position::fake()),
@@ -1953,6 +2185,79 @@ mod tests {
             .unwrap();
         }
 
+        #[test]
+        fn check_order_operation_with_strings() {
+            check_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        Some(types::Boolean::new(Position::fake()).into()),
+                        OrderOperation::new(
+                            Some(types::ByteString::new(Position::fake()).into()),
+                            OrderOperator::LessThan,
+                            ByteString::new("foo", Position::fake()),
+                            ByteString::new("bar", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+
+        #[test]
+        fn check_order_operation_with_nones() {
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Boolean::new(Position::fake()).into()),
+                            OrderOperation::new(
+                                Some(types::None::new(Position::fake()).into()),
+                                OrderOperator::LessThan,
+                                None::new(Position::fake()),
+                                None::new(Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ]),),
+                Err(AnalysisError::InvalidOrderOperand(Position::fake()))
+            );
+        }
+
+        #[test]
+        fn fail_to_check_order_operation_with_number_and_string() {
+            assert!(matches!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Boolean::new(Position::fake()).into()),
+                            OrderOperation::new(
+                                Some(types::Number::new(Position::fake()).into()),
+                                OrderOperator::LessThan,
+                                Number::new(0.0, Position::fake()),
+                                ByteString::new("foo", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ]),),
+                Err(AnalysisError::TypesNotMatched { .. })
+            ));
+        }
+
         #[test]
         fn check_try_operation() {
             let union_type = types::Union::new(
@@ -1965,8 +2270,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", union_type.clone())],
-                        union_type,
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(union_type.into()),
                         TryOperation::new(
                             Some(types::None::new(Position::fake()).into()),
                             Variable::new("x", Position::fake()),
@@ -1992,8 +2301,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", union_type.clone())],
-                        union_type,
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(union_type.into()),
                         ArithmeticOperation::new(
                             ArithmeticOperator::Subtract,
                             TryOperation::new(
@@ -2020,8 +2333,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", any_type.clone())],
-                        any_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(any_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(any_type.clone().into()),
                         TryOperation::new(
                             Some(any_type.into()),
                             Variable::new("x", Position::fake()),
@@ -2048,8 +2365,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", union_type.clone())],
-                            union_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(union_type.clone().into()),
                             TryOperation::new(
                                 Some(types::Number::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -2073,15 +2394,22 @@ mod tests {
         #[test]
         fn fail_to_check_try_operation_with_wrong_operand_type() {
             assert_eq!(
-                check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
+                check_module(&Module::empty().set_function_definitions(
+                    vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", types::None::new(Position::fake()))],
-                            types::Union::new(
-                                types::None::new(Position::fake()),
-                                types::Error::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(types::None::new(Position::fake()).into()),
                                 Position::fake(),
+                            )],
+                            Some(
+                                types::Union::new(
+                                    types::None::new(Position::fake()),
+                                    types::Error::new(Position::fake()),
+                                    Position::fake(),
+                                )
+                                .into()
                             ),
                             TryOperation::new(
                                 Some(types::Number::new(Position::fake()).into()),
@@ -2091,8 +2419,8 @@ mod tests {
                             Position::fake(),
                         ),
                         false,
-                    )
-                ]),),
+                    )]
+                ),),
                 Err(AnalysisError::TypesNotMatched {
                     found: (Position::fake(), types::Error::new(Position::fake()).into()),
                     expected: (Position::fake(), types::None::new(Position::fake()).into(),)
@@ -2125,7 +2453,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            reference_type.clone(),
+                            Some(reference_type.clone().into()),
                             RecordConstruction::new(
                                 reference_type,
                                 vec![RecordField::new(
@@ -2163,7 +2491,7 @@ mod tests {
                             "x",
                             Lambda::new(
                                 vec![],
-                                reference_type.clone(),
+                                Some(reference_type.clone().into()),
                                 RecordConstruction::new(
                                     reference_type,
                                     Default::default(),
@@ -2196,7 +2524,7 @@ mod tests {
                             "x",
                             Lambda::new(
                                 vec![],
-                                reference_type.clone(),
+                                Some(reference_type.clone().into()),
                                 RecordConstruction::new(
                                     reference_type,
                                     vec![RecordField::new(
@@ -2211,7 +2539,7 @@ mod tests {
                             false
                         )])
                 ),
-                Err(AnalysisError::UnknownRecordField(_))
+                Err(AnalysisError::UnknownRecordField(_, _))
             ));
         }
 
@@ -2234,8 +2562,12 @@ mod tests {
                     .set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", reference_type.clone())],
-                            reference_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(reference_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(reference_type.clone().into()),
                             RecordUpdate::new(
                                 reference_type,
                                 Variable::new("x", Position::fake()),
@@ -2272,8 +2604,12 @@ mod tests {
                     .set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", reference_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(reference_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             RecordDeconstruction::new(
                                 Some(reference_type.into()),
                                 Variable::new("x", Position::fake()),
@@ -2307,8 +2643,12 @@ mod tests {
                         .set_function_definitions(vec![FunctionDefinition::fake(
                             "x",
                             Lambda::new(
-                                vec![Argument::new("x", reference_type.clone())],
-                                types::None::new(Position::fake()),
+                                vec![Argument::new(
+                                    "x",
+                                    Some(reference_type.clone().into()),
+                                    Position::fake()
+                                )],
+                                Some(types::None::new(Position::fake()).into()),
                                 RecordDeconstruction::new(
                                     Some(reference_type.into()),
                                     Variable::new("x", Position::fake()),
@@ -2320,7 +2660,7 @@ mod tests {
                             false,
                         )])
                 ),
-                Err(AnalysisError::UnknownRecordField(Position::fake()))
+                Err(AnalysisError::UnknownRecordField(Position::fake(), None))
             );
         }
 
@@ -2338,9 +2678,10 @@ mod tests {
                             Lambda::new(
                                 vec![Argument::new(
                                     "x",
-                                    types::Reference::new("r1", Position::fake())
+                                    Some(types::Reference::new("r1", Position::fake()).into()),
+                                    Position::fake(),
                                 )],
-                                types::Reference::new("r2", Position::fake()),
+                                Some(types::Reference::new("r2", Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
                                 Position::fake(),
                             ),
@@ -2367,12 +2708,15 @@ mod tests {
 
         #[test]
         fn check_list_with_single_element() {
-            check_module(&Module::empty().set_function_definitions(vec![
-                FunctionDefinition::fake(
+            check_module(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
                         vec![],
-                        types::List::new(types::None::new(Position::fake()), Position::fake()),
+                        Some(
+                            types::List::new(types::None::new(Position::fake()), Position::fake())
+                                .into(),
+                        ),
                         List::new(
                             types::None::new(Position::fake()),
                             vec![ListElement::Single(None::new(Position::fake()).into())],
@@ -2381,8 +2725,8 @@ mod tests {
                         Position::fake(),
                     ),
                     false,
-                ),
-            ]))
+                )]),
+            )
             .unwrap();
         }
 
@@ -2394,8 +2738,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", list_type.clone())],
-                        list_type,
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(list_type.into()),
                         List::new(
                             types::None::new(Position::fake()),
                             vec![ListElement::Multiple(
@@ -2414,12 +2762,18 @@ mod tests {
         #[test]
         fn fail_to_check_list_with_single_element() {
             assert_eq!(
-                check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
+                check_module(&Module::empty().set_function_definitions(
+                    vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
                             vec![],
-                            types::List::new(types::None::new(Position::fake()), Position::fake()),
+                            Some(
+                                types::List::new(
+                                    types::None::new(Position::fake()),
+                                    Position::fake()
+                                )
+                                .into()
+                            ),
                             List::new(
                                 types::None::new(Position::fake()),
                                 vec![ListElement::Single(
@@ -2430,8 +2784,8 @@ mod tests {
                             Position::fake(),
                         ),
                         false,
-                    )
-                ])),
+                    )]
+                )),
                 Err(AnalysisError::TypesNotMatched {
                     found: (
                         Position::fake(),
@@ -2445,18 +2799,28 @@ mod tests {
         #[test]
         fn fail_to_check_list_with_multiple_element() {
             assert_eq!(
-                check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
+                check_module(&Module::empty().set_function_definitions(
+                    vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
                             vec![Argument::new(
                                 "x",
+                                Some(
+                                    types::List::new(
+                                        types::Number::new(Position::fake()),
+                                        Position::fake()
+                                    )
+                                    .into()
+                                ),
+                                Position::fake(),
+                            )],
+                            Some(
                                 types::List::new(
-                                    types::Number::new(Position::fake()),
+                                    types::None::new(Position::fake()),
                                     Position::fake()
                                 )
-                            )],
-                            types::List::new(types::None::new(Position::fake()), Position::fake()),
+                                .into()
+                            ),
                             List::new(
                                 types::None::new(Position::fake()),
                                 vec![ListElement::Multiple(
@@ -2467,8 +2831,8 @@ mod tests {
                             Position::fake(),
                         ),
                         false,
-                    )
-                ]),),
+                    )]
+                ),),
                 Err(AnalysisError::TypesNotMatched {
                     found: (
                         Position::fake(),
@@ -2492,7 +2856,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::List::new(union_type.clone(), Position::fake()),
+                        Some(types::List::new(union_type.clone(), Position::fake()).into()),
                         List::new(
                             union_type,
                             vec![ListElement::Single(None::new(Position::fake()).into())],
@@ -2520,9 +2884,16 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::List::new(types::None::new(Position::fake()), Position::fake()),
+                            Some(
+                                types::List::new(
+                                    types::None::new(Position::fake()),
+                                    Position::fake(),
+                                )
+                                .into(),
+                            ),
+                            Position::fake(),
                         )],
-                        types::List::new(union_type.clone(), Position::fake()),
+                        Some(types::List::new(union_type.clone(), Position::fake()).into()),
                         List::new(
                             union_type,
                             vec![ListElement::Multiple(
@@ -2552,7 +2923,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::List::new(element_type.clone(), Position::fake()),
+                            Some(types::List::new(element_type.clone(), Position::fake()).into()),
                             ListComprehension::new(
                                 element_type.clone(),
                                 Call::new(
@@ -2598,7 +2969,9 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                types::List::new(element_type.clone(), Position::fake()),
+                                Some(
+                                    types::List::new(element_type.clone(), Position::fake()).into()
+                                ),
                                 ListComprehension::new(
                                     element_type.clone(),
                                     Call::new(
@@ -2657,7 +3030,9 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                types::List::new(element_type.clone(), Position::fake()),
+                                Some(
+                                    types::List::new(element_type.clone(), Position::fake()).into()
+                                ),
                                 ListComprehension::new(
                                     element_type.clone(),
                                     None::new(Position::fake()),
@@ -2698,7 +3073,9 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                types::List::new(element_type.clone(), Position::fake()),
+                                Some(
+                                    types::List::new(element_type.clone(), Position::fake()).into()
+                                ),
                                 ListComprehension::new(
                                     element_type.clone(),
                                     Call::new(
@@ -2767,7 +3144,9 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                types::List::new(element_type.clone(), Position::fake()),
+                                Some(
+                                    types::List::new(element_type.clone(), Position::fake()).into()
+                                ),
                                 ListComprehension::new(
                                     element_type.clone(),
                                     Call::new(
@@ -2796,11 +3175,7 @@ mod tests {
                                             ),
                                             ListComprehensionIteratee::new(
                                                 Some(list_type.into()),
-                                                List::new(
-                                                    element_type,
-                                                    vec![],
-                                                    Position::fake(),
-                                                ),
+                                                List::new(element_type, vec![], Position::fake(),),
                                             ),
                                         ],
                                         None,
@@ -2821,26 +3196,83 @@ mod tests {
         }
     }
 
-    mod map {
+    mod vector {
         use super::*;
-        use pretty_assertions::assert_eq;
 
         #[test]
-        fn check_no_element() {
-            assert_eq!(
-                check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
-                        "x",
-                        Lambda::new(
-                            vec![],
-                            types::Map::new(
-                                types::None::new(Position::fake()),
+        fn check_vector() {
+            check_module(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        Some(
+                            types::Vector::new(
                                 types::None::new(Position::fake()),
                                 Position::fake(),
-                            ),
-                            Map::new(
-                                types::None::new(Position::fake()),
-                                types::None::new(Position::fake()),
+                            )
+                            .into(),
+                        ),
+                        Vector::new(
+                            types::None::new(Position::fake()),
+                            vec![None::new(Position::fake()).into()],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )]),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn fail_to_check_vector_element() {
+            assert!(matches!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Vector::new(
+                                types::None::new(Position::fake()),
+                                Position::fake()
+                            ).into()),
+                            Vector::new(
+                                types::None::new(Position::fake()),
+                                vec![Number::new(42.0, Position::fake()).into()],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ])),
+                Err(AnalysisError::TypesNotMatched { .. })
+            ));
+        }
+    }
+
+    mod map {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn check_no_element() {
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Map::new(
+                                types::None::new(Position::fake()),
+                                types::None::new(Position::fake()),
+                                Position::fake(),
+                            ).into()),
+                            Map::new(
+                                types::None::new(Position::fake()),
+                                types::None::new(Position::fake()),
                                 vec![],
                                 Position::fake(),
                             ),
@@ -2861,11 +3293,11 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Map::new(
+                            Some(types::Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
@@ -2894,11 +3326,11 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Map::new(
+                            Some(types::Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
@@ -2933,11 +3365,11 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::Map::new(
+                            Some(types::Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
@@ -2977,8 +3409,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            map_type,
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(map_type.into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
@@ -3014,8 +3450,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", wrong_map_type,)],
-                            map_type,
+                            vec![Argument::new(
+                                "x",
+                                Some(wrong_map_type.into()),
+                                Position::fake(),
+                            )],
+                            Some(map_type.into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
@@ -3057,8 +3497,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", wrong_map_type,)],
-                            map_type,
+                            vec![Argument::new(
+                                "x",
+                                Some(wrong_map_type.into()),
+                                Position::fake(),
+                            )],
+                            Some(map_type.into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 types::None::new(Position::fake()),
@@ -3094,8 +3538,8 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -3132,8 +3576,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", list_type.clone())],
-                        list_type,
+                        vec![Argument::new(
+                            "x",
+                            Some(list_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(list_type.into()),
                         IfList::new(
                             Some(types::None::new(Position::fake()).into()),
                             Variable::new("x", Position::fake()),
@@ -3158,12 +3606,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type)],
-                            types::Union::new(
+                            vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                            Some(types::Union::new(
                                 types::None::new(Position::fake()),
                                 types::Number::new(Position::fake()),
                                 Position::fake(),
-                            ),
+                            ).into()),
                             IfList::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -3203,8 +3651,8 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -3243,8 +3691,8 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -3295,8 +3743,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", map_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(map_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfMap::new(
                             Some(map_type.key().clone()),
                             Some(map_type.value().clone()),
@@ -3327,12 +3779,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", map_type.clone())],
-                        types::Union::new(
+                        vec![Argument::new("x", Some(map_type.clone().into()), Position::fake())],
+                        Some(types::Union::new(
                             types::Number::new(Position::fake()),
                             types::None::new(Position::fake()),
                             Position::fake(),
-                        ),
+                        ).into()),
                         IfMap::new(
                             Some(map_type.key().clone()),
                             Some(map_type.value().clone()),
@@ -3369,8 +3821,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", wrong_map_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(wrong_map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfMap::new(
                                 Some(map_type.key().clone()),
                                 Some(map_type.value().clone()),
@@ -3406,8 +3862,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfMap::new(
                                 Some(map_type.key().clone()),
                                 Some(map_type.value().clone()),
@@ -3449,8 +3909,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfMap::new(
                                 Some(map_type.key().clone()),
                                 Some(map_type.value().clone()),
@@ -3498,7 +3962,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        union_type.clone(),
+                        Some(union_type.clone().into()),
                         TypeCoercion::new(
                             types::None::new(Position::fake()),
                             union_type,
@@ -3520,7 +3984,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::Any::new(Position::fake()),
+                        Some(types::Any::new(Position::fake()).into()),
                         TypeCoercion::new(
                             types::None::new(Position::fake()),
                             types::Any::new(Position::fake()),
@@ -3546,8 +4010,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", none_list_type.clone())],
-                        any_list_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(none_list_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(any_list_type.clone().into()),
                         TypeCoercion::new(
                             none_list_type,
                             any_list_type,
@@ -3585,8 +4053,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", none_map_type.clone())],
-                        union_map_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(none_map_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(union_map_type.clone().into()),
                         TypeCoercion::new(
                             none_map_type,
                             union_map_type,
@@ -3605,6 +4077,127 @@ mod tests {
     mod built_in {
         use super::*;
 
+        mod decode {
+            use super::*;
+
+            fn create_module(type_: impl Into<Type>) -> Module {
+                let result_type = types::Union::new(
+                    type_.into(),
+                    types::Error::new(Position::fake()),
+                    Position::fake(),
+                );
+
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(result_type.clone().into()),
+                        Call::new(
+                            Some(
+                                types::Function::new(
+                                    vec![types::Any::new(Position::fake()).into()],
+                                    result_type,
+                                    Position::fake(),
+                                )
+                                .into(),
+                            ),
+                            BuiltInFunction::new(BuiltInFunctionName::Decode, Position::fake()),
+                            vec![Variable::new("x", Position::fake()).into()],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            }
+
+            #[test]
+            fn check() {
+                check_module(&create_module(types::List::new(
+                    types::Number::new(Position::fake()),
+                    Position::fake(),
+                )))
+                .unwrap();
+            }
+
+            #[test]
+            fn fail_to_check_function() {
+                assert!(matches!(
+                    check_module(&create_module(types::Function::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        Position::fake(),
+                    ))),
+                    Err(AnalysisError::TypeNotSerializable(_, _))
+                ));
+            }
+        }
+
+        mod encode {
+            use super::*;
+
+            fn create_module(type_: impl Into<Type>) -> Module {
+                let type_ = type_.into();
+
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", Some(type_.clone()), Position::fake())],
+                        Some(types::Any::new(Position::fake()).into()),
+                        Call::new(
+                            Some(
+                                types::Function::new(
+                                    vec![type_],
+                                    types::Any::new(Position::fake()),
+                                    Position::fake(),
+                                )
+                                .into(),
+                            ),
+                            BuiltInFunction::new(BuiltInFunctionName::Encode, Position::fake()),
+                            vec![Variable::new("x", Position::fake()).into()],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            }
+
+            #[test]
+            fn check() {
+                check_module(&create_module(types::Map::new(
+                    types::ByteString::new(Position::fake()),
+                    types::Number::new(Position::fake()),
+                    Position::fake(),
+                )))
+                .unwrap();
+            }
+
+            #[test]
+            fn fail_to_check_any() {
+                assert!(matches!(
+                    check_module(&create_module(types::Any::new(Position::fake()))),
+                    Err(AnalysisError::TypeNotSerializable(_, _))
+                ));
+            }
+
+            #[test]
+            fn fail_to_check_map_with_non_string_key() {
+                assert!(matches!(
+                    check_module(&create_module(types::Map::new(
+                        types::Number::new(Position::fake()),
+                        types::Number::new(Position::fake()),
+                        Position::fake(),
+                    ))),
+                    Err(AnalysisError::TypeNotSerializable(_, _))
+                ));
+            }
+        }
+
         mod delete {
             use super::*;
 
@@ -3616,12 +4209,16 @@ mod tests {
                     Position::fake(),
                 );
 
-                check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
+                check_module(&Module::empty().set_function_definitions(
+                    vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            map_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(map_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -3641,8 +4238,8 @@ mod tests {
                             Position::fake(),
                         ),
                         false,
-                    ),
-                ]))
+                    )],
+                ))
                 .unwrap();
             }
 
@@ -3659,8 +4256,12 @@ mod tests {
                         vec![FunctionDefinition::fake(
                             "f",
                             Lambda::new(
-                                vec![Argument::new("x", map_type.clone())],
-                                map_type.clone(),
+                                vec![Argument::new(
+                                    "x",
+                                    Some(map_type.clone().into()),
+                                    Position::fake()
+                                )],
+                                Some(map_type.clone().into()),
                                 Call::new(
                                     Some(
                                         types::Function::new(
@@ -3701,33 +4302,40 @@ mod tests {
                 );
 
                 assert!(matches!(
-                    check_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
-                        "f",
-                        Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            types::None::new(Position::fake()),
-                            Call::new(
-                                Some(
-                                    types::Function::new(
-                                        vec![map_type.clone().into(), map_type.key().clone()],
-                                        types::None::new(Position::fake()),
-                                        Position::fake(),
-                                    )
-                                    .into(),
+                    check_module(&Module::empty().set_function_definitions(
+                        vec![FunctionDefinition::fake(
+                            "f",
+                            Lambda::new(
+                                vec![Argument::new(
+                                    "x",
+                                    Some(map_type.clone().into()),
+                                    Position::fake()
+                                )],
+                                Some(types::None::new(Position::fake()).into()),
+                                Call::new(
+                                    Some(
+                                        types::Function::new(
+                                            vec![map_type.clone().into(), map_type.key().clone()],
+                                            types::None::new(Position::fake()),
+                                            Position::fake(),
+                                        )
+                                        .into(),
+                                    ),
+                                    BuiltInFunction::new(
+                                        BuiltInFunctionName::Delete,
+                                        Position::fake()
+                                    ),
+                                    vec![
+                                        Variable::new("x", Position::fake()).into(),
+                                        ByteString::new("", Position::fake()).into(),
+                                    ],
+                                    Position::fake(),
                                 ),
-                                BuiltInFunction::new(BuiltInFunctionName::Delete, Position::fake()),
-                                vec![
-                                    Variable::new("x", Position::fake()).into(),
-                                    ByteString::new("", Position::fake()).into(),
-                                ],
                                 Position::fake(),
                             ),
-                            Position::fake(),
-                        ),
-                        false,
-                    ),
-                ])),
+                            false,
+                        ),]
+                    )),
                     Err(AnalysisError::TypesNotMatched { .. })
                 ));
             }
@@ -3749,8 +4357,12 @@ mod tests {
                     vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            list_type.element().clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(list_type.element().clone()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -3785,8 +4397,12 @@ mod tests {
                     vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            types::Number::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(types::Number::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -3820,8 +4436,12 @@ mod tests {
                     vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            types::Number::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(types::Number::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -3850,8 +4470,12 @@ mod tests {
                         vec![FunctionDefinition::fake(
                             "x",
                             Lambda::new(
-                                vec![Argument::new("x", types::None::new(Position::fake()),)],
-                                types::Number::new(Position::fake()),
+                                vec![Argument::new(
+                                    "x",
+                                    Some(types::None::new(Position::fake()).into()),
+                                    Position::fake(),
+                                )],
+                                Some(types::Number::new(Position::fake()).into()),
                                 Call::new(
                                     Some(
                                         types::Function::new(
@@ -3878,6 +4502,104 @@ mod tests {
             }
         }
 
+        mod get {
+            use super::*;
+
+            #[test]
+            fn check_vector() {
+                let vector_type =
+                    types::Vector::new(types::Number::new(Position::fake()), Position::fake());
+                let result_type = types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                );
+
+                check_module(&Module::empty().set_function_definitions(
+                    vec![FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(vector_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(result_type.clone().into()),
+                            Call::new(
+                                Some(
+                                    types::Function::new(
+                                        vec![
+                                            vector_type.into(),
+                                            types::Number::new(Position::fake()).into(),
+                                        ],
+                                        result_type,
+                                        Position::fake(),
+                                    )
+                                    .into(),
+                                ),
+                                BuiltInFunction::new(BuiltInFunctionName::Get, Position::fake()),
+                                vec![
+                                    Variable::new("x", Position::fake()).into(),
+                                    Number::new(1.0, Position::fake()).into(),
+                                ],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )],
+                ))
+                .unwrap();
+            }
+
+            #[test]
+            fn fail_to_check_list() {
+                let list_type =
+                    types::List::new(types::Number::new(Position::fake()), Position::fake());
+
+                assert!(matches!(
+                    check_module(&Module::empty().set_function_definitions(
+                        vec![FunctionDefinition::fake(
+                            "x",
+                            Lambda::new(
+                                vec![Argument::new(
+                                    "x",
+                                    Some(list_type.clone().into()),
+                                    Position::fake()
+                                )],
+                                Some(types::Any::new(Position::fake()).into()),
+                                Call::new(
+                                    Some(
+                                        types::Function::new(
+                                            vec![
+                                                list_type.into(),
+                                                types::Number::new(Position::fake()).into(),
+                                            ],
+                                            types::Any::new(Position::fake()),
+                                            Position::fake(),
+                                        )
+                                        .into(),
+                                    ),
+                                    BuiltInFunction::new(
+                                        BuiltInFunctionName::Get,
+                                        Position::fake()
+                                    ),
+                                    vec![
+                                        Variable::new("x", Position::fake()).into(),
+                                        Number::new(1.0, Position::fake()).into(),
+                                    ],
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            false,
+                        )]
+                    )),
+                    Err(AnalysisError::VectorExpected(_, _)),
+                ));
+            }
+        }
+
         mod spawn {
             use super::*;
 
@@ -3894,7 +4616,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            function_type.clone(),
+                            Some(function_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -3907,7 +4629,7 @@ mod tests {
                                 BuiltInFunction::new(BuiltInFunctionName::Spawn, Position::fake()),
                                 vec![Lambda::new(
                                     vec![],
-                                    types::None::new(Position::fake()),
+                                    Some(types::None::new(Position::fake()).into()),
                                     None::new(Position::fake()),
                                     Position::fake(),
                                 )
@@ -3936,7 +4658,7 @@ mod tests {
                             "x",
                             Lambda::new(
                                 vec![],
-                                function_type.clone(),
+                                Some(function_type.clone().into()),
                                 Call::new(
                                     Some(
                                         types::Function::new(
@@ -3953,9 +4675,10 @@ mod tests {
                                     vec![Lambda::new(
                                         vec![Argument::new(
                                             "x",
-                                            types::None::new(Position::fake())
+                                            Some(types::None::new(Position::fake()).into()),
+                                            Position::fake(),
                                         )],
-                                        types::None::new(Position::fake()),
+                                        Some(types::None::new(Position::fake()).into()),
                                         None::new(Position::fake()),
                                         Position::fake(),
                                     )
@@ -3986,8 +4709,12 @@ mod tests {
                     vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            list_type.element().clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(list_type.element().clone()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -4026,8 +4753,12 @@ mod tests {
                     vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            list_type.element().clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake(),
+                            )],
+                            Some(list_type.element().clone()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -1,15 +1,15 @@
 use super::{context::AnalysisContext, AnalysisError};
 use crate::{
     analysis::{
-        module_environment, record_field_resolver, type_canonicalizer, type_equality_checker,
-        type_extractor,
+        module_environment, name_suggester, record_field_resolver, type_canonicalizer,
+        type_equality_checker, type_extractor,
     },
     ir::*,
     types::{self, Type},
 };
 
 pub fn coerce_types(context: &AnalysisContext, module: &Module) -> Result<Module, AnalysisError> {
-    let variables = plist::FlailMap::new(module_environment::create(module));
+    let variables = plist::FlailMap::new(module_environment::create(module)?);
 
     Ok(Module::new(
         module.type_definitions().to_vec(),
@@ -19,13 +19,13 @@ pub fn coerce_types(context: &AnalysisContext, module: &Module) -> Result<Module
         module
             .function_definitions()
             .iter()
-            .map(|definition| transform_function_definition(context, definition, &variables))
+            .map(|definition| coerce_function_definition(context, definition, &variables))
             .collect::<Result<_, _>>()?,
         module.position().clone(),
     ))
 }
 
-fn transform_function_definition(
+pub fn coerce_function_definition(
     context: &AnalysisContext,
     definition: &FunctionDefinition,
     variables: &plist::FlailMap<String, Type>,
@@ -45,20 +45,22 @@ fn transform_lambda(
     variables: &plist::FlailMap<String, Type>,
     context: &AnalysisContext,
 ) -> Result<Lambda, AnalysisError> {
+    let type_ = type_extractor::extract_from_lambda(lambda)?;
     let variables = variables.insert_iter(
         lambda
             .arguments()
             .iter()
-            .map(|argument| (argument.name().into(), argument.type_().clone())),
+            .zip(type_.arguments())
+            .map(|(argument, type_)| (argument.name().into(), type_.clone())),
     );
 
     Ok(Lambda::new(
         lambda.arguments().to_vec(),
-        lambda.result_type().clone(),
+        lambda.result_type().cloned(),
         coerce_expression(
             context,
             &transform_expression(context, lambda.body(), &variables)?,
-            lambda.result_type(),
+            type_.result(),
             &variables,
         )?,
         lambda.position().clone(),
@@ -406,6 +408,7 @@ fn transform_expression(
             )
             .into(),
             Operation::Boolean(operation) => BooleanOperation::new(
+                operation.type_().cloned(),
                 operation.operator(),
                 transform_expression(operation.lhs(), variables)?,
                 transform_expression(operation.rhs(), variables)?,
@@ -432,6 +435,7 @@ fn transform_expression(
             )
             .into(),
             Operation::Order(operation) => OrderOperation::new(
+                operation.type_().cloned(),
                 operation.operator(),
                 transform_expression(operation.lhs(), variables)?,
                 transform_expression(operation.rhs(), variables)?,
@@ -489,6 +493,16 @@ fn transform_expression(
             coercion.position().clone(),
         )
         .into(),
+        Expression::Vector(vector) => Vector::new(
+            vector.type_().clone(),
+            vector
+                .elements()
+                .iter()
+                .map(|element| transform_and_coerce_expression(element, vector.type_(), variables))
+                .collect::<Result<_, _>>()?,
+            vector.position().clone(),
+        )
+        .into(),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::None(_)
@@ -522,7 +536,15 @@ fn transform_record_fields(
                     field_types
                         .iter()
                         .find(|field_type| field_type.name() == field.name())
-                        .ok_or_else(|| AnalysisError::UnknownRecordField(field.position().clone()))?
+                        .ok_or_else(|| {
+                            AnalysisError::UnknownRecordField(
+                                field.position().clone(),
+                                name_suggester::suggest(
+                                    field.name(),
+                                    field_types.iter().map(|field_type| field_type.name()),
+                                ),
+                            )
+                        })?
                         .type_(),
                     variables,
                 )?,
@@ -589,7 +611,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        union_type.clone(),
+                        Some(union_type.clone().into()),
                         None::new(Position::fake()),
                         Position::fake(),
                     ),
@@ -601,7 +623,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        union_type.clone(),
+                        Some(union_type.clone().into()),
                         TypeCoercion::new(
                             types::None::new(Position::fake()),
                             union_type,
@@ -629,8 +651,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", types::None::new(Position::fake()))],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::None::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(union_type.clone().into()),
                         Variable::new("x", Position::fake()),
                         Position::fake(),
                     ),
@@ -641,8 +667,12 @@ mod tests {
                 Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", types::None::new(Position::fake()))],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::None::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(union_type.clone().into()),
                         TypeCoercion::new(
                             types::None::new(Position::fake()),
                             union_type,
@@ -663,8 +693,12 @@ mod tests {
         let module = Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
             "f",
             Lambda::new(
-                vec![Argument::new("x", list_type.clone())],
-                types::Number::new(Position::fake()),
+                vec![Argument::new(
+                    "x",
+                    Some(list_type.clone().into()),
+                    Position::fake(),
+                )],
+                Some(types::Number::new(Position::fake()).into()),
                 Call::new(
                     Some(
                         types::Function::new(
@@ -700,7 +734,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        union_type.clone(),
+                        Some(union_type.clone().into()),
                         If::new(
                             Boolean::new(true, Position::fake()),
                             Number::new(42.0, Position::fake()),
@@ -717,7 +751,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        union_type.clone(),
+                        Some(union_type.clone().into()),
                         If::new(
                             Boolean::new(true, Position::fake()),
                             TypeCoercion::new(
@@ -769,8 +803,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", list_type.clone())],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "xs",
+                            Some(list_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfList::new(
                             Some(types::Number::new(Position::fake()).into()),
                             Variable::new("xs", Position::fake()),
@@ -789,8 +827,12 @@ mod tests {
                 Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", list_type)],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "xs",
+                            Some(list_type.into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfList::new(
                             Some(types::Number::new(Position::fake()).into()),
                             Variable::new("xs", Position::fake()),
@@ -837,8 +879,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", list_type.clone())],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "xs",
+                            Some(list_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfList::new(
                             Some(types::Number::new(Position::fake()).into()),
                             Variable::new("xs", Position::fake()),
@@ -857,8 +903,12 @@ mod tests {
                 Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", list_type)],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "xs",
+                            Some(list_type.into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfList::new(
                             Some(types::Number::new(Position::fake()).into()),
                             Variable::new("xs", Position::fake()),
@@ -904,8 +954,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", map_type.clone())],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "xs",
+                            Some(map_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfMap::new(
                             Some(map_type.key().clone()),
                             Some(map_type.value().clone()),
@@ -925,8 +979,12 @@ mod tests {
                 Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", map_type.clone())],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "xs",
+                            Some(map_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfMap::new(
                             Some(map_type.key().clone()),
                             Some(map_type.value().clone()),
@@ -973,8 +1031,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", map_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "xs",
+                            Some(map_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfMap::new(
                             Some(map_type.key().clone()),
                             Some(map_type.value().clone()),
@@ -994,8 +1056,12 @@ mod tests {
                 Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("xs", map_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "xs",
+                            Some(map_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         IfMap::new(
                             Some(map_type.key().clone()),
                             Some(map_type.value().clone()),
@@ -1032,8 +1098,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", union_type.clone())],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1057,8 +1127,12 @@ mod tests {
                 Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", union_type.clone())],
-                        union_type.clone(),
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(union_type.clone().into()),
                         IfType::new(
                             "y",
                             Variable::new("x", Position::fake()),
@@ -1105,7 +1179,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         EqualityOperation::new(
                             Some(union_type.clone().into()),
                             EqualityOperator::Equal,
@@ -1123,7 +1197,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::Boolean::new(Position::fake()),
+                        Some(types::Boolean::new(Position::fake()).into()),
                         EqualityOperation::new(
                             Some(union_type.clone().into()),
                             EqualityOperator::Equal,
@@ -1173,7 +1247,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            record_type.clone(),
+                            Some(record_type.clone().into()),
                             RecordConstruction::new(
                                 record_type.clone(),
                                 vec![RecordField::new(
@@ -1194,7 +1268,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        record_type.clone(),
+                        Some(record_type.clone().into()),
                         RecordConstruction::new(
                             record_type,
                             vec![RecordField::new(
@@ -1239,8 +1313,12 @@ mod tests {
                     .set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("r", record_type.clone())],
-                            record_type.clone(),
+                            vec![Argument::new(
+                                "r",
+                                Some(record_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(record_type.clone().into()),
                             RecordUpdate::new(
                                 record_type.clone(),
                                 Variable::new("r", Position::fake()),
@@ -1261,8 +1339,12 @@ mod tests {
                 .set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("r", record_type.clone())],
-                        record_type.clone(),
+                        vec![Argument::new(
+                            "r",
+                            Some(record_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(record_type.clone().into()),
                         RecordUpdate::new(
                             record_type,
                             Variable::new("r", Position::fake()),
@@ -1294,12 +1376,15 @@ mod tests {
         );
 
         assert_eq!(
-            coerce_module(&Module::empty().set_function_definitions(vec![
-                FunctionDefinition::fake(
+            coerce_module(&Module::empty().set_function_definitions(
+                vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
                         vec![],
-                        types::Function::new(vec![], union_type.clone(), Position::fake()),
+                        Some(
+                            types::Function::new(vec![], union_type.clone(), Position::fake())
+                                .into()
+                        ),
                         Thunk::new(
                             Some(union_type.clone().into()),
                             None::new(Position::fake()),
@@ -1308,14 +1393,17 @@ mod tests {
                         Position::fake(),
                     ),
                     false,
-                )
-            ],)),
+                )],
+            )),
             Ok(
                 Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                     "f",
                     Lambda::new(
                         vec![],
-                        types::Function::new(vec![], union_type.clone(), Position::fake()),
+                        Some(
+                            types::Function::new(vec![], union_type.clone(), Position::fake())
+                                .into()
+                        ),
                         Thunk::new(
                             Some(union_type.clone().into()),
                             TypeCoercion::new(
@@ -1353,7 +1441,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             List::new(
                                 union_type.clone(),
                                 vec![ListElement::Single(None::new(Position::fake()).into())],
@@ -1369,7 +1457,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type,
+                            Some(list_type.into()),
                             List::new(
                                 union_type.clone(),
                                 vec![ListElement::Single(
@@ -1406,7 +1494,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             List::new(
                                 union_type.clone(),
                                 vec![ListElement::Multiple(
@@ -1429,7 +1517,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             List::new(
                                 union_type,
                                 vec![ListElement::Multiple(
@@ -1481,7 +1569,7 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                output_list_type.clone(),
+                                Some(output_list_type.clone().into()),
                                 ListComprehension::new(
                                     union_type.clone(),
                                     None::new(Position::fake()),
@@ -1506,7 +1594,7 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                output_list_type,
+                                Some(output_list_type.into()),
                                 ListComprehension::new(
                                     union_type.clone(),
                                     TypeCoercion::new(
@@ -1553,7 +1641,7 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                output_list_type.clone(),
+                                Some(output_list_type.clone().into()),
                                 ListComprehension::new(
                                     union_type.clone(),
                                     Call::new(
@@ -1590,7 +1678,7 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                output_list_type,
+                                Some(output_list_type.into()),
                                 ListComprehension::new(
                                     union_type.clone(),
                                     TypeCoercion::new(
@@ -1647,8 +1735,12 @@ mod tests {
                         FunctionDefinition::fake(
                             "f",
                             Lambda::new(
-                                vec![Argument::new("a", union_type.clone())],
-                                list_type.clone(),
+                                vec![Argument::new(
+                                    "a",
+                                    Some(union_type.clone().into()),
+                                    Position::fake()
+                                )],
+                                Some(list_type.clone().into()),
                                 ListComprehension::new(
                                     types::None::new(Position::fake()),
                                     None::new(Position::fake()),
@@ -1681,8 +1773,12 @@ mod tests {
                         Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                             "f",
                             Lambda::new(
-                                vec![Argument::new("a", union_type.clone())],
-                                list_type.clone(),
+                                vec![Argument::new(
+                                    "a",
+                                    Some(union_type.clone().into()),
+                                    Position::fake()
+                                )],
+                                Some(list_type.clone().into()),
                                 ListComprehension::new(
                                     types::None::new(Position::fake()),
                                     None::new(Position::fake()),
@@ -1744,7 +1840,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type.clone(),
+                            Some(map_type.clone().into()),
                             Map::new(
                                 union_type.clone(),
                                 types::None::new(Position::fake()),
@@ -1765,7 +1861,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type,
+                            Some(map_type.into()),
                             Map::new(
                                 union_type.clone(),
                                 types::None::new(Position::fake()),
@@ -1808,7 +1904,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type.clone(),
+                            Some(map_type.clone().into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 union_type.clone(),
@@ -1830,7 +1926,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type,
+                            Some(map_type.into()),
                             Map::new(
                                 types::None::new(Position::fake()),
                                 union_type.clone(),
@@ -1878,8 +1974,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", from_map_type.clone())],
-                            to_map_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(from_map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(to_map_type.clone().into()),
                             Map::new(
                                 to_map_type.key().clone(),
                                 to_map_type.value().clone(),
@@ -1897,8 +1997,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", from_map_type.clone())],
-                            to_map_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(from_map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(to_map_type.clone().into()),
                             Map::new(
                                 to_map_type.key().clone(),
                                 to_map_type.value().clone(),
@@ -1944,8 +2048,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", from_map_type.clone())],
-                            to_map_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(from_map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(to_map_type.clone().into()),
                             Map::new(
                                 to_map_type.key().clone(),
                                 to_map_type.value().clone(),
@@ -1963,8 +2071,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", from_map_type.clone())],
-                            to_map_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(from_map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(to_map_type.clone().into()),
                             Map::new(
                                 to_map_type.key().clone(),
                                 to_map_type.value().clone(),
@@ -58,6 +58,7 @@ fn check_with_cache(
             check_with_cache(union.lhs(), record_names)?
                 && check_with_cache(union.rhs(), record_names)?
         }
+        Type::Vector(vector) => check_with_cache(vector.element(), record_names)?,
     })
 }
 
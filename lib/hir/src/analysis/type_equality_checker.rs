@@ -31,6 +31,7 @@ fn check_canonical(one: &Type, other: &Type) -> bool {
         (Type::Union(one), Type::Union(other)) => {
             check_canonical(one.lhs(), other.lhs()) && check_canonical(one.rhs(), other.rhs())
         }
+        (Type::Vector(one), Type::Vector(other)) => check_canonical(one.element(), other.element()),
         (Type::Record(one), Type::Record(other)) => one.name() == other.name(),
         (Type::Any(_), Type::Any(_))
         | (Type::Boolean(_), Type::Boolean(_))
@@ -1,4 +1,4 @@
-use super::error::AnalysisError;
+use super::{error::AnalysisError, name_suggester, MAX_ERROR_COUNT};
 use crate::{analysis::type_transformer, ir::*, types::Type};
 use fnv::FnvHashSet;
 use std::cell::RefCell;
@@ -6,17 +6,21 @@ use std::cell::RefCell;
 pub fn validate(module: &Module) -> Result<(), AnalysisError> {
     let records = collect_existent_records(module);
     let types = collect_existent_types(module, &records);
+    let mut errors = vec![];
 
     for type_ in &collect_types(module) {
         match type_ {
             Type::Record(record) => {
                 if !records.contains(record.name()) {
-                    return Err(AnalysisError::RecordNotFound(record.clone()));
+                    errors.push(AnalysisError::RecordNotFound(record.clone()));
                 }
             }
             Type::Reference(reference) => {
                 if !types.contains(reference.name()) {
-                    return Err(AnalysisError::TypeNotFound(reference.clone()));
+                    errors.push(AnalysisError::TypeNotFound(
+                        reference.clone(),
+                        name_suggester::suggest(reference.name(), types.iter().copied()),
+                    ));
                 }
             }
             Type::Any(_)
@@ -28,11 +32,12 @@ pub fn validate(module: &Module) -> Result<(), AnalysisError> {
             | Type::None(_)
             | Type::Number(_)
             | Type::String(_)
-            | Type::Union(_) => {}
+            | Type::Union(_)
+            | Type::Vector(_) => {}
         }
     }
 
-    Ok(())
+    AnalysisError::collect(errors, MAX_ERROR_COUNT)
 }
 
 fn collect_types(module: &Module) -> Vec<Type> {
@@ -91,10 +96,10 @@ mod tests {
                 false,
                 false
             )]),),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("foo", Position::fake()),
+                None
+            ))
         );
     }
 
@@ -107,10 +112,10 @@ mod tests {
                 false,
                 false
             )]),),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("foo", Position::fake()),
+                None
+            ))
         );
     }
 
@@ -142,10 +147,10 @@ mod tests {
                     false
                 )
             ]),),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "Foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("Foo", Position::fake()),
+                None
+            ))
         );
     }
 
@@ -168,10 +173,10 @@ mod tests {
                         false
                     )]),
             ),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "Foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("Foo", Position::fake()),
+                None
+            ))
         );
     }
 
@@ -1,6 +1,6 @@
 use super::{AnalysisContext, AnalysisError};
 use crate::{
-    analysis::{record_field_resolver, type_canonicalizer, union_type_creator},
+    analysis::{name_suggester, record_field_resolver, type_canonicalizer, union_type_creator},
     ir::*,
     types::{self, Type},
 };
@@ -117,7 +117,7 @@ pub fn extract_from_expression(
             if_.position(),
         )
         .unwrap(),
-        Expression::Lambda(lambda) => extract_from_lambda(lambda).into(),
+        Expression::Lambda(lambda) => extract_from_lambda(lambda)?.into(),
         Expression::List(list) => {
             types::List::new(list.type_().clone(), list.position().clone()).into()
         }
@@ -157,10 +157,13 @@ pub fn extract_from_expression(
                 .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?
                 .clone(),
             Operation::Arithmetic(_) => types::Number::new(expression.position().clone()).into(),
-            Operation::Boolean(_)
-            | Operation::Equality(_)
-            | Operation::Not(_)
-            | Operation::Order(_) => types::Boolean::new(expression.position().clone()).into(),
+            Operation::Boolean(operation) => operation
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?
+                .clone(),
+            Operation::Equality(_) | Operation::Not(_) | Operation::Order(_) => {
+                types::Boolean::new(expression.position().clone()).into()
+            }
             Operation::Try(operation) => operation
                 .type_()
                 .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?
@@ -172,18 +175,28 @@ pub fn extract_from_expression(
                 .type_()
                 .ok_or_else(|| AnalysisError::TypeNotInferred(deconstruction.position().clone()))?;
 
-            record_field_resolver::resolve(
+            let field_types = record_field_resolver::resolve(
                 type_,
                 deconstruction.position(),
                 context.types(),
                 context.records(),
-            )?
-            .iter()
-            .find(|field| field.name() == deconstruction.field_name())
-            // TODO Use a field position.
-            .ok_or_else(|| AnalysisError::UnknownRecordField(deconstruction.position().clone()))?
-            .type_()
-            .clone()
+            )?;
+
+            field_types
+                .iter()
+                .find(|field| field.name() == deconstruction.field_name())
+                // TODO Use a field position.
+                .ok_or_else(|| {
+                    AnalysisError::UnknownRecordField(
+                        deconstruction.position().clone(),
+                        name_suggester::suggest(
+                            deconstruction.field_name(),
+                            field_types.iter().map(|field| field.name()),
+                        ),
+                    )
+                })?
+                .type_()
+                .clone()
         }
         Expression::RecordUpdate(update) => update.type_().clone(),
         Expression::String(string) => types::ByteString::new(string.position().clone()).into(),
@@ -200,20 +213,28 @@ pub fn extract_from_expression(
         Expression::Variable(variable) => variables
             .get(variable.name())
             .cloned()
-            .ok_or_else(|| AnalysisError::VariableNotFound(variable.clone()))?,
+            .ok_or_else(|| AnalysisError::VariableNotFound(variable.clone(), None))?,
+        Expression::Vector(vector) => {
+            types::Vector::new(vector.type_().clone(), vector.position().clone()).into()
+        }
     })
 }
 
-pub fn extract_from_lambda(lambda: &Lambda) -> types::Function {
-    types::Function::new(
+pub fn extract_from_lambda(lambda: &Lambda) -> Result<types::Function, AnalysisError> {
+    let type_not_inferred = || AnalysisError::TypeNotInferred(lambda.position().clone());
+
+    Ok(types::Function::new(
         lambda
             .arguments()
             .iter()
-            .map(|argument| argument.type_().clone())
-            .collect(),
-        lambda.result_type().clone(),
+            .map(|argument| argument.type_().cloned().ok_or_else(type_not_inferred))
+            .collect::<Result<_, _>>()?,
+        lambda
+            .result_type()
+            .cloned()
+            .ok_or_else(type_not_inferred)?,
         lambda.position().clone(),
-    )
+    ))
 }
 
 #[cfg(test)]
@@ -354,7 +375,7 @@ mod tests {
                         BuiltInFunction::new(BuiltInFunctionName::Spawn, Position::fake()),
                         vec![Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             None::new(Position::fake()),
                             Position::fake()
                         )
@@ -23,6 +23,7 @@ pub fn format(type_: &Type) -> String {
         Type::Reference(reference) => reference.name().into(),
         Type::String(_) => "string".into(),
         Type::Union(union) => format!("{} | {}", format(union.lhs()), format(union.rhs())),
+        Type::Vector(vector) => format!("[|{}|]", format(vector.element())),
     }
 }
 
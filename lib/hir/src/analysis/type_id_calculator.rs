@@ -53,6 +53,7 @@ fn calculate_string(type_: &Type) -> String {
             calculate_string(union.lhs()),
             calculate_string(union.rhs()),
         ),
+        Type::Vector(vector) => format!("[|{}|]", calculate_string(vector.element())),
         Type::Reference(_) => unreachable!(),
     }
 }
@@ -1,6 +1,6 @@
 use super::{
-    context::AnalysisContext, module_environment, type_canonicalizer, type_difference_calculator,
-    type_extractor, union_type_creator, AnalysisError,
+    context::AnalysisContext, module_environment, record_field_resolver, type_canonicalizer,
+    type_difference_calculator, type_extractor, union_type_creator, AnalysisError,
 };
 use crate::{
     ir::*,
@@ -8,7 +8,7 @@ use crate::{
 };
 
 pub fn infer(context: &AnalysisContext, module: &Module) -> Result<Module, AnalysisError> {
-    let variables = plist::FlailMap::new(module_environment::create(module));
+    let variables = plist::FlailMap::new(module_environment::create(module)?);
 
     Ok(Module::new(
         module.type_definitions().to_vec(),
@@ -18,13 +18,13 @@ pub fn infer(context: &AnalysisContext, module: &Module) -> Result<Module, Analy
         module
             .function_definitions()
             .iter()
-            .map(|definition| infer_definition(context, definition, &variables))
+            .map(|definition| infer_function_definition(context, definition, &variables))
             .collect::<Result<_, _>>()?,
         module.position().clone(),
     ))
 }
 
-fn infer_definition(
+pub fn infer_function_definition(
     context: &AnalysisContext,
     definition: &FunctionDefinition,
     variables: &plist::FlailMap<String, Type>,
@@ -44,23 +44,204 @@ fn infer_lambda(
     lambda: &Lambda,
     variables: &plist::FlailMap<String, Type>,
 ) -> Result<Lambda, AnalysisError> {
+    let type_ = type_extractor::extract_from_lambda(lambda)?;
+
     Ok(Lambda::new(
         lambda.arguments().to_vec(),
-        lambda.result_type().clone(),
+        lambda.result_type().cloned(),
         infer_expression(
             context,
-            lambda.body(),
+            &infer_decode_call(context, lambda.body(), type_.result())?,
             &variables.insert_iter(
                 lambda
                     .arguments()
                     .iter()
-                    .map(|argument| (argument.name().into(), argument.type_().clone())),
+                    .zip(type_.arguments())
+                    .map(|(argument, type_)| (argument.name().into(), type_.clone())),
             ),
         )?,
         lambda.position().clone(),
     ))
 }
 
+// Argument and result types of lambdas without annotations and result types of
+// `decode` calls are inferred from their expected types.
+fn infer_expected_expression(
+    context: &AnalysisContext,
+    expression: &Expression,
+    type_: Option<&Type>,
+    variables: &plist::FlailMap<String, Type>,
+) -> Result<Expression, AnalysisError> {
+    Ok(match (expression, type_) {
+        (Expression::Lambda(lambda), Some(type_)) => infer_lambda(
+            context,
+            &annotate_lambda(context, lambda, type_)?,
+            variables,
+        )?
+        .into(),
+        (Expression::Call(call), Some(type_)) if is_untyped_decode_call(call) => infer_expression(
+            context,
+            &annotate_decode_call(context, call, type_)?.into(),
+            variables,
+        )?,
+        _ => infer_expression(context, expression, variables)?,
+    })
+}
+
+fn annotate_lambda(
+    context: &AnalysisContext,
+    lambda: &Lambda,
+    type_: &Type,
+) -> Result<Lambda, AnalysisError> {
+    let Some(function_type) = type_canonicalizer::canonicalize_function(type_, context.types())?
+    else {
+        return Ok(lambda.clone());
+    };
+
+    if function_type.arguments().len() != lambda.arguments().len() {
+        return Ok(lambda.clone());
+    }
+
+    Ok(Lambda::new(
+        lambda
+            .arguments()
+            .iter()
+            .zip(function_type.arguments())
+            .map(|(argument, type_)| {
+                Argument::new(
+                    argument.name(),
+                    Some(argument.type_().unwrap_or(type_).clone()),
+                    argument.position().clone(),
+                )
+            })
+            .collect(),
+        Some(
+            lambda
+                .result_type()
+                .unwrap_or(function_type.result())
+                .clone(),
+        ),
+        lambda.body().clone(),
+        lambda.position().clone(),
+    ))
+}
+
+// Result types of `decode` calls are inferred from result types of functions
+// where they are evaluated last.
+fn infer_decode_call(
+    context: &AnalysisContext,
+    expression: &Expression,
+    result_type: &Type,
+) -> Result<Expression, AnalysisError> {
+    let infer = |expression| infer_decode_call(context, expression, result_type);
+
+    Ok(match expression {
+        Expression::Call(call) if is_untyped_decode_call(call) => {
+            annotate_decode_call(context, call, result_type)?.into()
+        }
+        Expression::If(if_) => If::new(
+            if_.condition().clone(),
+            infer(if_.then())?,
+            infer(if_.else_())?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::IfList(if_) => IfList::new(
+            if_.type_().cloned(),
+            if_.list().clone(),
+            if_.first_name(),
+            if_.rest_name(),
+            infer(if_.then())?,
+            infer(if_.else_())?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::IfMap(if_) => IfMap::new(
+            if_.key_type().cloned(),
+            if_.value_type().cloned(),
+            if_.name(),
+            if_.map().clone(),
+            if_.key().clone(),
+            infer(if_.then())?,
+            infer(if_.else_())?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::IfType(if_) => IfType::new(
+            if_.name(),
+            if_.argument().clone(),
+            if_.branches()
+                .iter()
+                .map(|branch| {
+                    Ok(IfTypeBranch::new(
+                        branch.type_().clone(),
+                        infer(branch.expression())?,
+                    ))
+                })
+                .collect::<Result<_, AnalysisError>>()?,
+            if_.else_()
+                .map(|branch| -> Result<_, AnalysisError> {
+                    Ok(ElseBranch::new(
+                        branch.type_().cloned(),
+                        infer(branch.expression())?,
+                        branch.position().clone(),
+                    ))
+                })
+                .transpose()?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::Let(let_) => Let::new(
+            let_.name().map(String::from),
+            let_.type_().cloned(),
+            let_.bound_expression().clone(),
+            infer(let_.expression())?,
+            let_.position().clone(),
+        )
+        .into(),
+        _ => expression.clone(),
+    })
+}
+
+fn is_untyped_decode_call(call: &Call) -> bool {
+    matches!(
+        call.function(),
+        Expression::BuiltInFunction(function) if function.name() == BuiltInFunctionName::Decode
+    ) && call.function_type().is_none()
+}
+
+// Errors are always added to expected types as `decode` calls can fail.
+fn annotate_decode_call(
+    context: &AnalysisContext,
+    call: &Call,
+    type_: &Type,
+) -> Result<Call, AnalysisError> {
+    let position = call.position();
+
+    Ok(Call::new(
+        Some(
+            types::Function::new(
+                vec![types::Any::new(position.clone()).into()],
+                types::Union::new(
+                    type_difference_calculator::calculate(
+                        type_,
+                        &types::Error::new(position.clone()).into(),
+                        context.types(),
+                    )?
+                    .unwrap_or_else(|| type_.clone()),
+                    types::Error::new(position.clone()),
+                    position.clone(),
+                ),
+                position.clone(),
+            )
+            .into(),
+        ),
+        call.function().clone(),
+        call.arguments().to_vec(),
+        position.clone(),
+    ))
+}
+
 fn infer_expression(
     context: &AnalysisContext,
     expression: &Expression,
@@ -75,15 +256,26 @@ fn infer_expression(
                 infer_built_in_call(context, call, function, variables)?.into()
             } else {
                 let function = infer_expression(call.function(), variables)?;
+                let type_ = type_extractor::extract_from_expression(context, &function, variables)?;
+                let function_type =
+                    type_canonicalizer::canonicalize_function(&type_, context.types())?;
 
                 Call::new(
-                    Some(type_extractor::extract_from_expression(
-                        context, &function, variables,
-                    )?),
+                    Some(type_),
                     function.clone(),
                     call.arguments()
                         .iter()
-                        .map(|argument| infer_expression(argument, variables))
+                        .enumerate()
+                        .map(|(index, argument)| {
+                            infer_expected_expression(
+                                context,
+                                argument,
+                                function_type
+                                    .as_ref()
+                                    .and_then(|type_| type_.arguments().get(index)),
+                                variables,
+                            )
+                        })
                         .collect::<Result<_, _>>()?,
                     call.position().clone(),
                 )
@@ -180,8 +372,13 @@ fn infer_expression(
             let else_ = if_
                 .else_()
                 .map(|branch| -> Result<_, AnalysisError> {
+                    let argument_type =
+                        type_extractor::extract_from_expression(context, &argument, variables)?;
+                    // An unreachable else block is reported as a warning later. Its
+                    // variable keeps the argument type so that the block is still
+                    // type-checked.
                     let type_ = type_difference_calculator::calculate(
-                        &type_extractor::extract_from_expression(context, &argument, variables)?,
+                        &argument_type,
                         &union_type_creator::create(
                             &if_.branches()
                                 .iter()
@@ -192,7 +389,7 @@ fn infer_expression(
                         .unwrap(),
                         context.types(),
                     )?
-                    .ok_or_else(|| AnalysisError::UnreachableCode(branch.position().clone()))?;
+                    .unwrap_or(argument_type);
 
                     Ok(ElseBranch::new(
                         Some(type_.clone()),
@@ -216,7 +413,12 @@ fn infer_expression(
         }
         Expression::Lambda(lambda) => infer_lambda(context, lambda, variables)?.into(),
         Expression::Let(let_) => {
-            let bound_expression = infer_expression(let_.bound_expression(), variables)?;
+            let bound_expression = infer_expected_expression(
+                context,
+                let_.bound_expression(),
+                let_.type_(),
+                variables,
+            )?;
             let bound_type =
                 type_extractor::extract_from_expression(context, &bound_expression, variables)?;
 
@@ -242,7 +444,12 @@ fn infer_expression(
                             ListElement::Multiple(infer_expression(element, variables)?)
                         }
                         ListElement::Single(element) => {
-                            ListElement::Single(infer_expression(element, variables)?)
+                            ListElement::Single(infer_expected_expression(
+                                context,
+                                element,
+                                Some(list.type_()),
+                                variables,
+                            )?)
                         }
                     })
                 })
@@ -365,13 +572,21 @@ fn infer_expression(
                 operation.position().clone(),
             )
             .into(),
-            Operation::Boolean(operation) => BooleanOperation::new(
-                operation.operator(),
-                infer_expression(operation.lhs(), variables)?,
-                infer_expression(operation.rhs(), variables)?,
-                operation.position().clone(),
-            )
-            .into(),
+            Operation::Boolean(operation) => {
+                let lhs = infer_expression(operation.lhs(), variables)?;
+
+                BooleanOperation::new(
+                    Some(type_canonicalizer::canonicalize(
+                        &type_extractor::extract_from_expression(context, &lhs, variables)?,
+                        context.types(),
+                    )?),
+                    operation.operator(),
+                    lhs,
+                    infer_expression(operation.rhs(), variables)?,
+                    operation.position().clone(),
+                )
+                .into()
+            }
             Operation::Equality(operation) => {
                 let lhs = infer_expression(operation.lhs(), variables)?;
                 let rhs = infer_expression(operation.rhs(), variables)?;
@@ -397,13 +612,21 @@ fn infer_expression(
                 operation.position().clone(),
             )
             .into(),
-            Operation::Order(operation) => OrderOperation::new(
-                operation.operator(),
-                infer_expression(operation.lhs(), variables)?,
-                infer_expression(operation.rhs(), variables)?,
-                operation.position().clone(),
-            )
-            .into(),
+            Operation::Order(operation) => {
+                let lhs = infer_expression(operation.lhs(), variables)?;
+
+                OrderOperation::new(
+                    Some(type_canonicalizer::canonicalize(
+                        &type_extractor::extract_from_expression(context, &lhs, variables)?,
+                        context.types(),
+                    )?),
+                    operation.operator(),
+                    lhs,
+                    infer_expression(operation.rhs(), variables)?,
+                    operation.position().clone(),
+                )
+                .into()
+            }
             Operation::Try(operation) => {
                 let position = operation.position();
                 let expression = infer_expression(operation.expression(), variables)?;
@@ -438,17 +661,12 @@ fn infer_expression(
         },
         Expression::RecordConstruction(construction) => RecordConstruction::new(
             construction.type_().clone(),
-            construction
-                .fields()
-                .iter()
-                .map(|field| {
-                    Ok(RecordField::new(
-                        field.name(),
-                        infer_expression(field.expression(), variables)?,
-                        field.position().clone(),
-                    ))
-                })
-                .collect::<Result<_, AnalysisError>>()?,
+            infer_record_fields(
+                context,
+                construction.type_(),
+                construction.fields(),
+                variables,
+            )?,
             construction.position().clone(),
         )
         .into(),
@@ -468,17 +686,7 @@ fn infer_expression(
         Expression::RecordUpdate(update) => RecordUpdate::new(
             update.type_().clone(),
             infer_expression(update.record(), variables)?,
-            update
-                .fields()
-                .iter()
-                .map(|field| {
-                    Ok(RecordField::new(
-                        field.name(),
-                        infer_expression(field.expression(), variables)?,
-                        field.position().clone(),
-                    ))
-                })
-                .collect::<Result<_, AnalysisError>>()?,
+            infer_record_fields(context, update.type_(), update.fields(), variables)?,
             update.position().clone(),
         )
         .into(),
@@ -499,6 +707,16 @@ fn infer_expression(
             coercion.position().clone(),
         )
         .into(),
+        Expression::Vector(vector) => Vector::new(
+            vector.type_().clone(),
+            vector
+                .elements()
+                .iter()
+                .map(|element| infer_expression(element, variables))
+                .collect::<Result<_, _>>()?,
+            vector.position().clone(),
+        )
+        .into(),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::None(_)
@@ -508,6 +726,41 @@ fn infer_expression(
     })
 }
 
+fn infer_record_fields(
+    context: &AnalysisContext,
+    type_: &Type,
+    fields: &[RecordField],
+    variables: &plist::FlailMap<String, Type>,
+) -> Result<Vec<RecordField>, AnalysisError> {
+    fields
+        .iter()
+        .map(|field| {
+            Ok(RecordField::new(
+                field.name(),
+                if let Expression::Lambda(_) = field.expression() {
+                    infer_expected_expression(
+                        context,
+                        field.expression(),
+                        record_field_resolver::resolve(
+                            type_,
+                            type_.position(),
+                            context.types(),
+                            context.records(),
+                        )?
+                        .iter()
+                        .find(|field_type| field_type.name() == field.name())
+                        .map(types::RecordField::type_),
+                        variables,
+                    )?
+                } else {
+                    infer_expression(context, field.expression(), variables)?
+                },
+                field.position().clone(),
+            ))
+        })
+        .collect()
+}
+
 fn infer_built_in_call(
     context: &AnalysisContext,
     call: &Call,
@@ -533,6 +786,19 @@ fn infer_built_in_call(
                     types::None::new(position.clone()),
                     position.clone(),
                 ),
+                BuiltInFunctionName::Decode => {
+                    let [_] = &arguments[..] else {
+                        return Err(AnalysisError::ArgumentCount(position.clone()));
+                    };
+
+                    call.function_type()
+                        .map(|type_| {
+                            type_canonicalizer::canonicalize_function(type_, context.types())
+                        })
+                        .transpose()?
+                        .flatten()
+                        .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?
+                }
                 BuiltInFunctionName::Delete => {
                     let Some(result_type) = argument_types.first().cloned() else {
                         return Err(AnalysisError::ArgumentCount(position.clone()));
@@ -540,11 +806,43 @@ fn infer_built_in_call(
 
                     types::Function::new(argument_types, result_type, position.clone())
                 }
+                BuiltInFunctionName::Encode => {
+                    let [argument_type] = &argument_types[..] else {
+                        return Err(AnalysisError::ArgumentCount(position.clone()));
+                    };
+
+                    types::Function::new(
+                        vec![argument_type.clone()],
+                        types::Any::new(position.clone()),
+                        position.clone(),
+                    )
+                }
                 BuiltInFunctionName::Error => types::Function::new(
                     vec![types::Any::new(position.clone()).into()],
                     types::Error::new(position.clone()),
                     position.clone(),
                 ),
+                BuiltInFunctionName::Get => {
+                    let ([vector, _], [vector_type, _]) = (&arguments[..], &argument_types[..])
+                    else {
+                        return Err(AnalysisError::ArgumentCount(position.clone()));
+                    };
+
+                    types::Function::new(
+                        vec![
+                            vector_type.clone(),
+                            types::Number::new(position.clone()).into(),
+                        ],
+                        types::Union::new(
+                            canonicalize_vector(context, vector, vector_type)?
+                                .element()
+                                .clone(),
+                            types::None::new(position.clone()),
+                            position.clone(),
+                        ),
+                        position.clone(),
+                    )
+                }
                 BuiltInFunctionName::Keys => {
                     let ([argument], [argument_type]) = (&arguments[..], &argument_types[..])
                     else {
@@ -568,6 +866,23 @@ fn infer_built_in_call(
                         position.clone(),
                     )
                 }
+                BuiltInFunctionName::Push => {
+                    let ([vector, _], [vector_type, _]) = (&arguments[..], &argument_types[..])
+                    else {
+                        return Err(AnalysisError::ArgumentCount(position.clone()));
+                    };
+
+                    types::Function::new(
+                        vec![
+                            vector_type.clone(),
+                            canonicalize_vector(context, vector, vector_type)?
+                                .element()
+                                .clone(),
+                        ],
+                        vector_type.clone(),
+                        position.clone(),
+                    )
+                }
                 BuiltInFunctionName::Race => {
                     let ([argument], [argument_type]) = (&arguments[..], &argument_types[..])
                     else {
@@ -605,11 +920,49 @@ fn infer_built_in_call(
                     ),
                     position.clone(),
                 ),
+                BuiltInFunctionName::Set => {
+                    let ([vector, _, _], [vector_type, _, _]) =
+                        (&arguments[..], &argument_types[..])
+                    else {
+                        return Err(AnalysisError::ArgumentCount(position.clone()));
+                    };
+
+                    types::Function::new(
+                        vec![
+                            vector_type.clone(),
+                            types::Number::new(position.clone()).into(),
+                            canonicalize_vector(context, vector, vector_type)?
+                                .element()
+                                .clone(),
+                        ],
+                        vector_type.clone(),
+                        position.clone(),
+                    )
+                }
                 BuiltInFunctionName::Size => types::Function::new(
                     argument_types,
                     types::Number::new(position.clone()),
                     position.clone(),
                 ),
+                BuiltInFunctionName::Slice => {
+                    let ([vector, _, _], [vector_type, _, _]) =
+                        (&arguments[..], &argument_types[..])
+                    else {
+                        return Err(AnalysisError::ArgumentCount(position.clone()));
+                    };
+
+                    canonicalize_vector(context, vector, vector_type)?;
+
+                    types::Function::new(
+                        vec![
+                            vector_type.clone(),
+                            types::Number::new(position.clone()).into(),
+                            types::Number::new(position.clone()).into(),
+                        ],
+                        vector_type.clone(),
+                        position.clone(),
+                    )
+                }
                 BuiltInFunctionName::Source => types::Function::new(
                     vec![types::Error::new(position.clone()).into()],
                     types::Any::new(position.clone()),
@@ -655,6 +1008,15 @@ fn infer_built_in_call(
     ))
 }
 
+fn canonicalize_vector(
+    context: &AnalysisContext,
+    argument: &Expression,
+    type_: &Type,
+) -> Result<types::Vector, AnalysisError> {
+    type_canonicalizer::canonicalize_vector(type_, context.types())?
+        .ok_or_else(|| AnalysisError::VectorExpected(argument.position().clone(), type_.clone()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -688,7 +1050,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Call::new(
                             None,
                             Variable::new("x", Position::fake()),
@@ -705,7 +1067,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Call::new(
                             Some(
                                 types::Function::new(
@@ -735,7 +1097,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         EqualityOperation::new(
                             None,
                             EqualityOperator::Equal,
@@ -753,7 +1115,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         EqualityOperation::new(
                             Some(
                                 types::Union::new(
@@ -776,6 +1138,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn infer_order_operation() {
+        assert_eq!(
+            infer_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        Some(types::Boolean::new(Position::fake()).into()),
+                        OrderOperation::new(
+                            None,
+                            OrderOperator::LessThan,
+                            ByteString::new("foo", Position::fake()),
+                            ByteString::new("bar", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ],)),
+            Ok(
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        Some(types::Boolean::new(Position::fake()).into()),
+                        OrderOperation::new(
+                            Some(types::ByteString::new(Position::fake()).into()),
+                            OrderOperator::LessThan,
+                            ByteString::new("foo", Position::fake()),
+                            ByteString::new("bar", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )],)
+            )
+        );
+    }
+
     #[test]
     fn infer_let() {
         assert_eq!(
@@ -784,7 +1188,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             Some("x".into()),
                             None,
@@ -802,7 +1206,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             Some("x".into()),
                             Some(types::None::new(Position::fake()).into()),
@@ -834,7 +1238,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             Let::new(
                                 Some("x".into()),
                                 None,
@@ -858,7 +1262,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             Some("x".into()),
                             Some(types::None::new(Position::fake()).into()),
@@ -893,7 +1297,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 element_type.clone(),
                                 Let::new(
@@ -929,7 +1333,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 element_type.clone(),
                                 Let::new(
@@ -982,7 +1386,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 element_type.clone(),
                                 Let::new(
@@ -1034,7 +1438,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 element_type.clone(),
                                 Let::new(
@@ -1109,7 +1513,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 None::new(Position::fake()),
@@ -1150,7 +1554,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 types::None::new(Position::fake()),
                                 None::new(Position::fake()),
@@ -1209,7 +1613,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type.clone(),
+                            Some(list_type.clone().into()),
                             ListComprehension::new(
                                 element_type.clone(),
                                 Let::new(
@@ -1259,7 +1663,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            list_type,
+                            Some(list_type.into()),
                             ListComprehension::new(
                                 element_type.clone(),
                                 Let::new(
@@ -1345,8 +1749,12 @@ mod tests {
                     .set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", types::Record::fake("r"))],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(types::Record::fake("r").into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             RecordDeconstruction::new(
                                 None,
                                 Variable::new("x", Position::fake()),
@@ -1363,8 +1771,12 @@ mod tests {
                 .set_function_definitions(vec![FunctionDefinition::fake(
                     "x",
                     Lambda::new(
-                        vec![Argument::new("x", types::Record::fake("r"))],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Record::fake("r").into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         RecordDeconstruction::new(
                             Some(types::Record::fake("r").into()),
                             Variable::new("x", Position::fake()),
@@ -1402,7 +1814,7 @@ mod tests {
                             "f",
                             Lambda::new(
                                 vec![],
-                                types::None::new(Position::fake()),
+                                Some(types::None::new(Position::fake()).into()),
                                 Call::new(
                                     None,
                                     Variable::new("f", Position::fake()),
@@ -1420,7 +1832,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -1451,7 +1863,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            none_type.clone(),
+                            Some(none_type.clone().into()),
                             Thunk::new(None, None::new(Position::fake()), Position::fake()),
                             Position::fake(),
                         ),
@@ -1463,7 +1875,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            none_type.clone(),
+                            Some(none_type.clone().into()),
                             Thunk::new(
                                 Some(none_type.into()),
                                 None::new(Position::fake()),
@@ -1499,8 +1911,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", union_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "x",
                                 Variable::new("x", Position::fake()),
@@ -1521,8 +1937,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", union_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "x",
                                 Variable::new("x", Position::fake()),
@@ -1563,8 +1983,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", union_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "x",
                                 Variable::new("x", Position::fake()),
@@ -1585,8 +2009,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", union_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "x",
                                 Variable::new("x", Position::fake()),
@@ -1632,8 +2060,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", union_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "y",
                                 Variable::new("x", Position::fake()),
@@ -1659,8 +2091,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", union_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "y",
                                 Variable::new("x", Position::fake()),
@@ -1698,8 +2134,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", any_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(any_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "x",
                                 Variable::new("x", Position::fake()),
@@ -1720,8 +2160,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", any_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(any_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfType::new(
                                 "x",
                                 Variable::new("x", Position::fake()),
@@ -1742,46 +2186,51 @@ mod tests {
         }
 
         #[test]
-        fn fail_to_infer_else_branch_type_due_to_unreachable_code() {
+        fn infer_unreachable_else_branch_type() {
             let union_type = types::Union::new(
                 types::Number::new(Position::fake()),
                 types::None::new(Position::fake()),
                 Position::fake(),
             );
-
-            assert_eq!(
-                infer_module(&Module::empty().set_function_definitions(vec![
-                    FunctionDefinition::fake(
-                        "x",
-                        Lambda::new(
-                            vec![Argument::new("x", union_type)],
-                            types::None::new(Position::fake()),
-                            IfType::new(
-                                "x",
-                                Variable::new("x", Position::fake()),
-                                vec![
-                                    IfTypeBranch::new(
-                                        types::Number::new(Position::fake()),
-                                        None::new(Position::fake()),
-                                    ),
-                                    IfTypeBranch::new(
-                                        types::None::new(Position::fake()),
-                                        None::new(Position::fake()),
-                                    )
-                                ],
-                                Some(ElseBranch::new(
-                                    None,
+            let create_module = |type_: Option<Type>| {
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(union_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
+                        IfType::new(
+                            "x",
+                            Variable::new("x", Position::fake()),
+                            vec![
+                                IfTypeBranch::new(
+                                    types::Number::new(Position::fake()),
                                     None::new(Position::fake()),
-                                    Position::fake()
-                                )),
-                                Position::fake()
-                            ),
+                                ),
+                                IfTypeBranch::new(
+                                    types::None::new(Position::fake()),
+                                    None::new(Position::fake()),
+                                ),
+                            ],
+                            Some(ElseBranch::new(
+                                type_,
+                                None::new(Position::fake()),
+                                Position::fake(),
+                            )),
                             Position::fake(),
                         ),
-                        false,
-                    )
-                ],)),
-                Err(AnalysisError::UnreachableCode(Position::fake()))
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            };
+
+            assert_eq!(
+                infer_module(&create_module(None)),
+                Ok(create_module(Some(union_type.clone().into())))
             );
         }
     }
@@ -1803,8 +2252,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", union_type.clone())],
-                            union_type.clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(union_type.clone().into()),
                             TryOperation::new(
                                 None,
                                 Variable::new("x", Position::fake()),
@@ -1819,8 +2272,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", union_type.clone())],
-                            union_type,
+                            vec![Argument::new(
+                                "x",
+                                Some(union_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(union_type.into()),
                             TryOperation::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -1841,8 +2298,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", types::Error::new(Position::fake()))],
-                            types::Error::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(types::Error::new(Position::fake()).into()),
+                                Position::fake(),
+                            )],
+                            Some(types::Error::new(Position::fake()).into()),
                             TryOperation::new(
                                 None,
                                 Variable::new("x", Position::fake()),
@@ -1874,8 +2335,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 None,
                                 Variable::new("x", Position::fake()),
@@ -1894,8 +2359,8 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", list_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -1922,8 +2387,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 None,
                                 Variable::new("x", Position::fake()),
@@ -1948,8 +2417,8 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", list_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -1989,8 +2458,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 None,
                                 Variable::new("x", Position::fake()),
@@ -2015,8 +2488,8 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", list_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(list_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfList::new(
                                 Some(types::None::new(Position::fake()).into()),
                                 Variable::new("x", Position::fake()),
@@ -2064,8 +2537,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfMap::new(
                                 None,
                                 None,
@@ -2085,8 +2562,8 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", map_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(map_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfMap::new(
                                 Some(types::Boolean::new(Position::fake()).into()),
                                 Some(types::None::new(Position::fake()).into()),
@@ -2118,8 +2595,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", map_type.clone())],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
                             IfMap::new(
                                 None,
                                 None,
@@ -2145,8 +2626,8 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("x", map_type)],
-                            types::None::new(Position::fake()),
+                            vec![Argument::new("x", Some(map_type.into()), Position::fake())],
+                            Some(types::None::new(Position::fake()).into()),
                             IfMap::new(
                                 Some(types::Boolean::new(Position::fake()).into()),
                                 Some(types::None::new(Position::fake()).into()),
@@ -2172,6 +2653,165 @@ mod tests {
         }
     }
 
+    mod lambda {
+        use super::*;
+        use crate::test::TypeDefinitionFake;
+        use pretty_assertions::assert_eq;
+
+        fn function_type() -> types::Function {
+            types::Function::new(
+                vec![types::Number::new(Position::fake()).into()],
+                types::Number::new(Position::fake()),
+                Position::fake(),
+            )
+        }
+
+        fn create_lambda(type_: Option<&types::Function>) -> Lambda {
+            Lambda::new(
+                vec![Argument::new(
+                    "x",
+                    type_.map(|type_| type_.arguments()[0].clone()),
+                    Position::fake(),
+                )],
+                type_.map(|type_| type_.result().clone()),
+                Variable::new("x", Position::fake()),
+                Position::fake(),
+            )
+        }
+
+        #[test]
+        fn infer_lambda_in_call() {
+            let function_type = function_type();
+            let create_module = |type_: Option<&types::Function>, call_type| {
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "g",
+                            Some(function_type.clone().into()),
+                            Position::fake(),
+                        )],
+                        Some(types::Number::new(Position::fake()).into()),
+                        Call::new(
+                            call_type,
+                            Variable::new("f", Position::fake()),
+                            vec![create_lambda(type_).into()],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            };
+
+            assert_eq!(
+                infer_module(&create_module(None, None)),
+                Ok(create_module(
+                    Some(&function_type),
+                    Some(
+                        types::Function::new(
+                            vec![function_type.clone().into()],
+                            types::Number::new(Position::fake()),
+                            Position::fake(),
+                        )
+                        .into()
+                    )
+                ))
+            );
+        }
+
+        #[test]
+        fn infer_lambda_in_let() {
+            let function_type = function_type();
+            let create_module = |type_: Option<&types::Function>| {
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(function_type.clone().into()),
+                        Let::new(
+                            Some("g".into()),
+                            Some(function_type.clone().into()),
+                            create_lambda(type_),
+                            Variable::new("g", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            };
+
+            assert_eq!(
+                infer_module(&create_module(None)),
+                Ok(create_module(Some(&function_type)))
+            );
+        }
+
+        #[test]
+        fn infer_lambda_in_record_construction() {
+            let function_type = function_type();
+            let create_module = |type_: Option<&types::Function>| {
+                Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "r",
+                        vec![types::RecordField::new("f", function_type.clone())],
+                        false,
+                        false,
+                        false,
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Record::fake("r").into()),
+                            RecordConstruction::new(
+                                types::Record::fake("r"),
+                                vec![RecordField::new(
+                                    "f",
+                                    create_lambda(type_),
+                                    Position::fake(),
+                                )],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+            };
+
+            assert_eq!(
+                infer_module(&create_module(None)),
+                Ok(create_module(Some(&function_type)))
+            );
+        }
+
+        #[test]
+        fn fail_to_infer_lambda_without_expected_type() {
+            assert_eq!(
+                infer_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            Some(types::None::new(Position::fake()).into()),
+                            Let::new(
+                                Some("g".into()),
+                                None,
+                                create_lambda(None),
+                                None::new(Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ])),
+                Err(AnalysisError::TypeNotInferred(Position::fake()))
+            );
+        }
+    }
+
     mod built_in_call {
         use super::*;
         use pretty_assertions::assert_eq;
@@ -2184,7 +2824,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Debug, Position::fake()),
@@ -2201,7 +2841,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::None::new(Position::fake()),
+                            Some(types::None::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2223,6 +2863,309 @@ mod tests {
             );
         }
 
+        #[test]
+        fn infer_decode() {
+            let result_type = types::Union::new(
+                types::Error::new(Position::fake()),
+                types::Number::new(Position::fake()),
+                Position::fake(),
+            );
+
+            assert_eq!(
+                infer_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(types::Any::new(Position::fake()).into()),
+                                Position::fake(),
+                            )],
+                            Some(result_type.clone().into()),
+                            Call::new(
+                                None,
+                                BuiltInFunction::new(BuiltInFunctionName::Decode, Position::fake()),
+                                vec![Variable::new("x", Position::fake()).into()],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ],)),
+                Ok(
+                    Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(types::Any::new(Position::fake()).into()),
+                                Position::fake(),
+                            )],
+                            Some(result_type.clone().into()),
+                            Call::new(
+                                Some(
+                                    types::Function::new(
+                                        vec![types::Any::new(Position::fake()).into()],
+                                        result_type,
+                                        Position::fake()
+                                    )
+                                    .into()
+                                ),
+                                BuiltInFunction::new(BuiltInFunctionName::Decode, Position::fake()),
+                                vec![Variable::new("x", Position::fake()).into()],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+                )
+            );
+        }
+
+        #[test]
+        fn infer_decode_in_let() {
+            let result_type = types::Union::new(
+                types::Error::new(Position::fake()),
+                types::Number::new(Position::fake()),
+                Position::fake(),
+            );
+            let create_module = |function_type: Option<Type>| {
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(result_type.clone().into()),
+                        Let::new(
+                            None,
+                            Some(types::None::new(Position::fake()).into()),
+                            None::new(Position::fake()),
+                            Call::new(
+                                function_type,
+                                BuiltInFunction::new(BuiltInFunctionName::Decode, Position::fake()),
+                                vec![Variable::new("x", Position::fake()).into()],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            };
+
+            assert_eq!(
+                infer_module(&create_module(None)),
+                Ok(create_module(Some(
+                    types::Function::new(
+                        vec![types::Any::new(Position::fake()).into()],
+                        result_type.clone(),
+                        Position::fake()
+                    )
+                    .into()
+                )))
+            );
+        }
+
+        #[test]
+        fn infer_decode_in_typed_let() {
+            let result_type = types::Union::new(
+                types::Error::new(Position::fake()),
+                types::Number::new(Position::fake()),
+                Position::fake(),
+            );
+            let create_module = |function_type: Option<Type>| {
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Any::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
+                        Let::new(
+                            Some("y".into()),
+                            Some(result_type.clone().into()),
+                            Call::new(
+                                function_type,
+                                BuiltInFunction::new(BuiltInFunctionName::Decode, Position::fake()),
+                                vec![Variable::new("x", Position::fake()).into()],
+                                Position::fake(),
+                            ),
+                            None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            };
+
+            assert_eq!(
+                infer_module(&create_module(None)),
+                Ok(create_module(Some(
+                    types::Function::new(
+                        vec![types::Any::new(Position::fake()).into()],
+                        result_type.clone(),
+                        Position::fake()
+                    )
+                    .into()
+                )))
+            );
+        }
+
+        #[test]
+        fn infer_decode_in_call_argument() {
+            let argument_type = types::Union::new(
+                types::Error::new(Position::fake()),
+                types::Number::new(Position::fake()),
+                Position::fake(),
+            );
+            let function_type = types::Function::new(
+                vec![argument_type.clone().into()],
+                types::None::new(Position::fake()),
+                Position::fake(),
+            );
+            let create_module = |decode_type: Option<Type>| {
+                Module::empty()
+                    .set_function_declarations(vec![FunctionDeclaration::new(
+                        "g",
+                        function_type.clone(),
+                        Position::fake(),
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(types::Any::new(Position::fake()).into()),
+                                Position::fake(),
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
+                            Call::new(
+                                Some(function_type.clone().into()),
+                                Variable::new("g", Position::fake()),
+                                vec![Call::new(
+                                    decode_type,
+                                    BuiltInFunction::new(
+                                        BuiltInFunctionName::Decode,
+                                        Position::fake(),
+                                    ),
+                                    vec![Variable::new("x", Position::fake()).into()],
+                                    Position::fake(),
+                                )
+                                .into()],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+            };
+
+            assert_eq!(
+                infer_module(&create_module(None)),
+                Ok(create_module(Some(
+                    types::Function::new(
+                        vec![types::Any::new(Position::fake()).into()],
+                        argument_type.clone(),
+                        Position::fake()
+                    )
+                    .into()
+                )))
+            );
+        }
+
+        #[test]
+        fn fail_to_infer_decode_without_expected_type() {
+            assert_eq!(
+                infer_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(types::Any::new(Position::fake()).into()),
+                                Position::fake(),
+                            )],
+                            Some(types::None::new(Position::fake()).into()),
+                            Let::new(
+                                Some("y".into()),
+                                None,
+                                Call::new(
+                                    None,
+                                    BuiltInFunction::new(
+                                        BuiltInFunctionName::Decode,
+                                        Position::fake()
+                                    ),
+                                    vec![Variable::new("x", Position::fake()).into()],
+                                    Position::fake()
+                                ),
+                                None::new(Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ],)),
+                Err(AnalysisError::TypeNotInferred(Position::fake()))
+            );
+        }
+
+        #[test]
+        fn infer_encode() {
+            assert_eq!(
+                infer_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Any::new(Position::fake()).into()),
+                            Call::new(
+                                None,
+                                BuiltInFunction::new(BuiltInFunctionName::Encode, Position::fake()),
+                                vec![Number::new(42.0, Position::fake()).into()],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ],)),
+                Ok(
+                    Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            Some(types::Any::new(Position::fake()).into()),
+                            Call::new(
+                                Some(
+                                    types::Function::new(
+                                        vec![types::Number::new(Position::fake()).into()],
+                                        types::Any::new(Position::fake()),
+                                        Position::fake()
+                                    )
+                                    .into()
+                                ),
+                                BuiltInFunction::new(BuiltInFunctionName::Encode, Position::fake()),
+                                vec![Number::new(42.0, Position::fake()).into()],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+                )
+            );
+        }
+
         #[test]
         fn infer_delete() {
             let map_type = types::Map::new(
@@ -2237,7 +3180,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type.clone(),
+                            Some(map_type.clone().into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Delete, Position::fake()),
@@ -2260,7 +3203,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type.clone(),
+                            Some(map_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2312,7 +3255,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type.clone(),
+                            Some(map_type.clone().into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Delete, Position::fake()),
@@ -2335,7 +3278,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            map_type.clone(),
+                            Some(map_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2383,8 +3326,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", map_type.clone())],
-                            list_type.clone(),
+                            vec![Argument::new(
+                                "xs",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(list_type.clone().into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Keys, Position::fake()),
@@ -2400,8 +3347,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", map_type.clone())],
-                            list_type.clone(),
+                            vec![Argument::new(
+                                "xs",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(list_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2435,8 +3386,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            list_type.element().clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(list_type.element().clone()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Race, Position::fake()),
@@ -2452,8 +3407,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            list_type.element().clone(),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(list_type.element().clone()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2483,7 +3442,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::ByteString::new(Position::fake()),
+                            Some(types::ByteString::new(Position::fake()).into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(
@@ -2503,7 +3462,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::ByteString::new(Position::fake()),
+                            Some(types::ByteString::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2542,7 +3501,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            result_type.clone(),
+                            Some(result_type.clone().into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(
@@ -2565,7 +3524,7 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            result_type.clone(),
+                            Some(result_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2596,6 +3555,108 @@ mod tests {
             );
         }
 
+        #[test]
+        fn infer_push() {
+            let vector_type =
+                types::Vector::new(types::Number::new(Position::fake()), Position::fake());
+            let arguments = vec![
+                Variable::new("x", Position::fake()).into(),
+                Number::new(42.0, Position::fake()).into(),
+            ];
+
+            assert_eq!(
+                infer_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(vector_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(vector_type.clone().into()),
+                            Call::new(
+                                None,
+                                BuiltInFunction::new(BuiltInFunctionName::Push, Position::fake()),
+                                arguments.clone(),
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ],)),
+                Ok(
+                    Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(vector_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(vector_type.clone().into()),
+                            Call::new(
+                                Some(
+                                    types::Function::new(
+                                        vec![
+                                            vector_type.clone().into(),
+                                            types::Number::new(Position::fake()).into()
+                                        ],
+                                        vector_type,
+                                        Position::fake()
+                                    )
+                                    .into()
+                                ),
+                                BuiltInFunction::new(BuiltInFunctionName::Push, Position::fake()),
+                                arguments,
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )],)
+                )
+            );
+        }
+
+        #[test]
+        fn fail_to_infer_push_with_list() {
+            let list_type =
+                types::List::new(types::Number::new(Position::fake()), Position::fake());
+
+            assert_eq!(
+                infer_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(list_type.clone().into()),
+                            Call::new(
+                                None,
+                                BuiltInFunction::new(BuiltInFunctionName::Push, Position::fake()),
+                                vec![
+                                    Variable::new("x", Position::fake()).into(),
+                                    Number::new(42.0, Position::fake()).into(),
+                                ],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ],)),
+                Err(AnalysisError::VectorExpected(
+                    Position::fake(),
+                    list_type.into()
+                ))
+            );
+        }
+
         #[test]
         fn infer_size() {
             let list_type = types::List::new(types::None::new(Position::fake()), Position::fake());
@@ -2605,8 +3666,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            types::Number::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::Number::new(Position::fake()).into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Size, Position::fake()),
@@ -2622,8 +3687,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "x",
                         Lambda::new(
-                            vec![Argument::new("x", list_type.clone())],
-                            types::Number::new(Position::fake()),
+                            vec![Argument::new(
+                                "x",
+                                Some(list_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(types::Number::new(Position::fake()).into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2656,13 +3725,13 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            function_type.clone(),
+                            Some(function_type.clone().into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Spawn, Position::fake()),
                                 vec![Lambda::new(
                                     vec![],
-                                    types::None::new(Position::fake()),
+                                    Some(types::None::new(Position::fake()).into()),
                                     None::new(Position::fake()),
                                     Position::fake(),
                                 )
@@ -2679,7 +3748,7 @@ mod tests {
                         "x",
                         Lambda::new(
                             vec![],
-                            function_type.clone(),
+                            Some(function_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -2692,7 +3761,7 @@ mod tests {
                                 BuiltInFunction::new(BuiltInFunctionName::Spawn, Position::fake()),
                                 vec![Lambda::new(
                                     vec![],
-                                    types::None::new(Position::fake()),
+                                    Some(types::None::new(Position::fake()).into()),
                                     None::new(Position::fake()),
                                     Position::fake(),
                                 )
@@ -2721,8 +3790,12 @@ mod tests {
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", map_type.clone())],
-                            list_type.clone(),
+                            vec![Argument::new(
+                                "xs",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(list_type.clone().into()),
                             Call::new(
                                 None,
                                 BuiltInFunction::new(BuiltInFunctionName::Values, Position::fake()),
@@ -2738,8 +3811,12 @@ mod tests {
                     Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
                         "f",
                         Lambda::new(
-                            vec![Argument::new("xs", map_type.clone())],
-                            list_type.clone(),
+                            vec![Argument::new(
+                                "xs",
+                                Some(map_type.clone().into()),
+                                Position::fake()
+                            )],
+                            Some(list_type.clone().into()),
                             Call::new(
                                 Some(
                                     types::Function::new(
@@ -1,4 +1,4 @@
-use super::AnalysisError;
+use super::{name_suggester, AnalysisError};
 use crate::types::*;
 use fnv::FnvHashMap;
 
@@ -8,7 +8,12 @@ pub fn resolve(
 ) -> Result<Type, AnalysisError> {
     Ok(types
         .get(reference.name())
-        .ok_or_else(|| AnalysisError::TypeNotFound(reference.clone()))?
+        .ok_or_else(|| {
+            AnalysisError::TypeNotFound(
+                reference.clone(),
+                name_suggester::suggest(reference.name(), types.keys().map(String::as_str)),
+            )
+        })?
         .clone()
         .set_position(reference.position().clone()))
 }
@@ -0,0 +1,179 @@
+use super::{record_field_resolver, type_canonicalizer, type_resolver, AnalysisError};
+use crate::types::{RecordField, Type};
+use fnv::{FnvHashMap, FnvHashSet};
+
+// Serializable types are ones convertible into trees of booleans, none,
+// numbers, strings, lists, and maps of string keys.
+pub fn check(
+    type_: &Type,
+    types: &FnvHashMap<String, Type>,
+    record_types: &FnvHashMap<String, Vec<RecordField>>,
+) -> Result<bool, AnalysisError> {
+    check_with_cache(type_, &Default::default(), types, record_types)
+}
+
+fn check_with_cache(
+    type_: &Type,
+    record_names: &FnvHashSet<String>,
+    types: &FnvHashMap<String, Type>,
+    record_types: &FnvHashMap<String, Vec<RecordField>>,
+) -> Result<bool, AnalysisError> {
+    let check_with_cache =
+        |type_, record_names| check_with_cache(type_, record_names, types, record_types);
+
+    Ok(match type_ {
+        Type::Any(_) => false,
+        Type::Boolean(_) => true,
+        Type::Error(_) => false,
+        Type::Function(_) => false,
+        Type::List(list) => check_with_cache(list.element(), record_names)?,
+        Type::Map(map) => {
+            matches!(
+                type_canonicalizer::canonicalize(map.key(), types)?,
+                Type::String(_)
+            ) && check_with_cache(map.value(), record_names)?
+        }
+        Type::None(_) => true,
+        Type::Number(_) => true,
+        Type::Record(record) => {
+            if record_names.contains(record.name()) {
+                true
+            } else {
+                let record_names = record_names
+                    .clone()
+                    .into_iter()
+                    .chain([record.name().into()])
+                    .collect();
+
+                record_field_resolver::resolve_record(record, record_types)?
+                    .iter()
+                    .map(|field| check_with_cache(field.type_(), &record_names))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .all(|flag| flag)
+            }
+        }
+        Type::Reference(reference) => {
+            check_with_cache(&type_resolver::resolve(reference, types)?, record_names)?
+        }
+        Type::String(_) => true,
+        Type::Union(union) => {
+            check_with_cache(union.lhs(), record_names)?
+                && check_with_cache(union.rhs(), record_names)?
+        }
+        Type::Vector(_) => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::RecordFake, types};
+    use position::{test::PositionFake, Position};
+
+    fn check_type(type_: impl Into<Type>) -> bool {
+        check(&type_.into(), &Default::default(), &Default::default()).unwrap()
+    }
+
+    #[test]
+    fn check_primitive_types() {
+        assert!(check_type(types::Boolean::new(Position::fake())));
+        assert!(check_type(types::None::new(Position::fake())));
+        assert!(check_type(types::Number::new(Position::fake())));
+        assert!(check_type(types::ByteString::new(Position::fake())));
+    }
+
+    #[test]
+    fn check_list_type() {
+        assert!(check_type(types::List::new(
+            types::Number::new(Position::fake()),
+            Position::fake()
+        )));
+    }
+
+    #[test]
+    fn check_map_type() {
+        assert!(check_type(types::Map::new(
+            types::ByteString::new(Position::fake()),
+            types::Number::new(Position::fake()),
+            Position::fake()
+        )));
+    }
+
+    #[test]
+    fn check_map_type_with_non_string_key() {
+        assert!(!check_type(types::Map::new(
+            types::Number::new(Position::fake()),
+            types::Number::new(Position::fake()),
+            Position::fake()
+        )));
+    }
+
+    #[test]
+    fn check_union_type() {
+        assert!(check_type(types::Union::new(
+            types::Number::new(Position::fake()),
+            types::None::new(Position::fake()),
+            Position::fake()
+        )));
+    }
+
+    #[test]
+    fn check_any_type() {
+        assert!(!check_type(types::Any::new(Position::fake())));
+    }
+
+    #[test]
+    fn check_error_type() {
+        assert!(!check_type(types::Error::new(Position::fake())));
+    }
+
+    #[test]
+    fn check_function_type() {
+        assert!(!check_type(types::Function::new(
+            vec![],
+            types::None::new(Position::fake()),
+            Position::fake()
+        )));
+    }
+
+    #[test]
+    fn check_recursive_record_type() {
+        assert!(check(
+            &types::Record::fake("foo").into(),
+            &Default::default(),
+            &[(
+                "foo".into(),
+                vec![types::RecordField::new(
+                    "x",
+                    types::List::new(types::Record::fake("foo"), Position::fake())
+                )]
+            )]
+            .into_iter()
+            .collect()
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn check_record_type_with_function_field() {
+        assert!(!check(
+            &types::Record::fake("foo").into(),
+            &Default::default(),
+            &[(
+                "foo".into(),
+                vec![types::RecordField::new(
+                    "x",
+                    types::Function::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        Position::fake(),
+                    )
+                )]
+            )]
+            .into_iter()
+            .collect()
+        )
+        .unwrap());
+    }
+}
@@ -57,6 +57,11 @@ fn transform_type(type_: &Type, transform: &impl Fn(&Type) -> Type) -> Type {
             union.position().clone(),
         )
         .into(),
+        Type::Vector(vector) => types::Vector::new(
+            transform_deeply(vector.element()),
+            vector.position().clone(),
+        )
+        .into(),
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
@@ -138,9 +143,15 @@ fn transform_lambda(lambda: &Lambda, transform: &impl Fn(&Type) -> Type) -> Lamb
         lambda
             .arguments()
             .iter()
-            .map(|argument| Argument::new(argument.name(), transform(argument.type_())))
+            .map(|argument| {
+                Argument::new(
+                    argument.name(),
+                    argument.type_().map(transform),
+                    argument.position().clone(),
+                )
+            })
             .collect(),
-        transform(lambda.result_type()),
+        lambda.result_type().map(transform),
         transform_expression(lambda.body(), transform),
         lambda.position().clone(),
     )
@@ -330,6 +341,12 @@ fn transform_expression(expression: &Expression, transform: &impl Fn(&Type) -> T
             coercion.position().clone(),
         )
         .into(),
+        Expression::Vector(vector) => Vector::new(
+            transform(vector.type_()),
+            vector.elements().iter().map(transform_expression).collect(),
+            vector.position().clone(),
+        )
+        .into(),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::String(_)
@@ -358,6 +375,7 @@ fn transform_operation(operation: &Operation, transform: &impl Fn(&Type) -> Type
         )
         .into(),
         Operation::Boolean(operation) => BooleanOperation::new(
+            operation.type_().map(transform),
             operation.operator(),
             transform_expression(operation.lhs()),
             transform_expression(operation.rhs()),
@@ -378,6 +396,7 @@ fn transform_operation(operation: &Operation, transform: &impl Fn(&Type) -> Type
         )
         .into(),
         Operation::Order(operation) => OrderOperation::new(
+            operation.type_().map(transform),
             operation.operator(),
             transform_expression(operation.lhs()),
             transform_expression(operation.rhs()),
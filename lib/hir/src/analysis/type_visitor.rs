@@ -57,12 +57,15 @@ fn visit_function_definition<'a>(
 }
 
 fn visit_lambda<'a>(lambda: &'a Lambda, visit: &mut impl FnMut(&'a Type)) {
-    for argument in lambda.arguments() {
-        visit_type(argument.type_(), visit);
+    for type_ in lambda
+        .arguments()
+        .iter()
+        .flat_map(Argument::type_)
+        .chain(lambda.result_type())
+    {
+        visit_type(type_, visit);
     }
 
-    visit_type(lambda.result_type(), visit);
-
     visit_expression(lambda.body(), visit)
 }
 
@@ -244,6 +247,13 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a T
 
             visit_expression(thunk.expression(), visit);
         }
+        Expression::Vector(vector) => {
+            visit_type(vector.type_(), visit);
+
+            for element in vector.elements() {
+                visit_expression(element, visit);
+            }
+        }
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::None(_)
@@ -269,6 +279,7 @@ fn visit_type<'a>(type_: &'a Type, visit: &mut impl FnMut(&'a Type)) {
             visit_type(union.lhs());
             visit_type(union.rhs())
         }
+        Type::Vector(vector) => visit_type(vector.element()),
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
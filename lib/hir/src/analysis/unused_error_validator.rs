@@ -6,7 +6,18 @@ use crate::{
 };
 
 pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
-    for expression in collect_expressions(module) {
+    for definition in module.function_definitions() {
+        validate_function_definition(context, definition)?;
+    }
+
+    Ok(())
+}
+
+pub fn validate_function_definition(
+    context: &AnalysisContext,
+    definition: &FunctionDefinition,
+) -> Result<(), AnalysisError> {
+    for expression in collect_expressions(definition) {
         if let Expression::Let(let_) = expression {
             let position = let_.position();
             let expression = let_.bound_expression();
@@ -32,10 +43,10 @@ pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), Analys
     Ok(())
 }
 
-fn collect_expressions(module: &Module) -> Vec<Expression> {
+fn collect_expressions(definition: &FunctionDefinition) -> Vec<Expression> {
     let mut expressions = vec![];
 
-    expression_visitor::visit(module, |expression| {
+    expression_visitor::visit_function_definition(definition, &mut |expression| {
         if matches!(expression, Expression::Let(_)) {
             expressions.push(expression.clone())
         }
@@ -72,7 +83,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             None,
                             Some(types::None::new(Position::fake()).into()),
@@ -97,7 +108,7 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             None,
                             Some(types::Any::new(Position::fake()).into()),
@@ -121,8 +132,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", types::Error::new(Position::fake()))],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(types::Error::new(Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             None,
                             Some(types::Error::new(Position::fake()).into()),
@@ -152,8 +167,12 @@ mod tests {
                 FunctionDefinition::fake(
                     "f",
                     Lambda::new(
-                        vec![Argument::new("x", result_type.clone())],
-                        types::None::new(Position::fake()),
+                        vec![Argument::new(
+                            "x",
+                            Some(result_type.clone().into()),
+                            Position::fake()
+                        )],
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             None,
                             Some(result_type.into()),
@@ -0,0 +1,428 @@
+use super::AnalysisContext;
+use crate::{
+    analysis::{type_canonicalizer, type_extractor},
+    ir::*,
+    types::{self, Type},
+};
+use position::Position;
+use std::collections::HashMap;
+
+// Collect variable references with their types and the positions of their
+// definitions.
+//
+// This function expects a module whose types are all inferred already.
+pub fn collect(context: &AnalysisContext, module: &Module) -> Vec<(Variable, Type, Position)> {
+    let variables = plist::FlailMap::new(
+        module
+            .function_declarations()
+            .iter()
+            .map(|declaration| {
+                (
+                    declaration.name().into(),
+                    (
+                        declaration.type_().clone().into(),
+                        declaration.position().clone(),
+                    ),
+                )
+            })
+            .chain(module.foreign_declarations().iter().map(|declaration| {
+                (
+                    declaration.name().into(),
+                    (declaration.type_().clone(), declaration.position().clone()),
+                )
+            }))
+            .chain(
+                module
+                    .function_definitions()
+                    .iter()
+                    .filter_map(|definition| {
+                        Some((
+                            definition.name().into(),
+                            (
+                                type_extractor::extract_from_lambda(definition.lambda())
+                                    .ok()?
+                                    .into(),
+                                definition.position().clone(),
+                            ),
+                        ))
+                    }),
+            )
+            .collect::<HashMap<_, _>>(),
+    );
+    let mut references = vec![];
+
+    for definition in module.function_definitions() {
+        collect_from_lambda(context, definition.lambda(), &variables, &mut references);
+    }
+
+    references
+}
+
+fn collect_from_lambda(
+    context: &AnalysisContext,
+    lambda: &Lambda,
+    variables: &plist::FlailMap<String, (Type, Position)>,
+    references: &mut Vec<(Variable, Type, Position)>,
+) {
+    collect_from_expression(
+        context,
+        lambda.body(),
+        &variables.insert_iter(lambda.arguments().iter().filter_map(|argument| {
+            Some((
+                argument.name().into(),
+                (argument.type_()?.clone(), argument.position().clone()),
+            ))
+        })),
+        references,
+    )
+}
+
+fn collect_from_expression(
+    context: &AnalysisContext,
+    expression: &Expression,
+    variables: &plist::FlailMap<String, (Type, Position)>,
+    references: &mut Vec<(Variable, Type, Position)>,
+) {
+    let mut collect = |expression, variables: &_| {
+        collect_from_expression(context, expression, variables, references)
+    };
+
+    match expression {
+        Expression::Call(call) => {
+            collect(call.function(), variables);
+
+            for argument in call.arguments() {
+                collect(argument, variables);
+            }
+        }
+        Expression::If(if_) => {
+            collect(if_.condition(), variables);
+            collect(if_.then(), variables);
+            collect(if_.else_(), variables);
+        }
+        Expression::IfList(if_) => {
+            collect(if_.list(), variables);
+
+            if let Some(type_) = if_.type_() {
+                collect(
+                    if_.then(),
+                    &variables.insert_iter([
+                        (
+                            if_.first_name().into(),
+                            (
+                                types::Function::new(vec![], type_.clone(), if_.position().clone())
+                                    .into(),
+                                if_.position().clone(),
+                            ),
+                        ),
+                        (
+                            if_.rest_name().into(),
+                            (
+                                types::List::new(type_.clone(), if_.position().clone()).into(),
+                                if_.position().clone(),
+                            ),
+                        ),
+                    ]),
+                );
+            }
+
+            collect(if_.else_(), variables);
+        }
+        Expression::IfMap(if_) => {
+            collect(if_.map(), variables);
+            collect(if_.key(), variables);
+
+            if let Some(type_) = if_.value_type() {
+                collect(
+                    if_.then(),
+                    &variables.insert(if_.name().into(), (type_.clone(), if_.position().clone())),
+                );
+            }
+
+            collect(if_.else_(), variables);
+        }
+        Expression::IfType(if_) => {
+            collect(if_.argument(), variables);
+
+            for branch in if_.branches() {
+                collect(
+                    branch.expression(),
+                    &variables.insert(
+                        if_.name().into(),
+                        (branch.type_().clone(), if_.position().clone()),
+                    ),
+                );
+            }
+
+            if let Some(branch) = if_.else_() {
+                if let Some(type_) = branch.type_() {
+                    collect(
+                        branch.expression(),
+                        &variables
+                            .insert(if_.name().into(), (type_.clone(), if_.position().clone())),
+                    );
+                }
+            }
+        }
+        Expression::Lambda(lambda) => collect_from_lambda(context, lambda, variables, references),
+        Expression::Let(let_) => {
+            collect(let_.bound_expression(), variables);
+            collect(
+                let_.expression(),
+                &variables.insert_iter(
+                    let_.name().zip(let_.type_()).map(|(name, type_)| {
+                        (name.into(), (type_.clone(), let_.position().clone()))
+                    }),
+                ),
+            );
+        }
+        Expression::List(list) => {
+            for element in list.elements() {
+                match element {
+                    ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                        collect(expression, variables)
+                    }
+                }
+            }
+        }
+        Expression::ListComprehension(comprehension) => {
+            let mut variables = variables.clone();
+
+            for branch in comprehension.branches() {
+                for (name, iteratee) in branch.names().iter().zip(branch.iteratees()) {
+                    collect(iteratee.expression(), &variables);
+
+                    if let Some(type_) = iteratee.type_().and_then(|type_| {
+                        type_canonicalizer::canonicalize_list(type_, context.types())
+                            .ok()
+                            .flatten()
+                    }) {
+                        variables = variables.insert(
+                            name.into(),
+                            (
+                                types::Function::new(
+                                    vec![],
+                                    type_.element().clone(),
+                                    iteratee.position().clone(),
+                                )
+                                .into(),
+                                branch.position().clone(),
+                            ),
+                        );
+                    }
+                }
+
+                if let Some(condition) = branch.condition() {
+                    collect(condition, &variables);
+                }
+            }
+
+            collect(comprehension.element(), &variables);
+        }
+        Expression::Map(map) => {
+            for element in map.elements() {
+                match element {
+                    MapElement::Single(entry) => {
+                        collect(entry.key(), variables);
+                        collect(entry.value(), variables);
+                    }
+                    MapElement::Multiple(expression) => collect(expression, variables),
+                }
+            }
+        }
+        Expression::Operation(operation) => match operation {
+            Operation::Addition(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Arithmetic(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Boolean(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Equality(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Not(operation) => collect(operation.expression(), variables),
+            Operation::Order(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Try(operation) => collect(operation.expression(), variables),
+        },
+        Expression::RecordConstruction(construction) => {
+            for field in construction.fields() {
+                collect(field.expression(), variables);
+            }
+        }
+        Expression::RecordDeconstruction(deconstruction) => {
+            collect(deconstruction.record(), variables)
+        }
+        Expression::RecordUpdate(update) => {
+            collect(update.record(), variables);
+
+            for field in update.fields() {
+                collect(field.expression(), variables);
+            }
+        }
+        Expression::Thunk(thunk) => collect(thunk.expression(), variables),
+        Expression::TypeCoercion(coercion) => collect(coercion.argument(), variables),
+        Expression::Variable(variable) => {
+            if let Some((type_, position)) = variables.get(variable.name()) {
+                references.push((variable.clone(), type_.clone(), position.clone()));
+            }
+        }
+        Expression::Vector(vector) => {
+            for element in vector.elements() {
+                collect(element, variables);
+            }
+        }
+        Expression::Boolean(_)
+        | Expression::BuiltInFunction(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::String(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::type_collector,
+        test::{FunctionDefinitionFake, ModuleFake},
+    };
+    use position::test::PositionFake;
+    use pretty_assertions::assert_eq;
+
+    fn collect_module(module: &Module) -> Vec<(Variable, Type, Position)> {
+        collect(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_record_fields(module),
+            ),
+            module,
+        )
+    }
+
+    #[test]
+    fn collect_nothing() {
+        assert_eq!(collect_module(&Module::empty()), vec![]);
+    }
+
+    #[test]
+    fn collect_argument() {
+        let line = "f = \\(x number) number { x }";
+        let position = Position::new("foo.pen", 1, 7, line);
+
+        let references = collect_module(&Module::empty().set_function_definitions(vec![
+            FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![Argument::new(
+                        "x",
+                        Some(types::Number::new(Position::fake()).into()),
+                        position.clone(),
+                    )],
+                    Some(types::Number::new(Position::fake()).into()),
+                    Variable::new("x", Position::fake()),
+                    Position::new("foo.pen", 1, 5, line),
+                ),
+                false,
+            ),
+        ]));
+
+        assert_eq!(
+            references,
+            vec![(
+                Variable::new("x", Position::fake()),
+                types::Number::new(Position::fake()).into(),
+                position,
+            )]
+        );
+        assert_eq!(references[0].2.column_number(), 7);
+    }
+
+    #[test]
+    fn collect_let() {
+        assert_eq!(
+            collect_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        Let::new(
+                            Some("x".into()),
+                            Some(types::None::new(Position::fake()).into()),
+                            None::new(Position::fake()),
+                            Variable::new("x", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            vec![(
+                Variable::new("x", Position::fake()),
+                types::None::new(Position::fake()).into(),
+                Position::fake(),
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_function() {
+        let function_type =
+            types::Function::new(vec![], types::None::new(Position::fake()), Position::fake());
+
+        assert_eq!(
+            collect_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        Call::new(
+                            Some(function_type.clone().into()),
+                            Variable::new("f", Position::fake()),
+                            vec![],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            vec![(
+                Variable::new("f", Position::fake()),
+                function_type.into(),
+                Position::fake(),
+            )]
+        );
+    }
+
+    #[test]
+    fn skip_unknown_variable() {
+        assert_eq!(
+            collect_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        Some(types::None::new(Position::fake()).into()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            vec![]
+        );
+    }
+}
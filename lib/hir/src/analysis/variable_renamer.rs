@@ -30,7 +30,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Variable::new("x", Position::fake()),
                         Position::fake()
                     ),
@@ -42,7 +42,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     Variable::new("foo.x", Position::fake()),
                     Position::fake()
                 ),
@@ -56,8 +56,12 @@ mod tests {
         let module = Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
             "x",
             Lambda::new(
-                vec![Argument::new("x", types::None::new(Position::fake()))],
-                types::None::new(Position::fake()),
+                vec![Argument::new(
+                    "x",
+                    Some(types::None::new(Position::fake()).into()),
+                    Position::fake(),
+                )],
+                Some(types::None::new(Position::fake()).into()),
                 Variable::new("x", Position::fake()),
                 Position::fake(),
             ),
@@ -81,7 +85,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Let::new(
                             Some("x".into()),
                             None,
@@ -99,7 +103,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     Let::new(
                         Some("x".into()),
                         None,
@@ -120,7 +124,7 @@ mod tests {
             "x",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 Let::new(
                     Some("x".into()),
                     None,
@@ -32,7 +32,7 @@ fn transform_function_definition(
 fn transform_lambda(lambda: &Lambda, transform: &dyn Fn(&Variable) -> Expression) -> Lambda {
     Lambda::new(
         lambda.arguments().to_vec(),
-        lambda.result_type().clone(),
+        lambda.result_type().cloned(),
         transform_expression(lambda.body(), &|variable| {
             if lambda
                 .arguments()
@@ -282,6 +282,16 @@ fn transform_expression(
         )
         .into(),
         Expression::Variable(variable) => transform(variable),
+        Expression::Vector(vector) => Vector::new(
+            vector.type_().clone(),
+            vector
+                .elements()
+                .iter()
+                .map(|element| transform_expression(element, transform))
+                .collect(),
+            vector.position().clone(),
+        )
+        .into(),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::String(_)
@@ -310,6 +320,7 @@ fn transform_operation(
         )
         .into(),
         Operation::Boolean(operation) => BooleanOperation::new(
+            operation.type_().cloned(),
             operation.operator(),
             transform_expression(operation.lhs(), transform),
             transform_expression(operation.rhs(), transform),
@@ -330,6 +341,7 @@ fn transform_operation(
         )
         .into(),
         Operation::Order(operation) => OrderOperation::new(
+            operation.type_().cloned(),
             operation.operator(),
             transform_expression(operation.lhs(), transform),
             transform_expression(operation.rhs(), transform),
@@ -363,7 +375,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         Variable::new("x", Position::fake()),
                         Position::fake()
                     ),
@@ -379,7 +391,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     Variable::new("y", Position::fake()),
                     Position::fake()
                 ),
@@ -393,8 +405,12 @@ mod tests {
         let module = Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
             "x",
             Lambda::new(
-                vec![Argument::new("x", types::None::new(Position::fake()))],
-                types::None::new(Position::fake()),
+                vec![Argument::new(
+                    "x",
+                    Some(types::None::new(Position::fake()).into()),
+                    Position::fake(),
+                )],
+                Some(types::None::new(Position::fake()).into()),
                 Variable::new("x", Position::fake()),
                 Position::fake(),
             ),
@@ -417,7 +433,7 @@ mod tests {
             "x",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 Let::new(
                     Some("x".into()),
                     None,
@@ -446,7 +462,7 @@ mod tests {
             "x",
             Lambda::new(
                 vec![],
-                types::None::new(Position::fake()),
+                Some(types::None::new(Position::fake()).into()),
                 Let::new(
                     Some("x".into()),
                     None,
@@ -480,7 +496,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         ListComprehension::new(
                             types::Number::new(Position::fake()),
                             Variable::new("x", Position::fake()),
@@ -518,7 +534,7 @@ mod tests {
                     "x",
                     Lambda::new(
                         vec![],
-                        types::None::new(Position::fake()),
+                        Some(types::None::new(Position::fake()).into()),
                         ListComprehension::new(
                             types::Number::new(Position::fake()),
                             Variable::new("x", Position::fake()),
@@ -555,7 +571,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     ListComprehension::new(
                         types::Number::new(Position::fake()),
                         Variable::new("x", Position::fake()),
@@ -591,7 +607,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     ListComprehension::new(
                         types::None::new(Position::fake()),
                         Variable::new("x", Position::fake()),
@@ -627,7 +643,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     ListComprehension::new(
                         types::Number::new(Position::fake()),
                         Variable::new("v", Position::fake()),
@@ -663,7 +679,7 @@ mod tests {
                 "x",
                 Lambda::new(
                     vec![],
-                    types::None::new(Position::fake()),
+                    Some(types::None::new(Position::fake()).into()),
                     ListComprehension::new(
                         types::Number::new(Position::fake()),
                         Variable::new("y", Position::fake()),
@@ -0,0 +1,471 @@
+use super::AnalysisWarning;
+use crate::ir::*;
+use position::Position;
+use std::collections::HashMap;
+
+const IGNORED_VARIABLE_PREFIX: &str = "_";
+
+struct Binding {
+    name: String,
+    position: Position,
+    shadowing: bool,
+    used: bool,
+}
+
+// Collect warnings of unused and shadowed local variables.
+//
+// Variables whose names start with `_` are ignored. Variables rebound to
+// themselves by if-type expressions or to their rests by if-list expressions
+// are not considered as shadowed.
+pub fn collect(module: &Module) -> Vec<AnalysisWarning> {
+    module
+        .function_definitions()
+        .iter()
+        .flat_map(collect_function_definition)
+        .collect()
+}
+
+pub fn collect_function_definition(definition: &FunctionDefinition) -> Vec<AnalysisWarning> {
+    let mut bindings = vec![];
+    let mut warnings = vec![];
+
+    collect_from_lambda(
+        definition.lambda(),
+        &plist::FlailMap::new(HashMap::new()),
+        &mut bindings,
+    );
+
+    for binding in bindings {
+        if binding.shadowing {
+            warnings.push(AnalysisWarning::ShadowedVariable(
+                binding.name.clone(),
+                binding.position.clone(),
+            ));
+        }
+
+        if !binding.used {
+            warnings.push(AnalysisWarning::UnusedVariable(
+                binding.name,
+                binding.position,
+            ));
+        }
+    }
+
+    warnings
+}
+
+fn collect_from_lambda(
+    lambda: &Lambda,
+    variables: &plist::FlailMap<String, usize>,
+    bindings: &mut Vec<Binding>,
+) {
+    let mut variables = variables.clone();
+
+    for argument in lambda.arguments() {
+        variables = bind(
+            argument.name(),
+            argument.position(),
+            false,
+            &variables,
+            bindings,
+        );
+    }
+
+    collect_from_expression(lambda.body(), &variables, bindings)
+}
+
+fn collect_from_expression(
+    expression: &Expression,
+    variables: &plist::FlailMap<String, usize>,
+    bindings: &mut Vec<Binding>,
+) {
+    let collect = collect_from_expression;
+
+    match expression {
+        Expression::Call(call) => {
+            collect(call.function(), variables, bindings);
+
+            for argument in call.arguments() {
+                collect(argument, variables, bindings);
+            }
+        }
+        Expression::If(if_) => {
+            collect(if_.condition(), variables, bindings);
+            collect(if_.then(), variables, bindings);
+            collect(if_.else_(), variables, bindings);
+        }
+        Expression::IfList(if_) => {
+            collect(if_.list(), variables, bindings);
+
+            let then_variables = bind(if_.first_name(), if_.position(), false, variables, bindings);
+            let then_variables = bind(
+                if_.rest_name(),
+                if_.position(),
+                matches!(
+                    if_.list(),
+                    Expression::Variable(variable) if variable.name() == if_.rest_name()
+                ),
+                &then_variables,
+                bindings,
+            );
+
+            collect(if_.then(), &then_variables, bindings);
+            collect(if_.else_(), variables, bindings);
+        }
+        Expression::IfMap(if_) => {
+            collect(if_.map(), variables, bindings);
+            collect(if_.key(), variables, bindings);
+
+            let then_variables = bind(if_.name(), if_.position(), false, variables, bindings);
+
+            collect(if_.then(), &then_variables, bindings);
+            collect(if_.else_(), variables, bindings);
+        }
+        Expression::IfType(if_) => {
+            collect(if_.argument(), variables, bindings);
+
+            let branch_variables = bind(
+                if_.name(),
+                if_.position(),
+                matches!(
+                    if_.argument(),
+                    Expression::Variable(variable) if variable.name() == if_.name()
+                ),
+                variables,
+                bindings,
+            );
+
+            for branch in if_.branches() {
+                collect(branch.expression(), &branch_variables, bindings);
+            }
+
+            if let Some(branch) = if_.else_() {
+                collect(branch.expression(), &branch_variables, bindings);
+            }
+        }
+        Expression::Lambda(lambda) => collect_from_lambda(lambda, variables, bindings),
+        Expression::Let(let_) => {
+            collect(let_.bound_expression(), variables, bindings);
+
+            let variables = if let Some(name) = let_.name() {
+                bind(name, let_.position(), false, variables, bindings)
+            } else {
+                variables.clone()
+            };
+
+            collect(let_.expression(), &variables, bindings);
+        }
+        Expression::List(list) => {
+            for element in list.elements() {
+                match element {
+                    ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                        collect(expression, variables, bindings)
+                    }
+                }
+            }
+        }
+        Expression::ListComprehension(comprehension) => {
+            let mut variables = variables.clone();
+
+            for branch in comprehension.branches() {
+                for iteratee in branch.iteratees() {
+                    collect(iteratee.expression(), &variables, bindings);
+                }
+
+                for name in branch.names() {
+                    variables = bind(name, branch.position(), false, &variables, bindings);
+                }
+
+                if let Some(condition) = branch.condition() {
+                    collect(condition, &variables, bindings);
+                }
+            }
+
+            collect(comprehension.element(), &variables, bindings);
+        }
+        Expression::Map(map) => {
+            for element in map.elements() {
+                match element {
+                    MapElement::Single(entry) => {
+                        collect(entry.key(), variables, bindings);
+                        collect(entry.value(), variables, bindings);
+                    }
+                    MapElement::Multiple(expression) => collect(expression, variables, bindings),
+                }
+            }
+        }
+        Expression::Operation(operation) => match operation {
+            Operation::Addition(operation) => {
+                collect(operation.lhs(), variables, bindings);
+                collect(operation.rhs(), variables, bindings);
+            }
+            Operation::Arithmetic(operation) => {
+                collect(operation.lhs(), variables, bindings);
+                collect(operation.rhs(), variables, bindings);
+            }
+            Operation::Boolean(operation) => {
+                collect(operation.lhs(), variables, bindings);
+                collect(operation.rhs(), variables, bindings);
+            }
+            Operation::Equality(operation) => {
+                collect(operation.lhs(), variables, bindings);
+                collect(operation.rhs(), variables, bindings);
+            }
+            Operation::Not(operation) => collect(operation.expression(), variables, bindings),
+            Operation::Order(operation) => {
+                collect(operation.lhs(), variables, bindings);
+                collect(operation.rhs(), variables, bindings);
+            }
+            Operation::Try(operation) => collect(operation.expression(), variables, bindings),
+        },
+        Expression::RecordConstruction(construction) => {
+            for field in construction.fields() {
+                collect(field.expression(), variables, bindings);
+            }
+        }
+        Expression::RecordDeconstruction(deconstruction) => {
+            collect(deconstruction.record(), variables, bindings)
+        }
+        Expression::RecordUpdate(update) => {
+            collect(update.record(), variables, bindings);
+
+            for field in update.fields() {
+                collect(field.expression(), variables, bindings);
+            }
+        }
+        Expression::Thunk(thunk) => collect(thunk.expression(), variables, bindings),
+        Expression::TypeCoercion(coercion) => collect(coercion.argument(), variables, bindings),
+        Expression::Variable(variable) => {
+            if let Some(&index) = variables.get(variable.name()) {
+                bindings[index].used = true;
+            }
+        }
+        Expression::Vector(vector) => {
+            for element in vector.elements() {
+                collect(element, variables, bindings);
+            }
+        }
+        Expression::Boolean(_)
+        | Expression::BuiltInFunction(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::String(_) => {}
+    }
+}
+
+fn bind(
+    name: &str,
+    position: &Position,
+    rebound: bool,
+    variables: &plist::FlailMap<String, usize>,
+    bindings: &mut Vec<Binding>,
+) -> plist::FlailMap<String, usize> {
+    if name.starts_with(IGNORED_VARIABLE_PREFIX) {
+        return variables.clone();
+    }
+
+    bindings.push(Binding {
+        name: name.into(),
+        position: position.clone(),
+        shadowing: !rebound && variables.get(name).is_some(),
+        used: false,
+    });
+
+    variables.insert(name.into(), bindings.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::{FunctionDefinitionFake, ModuleFake},
+        types::{self, Type},
+    };
+    use position::test::PositionFake;
+    use pretty_assertions::assert_eq;
+
+    fn collect_lambda(lambda: Lambda) -> Vec<AnalysisWarning> {
+        collect(
+            &Module::empty()
+                .set_function_definitions(vec![FunctionDefinition::fake("f", lambda, false)]),
+        )
+    }
+
+    fn create_lambda(arguments: Vec<Argument>, body: impl Into<Expression>) -> Lambda {
+        Lambda::new(
+            arguments,
+            Some(types::None::new(Position::fake()).into()),
+            body,
+            Position::fake(),
+        )
+    }
+
+    fn create_argument(name: &str) -> Argument {
+        Argument::new(
+            name,
+            Some(Type::from(types::None::new(Position::fake()))),
+            Position::fake(),
+        )
+    }
+
+    #[test]
+    fn collect_nothing() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![create_argument("x")],
+                Variable::new("x", Position::fake())
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_unused_argument() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![create_argument("x")],
+                None::new(Position::fake())
+            )),
+            vec![AnalysisWarning::UnusedVariable(
+                "x".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_ignored_argument() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![create_argument("_x")],
+                None::new(Position::fake())
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_unused_let_variable() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![],
+                Let::new(
+                    Some("x".into()),
+                    None,
+                    None::new(Position::fake()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                )
+            )),
+            vec![AnalysisWarning::UnusedVariable(
+                "x".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_unused_variable_in_nested_lambda() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![create_argument("x")],
+                create_lambda(
+                    vec![create_argument("y")],
+                    Variable::new("x", Position::fake())
+                )
+            )),
+            vec![AnalysisWarning::UnusedVariable(
+                "y".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_shadowed_variable() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![create_argument("x")],
+                Let::new(
+                    Some("x".into()),
+                    None,
+                    Variable::new("x", Position::fake()),
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                )
+            )),
+            vec![AnalysisWarning::ShadowedVariable(
+                "x".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_variable_rebound_by_if_type() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![create_argument("x")],
+                IfType::new(
+                    "x",
+                    Variable::new("x", Position::fake()),
+                    vec![IfTypeBranch::new(
+                        types::None::new(Position::fake()),
+                        Variable::new("x", Position::fake()),
+                    )],
+                    None,
+                    Position::fake(),
+                )
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_nothing_for_variable_rebound_by_if_list() {
+        let list_type = types::List::new(types::None::new(Position::fake()), Position::fake());
+
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![Argument::new(
+                    "xs",
+                    Some(list_type.into()),
+                    Position::fake()
+                )],
+                IfList::new(
+                    None,
+                    Variable::new("xs", Position::fake()),
+                    "_x",
+                    "xs",
+                    Variable::new("xs", Position::fake()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                )
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collect_shadowed_variable_in_if_type() {
+        assert_eq!(
+            collect_lambda(create_lambda(
+                vec![create_argument("x"), create_argument("y")],
+                IfType::new(
+                    "x",
+                    Variable::new("y", Position::fake()),
+                    vec![IfTypeBranch::new(
+                        types::None::new(Position::fake()),
+                        Variable::new("x", Position::fake()),
+                    )],
+                    None,
+                    Position::fake(),
+                )
+            )),
+            vec![
+                AnalysisWarning::UnusedVariable("x".into(), Position::fake()),
+                AnalysisWarning::ShadowedVariable("x".into(), Position::fake()),
+            ]
+        );
+    }
+}
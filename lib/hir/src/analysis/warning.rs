@@ -0,0 +1,115 @@
+use super::Severity;
+use position::Position;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnalysisWarning {
+    AlwaysFalseComparison(Position),
+    AlwaysTrueComparison(Position),
+    RedundantIfTypeBranch(Position),
+    ShadowedVariable(String, Position),
+    UnreachableElseBlock(Position),
+    UnusedFunction(String, Position),
+    UnusedImport(Position),
+    UnusedType(String, Position),
+    UnusedUnqualifiedName(String, Position),
+    UnusedVariable(String, Position),
+}
+
+impl AnalysisWarning {
+    // Warning codes are stable and never reused for other warnings.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::RedundantIfTypeBranch(_) => "W0001",
+            Self::UnreachableElseBlock(_) => "W0002",
+            Self::UnusedVariable(_, _) => "W0003",
+            Self::UnusedImport(_) => "W0004",
+            Self::UnusedUnqualifiedName(_, _) => "W0005",
+            Self::UnusedFunction(_, _) => "W0006",
+            Self::UnusedType(_, _) => "W0007",
+            Self::ShadowedVariable(_, _) => "W0008",
+            Self::AlwaysTrueComparison(_) => "W0009",
+            Self::AlwaysFalseComparison(_) => "W0010",
+        }
+    }
+
+    // Lint names are used to suppress warnings in source files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AlwaysFalseComparison(_) | Self::AlwaysTrueComparison(_) => "constant-comparison",
+            Self::RedundantIfTypeBranch(_) => "redundant-branch",
+            Self::ShadowedVariable(_, _) => "shadowed-variable",
+            Self::UnreachableElseBlock(_) => "unreachable-else",
+            Self::UnusedFunction(_, _) => "unused-function",
+            Self::UnusedImport(_) => "unused-import",
+            Self::UnusedType(_, _) => "unused-type",
+            Self::UnusedUnqualifiedName(_, _) => "unused-name",
+            Self::UnusedVariable(_, _) => "unused-variable",
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::ShadowedVariable(_, _) => Severity::Information,
+            _ => Severity::Warning,
+        }
+    }
+
+    pub fn position(&self) -> &Position {
+        match self {
+            Self::AlwaysFalseComparison(position)
+            | Self::AlwaysTrueComparison(position)
+            | Self::RedundantIfTypeBranch(position)
+            | Self::ShadowedVariable(_, position)
+            | Self::UnreachableElseBlock(position)
+            | Self::UnusedFunction(_, position)
+            | Self::UnusedImport(position)
+            | Self::UnusedType(_, position)
+            | Self::UnusedUnqualifiedName(_, position)
+            | Self::UnusedVariable(_, position) => position,
+        }
+    }
+}
+
+impl Display for AnalysisWarning {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::AlwaysFalseComparison(position) => {
+                write!(formatter, "comparison always false\n{position}")
+            }
+            Self::AlwaysTrueComparison(position) => {
+                write!(formatter, "comparison always true\n{position}")
+            }
+            Self::RedundantIfTypeBranch(position) => {
+                write!(
+                    formatter,
+                    "branch type already covered by previous branches\n{position}"
+                )
+            }
+            Self::ShadowedVariable(name, position) => {
+                write!(formatter, "variable \"{name}\" shadowed\n{position}")
+            }
+            Self::UnreachableElseBlock(position) => {
+                write!(formatter, "unreachable else block\n{position}")
+            }
+            Self::UnusedFunction(name, position) => {
+                write!(formatter, "function \"{name}\" never used\n{position}")
+            }
+            Self::UnusedImport(position) => {
+                write!(formatter, "unused import\n{position}")
+            }
+            Self::UnusedType(name, position) => {
+                write!(formatter, "type \"{name}\" never used\n{position}")
+            }
+            Self::UnusedUnqualifiedName(name, position) => {
+                write!(
+                    formatter,
+                    "unqualified name \"{name}\" never used\n{position}"
+                )
+            }
+            Self::UnusedVariable(name, position) => {
+                write!(formatter, "variable \"{name}\" never used\n{position}")
+            }
+        }
+    }
+}
@@ -45,6 +45,7 @@ mod type_alias;
 mod type_coercion;
 mod type_definition;
 mod variable;
+mod vector;
 
 pub use addition_operation::*;
 pub use argument::*;
@@ -93,3 +94,4 @@ pub use type_alias::*;
 pub use type_coercion::*;
 pub use type_definition::*;
 pub use variable::*;
+pub use vector::*;
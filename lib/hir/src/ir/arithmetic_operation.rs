@@ -7,6 +7,11 @@ pub enum ArithmeticOperator {
     Subtract,
     Multiply,
     Divide,
+    IntegerDivide,
+    Remainder,
+    Xor,
+    LeftShift,
+    RightShift,
 }
 
 #[derive(Clone, Debug, PartialEq)]
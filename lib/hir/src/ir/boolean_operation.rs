@@ -1,4 +1,5 @@
 use super::expression::Expression;
+use crate::types::Type;
 use position::Position;
 use std::rc::Rc;
 
@@ -10,6 +11,7 @@ pub enum BooleanOperator {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct BooleanOperation {
+    type_: Option<Type>,
     operator: BooleanOperator,
     lhs: Rc<Expression>,
     rhs: Rc<Expression>,
@@ -18,12 +20,14 @@ pub struct BooleanOperation {
 
 impl BooleanOperation {
     pub fn new(
+        type_: Option<Type>,
         operator: BooleanOperator,
         lhs: impl Into<Expression>,
         rhs: impl Into<Expression>,
         position: Position,
     ) -> Self {
         Self {
+            type_,
             operator,
             lhs: lhs.into().into(),
             rhs: rhs.into().into(),
@@ -31,6 +35,10 @@ impl BooleanOperation {
         }
     }
 
+    pub fn type_(&self) -> Option<&Type> {
+        self.type_.as_ref()
+    }
+
     pub fn operator(&self) -> BooleanOperator {
         self.operator
     }
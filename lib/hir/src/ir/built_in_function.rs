@@ -3,13 +3,19 @@ use position::Position;
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum BuiltInFunctionName {
     Debug,
+    Decode,
     Delete,
+    Encode,
     Error,
+    Get,
     Keys,
+    Push,
     Race,
     ReflectDebug,
     ReflectEqual,
+    Set,
     Size,
+    Slice,
     Source,
     Spawn,
     Values,
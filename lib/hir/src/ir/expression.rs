@@ -3,7 +3,7 @@ use super::{
     list::List, map::Map, none::None, number::Number, operation::Operation,
     record_construction::RecordConstruction, record_deconstruction::RecordDeconstruction,
     record_update::RecordUpdate, string::ByteString, type_coercion::TypeCoercion,
-    variable::Variable, BuiltInFunction, Lambda, Let, ListComprehension, Thunk,
+    variable::Variable, vector::Vector, BuiltInFunction, Lambda, Let, ListComprehension, Thunk,
 };
 use position::Position;
 
@@ -31,6 +31,7 @@ pub enum Expression {
     Thunk(Thunk),
     TypeCoercion(TypeCoercion),
     Variable(Variable),
+    Vector(Vector),
 }
 
 impl Expression {
@@ -58,6 +59,7 @@ impl Expression {
             Self::Thunk(thunk) => thunk.position(),
             Self::TypeCoercion(coercion) => coercion.position(),
             Self::Variable(variable) => variable.position(),
+            Self::Vector(vector) => vector.position(),
         }
     }
 }
@@ -193,3 +195,9 @@ impl From<Variable> for Expression {
         Self::Variable(variable)
     }
 }
+
+impl From<Vector> for Expression {
+    fn from(vector: Vector) -> Self {
+        Self::Vector(vector)
+    }
+}
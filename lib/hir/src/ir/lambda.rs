@@ -6,7 +6,7 @@ use std::rc::Rc;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Lambda {
     arguments: Vec<Argument>,
-    result_type: Type,
+    result_type: Option<Type>,
     body: Rc<Expression>,
     position: Position,
 }
@@ -14,13 +14,13 @@ pub struct Lambda {
 impl Lambda {
     pub fn new(
         arguments: Vec<Argument>,
-        result_type: impl Into<Type>,
+        result_type: Option<Type>,
         body: impl Into<Expression>,
         position: Position,
     ) -> Self {
         Self {
             arguments,
-            result_type: result_type.into(),
+            result_type,
             body: body.into().into(),
             position,
         }
@@ -30,8 +30,8 @@ impl Lambda {
         &self.arguments
     }
 
-    pub fn result_type(&self) -> &Type {
-        &self.result_type
+    pub fn result_type(&self) -> Option<&Type> {
+        self.result_type.as_ref()
     }
 
     pub fn body(&self) -> &Expression {
@@ -1,4 +1,5 @@
 use super::expression::Expression;
+use crate::types::Type;
 use position::Position;
 use std::rc::Rc;
 
@@ -12,6 +13,7 @@ pub enum OrderOperator {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct OrderOperation {
+    type_: Option<Type>,
     operator: OrderOperator,
     lhs: Rc<Expression>,
     rhs: Rc<Expression>,
@@ -20,12 +22,14 @@ pub struct OrderOperation {
 
 impl OrderOperation {
     pub fn new(
+        type_: Option<Type>,
         operator: OrderOperator,
         lhs: impl Into<Expression>,
         rhs: impl Into<Expression>,
         position: Position,
     ) -> Self {
         Self {
+            type_,
             operator,
             lhs: lhs.into().into(),
             rhs: rhs.into().into(),
@@ -33,6 +37,10 @@ impl OrderOperation {
         }
     }
 
+    pub fn type_(&self) -> Option<&Type> {
+        self.type_.as_ref()
+    }
+
     pub fn operator(&self) -> OrderOperator {
         self.operator
     }
@@ -12,6 +12,7 @@ mod record_field;
 mod reference;
 mod type_;
 mod union;
+mod vector;
 
 pub use any::*;
 pub use boolean::*;
@@ -27,3 +28,4 @@ pub use record_field::*;
 pub use reference::*;
 pub use type_::*;
 pub use union::*;
+pub use vector::*;
@@ -1,7 +1,7 @@
 use super::{
     any::Any, boolean::Boolean, byte_string::ByteString, error::Error, function::Function,
     list::List, map::Map, none::None, number::Number, record::Record, reference::Reference,
-    union::Union,
+    union::Union, vector::Vector,
 };
 use position::Position;
 use serde::{Deserialize, Serialize};
@@ -20,6 +20,7 @@ pub enum Type {
     Reference(Reference),
     String(ByteString),
     Union(Union),
+    Vector(Vector),
 }
 
 impl Type {
@@ -37,6 +38,7 @@ impl Type {
             Self::Reference(reference) => reference.position(),
             Self::String(string) => string.position(),
             Self::Union(union) => union.position(),
+            Self::Vector(vector) => vector.position(),
         }
     }
 
@@ -54,6 +56,7 @@ impl Type {
             Self::Reference(reference) => reference.set_position(position).into(),
             Self::String(string) => string.set_position(position).into(),
             Self::Union(union) => union.set_position(position).into(),
+            Self::Vector(vector) => vector.set_position(position).into(),
         }
     }
 
@@ -78,6 +81,13 @@ impl Type {
         }
     }
 
+    pub fn into_vector(self) -> Option<Vector> {
+        match self {
+            Self::Vector(vector) => Some(vector),
+            _ => None,
+        }
+    }
+
     pub fn into_record(self) -> Option<Record> {
         match self {
             Self::Record(record) => Some(record),
@@ -101,6 +111,10 @@ impl Type {
         matches!(self, Self::Map(_))
     }
 
+    pub fn is_vector(&self) -> bool {
+        matches!(self, Self::Vector(_))
+    }
+
     pub fn is_record(&self) -> bool {
         matches!(self, Self::Record(_))
     }
@@ -193,3 +207,9 @@ impl From<Union> for Type {
         Self::Union(union)
     }
 }
+
+impl From<Vector> for Type {
+    fn from(vector: Vector) -> Self {
+        Self::Vector(vector)
+    }
+}
@@ -0,0 +1,43 @@
+use super::Type;
+use position::Position;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Vector(Rc<VectorInner>);
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+struct VectorInner {
+    element: Type,
+    position: Position,
+}
+
+impl Vector {
+    pub fn new(element: impl Into<Type>, position: Position) -> Self {
+        Self(
+            VectorInner {
+                element: element.into(),
+                position,
+            }
+            .into(),
+        )
+    }
+
+    pub fn element(&self) -> &Type {
+        &self.0.element
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.0.position
+    }
+
+    pub fn set_position(&self, position: Position) -> Self {
+        Self(
+            VectorInner {
+                element: self.0.element.clone(),
+                position,
+            }
+            .into(),
+        )
+    }
+}
@@ -20,9 +20,14 @@ impl CommandRunner {
 }
 
 impl app::infra::CommandRunner for CommandRunner {
-    fn run(&self, executable_file: &app::infra::FilePath) -> Result<(), Box<dyn Error>> {
+    fn run(
+        &self,
+        executable_file: &app::infra::FilePath,
+        arguments: &[String],
+    ) -> Result<(), Box<dyn Error>> {
         run_command(
             Command::new(self.file_path_converter.convert_to_os_path(executable_file))
+                .args(arguments)
                 .stdout(Stdio::inherit())
                 .stderr(Stdio::inherit()),
         )?;
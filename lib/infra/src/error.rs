@@ -12,6 +12,9 @@ pub enum InfrastructureError {
     ReadFile { path: PathBuf, source: io::Error },
     MultipleFfiBuildScripts(PathBuf),
     MultipleLinkScripts(Vec<PathBuf>),
+    MultiplePackageReferences,
+    PackageHashNotMatched(url::Url),
+    PackageNotCached(url::Url),
     WriteFile { path: PathBuf, source: io::Error },
 }
 
@@ -28,6 +31,9 @@ impl Error for InfrastructureError {
             Self::ReadFile { path: _, source } => Some(source),
             Self::MultipleFfiBuildScripts(_) => None,
             Self::MultipleLinkScripts(_) => None,
+            Self::MultiplePackageReferences => None,
+            Self::PackageHashNotMatched(_) => None,
+            Self::PackageNotCached(_) => None,
             Self::WriteFile { path: _, source } => Some(source),
         }
     }
@@ -85,6 +91,21 @@ impl Display for InfrastructureError {
                         .join(", ")
                 )
             }
+            Self::MultiplePackageReferences => {
+                write!(
+                    formatter,
+                    "only one of branch, commit, or tag can be specified for a package"
+                )
+            }
+            Self::PackageHashNotMatched(url) => {
+                write!(
+                    formatter,
+                    "package content hash not matched with lock file for {url}"
+                )
+            }
+            Self::PackageNotCached(url) => {
+                write!(formatter, "package not cached for offline build {url}")
+            }
             Self::WriteFile { path, source: _ } => {
                 write!(formatter, "failed to write file {}", path.to_string_lossy())
             }
@@ -1,6 +1,13 @@
 use super::{command_runner, file_path_converter::FilePathConverter};
 use crate::{environment_variable_reader, InfrastructureError};
-use std::{error::Error, path::PathBuf, process::Command, rc::Rc};
+use std::{
+    env,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    rc::Rc,
+};
 
 pub struct ExternalPackageInitializer {
     file_system: Rc<dyn app::infra::FileSystem>,
@@ -9,9 +16,12 @@ pub struct ExternalPackageInitializer {
     language_root_environment_variable: &'static str,
     packages_directory: &'static str,
     build_configuration_filename: &'static str,
+    cache_directory: PathBuf,
+    offline: bool,
 }
 
 impl ExternalPackageInitializer {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         file_system: Rc<dyn app::infra::FileSystem>,
         file_path_converter: Rc<FilePathConverter>,
@@ -19,6 +29,8 @@ impl ExternalPackageInitializer {
         language_root_environment_variable: &'static str,
         packages_directory: &'static str,
         build_configuration_filename: &'static str,
+        cache_directory: impl AsRef<Path>,
+        offline: bool,
     ) -> Self {
         Self {
             file_system,
@@ -27,54 +39,255 @@ impl ExternalPackageInitializer {
             language_root_environment_variable,
             packages_directory,
             build_configuration_filename,
+            cache_directory: cache_directory.as_ref().into(),
+            offline,
         }
     }
-}
 
-impl app::infra::ExternalPackageInitializer for ExternalPackageInitializer {
-    fn initialize(
+    fn initialize_git_repository(
         &self,
         url: &url::Url,
-        package_directory: &app::infra::FilePath,
-    ) -> Result<(), Box<dyn Error>> {
-        if self
-            .file_system
+        reference: Option<&app::PackageReference>,
+        locked_package: Option<&app::LockedPackage>,
+        directory: &Path,
+    ) -> Result<app::LockedPackage, Box<dyn Error>> {
+        let commit = locked_package
+            .map(|package| package.commit())
+            .or(match reference {
+                Some(app::PackageReference::Commit(commit)) => Some(commit.as_str()),
+                _ => None,
+            });
+
+        if let Some(commit) = commit {
+            if directory.join(".git").exists()
+                && Self::resolve_revision(directory, "HEAD")? == commit
+            {
+                return Self::lock_git_repository(url, reference, locked_package, directory);
+            }
+
+            let cache_directory = self.resolve_cache_directory(url, commit);
+
+            if cache_directory.exists() {
+                Self::copy_directory(&cache_directory, directory)?;
+
+                return Self::lock_git_repository(url, reference, locked_package, directory);
+            }
+        }
+
+        if self.offline {
+            return Err(InfrastructureError::PackageNotCached(url.clone()).into());
+        }
+
+        Self::prepare_directory(directory)?;
+
+        command_runner::run_command(
+            Command::new("git")
+                .arg("clone")
+                .arg("--quiet")
+                .arg(url.as_str())
+                .arg(directory),
+        )?;
+        command_runner::run_command(
+            Command::new("git")
+                .arg("-C")
+                .arg(directory)
+                .arg("checkout")
+                .arg("--quiet")
+                .arg("--detach")
+                .arg(match (commit, reference) {
+                    (Some(commit), _) => commit.into(),
+                    (None, Some(app::PackageReference::Branch(branch))) => {
+                        format!("origin/{branch}")
+                    }
+                    (None, Some(app::PackageReference::Tag(tag))) => format!("refs/tags/{tag}"),
+                    (None, _) => "HEAD".into(),
+                }),
+        )?;
+
+        let package = Self::lock_git_repository(url, reference, locked_package, directory)?;
+        let cache_directory = self.resolve_cache_directory(url, package.commit());
+
+        if !cache_directory.exists() {
+            Self::copy_directory(directory, &cache_directory)?;
+        }
+
+        Ok(package)
+    }
+
+    fn lock_git_repository(
+        url: &url::Url,
+        reference: Option<&app::PackageReference>,
+        locked_package: Option<&app::LockedPackage>,
+        directory: &Path,
+    ) -> Result<app::LockedPackage, Box<dyn Error>> {
+        let commit = Self::resolve_revision(directory, "HEAD")?;
+        // Use a tree hash as a content hash independent from commit metadata.
+        let hash = Self::resolve_revision(directory, "HEAD^{tree}")?;
+
+        if let Some(package) = locked_package {
+            if hash != package.hash() {
+                return Err(InfrastructureError::PackageHashNotMatched(url.clone()).into());
+            }
+        }
+
+        Ok(app::LockedPackage::new(reference.cloned(), commit, hash))
+    }
+
+    fn resolve_revision(directory: &Path, revision: &str) -> Result<String, Box<dyn Error>> {
+        Ok(command_runner::run_command(
+            Command::new("git")
+                .arg("-C")
+                .arg(directory)
+                .arg("rev-parse")
+                .arg(revision),
+        )?
+        .trim()
+        .into())
+    }
+
+    // Calculate a tree hash of a directory without Git metadata in the same
+    // way as locked packages.
+    fn calculate_hash(directory: &Path) -> Result<String, Box<dyn Error>> {
+        let git_directory = env::temp_dir().join(format!("pen-package-hash-{}", process::id()));
+
+        Self::prepare_directory(&git_directory)?;
+
+        let hash = (|| -> Result<String, Box<dyn Error>> {
+            command_runner::run_command(
+                Command::new("git")
+                    .arg("init")
+                    .arg("--quiet")
+                    .arg("--bare")
+                    .arg(&git_directory),
+            )?;
+
+            let run = |arguments: &[&str]| {
+                command_runner::run_command(
+                    Command::new("git")
+                        .arg("--git-dir")
+                        .arg(&git_directory)
+                        .arg("--work-tree")
+                        .arg(directory)
+                        .args(arguments),
+                )
+            };
+
+            run(&["add", "--all"])?;
+
+            Ok(run(&["write-tree"])?.trim().into())
+        })();
+
+        fs::remove_dir_all(&git_directory)?;
+
+        hash
+    }
+
+    // Cached packages are keyed by their URLs and commits.
+    fn resolve_cache_directory(&self, url: &url::Url, commit: &str) -> PathBuf {
+        self.cache_directory
+            .join(url.scheme())
+            .join(url.host_str().unwrap_or_default())
+            .join(url.path().trim_matches('/'))
+            .join(commit)
+    }
+
+    fn copy_directory(source: &Path, destination: &Path) -> Result<(), Box<dyn Error>> {
+        Self::prepare_directory(destination)?;
+
+        command_runner::run_command(Command::new("cp").arg("-r").arg(source).arg(destination))?;
+
+        Ok(())
+    }
+
+    fn is_initialized(&self, package_directory: &app::infra::FilePath) -> bool {
+        self.file_system
             .exists(&package_directory.join(&app::infra::FilePath::new([
                 self.build_configuration_filename,
             ])))
-        {
-            return Ok(());
-        }
-
-        let directory = self
-            .file_path_converter
-            .convert_to_os_path(package_directory);
+    }
 
+    fn prepare_directory(directory: &Path) -> Result<(), Box<dyn Error>> {
         // Remove a stale directory left by cache restoration (e.g. cargo
         // cache restoring `**/target/` may recreate parent directories
         // without source files).
         if directory.exists() {
-            std::fs::remove_dir_all(&directory)?;
+            fs::remove_dir_all(directory)?;
         }
 
         if let Some(directory) = directory.parent() {
-            std::fs::create_dir_all(directory)?;
+            fs::create_dir_all(directory)?;
         }
 
+        Ok(())
+    }
+}
+
+impl app::infra::ExternalPackageInitializer for ExternalPackageInitializer {
+    fn initialize(
+        &self,
+        url: &url::Url,
+        reference: Option<&app::PackageReference>,
+        locked_package: Option<&app::LockedPackage>,
+        package_directory: &app::infra::FilePath,
+        vendored_package_directory: &app::infra::FilePath,
+    ) -> Result<Option<app::LockedPackage>, Box<dyn Error>> {
+        let directory = self
+            .file_path_converter
+            .convert_to_os_path(package_directory);
+        let vendored_directory = self
+            .file_path_converter
+            .convert_to_os_path(vendored_package_directory);
+
+        // Packages at file URLs are copied unless their references are specified.
+        let is_git_repository =
+            url.scheme() == "git" || url.scheme() == "file" && reference.is_some();
+
+        // Vendored packages are used as they are without any network access.
+        // Vendored packages in Git repositories are used only if they are
+        // locked so that their content hashes are verified.
+        if vendored_directory.exists() && (!is_git_repository || locked_package.is_some()) {
+            let hash = Self::calculate_hash(&vendored_directory)?;
+
+            if let Some(package) = locked_package {
+                if hash != package.hash() {
+                    return Err(InfrastructureError::PackageHashNotMatched(url.clone()).into());
+                }
+            }
+
+            // Re-vendored packages are copied again if their contents change.
+            let hash_file = directory.with_extension("hash");
+
+            if !self.is_initialized(package_directory)
+                || fs::read_to_string(&hash_file).ok().as_deref() != Some(hash.as_str())
+            {
+                Self::copy_directory(&vendored_directory, &directory)?;
+                fs::write(&hash_file, &hash)?;
+            }
+
+            return Ok(locked_package.cloned());
+        }
+
+        if is_git_repository {
+            return Ok(Some(self.initialize_git_repository(
+                url,
+                reference,
+                locked_package,
+                &directory,
+            )?));
+        }
+
+        if self.is_initialized(package_directory) {
+            return Ok(None);
+        }
+
+        Self::prepare_directory(&directory)?;
+
         match url.scheme() {
             "file" => {
                 command_runner::run_command(
                     Command::new("cp").arg("-r").arg(url.path()).arg(directory),
                 )?;
             }
-            "git" => {
-                command_runner::run_command(
-                    Command::new("git")
-                        .arg("clone")
-                        .arg(url.as_str())
-                        .arg(directory),
-                )?;
-            }
             _ => {
                 if url.scheme() != self.language_root_scheme {
                     return Err(
@@ -97,6 +310,48 @@ impl app::infra::ExternalPackageInitializer for ExternalPackageInitializer {
             }
         }
 
+        Ok(None)
+    }
+
+    fn vendor(
+        &self,
+        url: &url::Url,
+        package_directory: &app::infra::FilePath,
+        vendored_package_directory: &app::infra::FilePath,
+    ) -> Result<(), Box<dyn Error>> {
+        // Standard packages are bundled with the language.
+        if url.scheme() == self.language_root_scheme {
+            return Ok(());
+        }
+
+        let directory = self
+            .file_path_converter
+            .convert_to_os_path(package_directory);
+        let vendored_directory = self
+            .file_path_converter
+            .convert_to_os_path(vendored_package_directory);
+
+        // Vendored packages are committed into repositories of their dependents.
+        // So we export only files tracked by Git to keep their content hashes.
+        if directory.join(".git").exists() {
+            Self::prepare_directory(&vendored_directory)?;
+            fs::create_dir_all(&vendored_directory)?;
+
+            command_runner::run_command(
+                Command::new("git")
+                    .arg("-C")
+                    .arg(&directory)
+                    .arg("checkout-index")
+                    .arg("--all")
+                    .arg(format!(
+                        "--prefix={}/",
+                        fs::canonicalize(&vendored_directory)?.display()
+                    )),
+            )?;
+        } else {
+            Self::copy_directory(&directory, &vendored_directory)?;
+        }
+
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
+use crate::InfrastructureError;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, error::Error};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,23 +30,97 @@ impl From<JsonPackageType> for app::PackageType {
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct JsonPackageReference {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+impl JsonPackageReference {
+    pub fn new(reference: Option<&app::PackageReference>) -> Self {
+        match reference {
+            Some(app::PackageReference::Branch(branch)) => Self {
+                branch: Some(branch.clone()),
+                ..Default::default()
+            },
+            Some(app::PackageReference::Commit(commit)) => Self {
+                commit: Some(commit.clone()),
+                ..Default::default()
+            },
+            Some(app::PackageReference::Tag(tag)) => Self {
+                tag: Some(tag.clone()),
+                ..Default::default()
+            },
+            None => Default::default(),
+        }
+    }
+
+    pub fn into_reference(self) -> Result<Option<app::PackageReference>, InfrastructureError> {
+        Ok(match (self.branch, self.commit, self.tag) {
+            (None, None, None) => None,
+            (Some(branch), None, None) => Some(app::PackageReference::Branch(branch)),
+            (None, Some(commit), None) => Some(app::PackageReference::Commit(commit)),
+            (None, None, Some(tag)) => Some(app::PackageReference::Tag(tag)),
+            _ => return Err(InfrastructureError::MultiplePackageReferences),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Default::default()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum JsonPackageDependency {
+    Url(String),
+    Versioned {
+        url: String,
+        #[serde(flatten)]
+        reference: JsonPackageReference,
+    },
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JsonPackageConfiguration {
     #[serde(rename = "type")]
     pub type_: JsonPackageType,
-    pub dependencies: BTreeMap<String, String>,
+    pub dependencies: BTreeMap<String, JsonPackageDependency>,
 }
 
 impl JsonPackageConfiguration {
+    // File URLs are written relatively to a base URL if it is available.
     pub fn new(
         type_: impl Into<JsonPackageType>,
-        dependencies: BTreeMap<String, url::Url>,
+        dependencies: &BTreeMap<String, app::PackageDependency>,
+        base_url: Option<&url::Url>,
     ) -> Self {
         Self {
             type_: type_.into(),
             dependencies: dependencies
                 .iter()
-                .map(|(name, url)| (name.clone(), url.as_str().into()))
+                .map(|(name, dependency)| {
+                    let url = dependency.url();
+                    let url = base_url
+                        .filter(|_| url.scheme() == "file")
+                        .and_then(|base_url| base_url.make_relative(url))
+                        .filter(|url| !url.is_empty())
+                        .unwrap_or_else(|| url.as_str().into());
+                    let reference = JsonPackageReference::new(dependency.reference());
+
+                    (
+                        name.clone(),
+                        if reference.is_empty() {
+                            JsonPackageDependency::Url(url)
+                        } else {
+                            JsonPackageDependency::Versioned { url, reference }
+                        },
+                    )
+                })
                 .collect(),
         }
     }
@@ -53,18 +128,28 @@ impl JsonPackageConfiguration {
     pub fn into_configuration(
         self,
         base_url: &url::Url,
-    ) -> Result<app::PackageConfiguration, url::ParseError> {
+    ) -> Result<app::PackageConfiguration, Box<dyn Error>> {
         Ok(app::PackageConfiguration::new(
             self.type_.into(),
             self.dependencies
                 .into_iter()
-                .map(|(name, url)| {
+                .map(|(name, dependency)| {
+                    let (url, reference) = match dependency {
+                        JsonPackageDependency::Url(url) => (url, None),
+                        JsonPackageDependency::Versioned { url, reference } => {
+                            (url, reference.into_reference()?)
+                        }
+                    };
+
                     Ok((
                         name,
-                        url::Url::options().base_url(Some(base_url)).parse(&url)?,
+                        app::PackageDependency::new(
+                            url::Url::options().base_url(Some(base_url)).parse(&url)?,
+                            reference,
+                        ),
                     ))
                 })
-                .collect::<Result<_, url::ParseError>>()?,
+                .collect::<Result<_, Box<dyn Error>>>()?,
         ))
     }
 }
@@ -80,4 +165,93 @@ mod tests {
             url::Url::parse("file:///foo/baz")
         );
     }
+
+    #[test]
+    fn make_relative_path() {
+        assert_eq!(
+            url::Url::parse("file:///foo/bar/")
+                .unwrap()
+                .make_relative(&url::Url::parse("file:///foo/baz").unwrap()),
+            Some("../baz".into())
+        );
+    }
+
+    #[test]
+    fn parse_dependencies() {
+        let configuration = serde_json::from_str::<JsonPackageConfiguration>(
+            r#"{
+                "type": "library",
+                "dependencies": {
+                    "Foo": "../foo",
+                    "Bar": { "url": "git://github.com/foo/bar", "tag": "v1.0.0" }
+                }
+            }"#,
+        )
+        .unwrap()
+        .into_configuration(&url::Url::parse("file:///foo/bar/").unwrap())
+        .unwrap();
+
+        assert_eq!(
+            configuration.dependencies()["Foo"],
+            app::PackageDependency::new(url::Url::parse("file:///foo/foo").unwrap(), None)
+        );
+        assert_eq!(
+            configuration.dependencies()["Bar"],
+            app::PackageDependency::new(
+                url::Url::parse("git://github.com/foo/bar").unwrap(),
+                Some(app::PackageReference::Tag("v1.0.0".into()))
+            )
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_multiple_references() {
+        assert!(serde_json::from_str::<JsonPackageConfiguration>(
+            r#"{
+                "type": "library",
+                "dependencies": {
+                    "Foo": { "url": "git://github.com/foo/foo", "tag": "v1", "branch": "main" }
+                }
+            }"#,
+        )
+        .unwrap()
+        .into_configuration(&url::Url::parse("file:///foo/").unwrap())
+        .is_err());
+    }
+
+    #[test]
+    fn serialize_dependencies() {
+        assert_eq!(
+            serde_json::to_value(JsonPackageConfiguration::new(
+                app::PackageType::Library,
+                &[
+                    (
+                        "Foo".into(),
+                        app::PackageDependency::new(
+                            url::Url::parse("file:///foo/foo").unwrap(),
+                            None
+                        )
+                    ),
+                    (
+                        "Bar".into(),
+                        app::PackageDependency::new(
+                            url::Url::parse("git://github.com/foo/bar").unwrap(),
+                            Some(app::PackageReference::Branch("main".into()))
+                        )
+                    )
+                ]
+                .into_iter()
+                .collect(),
+                Some(&url::Url::parse("file:///foo/bar/").unwrap()),
+            ))
+            .unwrap(),
+            serde_json::json!({
+                "type": "library",
+                "dependencies": {
+                    "Foo": "../foo",
+                    "Bar": { "url": "git://github.com/foo/bar", "branch": "main" }
+                }
+            })
+        );
+    }
 }
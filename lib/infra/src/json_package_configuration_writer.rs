@@ -1,18 +1,22 @@
 use super::json_package_configuration::JsonPackageConfiguration;
+use crate::FilePathConverter;
 use std::{error::Error, rc::Rc};
 
 pub struct JsonPackageConfigurationWriter {
     file_system: Rc<dyn app::infra::FileSystem>,
+    file_path_converter: Rc<FilePathConverter>,
     build_configuration_filename: &'static str,
 }
 
 impl JsonPackageConfigurationWriter {
     pub fn new(
         file_system: Rc<dyn app::infra::FileSystem>,
+        file_path_converter: Rc<FilePathConverter>,
         build_configuration_filename: &'static str,
     ) -> Self {
         Self {
             file_system,
+            file_path_converter,
             build_configuration_filename,
         }
     }
@@ -24,13 +28,22 @@ impl app::infra::PackageConfigurationWriter for JsonPackageConfigurationWriter {
         configuration: &app::PackageConfiguration,
         package_directory: &app::infra::FilePath,
     ) -> Result<(), Box<dyn Error>> {
+        // A package directory might not exist yet on package creation.
+        let package_url = self
+            .file_path_converter
+            .convert_to_os_path(package_directory)
+            .canonicalize()
+            .ok()
+            .and_then(|path| url::Url::from_directory_path(path).ok());
+
         self.file_system.write(
             &package_directory.join(&app::infra::FilePath::new(vec![
                 self.build_configuration_filename,
             ])),
             (serde_json::to_string_pretty(&JsonPackageConfiguration::new(
                 configuration.type_(),
-                configuration.dependencies().clone(),
+                configuration.dependencies(),
+                package_url.as_ref(),
             ))? + "\n")
                 .as_bytes(),
         )?;
@@ -0,0 +1,92 @@
+use super::json_package_configuration::JsonPackageReference;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, error::Error};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JsonLockedPackage {
+    #[serde(default, skip_serializing_if = "JsonPackageReference::is_empty")]
+    pub reference: JsonPackageReference,
+    pub commit: String,
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JsonPackageLock {
+    pub packages: BTreeMap<String, JsonLockedPackage>,
+}
+
+impl JsonPackageLock {
+    pub fn new(lock: &app::PackageLock) -> Self {
+        Self {
+            packages: lock
+                .packages()
+                .iter()
+                .map(|(url, package)| {
+                    (
+                        url.as_str().into(),
+                        JsonLockedPackage {
+                            reference: JsonPackageReference::new(package.reference()),
+                            commit: package.commit().into(),
+                            hash: package.hash().into(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn into_lock(self) -> Result<app::PackageLock, Box<dyn Error>> {
+        Ok(app::PackageLock::new(
+            self.packages
+                .into_iter()
+                .map(|(url, package)| -> Result<_, Box<dyn Error>> {
+                    Ok((
+                        url::Url::parse(&url)?,
+                        app::LockedPackage::new(
+                            package.reference.into_reference()?,
+                            package.commit,
+                            package.hash,
+                        ),
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_lock() {
+        let lock = app::PackageLock::new(
+            [
+                (
+                    url::Url::parse("git://github.com/foo/foo").unwrap(),
+                    app::LockedPackage::new(None, "foo", "bar"),
+                ),
+                (
+                    url::Url::parse("file:///foo/bar").unwrap(),
+                    app::LockedPackage::new(
+                        Some(app::PackageReference::Tag("v1.0.0".into())),
+                        "baz",
+                        "qux",
+                    ),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(
+            serde_json::from_value::<JsonPackageLock>(
+                serde_json::to_value(JsonPackageLock::new(&lock)).unwrap()
+            )
+            .unwrap()
+            .into_lock()
+            .unwrap(),
+            lock
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use super::json_package_lock::JsonPackageLock;
+use std::{error::Error, rc::Rc};
+
+pub struct JsonPackageLockReader {
+    file_system: Rc<dyn app::infra::FileSystem>,
+    lock_filename: &'static str,
+}
+
+impl JsonPackageLockReader {
+    pub fn new(file_system: Rc<dyn app::infra::FileSystem>, lock_filename: &'static str) -> Self {
+        Self {
+            file_system,
+            lock_filename,
+        }
+    }
+}
+
+impl app::infra::PackageLockReader for JsonPackageLockReader {
+    fn read(
+        &self,
+        package_directory: &app::infra::FilePath,
+    ) -> Result<app::PackageLock, Box<dyn Error>> {
+        let file = package_directory.join(&app::infra::FilePath::new([self.lock_filename]));
+
+        if !self.file_system.exists(&file) {
+            return Ok(Default::default());
+        }
+
+        serde_json::from_str::<JsonPackageLock>(&self.file_system.read_to_string(&file)?)?
+            .into_lock()
+    }
+}
@@ -0,0 +1,31 @@
+use super::json_package_lock::JsonPackageLock;
+use std::{error::Error, rc::Rc};
+
+pub struct JsonPackageLockWriter {
+    file_system: Rc<dyn app::infra::FileSystem>,
+    lock_filename: &'static str,
+}
+
+impl JsonPackageLockWriter {
+    pub fn new(file_system: Rc<dyn app::infra::FileSystem>, lock_filename: &'static str) -> Self {
+        Self {
+            file_system,
+            lock_filename,
+        }
+    }
+}
+
+impl app::infra::PackageLockWriter for JsonPackageLockWriter {
+    fn write(
+        &self,
+        lock: &app::PackageLock,
+        package_directory: &app::infra::FilePath,
+    ) -> Result<(), Box<dyn Error>> {
+        self.file_system.write(
+            &package_directory.join(&app::infra::FilePath::new([self.lock_filename])),
+            (serde_json::to_string_pretty(&JsonPackageLock::new(lock))? + "\n").as_bytes(),
+        )?;
+
+        Ok(())
+    }
+}
@@ -12,6 +12,9 @@ mod file_system;
 mod json_package_configuration;
 mod json_package_configuration_reader;
 mod json_package_configuration_writer;
+mod json_package_lock;
+mod json_package_lock_reader;
+mod json_package_lock_writer;
 mod llvm_command_finder;
 mod logger;
 mod ninja_build_script_compiler;
@@ -28,6 +31,8 @@ pub use file_path_displayer::*;
 pub use file_system::*;
 pub use json_package_configuration_reader::*;
 pub use json_package_configuration_writer::*;
+pub use json_package_lock_reader::*;
+pub use json_package_lock_writer::*;
 pub use logger::*;
 pub use ninja_build_script_compiler::*;
 pub use ninja_build_script_dependency_compiler::*;
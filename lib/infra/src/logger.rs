@@ -1,7 +1,63 @@
-use std::io::Write;
+use std::io::{stderr, Write};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
-pub fn log_error(error: &dyn std::error::Error) -> Result<(), Box<dyn std::error::Error>> {
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MessageFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+pub fn log_error(
+    error: &(dyn std::error::Error + 'static),
+    format: MessageFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        MessageFormat::Human => log_human_error(error),
+        MessageFormat::Json => log_json_error(error),
+    }
+}
+
+pub fn log_diagnostic(
+    diagnostic: &app::Diagnostic,
+    format: MessageFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        MessageFormat::Human => log_human_diagnostic(diagnostic),
+        MessageFormat::Json => {
+            writeln!(stderr().lock(), "{}", format_json_diagnostic(diagnostic))?;
+
+            Ok(())
+        }
+    }
+}
+
+fn log_human_diagnostic(diagnostic: &app::Diagnostic) -> Result<(), Box<dyn std::error::Error>> {
+    let mut stderr = StandardStream::stderr(ColorChoice::Auto);
+    let (label, color) = match diagnostic.severity() {
+        app::DiagnosticSeverity::Error => ("error", Color::Red),
+        app::DiagnosticSeverity::Warning => ("warning", Color::Yellow),
+        app::DiagnosticSeverity::Information => ("info", Color::Cyan),
+    };
+
+    stderr.set_color(ColorSpec::new().set_fg(Some(color)))?;
+    write!(stderr, "{label}")?;
+    stderr.set_color(ColorSpec::new().set_fg(None))?;
+
+    if let Some(code) = diagnostic.code() {
+        write!(stderr, "[{code}]")?;
+    }
+
+    writeln!(
+        stderr,
+        ": {}",
+        diagnostic.rendered().replace('\n', "\n  ").trim()
+    )?;
+
+    Ok(())
+}
+
+fn log_human_error(error: &dyn std::error::Error) -> Result<(), Box<dyn std::error::Error>> {
     let mut stderr = StandardStream::stderr(ColorChoice::Auto);
 
     stderr.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
@@ -15,12 +71,44 @@ pub fn log_error(error: &dyn std::error::Error) -> Result<(), Box<dyn std::error
     )?;
 
     if let Some(error) = error.source() {
-        log_error(error)?;
+        log_human_error(error)?;
+    }
+
+    Ok(())
+}
+
+fn log_json_error(
+    error: &(dyn std::error::Error + 'static),
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut stderr = stderr().lock();
+
+    for diagnostic in app::diagnostic_collector::collect(error) {
+        writeln!(stderr, "{}", format_json_diagnostic(&diagnostic))?;
     }
 
     Ok(())
 }
 
+fn format_json_diagnostic(diagnostic: &app::Diagnostic) -> serde_json::Value {
+    let position = diagnostic.position();
+
+    serde_json::json!({
+        "severity": match diagnostic.severity() {
+            app::DiagnosticSeverity::Error => "error",
+            app::DiagnosticSeverity::Warning => "warning",
+            app::DiagnosticSeverity::Information => "information",
+        },
+        "code": diagnostic.code(),
+        "message": diagnostic.message(),
+        "path": position.map(|position| position.path()),
+        "line": position.map(|position| position.line_number()),
+        "column": position.map(|position| position.column_number()),
+        "end_line": position.map(|position| position.end_line_number()),
+        "end_column": position.map(|position| position.end_column_number()),
+        "rendered": diagnostic.rendered(),
+    })
+}
+
 pub fn log_info(log: &str) -> Result<(), Box<dyn std::error::Error>> {
     let mut stderr = StandardStream::stderr(ColorChoice::Auto);
 
@@ -32,3 +120,70 @@ pub fn log_info(log: &str) -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_diagnostic_with_position() {
+        assert_eq!(
+            format_json_diagnostic(&app::Diagnostic::new(
+                app::DiagnosticSeverity::Error,
+                Some("E0001"),
+                "foo",
+                Some(position::Position::new("foo.pen", 1, 2, "bar").set_end(1, 4)),
+                "foo\nbar",
+            )),
+            serde_json::json!({
+                "severity": "error",
+                "code": "E0001",
+                "message": "foo",
+                "path": "foo.pen",
+                "line": 1,
+                "column": 2,
+                "end_line": 1,
+                "end_column": 4,
+                "rendered": "foo\nbar",
+            })
+        );
+    }
+
+    #[test]
+    fn format_diagnostic_without_position() {
+        assert_eq!(
+            format_json_diagnostic(&app::Diagnostic::new(
+                app::DiagnosticSeverity::Error,
+                None,
+                "foo",
+                None,
+                "foo"
+            )),
+            serde_json::json!({
+                "severity": "error",
+                "code": null,
+                "message": "foo",
+                "path": null,
+                "line": null,
+                "column": null,
+                "end_line": null,
+                "end_column": null,
+                "rendered": "foo",
+            })
+        );
+    }
+
+    #[test]
+    fn format_warning_diagnostic() {
+        assert_eq!(
+            format_json_diagnostic(&app::Diagnostic::new(
+                app::DiagnosticSeverity::Warning,
+                Some("W0003"),
+                "foo",
+                None,
+                "foo"
+            ))["severity"],
+            "warning"
+        );
+    }
+}
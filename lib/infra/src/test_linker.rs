@@ -24,80 +24,67 @@ impl TestLinker {
         }
     }
 
-    fn format_main_rs(
-        &self,
-        package_test_information: &test_info::Package,
-    ) -> Result<String, Box<dyn Error>> {
-        Ok(format!(
+    fn format_main_rs(&self, package_test_information: &test_info::Package) -> String {
+        let functions = package_test_information
+            .modules()
+            .iter()
+            .flat_map(|(name, module)| {
+                module
+                    .functions()
+                    .iter()
+                    .map(move |function| (name.as_str(), function))
+            })
+            .collect::<Vec<_>>();
+
+        format!(
             r#"
+            mod coverage;
             mod debug;
             mod heap;
+            mod reporter;
+            mod runner;
             mod spawn;
             mod unreachable;
 
-            fn main() {{
-                #[allow(unused_mut)]
-                let mut success: usize = 0;
-                #[allow(unused_mut)]
-                let mut error: usize = 0;
-
+            #[link(name = "main_test")]
+            extern "C" {{
                 {}
+            }}
 
-                println!("summary");
-                println!(
-                    "\t{{}}\t{{}} passed, {{}} failed",
-                    if error == 0 {{ "OK" }} else {{ "FAIL" }},
-                    success, error
-                );
+            const TESTS: &[runner::Test] = &[{}];
 
-                if error > 0 {{
-                    std::process::exit(1);
-                }}
+            fn main() {{
+                runner::run(TESTS);
             }}
             "#,
-            self.format_tests(package_test_information)?,
-        ))
+            self.format_test_declarations(&functions),
+            self.format_tests(&functions),
+        )
     }
 
-    fn format_tests(
-        &self,
-        package_test_information: &test_info::Package,
-    ) -> Result<String, Box<dyn Error>> {
-        Ok(package_test_information
-            .modules()
+    fn format_test_declarations(&self, functions: &[(&str, &test_info::Function)]) -> String {
+        functions
             .iter()
-            .map(|(name, module)| {
-                format!(r#"println!("{name}");"#)
-                    + &module
-                        .functions()
-                        .iter()
-                        .map(|function| {
-                            self.format_test_function(function.name(), function.foreign_name())
-                        })
-                        .collect::<Vec<_>>()
-                        .join("\n")
-            })
+            .map(|(_, function)| format!("fn {}() -> ffi::ByteString;", function.foreign_name()))
             .collect::<Vec<_>>()
-            .join("\n"))
+            .join("\n")
     }
 
-    fn format_test_function(&self, name: &str, foreign_name: &str) -> String {
-        format!(
-            r#"
-            #[link(name = "main_test")]
-            extern "C" {{ fn {foreign_name}() -> ffi::ByteString; }}
-
-            let message = unsafe {{ {foreign_name}() }};
-            println!("\t{{}}\t{name}", if message.as_slice().is_empty() {{ "OK" }} else {{ "FAIL" }});
-
-            if message.as_slice().is_empty() {{
-                success += 1;
-            }} else {{
-                println!("\t\tMessage: {{}}", String::from_utf8_lossy(message.as_slice()));
-                error += 1;
-            }}
-            "#,
-        )
+    // Tests are run by a runtime of the test crate so that their filters can be
+    // changed without relinking.
+    fn format_tests(&self, functions: &[(&str, &test_info::Function)]) -> String {
+        functions
+            .iter()
+            .map(|(module, function)| {
+                format!(
+                    "runner::Test {{ module: {:?}, name: {:?}, function: {} }},",
+                    module,
+                    function.name(),
+                    function.foreign_name()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
@@ -130,7 +117,7 @@ impl app::infra::TestLinker for TestLinker {
             .write(true)
             .truncate(true)
             .open(main_crate_directory.join("src/main.rs"))?
-            .write_all(self.format_main_rs(package_test_information)?.as_bytes())?;
+            .write_all(self.format_main_rs(package_test_information).as_bytes())?;
 
         command_runner::run_command(
             Command::new("cargo")
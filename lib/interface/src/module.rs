@@ -1,11 +1,13 @@
 use super::{FunctionDeclaration, TypeAlias, TypeDefinition};
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Module {
     type_definitions: Vec<TypeDefinition>,
     type_aliases: Vec<TypeAlias>,
     function_declarations: Vec<FunctionDeclaration>,
+    // Definitions of public functions which other modules can specialize.
+    function_definitions: Vec<mir::ir::FunctionDefinition>,
 }
 
 impl Module {
@@ -13,11 +15,13 @@ impl Module {
         type_definitions: Vec<TypeDefinition>,
         type_aliases: Vec<TypeAlias>,
         declarations: Vec<FunctionDeclaration>,
+        definitions: Vec<mir::ir::FunctionDefinition>,
     ) -> Self {
         Self {
             type_definitions,
             type_aliases,
             function_declarations: declarations,
+            function_definitions: definitions,
         }
     }
 
@@ -32,4 +36,8 @@ impl Module {
     pub fn function_declarations(&self) -> &[FunctionDeclaration] {
         &self.function_declarations
     }
+
+    pub fn function_definitions(&self) -> &[mir::ir::FunctionDefinition] {
+        &self.function_definitions
+    }
 }
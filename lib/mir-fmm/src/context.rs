@@ -1,5 +1,5 @@
 use crate::configuration::Configuration;
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use std::cell::RefCell;
 
 pub struct Context {
@@ -7,6 +7,7 @@ pub struct Context {
     types: FnvHashMap<String, mir::types::RecordBody>,
     fmm_types: RefCell<FnvHashMap<mir::types::Type, fmm::types::Type>>,
     type_information: mir::ir::TypeInformation,
+    unsynchronized_variables: RefCell<FnvHashSet<String>>,
     configuration: Configuration,
 }
 
@@ -21,6 +22,7 @@ impl Context {
                 .collect(),
             fmm_types: Default::default(),
             type_information: module.type_information().clone(),
+            unsynchronized_variables: Default::default(),
             configuration,
         }
     }
@@ -41,6 +43,11 @@ impl Context {
         &self.fmm_types
     }
 
+    // Variables of records never synchronized in a function being compiled.
+    pub fn unsynchronized_variables(&self) -> &RefCell<FnvHashSet<String>> {
+        &self.unsynchronized_variables
+    }
+
     pub fn configuration(&self) -> &Configuration {
         &self.configuration
     }
@@ -5,6 +5,15 @@ use crate::{
 };
 use fnv::FnvHashMap;
 
+// Numbers not less than this do not have any fractional parts.
+const MAXIMUM_FRACTIONAL_NUMBER: f64 = 4503599627370496.0;
+// Integers of less than 51 bits added to this number are represented in the
+// lower bits of its mantissa.
+const INTEGER_CONVERSION_OFFSET: f64 = 6755399441055744.0;
+const INTEGER32_RANGE: f64 = 4294967296.0;
+const INTEGER64_LIMIT: f64 = 9223372036854775808.0;
+const REMAINDER_FUNCTION_NAME: &str = "fmod";
+
 pub fn compile(
     context: &Context,
     builder: &fmm::build::InstructionBuilder,
@@ -15,7 +24,7 @@ pub fn compile(
 
     Ok(match expression {
         mir::ir::Expression::ArithmeticOperation(operation) => {
-            compile_arithmetic_operation(context, builder, operation, variables)?.into()
+            compile_arithmetic_operation(context, builder, operation, variables)?
         }
         mir::ir::Expression::Boolean(boolean) => fmm::ir::Primitive::Boolean(*boolean).into(),
         mir::ir::Expression::Case(case) => compile_case(context, builder, case, variables)?,
@@ -28,7 +37,13 @@ pub fn compile(
                     .map(|(variable, type_)| {
                         Ok((
                             variable.into(),
-                            reference_count::clone(context, builder, &variables[variable], type_)?,
+                            clone_variable(
+                                context,
+                                builder,
+                                variable,
+                                &variables[variable],
+                                type_,
+                            )?,
                         ))
                     })
                     .collect::<Result<Vec<_>, CompileError>>()?,
@@ -39,7 +54,7 @@ pub fn compile(
         }
         mir::ir::Expression::DropVariables(drop) => {
             for (variable, type_) in drop.variables() {
-                reference_count::drop(context, builder, &variables[variable], type_)?;
+                drop_variable(context, builder, variable, &variables[variable], type_)?;
             }
 
             compile(drop.expression(), variables)?
@@ -66,20 +81,31 @@ pub fn compile(
         mir::ir::Expression::Record(record) => compile_record(context, builder, record, variables)?,
         mir::ir::Expression::RecordField(field) => {
             let record_type = field.type_().clone();
-            let field_index = field.index();
 
             let record = compile(field.record(), variables)?;
-            let field = record::get_field(context, builder, &record, field.type_(), field.index())?;
-
-            let field = reference_count::clone(
+            let value = reference_count::clone(
                 context,
                 builder,
-                &field,
-                &context.types()[record_type.name()].fields()[field_index],
+                &record::get_field(context, builder, &record, field.type_(), field.index())?,
+                &context.types()[record_type.name()].fields()[field.index()],
             )?;
-            reference_count::drop(context, builder, &record, &record_type.into())?;
 
-            field
+            if let mir::ir::Expression::Variable(variable) = field.record() {
+                drop_variable(
+                    context,
+                    builder,
+                    variable.name(),
+                    &record,
+                    &record_type.into(),
+                )?;
+            } else {
+                reference_count::drop(context, builder, &record, &record_type.into())?;
+            }
+
+            value
+        }
+        mir::ir::Expression::ReuseRecord(reuse) => {
+            compile_reuse_record(context, builder, reuse, variables)?
         }
         mir::ir::Expression::RecordUpdate(update) => {
             let record = compile(update.record(), variables)?;
@@ -159,7 +185,7 @@ pub fn compile(
             if type_::is_record_boxed(context, update.type_()) {
                 builder.if_(
                     reference_count::pointer::is_unique(builder, &record)?,
-                    |builder| -> Result<_, CompileError> {
+                    |builder| {
                         Ok(builder.branch(compile_boxed_record(
                             &builder,
                             record.clone(),
@@ -384,7 +410,7 @@ fn compile_alternatives(
                     .into())
                 },
             )?,
-            |builder| -> Result<_, CompileError> {
+            |builder| {
                 Ok(builder.branch(compile(
                     context,
                     &builder,
@@ -506,7 +532,7 @@ fn compile_arithmetic_operation(
     builder: &fmm::build::InstructionBuilder,
     operation: &mir::ir::ArithmeticOperation,
     variables: &plist::FlailMap<String, fmm::build::TypedExpression>,
-) -> Result<fmm::ir::ArithmeticOperation, CompileError> {
+) -> Result<fmm::build::TypedExpression, CompileError> {
     let compile = |expression| compile(context, builder, expression, variables);
 
     let lhs = compile(operation.lhs())?;
@@ -514,20 +540,339 @@ fn compile_arithmetic_operation(
 
     Ok(match operation.operator() {
         mir::ir::ArithmeticOperator::Add => {
-            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Add, lhs, rhs)?
+            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Add, lhs, rhs)?.into()
         }
         mir::ir::ArithmeticOperator::Subtract => {
             fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Subtract, lhs, rhs)?
+                .into()
         }
         mir::ir::ArithmeticOperator::Multiply => {
             fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Multiply, lhs, rhs)?
+                .into()
         }
         mir::ir::ArithmeticOperator::Divide => {
-            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Divide, lhs, rhs)?
+            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Divide, lhs, rhs)?.into()
+        }
+        mir::ir::ArithmeticOperator::IntegerDivide => compile_truncation(
+            builder,
+            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Divide, lhs, rhs)?.into(),
+        )?,
+        mir::ir::ArithmeticOperator::Remainder => builder.call(
+            context.module_builder().declare_function(
+                REMAINDER_FUNCTION_NAME,
+                fmm::types::Function::new(
+                    vec![
+                        fmm::types::Primitive::Float64.into(),
+                        fmm::types::Primitive::Float64.into(),
+                    ],
+                    fmm::types::Primitive::Float64,
+                    fmm::types::CallingConvention::Target,
+                ),
+            ),
+            vec![lhs, rhs],
+        )?,
+        mir::ir::ArithmeticOperator::And => {
+            compile_bitwise_operation(builder, fmm::ir::BitwiseOperator::And, lhs, rhs)?
         }
+        mir::ir::ArithmeticOperator::Or => {
+            compile_bitwise_operation(builder, fmm::ir::BitwiseOperator::Or, lhs, rhs)?
+        }
+        mir::ir::ArithmeticOperator::Xor => {
+            compile_bitwise_operation(builder, fmm::ir::BitwiseOperator::Xor, lhs, rhs)?
+        }
+        mir::ir::ArithmeticOperator::LeftShift => {
+            compile_shift_operation(builder, fmm::ir::BitwiseOperator::LeftShift, lhs, rhs)?
+        }
+        mir::ir::ArithmeticOperator::RightShift => compile_shift_operation(
+            builder,
+            fmm::ir::BitwiseOperator::RightShift(true),
+            lhs,
+            rhs,
+        )?,
     })
 }
 
+// Bitwise operations convert numbers into 64-bit integers and back.
+fn compile_bitwise_operation(
+    builder: &fmm::build::InstructionBuilder,
+    operator: fmm::ir::BitwiseOperator,
+    lhs: fmm::build::TypedExpression,
+    rhs: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    compile_integer64_to_number(
+        fmm::build::bitwise_operation(
+            operator,
+            compile_number_to_integer64(builder, lhs)?,
+            compile_number_to_integer64(builder, rhs)?,
+        )?
+        .into(),
+    )
+}
+
+// Shift counts are masked into the range of 0 to 63 as shifts by 64 bits or
+// more are undefined.
+fn compile_shift_operation(
+    builder: &fmm::build::InstructionBuilder,
+    operator: fmm::ir::BitwiseOperator,
+    lhs: fmm::build::TypedExpression,
+    rhs: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    compile_integer64_to_number(
+        fmm::build::bitwise_operation(
+            operator,
+            compile_number_to_integer64(builder, lhs)?,
+            fmm::build::bitwise_operation(
+                fmm::ir::BitwiseOperator::And,
+                compile_number_to_integer64(builder, rhs)?,
+                fmm::ir::Primitive::Integer64(63),
+            )?,
+        )?
+        .into(),
+    )
+}
+
+// We convert numbers into 64-bit integers with truncation and saturation in the
+// same way as Rust's `as` operator. NaN is converted into zero.
+fn compile_number_to_integer64(
+    builder: &fmm::build::InstructionBuilder,
+    number: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    builder.if_::<CompileError>(
+        fmm::build::comparison_operation(
+            fmm::ir::ComparisonOperator::LessThan(false),
+            number.clone(),
+            fmm::ir::Primitive::Float64(-INTEGER64_LIMIT),
+        )?,
+        |builder| Ok(builder.branch(fmm::ir::Primitive::Integer64(i64::MIN as u64))),
+        |builder| {
+            Ok(builder.branch(builder.if_::<CompileError>(
+                fmm::build::comparison_operation(
+                    fmm::ir::ComparisonOperator::LessThan(false),
+                    number.clone(),
+                    fmm::ir::Primitive::Float64(INTEGER64_LIMIT),
+                )?,
+                |builder| {
+                    Ok(builder.branch(compile_bounded_number_to_integer64(
+                        &builder,
+                        compile_truncation(&builder, number.clone())?,
+                    )?))
+                },
+                |builder| {
+                    Ok(builder.branch(builder.if_::<CompileError>(
+                        fmm::build::comparison_operation(
+                            fmm::ir::ComparisonOperator::GreaterThan(false),
+                            number.clone(),
+                            fmm::ir::Primitive::Float64(0.0),
+                        )?,
+                        |builder| {
+                            Ok(builder.branch(fmm::ir::Primitive::Integer64(i64::MAX as u64)))
+                        },
+                        |builder| Ok(builder.branch(fmm::ir::Primitive::Integer64(0))),
+                    )?))
+                },
+            )?))
+        },
+    )
+}
+
+// We split an integral number into its upper and lower 32 bits so that each of
+// them fits in a mantissa.
+fn compile_bounded_number_to_integer64(
+    builder: &fmm::build::InstructionBuilder,
+    number: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    let upper = compile_floor(
+        builder,
+        fmm::build::arithmetic_operation(
+            fmm::ir::ArithmeticOperator::Divide,
+            number.clone(),
+            fmm::ir::Primitive::Float64(INTEGER32_RANGE),
+        )?
+        .into(),
+    )?;
+    let lower = fmm::build::arithmetic_operation(
+        fmm::ir::ArithmeticOperator::Subtract,
+        number,
+        fmm::build::arithmetic_operation(
+            fmm::ir::ArithmeticOperator::Multiply,
+            upper.clone(),
+            fmm::ir::Primitive::Float64(INTEGER32_RANGE),
+        )?,
+    )?;
+
+    Ok(fmm::build::arithmetic_operation(
+        fmm::ir::ArithmeticOperator::Add,
+        fmm::build::bitwise_operation(
+            fmm::ir::BitwiseOperator::LeftShift,
+            compile_small_number_to_integer64(upper)?,
+            fmm::ir::Primitive::Integer64(32),
+        )?,
+        compile_small_number_to_integer64(lower.into())?,
+    )?
+    .into())
+}
+
+fn compile_integer64_to_number(
+    integer: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    Ok(fmm::build::arithmetic_operation(
+        fmm::ir::ArithmeticOperator::Add,
+        fmm::build::arithmetic_operation(
+            fmm::ir::ArithmeticOperator::Multiply,
+            compile_small_integer64_to_number(
+                fmm::build::bitwise_operation(
+                    fmm::ir::BitwiseOperator::RightShift(true),
+                    integer.clone(),
+                    fmm::ir::Primitive::Integer64(32),
+                )?
+                .into(),
+            )?,
+            fmm::ir::Primitive::Float64(INTEGER32_RANGE),
+        )?,
+        compile_small_integer64_to_number(
+            fmm::build::bitwise_operation(
+                fmm::ir::BitwiseOperator::And,
+                integer,
+                fmm::ir::Primitive::Integer64(u32::MAX.into()),
+            )?
+            .into(),
+        )?,
+    )?
+    .into())
+}
+
+fn compile_small_number_to_integer64(
+    number: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    Ok(fmm::build::arithmetic_operation(
+        fmm::ir::ArithmeticOperator::Subtract,
+        fmm::build::bit_cast(
+            fmm::types::Primitive::Integer64,
+            fmm::build::arithmetic_operation(
+                fmm::ir::ArithmeticOperator::Add,
+                number,
+                fmm::ir::Primitive::Float64(INTEGER_CONVERSION_OFFSET),
+            )?,
+        ),
+        fmm::ir::Primitive::Integer64(INTEGER_CONVERSION_OFFSET.to_bits()),
+    )?
+    .into())
+}
+
+fn compile_small_integer64_to_number(
+    integer: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    Ok(fmm::build::arithmetic_operation(
+        fmm::ir::ArithmeticOperator::Subtract,
+        fmm::build::bit_cast(
+            fmm::types::Primitive::Float64,
+            fmm::build::arithmetic_operation(
+                fmm::ir::ArithmeticOperator::Add,
+                integer,
+                fmm::ir::Primitive::Integer64(INTEGER_CONVERSION_OFFSET.to_bits()),
+            )?,
+        ),
+        fmm::ir::Primitive::Float64(INTEGER_CONVERSION_OFFSET),
+    )?
+    .into())
+}
+
+fn compile_floor(
+    builder: &fmm::build::InstructionBuilder,
+    number: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    let truncated = compile_truncation(builder, number.clone())?;
+
+    builder.if_::<CompileError>(
+        fmm::build::comparison_operation(
+            fmm::ir::ComparisonOperator::GreaterThan(false),
+            truncated.clone(),
+            number,
+        )?,
+        |builder| {
+            Ok(builder.branch(fmm::build::arithmetic_operation(
+                fmm::ir::ArithmeticOperator::Subtract,
+                truncated.clone(),
+                fmm::ir::Primitive::Float64(1.0),
+            )?))
+        },
+        |builder| Ok(builder.branch(truncated.clone())),
+    )
+}
+
+// We truncate numbers with floating-point arithmetic as FMM does not have any
+// conversion between integers and floating-point numbers.
+fn compile_truncation(
+    builder: &fmm::build::InstructionBuilder,
+    number: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    builder.if_::<CompileError>(
+        fmm::build::comparison_operation(
+            fmm::ir::ComparisonOperator::LessThan(false),
+            number.clone(),
+            fmm::ir::Primitive::Float64(0.0),
+        )?,
+        |builder| {
+            Ok(builder.branch(fmm::build::arithmetic_operation(
+                fmm::ir::ArithmeticOperator::Subtract,
+                fmm::ir::Primitive::Float64(0.0),
+                compile_non_negative_floor(
+                    &builder,
+                    fmm::build::arithmetic_operation(
+                        fmm::ir::ArithmeticOperator::Subtract,
+                        fmm::ir::Primitive::Float64(0.0),
+                        number.clone(),
+                    )?
+                    .into(),
+                )?,
+            )?))
+        },
+        |builder| Ok(builder.branch(compile_non_negative_floor(&builder, number.clone())?)),
+    )
+}
+
+fn compile_non_negative_floor(
+    builder: &fmm::build::InstructionBuilder,
+    number: fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    builder.if_::<CompileError>(
+        fmm::build::comparison_operation(
+            fmm::ir::ComparisonOperator::LessThan(false),
+            number.clone(),
+            fmm::ir::Primitive::Float64(MAXIMUM_FRACTIONAL_NUMBER),
+        )?,
+        |builder| {
+            // Round a number to the nearest integer.
+            let rounded = fmm::build::TypedExpression::from(fmm::build::arithmetic_operation(
+                fmm::ir::ArithmeticOperator::Subtract,
+                fmm::build::arithmetic_operation(
+                    fmm::ir::ArithmeticOperator::Add,
+                    number.clone(),
+                    fmm::ir::Primitive::Float64(MAXIMUM_FRACTIONAL_NUMBER),
+                )?,
+                fmm::ir::Primitive::Float64(MAXIMUM_FRACTIONAL_NUMBER),
+            )?);
+
+            Ok(builder.branch(builder.if_::<CompileError>(
+                fmm::build::comparison_operation(
+                    fmm::ir::ComparisonOperator::GreaterThan(false),
+                    rounded.clone(),
+                    number.clone(),
+                )?,
+                |builder| {
+                    Ok(builder.branch(fmm::build::arithmetic_operation(
+                        fmm::ir::ArithmeticOperator::Subtract,
+                        rounded.clone(),
+                        fmm::ir::Primitive::Float64(1.0),
+                    )?))
+                },
+                |builder| Ok(builder.branch(rounded.clone())),
+            )?))
+        },
+        |builder| Ok(builder.branch(number.clone())),
+    )
+}
+
 fn compile_comparison_operation(
     context: &Context,
     builder: &fmm::build::InstructionBuilder,
@@ -576,6 +921,84 @@ fn compile_record(
     })
 }
 
+fn clone_variable(
+    context: &Context,
+    builder: &fmm::build::InstructionBuilder,
+    name: &str,
+    value: &fmm::build::TypedExpression,
+    type_: &mir::types::Type,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    if context.unsynchronized_variables().borrow().contains(name) {
+        reference_count::clone_unsynchronized(context, builder, value, type_)
+    } else {
+        reference_count::clone(context, builder, value, type_)
+    }
+}
+
+fn drop_variable(
+    context: &Context,
+    builder: &fmm::build::InstructionBuilder,
+    name: &str,
+    value: &fmm::build::TypedExpression,
+    type_: &mir::types::Type,
+) -> Result<(), CompileError> {
+    if context.unsynchronized_variables().borrow().contains(name) {
+        reference_count::drop_unsynchronized(context, builder, value, type_)
+    } else {
+        reference_count::drop(context, builder, value, type_)
+    }
+}
+
+fn compile_reuse_record(
+    context: &Context,
+    builder: &fmm::build::InstructionBuilder,
+    reuse: &mir::ir::ReuseRecord,
+    variables: &plist::FlailMap<String, fmm::build::TypedExpression>,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    let record_type = reuse.record().type_();
+    let old_record = variables[reuse.variable()].clone();
+    let unboxed_record = compile_unboxed_record(context, builder, reuse.record(), variables)?;
+
+    if !type_::is_record_boxed(context, record_type) {
+        reference_count::drop(context, builder, &old_record, &record_type.clone().into())?;
+
+        return Ok(unboxed_record.into());
+    }
+
+    builder.if_(
+        reference_count::pointer::is_unique(builder, &old_record)?,
+        |builder| {
+            for (index, field_type) in context.types()[record_type.name()]
+                .fields()
+                .iter()
+                .enumerate()
+            {
+                reference_count::drop(
+                    context,
+                    &builder,
+                    &record::get_field(context, &builder, &old_record, record_type, index)?,
+                    field_type,
+                )?;
+            }
+
+            Ok(builder.branch(compile_boxed_record(
+                &builder,
+                old_record.clone(),
+                unboxed_record.clone(),
+            )?))
+        },
+        |builder| {
+            reference_count::drop(context, &builder, &old_record, &record_type.clone().into())?;
+
+            Ok(builder.branch(compile_boxed_record(
+                &builder,
+                allocate_record_heap(context, &builder, record_type)?,
+                unboxed_record.clone(),
+            )?))
+        },
+    )
+}
+
 fn allocate_record_heap(
     context: &Context,
     builder: &fmm::build::InstructionBuilder,
@@ -628,7 +1051,7 @@ fn compile_try_operation(
 
     builder.if_(
         variant::compile_tag_comparison(builder, &operand, operation.type_())?,
-        |builder| -> Result<_, CompileError> {
+        |builder| {
             Ok(builder.return_(compile(
                 context,
                 &builder,
@@ -1,15 +1,22 @@
 use crate::{
     closure, context::Context, entry_function, error::CompileError, reference_count, type_,
 };
+use fnv::{FnvHashMap, FnvHashSet};
 
 pub fn compile(
     context: &Context,
     definition: &mir::ir::GlobalFunctionDefinition,
     global_variables: &plist::FlailMap<String, fmm::build::TypedExpression>,
+    unsynchronized_variables: &FnvHashMap<String, FnvHashSet<String>>,
 ) -> Result<(), CompileError> {
     let public = definition.is_public();
     let definition = definition.definition();
 
+    *context.unsynchronized_variables().borrow_mut() = unsynchronized_variables
+        .get(definition.name())
+        .cloned()
+        .unwrap_or_default();
+
     context.module_builder().define_variable(
         definition.name(),
         reference_count::block::compile_static(closure::compile_content(
@@ -28,15 +28,38 @@ use std::collections::HashMap;
 pub fn compile(
     module: &mir::ir::Module,
     configuration: &Configuration,
+) -> Result<fmm::ir::Module, CompileError> {
+    compile_with_passes(module, configuration, &mut |_, _| {})
+}
+
+// Compile a module calling a callback with a name of each pass and its result
+// module.
+pub fn compile_with_passes(
+    module: &mir::ir::Module,
+    configuration: &Configuration,
+    callback: &mut dyn FnMut(&str, &mir::ir::Module),
 ) -> Result<fmm::ir::Module, CompileError> {
     mir::analysis::type_check::check(module)?;
 
     let module = mir::analysis::optimization::string_concatenation::transform(module);
+    callback("string_concatenation", &module);
+    let module = if configuration.optimize {
+        let module = mir::analysis::optimization::transform(&module);
+        callback("optimization", &module);
+        module
+    } else {
+        module
+    };
     let module = mir::analysis::alpha_conversion::transform(&module);
+    callback("alpha_conversion", &module);
     let module = mir::analysis::normalization::transform(&module);
+    callback("normalization", &module);
     let module = mir::analysis::environment_inference::transform(&module);
+    callback("environment_inference", &module);
     let module = mir::analysis::lambda_lifting::transform(&module);
+    callback("lambda_lifting", &module);
     let module = mir::analysis::reference_count::transform(&module)?;
+    callback("reference_count", &module);
 
     mir::analysis::type_check::check(&module)?;
 
@@ -69,9 +92,16 @@ pub fn compile(
     }
 
     let global_variables = plist::FlailMap::new(global_variables);
+    let unsynchronized_variables =
+        mir::analysis::unsynchronized_variable::find_unsynchronized_variables(&module);
 
     for definition in module.function_definitions() {
-        function_definition::compile(&context, definition, &global_variables)?;
+        function_definition::compile(
+            &context,
+            definition,
+            &global_variables,
+            &unsynchronized_variables,
+        )?;
     }
 
     let function_types = module
@@ -179,26 +209,28 @@ mod tests {
     }
 
     fn compile_module(module: &mir::ir::Module) {
+        compile_module_without_type_information(&add_type_information(module));
+    }
+
+    fn add_type_information(module: &mir::ir::Module) -> mir::ir::Module {
         const DEFAULT_TYPE_INFORMATION_FUNCTION_NAME: &str = "defaultTypeInformationFunction";
 
-        compile_module_without_type_information(
-            &module
-                .set_type_information(mir::ir::TypeInformation::new(
-                    Default::default(),
-                    DEFAULT_TYPE_INFORMATION_FUNCTION_NAME.into(),
-                ))
-                .set_function_declarations(
-                    module
-                        .function_declarations()
-                        .iter()
-                        .cloned()
-                        .chain([mir::ir::FunctionDeclaration::new(
-                            DEFAULT_TYPE_INFORMATION_FUNCTION_NAME,
-                            mir::types::Function::new(vec![], mir::types::Type::None),
-                        )])
-                        .collect(),
-                ),
-        );
+        module
+            .set_type_information(mir::ir::TypeInformation::new(
+                Default::default(),
+                DEFAULT_TYPE_INFORMATION_FUNCTION_NAME.into(),
+            ))
+            .set_function_declarations(
+                module
+                    .function_declarations()
+                    .iter()
+                    .cloned()
+                    .chain([mir::ir::FunctionDeclaration::new(
+                        DEFAULT_TYPE_INFORMATION_FUNCTION_NAME,
+                        mir::types::Function::new(vec![], mir::types::Type::None),
+                    )])
+                    .collect(),
+            )
     }
 
     fn compile_module_without_type_information(module: &mir::ir::Module) {
@@ -1240,6 +1272,145 @@ mod tests {
                         )]),
                 );
             }
+
+            fn count_heap_allocations(block: &fmm::ir::Block) -> usize {
+                block
+                    .instructions()
+                    .iter()
+                    .map(|instruction| match instruction {
+                        fmm::ir::Instruction::AllocateHeap(_) => 1,
+                        fmm::ir::Instruction::If(if_) => {
+                            count_heap_allocations(if_.then()) + count_heap_allocations(if_.else_())
+                        }
+                        _ => 0,
+                    })
+                    .sum()
+            }
+
+            fn collect_if_allocations(block: &fmm::ir::Block) -> Vec<(usize, usize)> {
+                block
+                    .instructions()
+                    .iter()
+                    .flat_map(|instruction| match instruction {
+                        fmm::ir::Instruction::If(if_) => [(
+                            count_heap_allocations(if_.then()),
+                            count_heap_allocations(if_.else_()),
+                        )]
+                        .into_iter()
+                        .chain(collect_if_allocations(if_.then()))
+                        .chain(collect_if_allocations(if_.else_()))
+                        .collect(),
+                        _ => vec![],
+                    })
+                    .collect()
+            }
+
+            #[test]
+            fn update_unique_record_in_place() {
+                let record_type = mir::types::Record::new("foo");
+                let module = compile(
+                    &add_type_information(
+                        &mir::ir::Module::empty()
+                            .set_type_definitions(vec![mir::ir::TypeDefinition::new(
+                                "foo",
+                                mir::types::RecordBody::new(vec![mir::types::Type::Number]),
+                            )])
+                            .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                                "f",
+                                vec![mir::ir::Argument::new("x", record_type.clone())],
+                                record_type.clone(),
+                                mir::ir::RecordUpdate::new(
+                                    record_type,
+                                    mir::ir::Variable::new("x"),
+                                    vec![mir::ir::RecordUpdateField::new(0, 42.0)],
+                                ),
+                            )]),
+                    ),
+                    &CONFIGURATION,
+                )
+                .unwrap();
+
+                // A unique record is updated without allocation while a shared
+                // one is copied into a new heap block.
+                assert!(module
+                    .function_definitions()
+                    .iter()
+                    .flat_map(|definition| collect_if_allocations(definition.body()))
+                    .any(|allocations| allocations == (0, 1)));
+            }
+        }
+
+        mod reuse_record {
+            use super::*;
+
+            fn compile_reuse(field_types: Vec<mir::types::Type>, fields: Vec<mir::ir::Expression>) {
+                let record_type = mir::types::Record::new("foo");
+
+                compile_module(
+                    &mir::ir::Module::empty()
+                        .set_type_definitions(vec![mir::ir::TypeDefinition::new(
+                            "foo",
+                            mir::types::RecordBody::new(field_types),
+                        )])
+                        .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![mir::ir::Argument::new("x", record_type.clone())],
+                            record_type.clone(),
+                            mir::ir::Record::new(record_type, fields),
+                        )]),
+                );
+            }
+
+            #[test]
+            fn compile_with_empty_record() {
+                compile_reuse(vec![], vec![]);
+            }
+
+            #[test]
+            fn compile_with_1_field() {
+                compile_reuse(vec![mir::types::Type::Number], vec![42.0.into()]);
+            }
+
+            #[test]
+            fn compile_with_2_fields() {
+                compile_reuse(
+                    vec![mir::types::Type::Number, mir::types::Type::ByteString],
+                    vec![42.0.into(), mir::ir::ByteString::new("foo").into()],
+                );
+            }
+        }
+
+        mod arithmetic_operation {
+            use super::*;
+
+            #[test]
+            fn compile_operators() {
+                for operator in [
+                    mir::ir::ArithmeticOperator::IntegerDivide,
+                    mir::ir::ArithmeticOperator::Remainder,
+                    mir::ir::ArithmeticOperator::And,
+                    mir::ir::ArithmeticOperator::Or,
+                    mir::ir::ArithmeticOperator::Xor,
+                    mir::ir::ArithmeticOperator::LeftShift,
+                    mir::ir::ArithmeticOperator::RightShift,
+                ] {
+                    compile_module(&mir::ir::Module::empty().set_function_definitions(vec![
+                        mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![
+                                mir::ir::Argument::new("x", mir::types::Type::Number),
+                                mir::ir::Argument::new("y", mir::types::Type::Number),
+                            ],
+                            mir::types::Type::Number,
+                            mir::ir::ArithmeticOperation::new(
+                                operator,
+                                mir::ir::Variable::new("x"),
+                                mir::ir::Variable::new("y"),
+                            ),
+                        ),
+                    ]));
+                }
+            }
         }
 
         mod string_concatenation {
@@ -1671,6 +1842,261 @@ mod tests {
                     )]),
             );
         }
+
+        #[test]
+        fn clone_and_drop_unsynchronized_record() {
+            let record_type = mir::types::Record::new("a");
+
+            compile_module(
+                &mir::ir::Module::empty()
+                    .set_type_definitions(vec![mir::ir::TypeDefinition::new(
+                        "a",
+                        mir::types::RecordBody::new(vec![
+                            mir::types::Type::Number,
+                            mir::types::Type::ByteString,
+                        ]),
+                    )])
+                    .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                        "f",
+                        vec![mir::ir::Argument::new("y", mir::types::Type::ByteString)],
+                        mir::types::Type::Number,
+                        mir::ir::Let::new(
+                            "x",
+                            record_type.clone(),
+                            mir::ir::Record::new(
+                                record_type.clone(),
+                                vec![42.0.into(), mir::ir::Variable::new("y").into()],
+                            ),
+                            mir::ir::ArithmeticOperation::new(
+                                mir::ir::ArithmeticOperator::Add,
+                                mir::ir::RecordField::new(
+                                    record_type.clone(),
+                                    0,
+                                    mir::ir::Variable::new("x"),
+                                ),
+                                mir::ir::RecordField::new(
+                                    record_type,
+                                    0,
+                                    mir::ir::Variable::new("x"),
+                                ),
+                            ),
+                        ),
+                    )]),
+            );
+        }
+
+        // Reference count operations on synchronized memory blocks are atomic
+        // operations or calls of reference count functions.
+        fn count_synchronized_reference_count_operations(block: &fmm::ir::Block) -> usize {
+            block
+                .instructions()
+                .iter()
+                .map(|instruction| match instruction {
+                    fmm::ir::Instruction::AtomicLoad(_)
+                    | fmm::ir::Instruction::AtomicOperation(_)
+                    | fmm::ir::Instruction::AtomicStore(_)
+                    | fmm::ir::Instruction::CompareAndSwap(_)
+                    | fmm::ir::Instruction::Fence(_) => 1,
+                    fmm::ir::Instruction::Call(call) => match call.function() {
+                        fmm::ir::Expression::Variable(variable)
+                            if variable.name().starts_with("mir:clone:")
+                                || variable.name().starts_with("mir:drop:") =>
+                        {
+                            1
+                        }
+                        _ => 0,
+                    },
+                    fmm::ir::Instruction::If(if_) => {
+                        count_synchronized_reference_count_operations(if_.then())
+                            + count_synchronized_reference_count_operations(if_.else_())
+                    }
+                    _ => 0,
+                })
+                .sum()
+        }
+
+        fn count_synchronized_reference_count_operations_in_module(
+            module: &mir::ir::Module,
+        ) -> usize {
+            compile(
+                &add_type_information(module),
+                &Configuration {
+                    optimize: false,
+                    ..CONFIGURATION.clone()
+                },
+            )
+            .unwrap()
+            .function_definitions()
+            .iter()
+            .filter(|definition| !definition.name().starts_with("mir:"))
+            .map(|definition| count_synchronized_reference_count_operations(definition.body()))
+            .sum()
+        }
+
+        fn count_operations_of_record_passed_to(function_name: &str) -> usize {
+            let record_type = mir::types::Record::new("a");
+            let function_type = mir::types::Function::new(
+                vec![record_type.clone().into()],
+                mir::types::Type::Number,
+            );
+
+            count_synchronized_reference_count_operations_in_module(
+                &mir::ir::Module::empty()
+                    .set_type_definitions(vec![mir::ir::TypeDefinition::new(
+                        "a",
+                        mir::types::RecordBody::new(vec![
+                            mir::types::Type::Number,
+                            mir::types::Type::ByteString,
+                        ]),
+                    )])
+                    .set_function_declarations(vec![mir::ir::FunctionDeclaration::new(
+                        "h",
+                        function_type.clone(),
+                    )])
+                    .set_function_definitions(vec![
+                        mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![mir::ir::Argument::new("y", mir::types::Type::ByteString)],
+                            mir::types::Type::Number,
+                            mir::ir::Let::new(
+                                "x",
+                                record_type.clone(),
+                                mir::ir::Record::new(
+                                    record_type.clone(),
+                                    vec![42.0.into(), mir::ir::Variable::new("y").into()],
+                                ),
+                                mir::ir::ArithmeticOperation::new(
+                                    mir::ir::ArithmeticOperator::Add,
+                                    mir::ir::Call::new(
+                                        function_type,
+                                        mir::ir::Variable::new(function_name),
+                                        vec![mir::ir::Variable::new("x").into()],
+                                    ),
+                                    mir::ir::RecordField::new(
+                                        record_type.clone(),
+                                        0,
+                                        mir::ir::Variable::new("x"),
+                                    ),
+                                ),
+                            ),
+                        ),
+                        mir::ir::FunctionDefinition::new(
+                            "g",
+                            vec![mir::ir::Argument::new("z", record_type.clone())],
+                            mir::types::Type::Number,
+                            mir::ir::RecordField::new(record_type, 0, mir::ir::Variable::new("z")),
+                        ),
+                    ]),
+            )
+        }
+
+        #[test]
+        fn clone_and_drop_record_passed_to_non_escaping_function_without_atomic_operations() {
+            assert!(
+                count_operations_of_record_passed_to("g")
+                    < count_operations_of_record_passed_to("h")
+            );
+        }
+
+        #[test]
+        fn compile_fibonacci_without_atomic_operations() {
+            let function_type =
+                mir::types::Function::new(vec![mir::types::Type::Number], mir::types::Type::Number);
+            let call = |difference: f64| {
+                mir::ir::Call::new(
+                    function_type.clone(),
+                    mir::ir::Variable::new("fibonacci"),
+                    vec![mir::ir::ArithmeticOperation::new(
+                        mir::ir::ArithmeticOperator::Subtract,
+                        mir::ir::Variable::new("n"),
+                        difference,
+                    )
+                    .into()],
+                )
+            };
+
+            assert_eq!(
+                count_synchronized_reference_count_operations_in_module(
+                    &mir::ir::Module::empty().set_function_definitions(vec![
+                        mir::ir::FunctionDefinition::new(
+                            "fibonacci",
+                            vec![mir::ir::Argument::new("n", mir::types::Type::Number)],
+                            mir::types::Type::Number,
+                            mir::ir::If::new(
+                                mir::ir::ComparisonOperation::new(
+                                    mir::ir::ComparisonOperator::LessThanOrEqual,
+                                    mir::ir::Variable::new("n"),
+                                    0.0,
+                                ),
+                                0.0,
+                                mir::ir::If::new(
+                                    mir::ir::ComparisonOperation::new(
+                                        mir::ir::ComparisonOperator::Equal,
+                                        mir::ir::Variable::new("n"),
+                                        1.0,
+                                    ),
+                                    1.0,
+                                    mir::ir::ArithmeticOperation::new(
+                                        mir::ir::ArithmeticOperator::Add,
+                                        call(1.0),
+                                        call(2.0),
+                                    ),
+                                ),
+                            ),
+                        )
+                    ]),
+                ),
+                0
+            );
+        }
+
+        #[test]
+        fn compile_sum_without_atomic_operations() {
+            assert_eq!(
+                count_synchronized_reference_count_operations_in_module(
+                    &mir::ir::Module::empty().set_function_definitions(vec![
+                        mir::ir::FunctionDefinition::new(
+                            "sum",
+                            vec![
+                                mir::ir::Argument::new("x", mir::types::Type::Number),
+                                mir::ir::Argument::new("i", mir::types::Type::Number),
+                            ],
+                            mir::types::Type::Number,
+                            mir::ir::If::new(
+                                mir::ir::ComparisonOperation::new(
+                                    mir::ir::ComparisonOperator::Equal,
+                                    mir::ir::Variable::new("i"),
+                                    0.0,
+                                ),
+                                mir::ir::Variable::new("x"),
+                                mir::ir::Call::new(
+                                    mir::types::Function::new(
+                                        vec![mir::types::Type::Number, mir::types::Type::Number],
+                                        mir::types::Type::Number,
+                                    ),
+                                    mir::ir::Variable::new("sum"),
+                                    vec![
+                                        mir::ir::ArithmeticOperation::new(
+                                            mir::ir::ArithmeticOperator::Add,
+                                            mir::ir::Variable::new("x"),
+                                            mir::ir::Variable::new("i"),
+                                        )
+                                        .into(),
+                                        mir::ir::ArithmeticOperation::new(
+                                            mir::ir::ArithmeticOperator::Subtract,
+                                            mir::ir::Variable::new("i"),
+                                            1.0,
+                                        )
+                                        .into(),
+                                    ],
+                                ),
+                            ),
+                        )
+                    ]),
+                ),
+                0
+            );
+        }
     }
 
     mod thunk {
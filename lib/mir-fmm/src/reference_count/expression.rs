@@ -35,6 +35,38 @@ pub fn drop(
     Ok(())
 }
 
+// Memory blocks of values never synchronized can be cloned or dropped without
+// atomic operations.
+pub fn clone_unsynchronized(
+    context: &Context,
+    builder: &fmm::build::InstructionBuilder,
+    expression: &fmm::build::TypedExpression,
+    type_: &mir::types::Type,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    Ok(match type_ {
+        mir::types::Type::Record(record) => {
+            record::clone_unsynchronized(context, builder, expression, record)?
+        }
+        _ => clone(context, builder, expression, type_)?,
+    })
+}
+
+pub fn drop_unsynchronized(
+    context: &Context,
+    builder: &fmm::build::InstructionBuilder,
+    expression: &fmm::build::TypedExpression,
+    type_: &mir::types::Type,
+) -> Result<(), CompileError> {
+    match type_ {
+        mir::types::Type::Record(record) => {
+            record::drop_unsynchronized(context, builder, expression, record)?
+        }
+        _ => drop(context, builder, expression, type_)?,
+    }
+
+    Ok(())
+}
+
 pub fn synchronize(
     context: &Context,
     builder: &fmm::build::InstructionBuilder,
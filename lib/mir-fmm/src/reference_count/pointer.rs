@@ -167,6 +167,59 @@ pub fn drop(
     Ok(())
 }
 
+// Counts of unsynchronized memory blocks are updated without atomic operations
+// or any checks of their synchronization.
+pub fn clone_unsynchronized(
+    builder: &fmm::build::InstructionBuilder,
+    pointer: &fmm::build::TypedExpression,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    let count_pointer = block::compile_count_pointer(pointer)?;
+
+    builder.store(
+        fmm::build::arithmetic_operation(
+            fmm::ir::ArithmeticOperator::Add,
+            builder.load(count_pointer.clone())?,
+            count::compile(1),
+        )?,
+        count_pointer,
+    );
+
+    Ok(pointer.clone())
+}
+
+pub fn drop_unsynchronized(
+    builder: &fmm::build::InstructionBuilder,
+    pointer: &fmm::build::TypedExpression,
+    drop_content: impl Fn(&fmm::build::InstructionBuilder) -> Result<(), CompileError>,
+) -> Result<(), CompileError> {
+    let count_pointer = block::compile_count_pointer(pointer)?;
+    let count = builder.load(count_pointer.clone())?;
+
+    builder.if_(
+        count::is_unique(&count)?,
+        |builder| -> Result<_, CompileError> {
+            drop_content(&builder)?;
+            heap::free(&builder, pointer.clone())?;
+
+            Ok(builder.branch(fmm::ir::void_value()))
+        },
+        |builder| {
+            builder.store(
+                fmm::build::arithmetic_operation(
+                    fmm::ir::ArithmeticOperator::Subtract,
+                    count.clone(),
+                    count::compile(1),
+                )?,
+                count_pointer.clone(),
+            );
+
+            Ok(builder.branch(fmm::ir::void_value()))
+        },
+    )?;
+
+    Ok(())
+}
+
 pub fn synchronize(
     builder: &fmm::build::InstructionBuilder,
     pointer: &fmm::build::TypedExpression,
@@ -269,6 +269,39 @@ pub fn drop(
     Ok(())
 }
 
+pub fn clone_unsynchronized(
+    context: &Context,
+    builder: &fmm::build::InstructionBuilder,
+    expression: &fmm::build::TypedExpression,
+    record_type: &mir::types::Record,
+) -> Result<fmm::build::TypedExpression, CompileError> {
+    if type_::is_record_boxed(context, record_type) {
+        pointer::clone_unsynchronized(builder, expression)
+    } else {
+        clone(context, builder, expression, record_type)
+    }
+}
+
+pub fn drop_unsynchronized(
+    context: &Context,
+    builder: &fmm::build::InstructionBuilder,
+    expression: &fmm::build::TypedExpression,
+    record_type: &mir::types::Record,
+) -> Result<(), CompileError> {
+    if type_::is_record_boxed(context, record_type) {
+        pointer::drop_unsynchronized(builder, expression, |builder| {
+            drop_unboxed(
+                context,
+                builder,
+                &record::load(context, builder, expression, record_type)?,
+                record_type,
+            )
+        })
+    } else {
+        drop(context, builder, expression, record_type)
+    }
+}
+
 pub fn synchronize(
     context: &Context,
     builder: &fmm::build::InstructionBuilder,
@@ -0,0 +1,73 @@
+use crate::value::Value;
+use std::rc::Rc;
+
+// A persistent environment of local variables.
+//
+// Environments are shared by closures without copying their variables.
+#[derive(Clone, Debug, Default)]
+pub struct Environment(Option<Rc<Entry>>);
+
+#[derive(Debug)]
+struct Entry {
+    name: String,
+    value: Value,
+    parent: Environment,
+}
+
+impl Environment {
+    pub fn insert(&self, name: impl Into<String>, value: Value) -> Self {
+        Self(Some(
+            Entry {
+                name: name.into(),
+                value,
+                parent: self.clone(),
+            }
+            .into(),
+        ))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        let mut environment = self;
+
+        while let Some(entry) = &environment.0 {
+            if entry.name == name {
+                return Some(&entry.value);
+            }
+
+            environment = &entry.parent;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_nothing() {
+        assert_eq!(Environment::default().get("x"), None);
+    }
+
+    #[test]
+    fn get_variable() {
+        assert_eq!(
+            Environment::default()
+                .insert("x", Value::Number(42.0))
+                .get("x"),
+            Some(&Value::Number(42.0))
+        );
+    }
+
+    #[test]
+    fn get_shadowed_variable() {
+        let environment = Environment::default().insert("x", Value::Number(1.0));
+
+        assert_eq!(
+            environment.insert("x", Value::Number(2.0)).get("x"),
+            Some(&Value::Number(2.0))
+        );
+        assert_eq!(environment.get("x"), Some(&Value::Number(1.0)));
+    }
+}
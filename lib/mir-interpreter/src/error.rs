@@ -0,0 +1,44 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterpretError {
+    BooleanExpected,
+    ByteStringExpected,
+    CaseNotMatched(mir::types::Type),
+    ForeignFunctionNotSupported(String),
+    FunctionExpected,
+    NumberExpected,
+    RecordExpected,
+    VariableNotFound(String),
+    VariantExpected,
+    WrongArgumentCount { expected: usize, actual: usize },
+}
+
+impl Display for InterpretError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::BooleanExpected => write!(formatter, "boolean expected"),
+            Self::ByteStringExpected => write!(formatter, "string expected"),
+            Self::CaseNotMatched(type_) => {
+                write!(formatter, "no alternative matched for type {type_:?}")
+            }
+            Self::ForeignFunctionNotSupported(name) => {
+                write!(formatter, "foreign function \"{name}\" not supported")
+            }
+            Self::FunctionExpected => write!(formatter, "function expected"),
+            Self::NumberExpected => write!(formatter, "number expected"),
+            Self::RecordExpected => write!(formatter, "record expected"),
+            Self::VariableNotFound(name) => write!(formatter, "variable \"{name}\" not found"),
+            Self::VariantExpected => write!(formatter, "variant expected"),
+            Self::WrongArgumentCount { expected, actual } => write!(
+                formatter,
+                "wrong argument count (expected {expected} but got {actual})"
+            ),
+        }
+    }
+}
+
+impl Error for InterpretError {}
@@ -0,0 +1,83 @@
+use crate::{
+    environment::Environment, error::InterpretError, native_function::NativeFunction, value::Value,
+};
+use std::cell::OnceCell;
+
+#[derive(Debug)]
+pub enum Function {
+    Closure(Closure),
+    Foreign(ForeignFunction),
+}
+
+// A closure keeps a result value once it is evaluated if it is a thunk.
+#[derive(Debug)]
+pub struct Closure {
+    definition: mir::ir::FunctionDefinition,
+    environment: Environment,
+    value: OnceCell<Value>,
+}
+
+impl Closure {
+    pub(crate) fn new(definition: mir::ir::FunctionDefinition, environment: Environment) -> Self {
+        Self {
+            definition,
+            environment,
+            value: OnceCell::new(),
+        }
+    }
+
+    pub fn definition(&self) -> &mir::ir::FunctionDefinition {
+        &self.definition
+    }
+
+    pub(crate) fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    pub(crate) fn value(&self) -> Option<&Value> {
+        self.value.get()
+    }
+
+    pub(crate) fn set_value(&self, value: Value) {
+        self.value.get_or_init(|| value);
+    }
+}
+
+// A foreign function is called natively if it is supported by an interpreter.
+#[derive(Debug)]
+pub struct ForeignFunction {
+    name: String,
+    function: Option<NativeFunction>,
+}
+
+impl ForeignFunction {
+    pub(crate) fn new(name: impl Into<String>, function: Option<NativeFunction>) -> Self {
+        Self {
+            name: name.into(),
+            function,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn call(&self, arguments: &[Value]) -> Result<Value, InterpretError> {
+        self.function
+            .ok_or_else(|| InterpretError::ForeignFunctionNotSupported(self.name.clone()))?(
+            arguments,
+        )
+    }
+}
+
+impl From<Closure> for Value {
+    fn from(closure: Closure) -> Self {
+        Function::Closure(closure).into()
+    }
+}
+
+impl From<ForeignFunction> for Value {
+    fn from(function: ForeignFunction) -> Self {
+        Function::Foreign(function).into()
+    }
+}
@@ -0,0 +1,724 @@
+use crate::{
+    environment::Environment,
+    error::InterpretError,
+    function::{Closure, ForeignFunction, Function},
+    native_function,
+    value::{self, Value},
+};
+use fnv::FnvHashMap;
+use mir::{ir::*, types::Type};
+
+// Control flow of expression evaluation where try operations return from their
+// functions early.
+enum Flow {
+    Return(Value),
+    Error(InterpretError),
+}
+
+impl From<InterpretError> for Flow {
+    fn from(error: InterpretError) -> Self {
+        Self::Error(error)
+    }
+}
+
+pub struct Interpreter {
+    variables: FnvHashMap<String, Value>,
+    type_information: FnvHashMap<Type, String>,
+    type_information_fallback: String,
+}
+
+impl Interpreter {
+    // Link modules into an interpreter.
+    //
+    // Foreign functions are resolved to functions exported by the modules
+    // first and then native functions of the interpreter.
+    pub fn new(modules: &[Module]) -> Self {
+        let mut variables = FnvHashMap::default();
+        let mut type_information = FnvHashMap::default();
+        let mut type_information_fallback = String::new();
+
+        for module in modules {
+            for definition in module.function_definitions() {
+                variables.insert(
+                    definition.definition().name().into(),
+                    Closure::new(definition.definition().clone(), Default::default()).into(),
+                );
+            }
+
+            for (type_, name) in module.type_information().information() {
+                type_information
+                    .entry(type_.clone())
+                    .or_insert_with(|| name.clone());
+            }
+
+            if type_information_fallback.is_empty() {
+                module
+                    .type_information()
+                    .fallback()
+                    .clone_into(&mut type_information_fallback);
+            }
+        }
+
+        for module in modules {
+            for declaration in module.foreign_declarations() {
+                let value = modules
+                    .iter()
+                    .flat_map(|module| module.foreign_definitions())
+                    .find(|definition| definition.foreign_name() == declaration.foreign_name())
+                    .and_then(|definition| variables.get(definition.name()).cloned())
+                    .unwrap_or_else(|| {
+                        ForeignFunction::new(
+                            declaration.foreign_name(),
+                            native_function::find(declaration.foreign_name()),
+                        )
+                        .into()
+                    });
+
+                variables.entry(declaration.name().into()).or_insert(value);
+            }
+        }
+
+        Self {
+            variables,
+            type_information,
+            type_information_fallback,
+        }
+    }
+
+    // Find a global function.
+    pub fn function(&self, name: &str) -> Result<&Value, InterpretError> {
+        self.variables
+            .get(name)
+            .ok_or_else(|| InterpretError::VariableNotFound(name.into()))
+    }
+
+    pub fn call(&self, function: &Value, arguments: &[Value]) -> Result<Value, InterpretError> {
+        match function.to_function()? {
+            Function::Closure(closure) => {
+                if let Some(value) = closure.value() {
+                    return Ok(value.clone());
+                }
+
+                let definition = closure.definition();
+
+                if arguments.len() != definition.arguments().len() {
+                    return Err(InterpretError::WrongArgumentCount {
+                        expected: definition.arguments().len(),
+                        actual: arguments.len(),
+                    });
+                }
+
+                let mut environment = closure
+                    .environment()
+                    .insert(definition.name(), function.clone());
+
+                for (argument, value) in definition.arguments().iter().zip(arguments) {
+                    environment = environment.insert(argument.name(), value.clone());
+                }
+
+                let value = match self.evaluate(definition.body(), &environment) {
+                    Ok(value) | Err(Flow::Return(value)) => value,
+                    Err(Flow::Error(error)) => return Err(error),
+                };
+
+                if definition.is_thunk() {
+                    closure.set_value(value.clone());
+                }
+
+                Ok(value)
+            }
+            Function::Foreign(function) => function.call(arguments),
+        }
+    }
+
+    fn evaluate(&self, expression: &Expression, environment: &Environment) -> Result<Value, Flow> {
+        let evaluate = |expression| self.evaluate(expression, environment);
+
+        Ok(match expression {
+            Expression::ArithmeticOperation(operation) => evaluate_arithmetic_operation(
+                operation.operator(),
+                evaluate(operation.lhs())?.to_number()?,
+                evaluate(operation.rhs())?.to_number()?,
+            )
+            .into(),
+            Expression::Boolean(boolean) => (*boolean).into(),
+            Expression::ByteString(string) => string.value().into(),
+            Expression::Call(call) => {
+                let function = evaluate(call.function())?;
+                let arguments = call
+                    .arguments()
+                    .iter()
+                    .map(evaluate)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                self.call(&function, &arguments)?
+            }
+            Expression::Case(case) => self.evaluate_case(case, environment)?,
+            Expression::CloneVariables(clone) => evaluate(clone.expression())?,
+            Expression::ComparisonOperation(operation) => evaluate_comparison_operation(
+                operation.operator(),
+                evaluate(operation.lhs())?.to_number()?,
+                evaluate(operation.rhs())?.to_number()?,
+            )
+            .into(),
+            Expression::DropVariables(drop) => evaluate(drop.expression())?,
+            Expression::If(if_) => {
+                if evaluate(if_.condition())?.to_boolean()? {
+                    evaluate(if_.then())?
+                } else {
+                    evaluate(if_.else_())?
+                }
+            }
+            Expression::Let(let_) => self.evaluate(
+                let_.expression(),
+                &environment.insert(let_.name(), evaluate(let_.bound_expression())?),
+            )?,
+            Expression::LetRecursive(let_) => self.evaluate(
+                let_.expression(),
+                &environment.insert(
+                    let_.definition().name(),
+                    Closure::new(let_.definition().clone(), environment.clone()).into(),
+                ),
+            )?,
+            Expression::Synchronize(synchronize) => evaluate(synchronize.expression())?,
+            Expression::None => Value::None,
+            Expression::Number(number) => (*number).into(),
+            Expression::Record(record) => self.evaluate_record(record, environment)?,
+            Expression::RecordField(field) => {
+                evaluate(field.record())?.to_record()?.fields()[field.index()].clone()
+            }
+            Expression::RecordUpdate(update) => {
+                let mut fields = evaluate(update.record())?.to_record()?.fields().to_vec();
+
+                for field in update.fields() {
+                    fields[field.index()] = evaluate(field.expression())?;
+                }
+
+                value::Record::new(update.type_().clone(), fields).into()
+            }
+            Expression::ReuseRecord(reuse) => self.evaluate_record(reuse.record(), environment)?,
+            Expression::StringConcatenation(concatenation) => {
+                let mut string = vec![];
+
+                for operand in concatenation.operands() {
+                    string.extend_from_slice(evaluate(operand)?.to_byte_string()?);
+                }
+
+                string.into()
+            }
+            Expression::TryOperation(operation) => {
+                let operand = evaluate(operation.operand())?;
+                let variant = operand.to_variant()?;
+
+                if variant.type_() == operation.type_() {
+                    return Err(Flow::Return(self.evaluate(
+                        operation.then(),
+                        &environment.insert(operation.name(), variant.payload().clone()),
+                    )?));
+                }
+
+                operand
+            }
+            Expression::TypeInformationFunction(information) => {
+                let variant = evaluate(information.variant())?;
+
+                self.function(
+                    self.type_information
+                        .get(variant.to_variant()?.type_())
+                        .unwrap_or(&self.type_information_fallback),
+                )?
+                .clone()
+            }
+            Expression::Variable(variable) => environment
+                .get(variable.name())
+                .map(Ok)
+                .unwrap_or_else(|| self.function(variable.name()))?
+                .clone(),
+            Expression::Variant(variant) => {
+                value::Variant::new(variant.type_().clone(), evaluate(variant.payload())?).into()
+            }
+        })
+    }
+
+    fn evaluate_case(&self, case: &Case, environment: &Environment) -> Result<Value, Flow> {
+        let argument = self.evaluate(case.argument(), environment)?;
+        let variant = argument.to_variant()?;
+
+        for alternative in case.alternatives() {
+            if alternative.types().contains(variant.type_()) {
+                return self.evaluate(
+                    alternative.expression(),
+                    &environment.insert(
+                        alternative.name(),
+                        if alternative.types().len() == 1 {
+                            variant.payload().clone()
+                        } else {
+                            argument.clone()
+                        },
+                    ),
+                );
+            }
+        }
+
+        if let Some(alternative) = case.default_alternative() {
+            self.evaluate(
+                alternative.expression(),
+                &environment.insert(alternative.name(), argument.clone()),
+            )
+        } else {
+            Err(InterpretError::CaseNotMatched(variant.type_().clone()).into())
+        }
+    }
+
+    fn evaluate_record(&self, record: &Record, environment: &Environment) -> Result<Value, Flow> {
+        Ok(value::Record::new(
+            record.type_().clone(),
+            record
+                .fields()
+                .iter()
+                .map(|field| self.evaluate(field, environment))
+                .collect::<Result<_, _>>()?,
+        )
+        .into())
+    }
+}
+
+fn evaluate_arithmetic_operation(operator: ArithmeticOperator, lhs: f64, rhs: f64) -> f64 {
+    // Bitwise operations convert numbers into 64-bit integers and back. Shift
+    // counts are masked into the range of 0 to 63.
+    let evaluate_bitwise_operation =
+        |operate: fn(i64, i64) -> i64| operate(lhs as i64, rhs as i64) as f64;
+
+    match operator {
+        ArithmeticOperator::Add => lhs + rhs,
+        ArithmeticOperator::Subtract => lhs - rhs,
+        ArithmeticOperator::Multiply => lhs * rhs,
+        ArithmeticOperator::Divide => lhs / rhs,
+        ArithmeticOperator::IntegerDivide => (lhs / rhs).trunc(),
+        ArithmeticOperator::Remainder => lhs % rhs,
+        ArithmeticOperator::And => evaluate_bitwise_operation(|lhs, rhs| lhs & rhs),
+        ArithmeticOperator::Or => evaluate_bitwise_operation(|lhs, rhs| lhs | rhs),
+        ArithmeticOperator::Xor => evaluate_bitwise_operation(|lhs, rhs| lhs ^ rhs),
+        ArithmeticOperator::LeftShift => {
+            evaluate_bitwise_operation(|lhs, rhs| lhs.wrapping_shl(rhs as u32))
+        }
+        ArithmeticOperator::RightShift => {
+            evaluate_bitwise_operation(|lhs, rhs| lhs.wrapping_shr(rhs as u32))
+        }
+    }
+}
+
+fn evaluate_comparison_operation(operator: ComparisonOperator, lhs: f64, rhs: f64) -> bool {
+    match operator {
+        ComparisonOperator::Equal => lhs == rhs,
+        ComparisonOperator::NotEqual => lhs != rhs,
+        ComparisonOperator::LessThan => lhs < rhs,
+        ComparisonOperator::GreaterThan => lhs > rhs,
+        ComparisonOperator::LessThanOrEqual => lhs <= rhs,
+        ComparisonOperator::GreaterThanOrEqual => lhs >= rhs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mir::{test::ModuleFake, types};
+    use pretty_assertions::assert_eq;
+    use std::slice;
+
+    fn call_function(
+        module: &Module,
+        name: &str,
+        arguments: &[Value],
+    ) -> Result<Value, InterpretError> {
+        let interpreter = Interpreter::new(slice::from_ref(module));
+
+        interpreter.call(interpreter.function(name)?, arguments)
+    }
+
+    fn evaluate(expression: impl Into<Expression>) -> Result<Value, InterpretError> {
+        call_function(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec![],
+                Type::None,
+                expression,
+            )]),
+            "f",
+            &[],
+        )
+    }
+
+    #[test]
+    fn evaluate_number() {
+        assert_eq!(evaluate(42.0), Ok(42.0.into()));
+    }
+
+    #[test]
+    fn evaluate_arithmetic_operations() {
+        for (operator, value) in [
+            (ArithmeticOperator::Add, 9.0),
+            (ArithmeticOperator::Subtract, 5.0),
+            (ArithmeticOperator::Multiply, 14.0),
+            (ArithmeticOperator::Divide, 3.5),
+            (ArithmeticOperator::IntegerDivide, 3.0),
+            (ArithmeticOperator::Remainder, 1.0),
+        ] {
+            assert_eq!(
+                evaluate(ArithmeticOperation::new(operator, 7.0, 2.0)),
+                Ok(value.into())
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_negative_remainder() {
+        assert_eq!(
+            evaluate(ArithmeticOperation::new(
+                ArithmeticOperator::Remainder,
+                -7.0,
+                2.0
+            )),
+            Ok((-1.0).into())
+        );
+    }
+
+    #[test]
+    fn evaluate_bitwise_operation() {
+        assert_eq!(
+            evaluate(ArithmeticOperation::new(
+                ArithmeticOperator::Xor,
+                42.0,
+                42.0
+            )),
+            Ok(0.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_shift_operations() {
+        for (operator, lhs, rhs, value) in [
+            (ArithmeticOperator::LeftShift, 3.0, 2.0, 12.0),
+            (ArithmeticOperator::LeftShift, 1.0, 65.0, 2.0),
+            (ArithmeticOperator::RightShift, -8.0, 1.0, -4.0),
+        ] {
+            assert_eq!(
+                evaluate(ArithmeticOperation::new(operator, lhs, rhs)),
+                Ok(value.into())
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_comparison_operation() {
+        assert_eq!(
+            evaluate(ComparisonOperation::new(
+                ComparisonOperator::LessThan,
+                1.0,
+                2.0
+            )),
+            Ok(true.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_if() {
+        assert_eq!(evaluate(If::new(false, 1.0, 2.0)), Ok(2.0.into()));
+    }
+
+    #[test]
+    fn evaluate_let() {
+        assert_eq!(
+            evaluate(Let::new("x", Type::Number, 42.0, Variable::new("x"))),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_string_concatenation() {
+        assert_eq!(
+            evaluate(StringConcatenation::new(vec![
+                ByteString::new("foo").into(),
+                ByteString::new("bar").into(),
+            ])),
+            Ok("foobar".into())
+        );
+    }
+
+    #[test]
+    fn evaluate_record_field() {
+        let record_type = types::Record::new("r");
+
+        assert_eq!(
+            evaluate(RecordField::new(
+                record_type.clone(),
+                1,
+                Record::new(record_type, vec![1.0.into(), 2.0.into()]),
+            )),
+            Ok(2.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_record_update() {
+        let record_type = types::Record::new("r");
+
+        assert_eq!(
+            evaluate(RecordUpdate::new(
+                record_type.clone(),
+                Record::new(record_type.clone(), vec![1.0.into(), 2.0.into()]),
+                vec![RecordUpdateField::new(0, 3.0)],
+            )),
+            Ok(crate::Record::new(record_type, vec![3.0.into(), 2.0.into()]).into())
+        );
+    }
+
+    #[test]
+    fn evaluate_case() {
+        assert_eq!(
+            evaluate(Case::new(
+                Variant::new(Type::Number, 42.0),
+                vec![
+                    Alternative::new(vec![Type::Boolean], "x", 0.0),
+                    Alternative::new(vec![Type::Number], "x", Variable::new("x")),
+                ],
+                None,
+            )),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_case_with_default_alternative() {
+        assert_eq!(
+            evaluate(Case::new(
+                Variant::new(Type::Number, 42.0),
+                vec![Alternative::new(vec![Type::Boolean], "x", 0.0)],
+                Some(DefaultAlternative::new("x", Variable::new("x"))),
+            )),
+            Ok(crate::Variant::new(Type::Number, 42.0.into()).into())
+        );
+    }
+
+    #[test]
+    fn evaluate_case_with_union_alternative() {
+        assert_eq!(
+            evaluate(Case::new(
+                Variant::new(Type::Number, 42.0),
+                vec![Alternative::new(
+                    vec![Type::Boolean, Type::Number],
+                    "x",
+                    Variable::new("x")
+                )],
+                None,
+            )),
+            Ok(crate::Variant::new(Type::Number, 42.0.into()).into())
+        );
+    }
+
+    #[test]
+    fn evaluate_try_operation() {
+        let error_type = types::Record::new("error");
+        let error = crate::Variant::new(
+            error_type.clone().into(),
+            crate::Record::new(error_type.clone(), vec![]).into(),
+        );
+
+        assert_eq!(
+            evaluate(Let::new(
+                "x",
+                Type::Number,
+                TryOperation::new(
+                    Variant::new(error_type.clone(), Record::new(error_type.clone(), vec![])),
+                    "error",
+                    error_type.clone(),
+                    Variant::new(error_type, Variable::new("error")),
+                ),
+                42.0,
+            )),
+            Ok(error.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_try_operation_without_error() {
+        assert_eq!(
+            evaluate(TryOperation::new(
+                Variant::new(Type::Number, 42.0),
+                "error",
+                types::Record::new("error"),
+                Expression::None,
+            )),
+            Ok(crate::Variant::new(Type::Number, 42.0.into()).into())
+        );
+    }
+
+    #[test]
+    fn call_closure() {
+        let function_type = types::Function::new(vec![Type::Number], Type::Number);
+
+        assert_eq!(
+            evaluate(Let::new(
+                "y",
+                Type::Number,
+                1.0,
+                LetRecursive::new(
+                    FunctionDefinition::new(
+                        "g",
+                        vec![Argument::new("x", Type::Number)],
+                        Type::Number,
+                        ArithmeticOperation::new(
+                            ArithmeticOperator::Add,
+                            Variable::new("x"),
+                            Variable::new("y"),
+                        ),
+                    ),
+                    Call::new(function_type, Variable::new("g"), vec![41.0.into()]),
+                ),
+            )),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn call_recursive_global_function() {
+        let function_type = types::Function::new(vec![Type::Number], Type::Number);
+
+        assert_eq!(
+            call_function(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+                    "f",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    If::new(
+                        ComparisonOperation::new(
+                            ComparisonOperator::LessThanOrEqual,
+                            Variable::new("x"),
+                            0.0,
+                        ),
+                        0.0,
+                        ArithmeticOperation::new(
+                            ArithmeticOperator::Add,
+                            Variable::new("x"),
+                            Call::new(
+                                function_type,
+                                Variable::new("f"),
+                                vec![ArithmeticOperation::new(
+                                    ArithmeticOperator::Subtract,
+                                    Variable::new("x"),
+                                    1.0,
+                                )
+                                .into()],
+                            ),
+                        ),
+                    ),
+                )]),
+                "f",
+                &[3.0.into()],
+            ),
+            Ok(6.0.into())
+        );
+    }
+
+    #[test]
+    fn call_foreign_function() {
+        assert_eq!(
+            call_function(
+                &Module::empty()
+                    .set_foreign_declarations(vec![ForeignDeclaration::new(
+                        "g",
+                        "_pen_prelude_equal_strings",
+                        types::Function::new(
+                            vec![Type::ByteString, Type::ByteString],
+                            Type::Boolean
+                        ),
+                        CallingConvention::Target,
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::new(
+                        "f",
+                        vec![],
+                        Type::Boolean,
+                        Call::new(
+                            types::Function::new(
+                                vec![Type::ByteString, Type::ByteString],
+                                Type::Boolean
+                            ),
+                            Variable::new("g"),
+                            vec![ByteString::new("a").into(), ByteString::new("a").into()],
+                        ),
+                    )]),
+                "f",
+                &[],
+            ),
+            Ok(true.into())
+        );
+    }
+
+    #[test]
+    fn fail_to_call_unsupported_foreign_function() {
+        let function_type = types::Function::new(vec![], Type::None);
+
+        assert_eq!(
+            call_function(
+                &Module::empty()
+                    .set_foreign_declarations(vec![ForeignDeclaration::new(
+                        "g",
+                        "foo",
+                        function_type.clone(),
+                        CallingConvention::Target,
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::new(
+                        "f",
+                        vec![],
+                        Type::None,
+                        Call::new(function_type, Variable::new("g"), vec![]),
+                    )]),
+                "f",
+                &[],
+            ),
+            Err(InterpretError::ForeignFunctionNotSupported("foo".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_type_information_function() {
+        let function_type = types::Function::new(vec![], Type::Number);
+
+        assert_eq!(
+            call_function(
+                &Module::empty()
+                    .set_function_definitions(vec![
+                        FunctionDefinition::new("g", vec![], Type::Number, 42.0),
+                        FunctionDefinition::new(
+                            "f",
+                            vec![],
+                            Type::Number,
+                            Call::new(
+                                function_type,
+                                TypeInformationFunction::new(Variant::new(Type::Boolean, true)),
+                                vec![],
+                            ),
+                        ),
+                    ])
+                    .set_type_information(TypeInformation::new(
+                        [(Type::Boolean, "g".into())].into_iter().collect(),
+                        "h".into(),
+                    )),
+                "f",
+                &[],
+            ),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_thunk_once() {
+        let interpreter = Interpreter::new(&[Module::empty().set_function_definitions(vec![
+            FunctionDefinition::thunk("f", Type::ByteString, ByteString::new("foo")),
+        ])]);
+        let function = interpreter.function("f").unwrap();
+
+        assert_eq!(interpreter.call(function, &[]), Ok("foo".into()));
+        assert_eq!(interpreter.call(function, &[]), Ok("foo".into()));
+    }
+}
@@ -0,0 +1,11 @@
+mod environment;
+mod error;
+mod function;
+mod interpreter;
+mod native_function;
+mod value;
+
+pub use error::InterpretError;
+pub use function::{Closure, ForeignFunction, Function};
+pub use interpreter::Interpreter;
+pub use value::{Record, Value, Variant};
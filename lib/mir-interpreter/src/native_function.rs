@@ -0,0 +1,212 @@
+use crate::{error::InterpretError, value::Value};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    str,
+};
+
+const HASH_MULTIPLIER: u64 = 31;
+
+pub type NativeFunction = fn(&[Value]) -> Result<Value, InterpretError>;
+
+// Find a native implementation of a foreign function in runtime or prelude
+// packages.
+pub fn find(name: &str) -> Option<NativeFunction> {
+    Some(match name {
+        "_pen_debug" => debug,
+        "_pen_spawn" => spawn,
+        "_pen_prelude_combine_hashes" => combine_hashes,
+        "_pen_prelude_compare_strings" => compare_strings,
+        "_pen_prelude_debug_number" => debug_number,
+        "_pen_prelude_equal_strings" => equal_strings,
+        "_pen_prelude_hash_number" => hash_number,
+        "_pen_prelude_hash_string" => hash_string,
+        "_pen_prelude_hash_to_index" => hash_to_index,
+        "_pen_prelude_vector_index" => vector_index,
+        _ => return None,
+    })
+}
+
+fn debug(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [message] = get_arguments(arguments)?;
+
+    eprintln!(
+        "{}",
+        str::from_utf8(message.to_byte_string()?).unwrap_or("failed to decode debug message")
+    );
+
+    Ok(Value::None)
+}
+
+// Spawned thunks are evaluated lazily when their values are required.
+fn spawn(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [closure] = get_arguments(arguments)?;
+
+    closure.to_function()?;
+
+    Ok(closure.clone())
+}
+
+fn combine_hashes(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [one, other] = get_arguments(arguments)?;
+
+    Ok(calculate_combined_hash(one.to_number()?, other.to_number()?).into())
+}
+
+fn compare_strings(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [one, other] = get_arguments(arguments)?;
+
+    Ok((one.to_byte_string()?.cmp(other.to_byte_string()?) as i8 as f64).into())
+}
+
+fn debug_number(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [number] = get_arguments(arguments)?;
+
+    Ok(number.to_number()?.to_string().as_str().into())
+}
+
+fn equal_strings(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [one, other] = get_arguments(arguments)?;
+
+    Ok((one.to_byte_string()? == other.to_byte_string()?).into())
+}
+
+fn hash_number(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [number] = get_arguments(arguments)?;
+
+    Ok(calculate_number_hash(number.to_number()?).into())
+}
+
+fn hash_string(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [string] = get_arguments(arguments)?;
+
+    Ok(calculate_hash(string.to_byte_string()?).into())
+}
+
+fn hash_to_index(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [hash, layer, level] = get_arguments(arguments)?;
+
+    Ok(
+        ((((calculate_combined_hash(hash.to_number()?, calculate_number_hash(layer.to_number()?))
+            .to_bits()
+            >> (5 * (level.to_number()? as u64 - 1)))
+            & 0b11111)
+            + 1) as f64)
+            .into(),
+    )
+}
+
+fn vector_index(arguments: &[Value]) -> Result<Value, InterpretError> {
+    let [index, size] = get_arguments(arguments)?;
+    let index = index.to_number()?;
+
+    Ok((index.fract() == 0.0 && index >= 1.0 && index <= size.to_number()?).into())
+}
+
+fn calculate_combined_hash(one: f64, other: f64) -> f64 {
+    f64::from_bits(
+        one.to_bits()
+            .wrapping_mul(HASH_MULTIPLIER)
+            .wrapping_add(other.to_bits()),
+    )
+}
+
+// We normalize a floating point number before hash calculation.
+fn calculate_number_hash(number: f64) -> f64 {
+    calculate_hash(
+        &(if number == 0.0 {
+            0.0
+        } else if number.is_nan() {
+            f64::NAN
+        } else {
+            number
+        })
+        .to_bits(),
+    )
+}
+
+fn calculate_hash(value: &(impl Hash + ?Sized)) -> f64 {
+    let mut hasher = DefaultHasher::new();
+
+    value.hash(&mut hasher);
+
+    f64::from_bits(hasher.finish())
+}
+
+fn get_arguments<const N: usize>(arguments: &[Value]) -> Result<&[Value; N], InterpretError> {
+    arguments
+        .try_into()
+        .map_err(|_| InterpretError::WrongArgumentCount {
+            expected: N,
+            actual: arguments.len(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(name: &str, arguments: &[Value]) -> Result<Value, InterpretError> {
+        find(name).unwrap()(arguments)
+    }
+
+    #[test]
+    fn find_nothing() {
+        assert!(find("_pen_race").is_none());
+    }
+
+    #[test]
+    fn debug_number() {
+        assert_eq!(
+            call("_pen_prelude_debug_number", &[42.0.into()]),
+            Ok("42".into())
+        );
+    }
+
+    #[test]
+    fn compare_strings() {
+        assert_eq!(
+            call("_pen_prelude_compare_strings", &["a".into(), "b".into()]),
+            Ok((-1.0).into())
+        );
+    }
+
+    #[test]
+    fn equal_strings() {
+        assert_eq!(
+            call("_pen_prelude_equal_strings", &["a".into(), "a".into()]),
+            Ok(true.into())
+        );
+    }
+
+    #[test]
+    fn hash_positive_and_negative_zeros() {
+        assert_eq!(
+            call("_pen_prelude_hash_number", &[0.0.into()]),
+            call("_pen_prelude_hash_number", &[(-0.0).into()]),
+        );
+    }
+
+    #[test]
+    fn vector_index() {
+        assert_eq!(
+            call("_pen_prelude_vector_index", &[1.0.into(), 1.0.into()]),
+            Ok(true.into())
+        );
+        assert_eq!(
+            call("_pen_prelude_vector_index", &[1.5.into(), 2.0.into()]),
+            Ok(false.into())
+        );
+    }
+
+    #[test]
+    fn fail_with_wrong_argument_count() {
+        assert_eq!(
+            call("_pen_prelude_debug_number", &[]),
+            Err(InterpretError::WrongArgumentCount {
+                expected: 1,
+                actual: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{error::InterpretError, function::Function};
+use std::rc::Rc;
+
+#[derive(Clone, Debug)]
+pub enum Value {
+    Boolean(bool),
+    ByteString(Rc<[u8]>),
+    Function(Rc<Function>),
+    None,
+    Number(f64),
+    Record(Rc<Record>),
+    Variant(Rc<Variant>),
+}
+
+impl Value {
+    pub fn to_boolean(&self) -> Result<bool, InterpretError> {
+        match self {
+            Self::Boolean(boolean) => Ok(*boolean),
+            _ => Err(InterpretError::BooleanExpected),
+        }
+    }
+
+    pub fn to_byte_string(&self) -> Result<&[u8], InterpretError> {
+        match self {
+            Self::ByteString(string) => Ok(string),
+            _ => Err(InterpretError::ByteStringExpected),
+        }
+    }
+
+    pub fn to_function(&self) -> Result<&Function, InterpretError> {
+        match self {
+            Self::Function(function) => Ok(function),
+            _ => Err(InterpretError::FunctionExpected),
+        }
+    }
+
+    pub fn to_number(&self) -> Result<f64, InterpretError> {
+        match self {
+            Self::Number(number) => Ok(*number),
+            _ => Err(InterpretError::NumberExpected),
+        }
+    }
+
+    pub fn to_record(&self) -> Result<&Record, InterpretError> {
+        match self {
+            Self::Record(record) => Ok(record),
+            _ => Err(InterpretError::RecordExpected),
+        }
+    }
+
+    pub fn to_variant(&self) -> Result<&Variant, InterpretError> {
+        match self {
+            Self::Variant(variant) => Ok(variant),
+            _ => Err(InterpretError::VariantExpected),
+        }
+    }
+}
+
+// Functions are equal only if they are identical.
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Boolean(one), Self::Boolean(other)) => one == other,
+            (Self::ByteString(one), Self::ByteString(other)) => one == other,
+            (Self::Function(one), Self::Function(other)) => Rc::ptr_eq(one, other),
+            (Self::None, Self::None) => true,
+            (Self::Number(one), Self::Number(other)) => one == other,
+            (Self::Record(one), Self::Record(other)) => one == other,
+            (Self::Variant(one), Self::Variant(other)) => one == other,
+            _ => false,
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(boolean: bool) -> Self {
+        Self::Boolean(boolean)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(number: f64) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<&[u8]> for Value {
+    fn from(string: &[u8]) -> Self {
+        Self::ByteString(string.into())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(string: &str) -> Self {
+        string.as_bytes().into()
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(string: Vec<u8>) -> Self {
+        Self::ByteString(string.into())
+    }
+}
+
+impl From<Function> for Value {
+    fn from(function: Function) -> Self {
+        Self::Function(function.into())
+    }
+}
+
+impl From<Record> for Value {
+    fn from(record: Record) -> Self {
+        Self::Record(record.into())
+    }
+}
+
+impl From<Variant> for Value {
+    fn from(variant: Variant) -> Self {
+        Self::Variant(variant.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    type_: mir::types::Record,
+    fields: Vec<Value>,
+}
+
+impl Record {
+    pub fn new(type_: mir::types::Record, fields: Vec<Value>) -> Self {
+        Self { type_, fields }
+    }
+
+    pub fn type_(&self) -> &mir::types::Record {
+        &self.type_
+    }
+
+    pub fn fields(&self) -> &[Value] {
+        &self.fields
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Variant {
+    type_: mir::types::Type,
+    payload: Value,
+}
+
+impl Variant {
+    pub fn new(type_: mir::types::Type, payload: Value) -> Self {
+        Self { type_, payload }
+    }
+
+    pub fn type_(&self) -> &mir::types::Type {
+        &self.type_
+    }
+
+    pub fn payload(&self) -> &Value {
+        &self.payload
+    }
+}
@@ -1,6 +1,7 @@
 pub mod alpha_conversion;
 pub mod environment_inference;
 mod expression_conversion;
+pub mod format;
 pub mod free_variable;
 pub mod lambda_lifting;
 pub mod normalization;
@@ -8,4 +9,85 @@ pub mod optimization;
 pub mod reference_count;
 pub mod type_check;
 pub mod type_id;
+pub mod unsynchronized_variable;
 pub mod variant_type_collection;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::*,
+        test::ModuleFake,
+        types::{self, Type},
+    };
+    use position::Position;
+
+    fn transform(module: &Module, optimize: bool) -> Module {
+        let module = optimization::string_concatenation::transform(module);
+        let module = if optimize {
+            optimization::transform(&module)
+        } else {
+            module
+        };
+        let module = alpha_conversion::transform(&module);
+        let module = normalization::transform(&module);
+        let module = environment_inference::transform(&module);
+        let module = lambda_lifting::transform(&module);
+
+        reference_count::transform(&module).unwrap()
+    }
+
+    fn position(line_number: usize) -> Option<Position> {
+        Some(Position::new("foo.pen", line_number, 1, ""))
+    }
+
+    #[test]
+    fn keep_positions() {
+        let function_type = types::Function::new(vec![Type::Number], Type::Number);
+        let module = Module::empty()
+            .set_function_declarations(vec![FunctionDeclaration::new(
+                "g",
+                function_type.clone(),
+            )])
+            .set_global_function_definitions(vec![GlobalFunctionDefinition::new(
+                FunctionDefinition::new(
+                    "f",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    LetRecursive::new(
+                        FunctionDefinition::new(
+                            "h",
+                            vec![Argument::new("y", Type::Number)],
+                            Type::Number,
+                            Call::with_position(
+                                function_type.clone(),
+                                Variable::new("g"),
+                                vec![Variable::new("x").into()],
+                                position(3),
+                            ),
+                        )
+                        .set_position(position(2)),
+                        Call::with_position(
+                            function_type,
+                            Variable::new("h"),
+                            vec![Variable::new("x").into()],
+                            position(4),
+                        ),
+                    ),
+                )
+                .set_position(position(1)),
+                true,
+            )]);
+
+        for optimize in [false, true] {
+            let module = format::format_module(&transform(&module, optimize));
+
+            for line_number in 1..=4 {
+                assert!(
+                    module.contains(&format!("@ foo.pen:{line_number}:1")),
+                    "{module}"
+                );
+            }
+        }
+    }
+}
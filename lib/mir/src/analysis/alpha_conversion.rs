@@ -48,6 +48,7 @@ pub fn transform(module: &Module) -> Module {
                                 &Default::default(),
                             ),
                             definition.is_thunk(),
+                            definition.position().cloned(),
                         )
                     },
                     definition.is_public(),
@@ -116,10 +117,11 @@ fn transform_expression<'a>(
         Expression::DropVariables(drop) => {
             DropVariables::new(drop.variables().clone(), transform(drop.expression())).into()
         }
-        Expression::Call(call) => Call::new(
+        Expression::Call(call) => Call::with_position(
             call.type_().clone(),
             transform(call.function()),
             call.arguments().iter().map(transform).collect(),
+            call.position().cloned(),
         )
         .into(),
         Expression::If(if_) => If::new(
@@ -188,6 +190,7 @@ fn transform_expression<'a>(
                         definition.result_type().clone(),
                         transform_expression(context, definition.body(), &variables),
                         definition.is_thunk(),
+                        definition.position().cloned(),
                     )
                 },
                 transform_expression(context, let_.expression(), &variables),
@@ -204,6 +207,17 @@ fn transform_expression<'a>(
             record.fields().iter().map(transform).collect(),
         )
         .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            variables
+                .get(reuse.variable())
+                .map(String::as_str)
+                .unwrap_or_else(|| reuse.variable()),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
         Expression::RecordField(field) => RecordField::new(
             field.type_().clone(),
             field.index(),
@@ -495,6 +509,7 @@ mod tests {
                                     Type::Number,
                                     Variable::new("x"),
                                     false,
+                                    None,
                                 ),
                                 Variable::new("x"),
                             )
@@ -522,6 +537,7 @@ mod tests {
                                 Type::Number,
                                 Variable::new("x:1"),
                                 false,
+                                None,
                             ),
                             Variable::new("x:1"),
                         )
@@ -547,6 +563,7 @@ mod tests {
                             Type::Number,
                             Expression::None,
                             false,
+                            None,
                         ),
                         Expression::None,
                     )
@@ -564,6 +581,7 @@ mod tests {
                         Type::Number,
                         Expression::None,
                         false,
+                        None,
                     ),
                     Expression::None,
                 )
@@ -593,10 +611,12 @@ mod tests {
                                     Type::Number,
                                     Expression::None,
                                     false,
+                                    None,
                                 ),
                                 Expression::None,
                             ),
                             false,
+                            None,
                         ),
                         Expression::None,
                     )
@@ -620,10 +640,12 @@ mod tests {
                                 Type::Number,
                                 Expression::None,
                                 false,
+                                None,
                             ),
                             Expression::None,
                         ),
                         false,
+                        None,
                     ),
                     Expression::None,
                 )
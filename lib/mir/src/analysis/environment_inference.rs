@@ -38,6 +38,7 @@ fn transform_global_function_definition(
                     .collect(),
             ),
             definition.is_thunk(),
+            definition.position().cloned(),
         ),
         public,
     )
@@ -72,6 +73,7 @@ fn transform_local_function_definition(
         definition.result_type().clone(),
         transform_expression(definition.body(), &variables.insert_iter(local_variables)),
         definition.is_thunk(),
+        definition.position().cloned(),
     )
 }
 
@@ -88,10 +90,11 @@ fn transform_expression(
             transform(operation.rhs()),
         )
         .into(),
-        Expression::Call(call) => Call::new(
+        Expression::Call(call) => Call::with_position(
             call.type_().clone(),
             transform(call.function()),
             call.arguments().iter().map(transform).collect(),
+            call.position().cloned(),
         )
         .into(),
         Expression::Case(case) => Case::new(
@@ -165,6 +168,14 @@ fn transform_expression(
             record.fields().iter().map(transform).collect(),
         )
         .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            reuse.variable(),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
         Expression::RecordField(field) => RecordField::new(
             field.type_().clone(),
             field.index(),
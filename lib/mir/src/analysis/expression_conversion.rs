@@ -31,6 +31,7 @@ fn transform_function_definition(
         definition.result_type().clone(),
         transform_expression(definition.body(), convert),
         definition.is_thunk(),
+        definition.position().cloned(),
     )
 }
 
@@ -76,10 +77,11 @@ fn transform_expression(
         Expression::DropVariables(drop) => {
             DropVariables::new(drop.variables().clone(), transform(drop.expression())).into()
         }
-        Expression::Call(call) => Call::new(
+        Expression::Call(call) => Call::with_position(
             call.type_().clone(),
             transform(call.function()),
             call.arguments().iter().map(transform).collect(),
+            call.position().cloned(),
         )
         .into(),
         Expression::If(if_) => If::new(
@@ -110,6 +112,14 @@ fn transform_expression(
             record.fields().iter().map(transform).collect(),
         )
         .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            reuse.variable(),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
         Expression::RecordField(field) => RecordField::new(
             field.type_().clone(),
             field.index(),
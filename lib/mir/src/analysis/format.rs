@@ -0,0 +1,453 @@
+use crate::{ir::*, types, types::Type};
+use fnv::FnvHashMap;
+use position::Position;
+
+const INDENT: &str = "  ";
+
+pub fn format_module(module: &Module) -> String {
+    module
+        .type_definitions()
+        .iter()
+        .map(format_type_definition)
+        .chain(
+            module
+                .foreign_declarations()
+                .iter()
+                .map(format_foreign_declaration),
+        )
+        .chain(
+            module
+                .foreign_definitions()
+                .iter()
+                .map(format_foreign_definition),
+        )
+        .chain(
+            module
+                .function_declarations()
+                .iter()
+                .map(format_function_declaration),
+        )
+        .chain(module.function_definitions().iter().map(|definition| {
+            (if definition.is_public() {
+                "public "
+            } else {
+                ""
+            })
+            .to_owned()
+                + &format_function_definition(definition.definition())
+        }))
+        .chain([format_type_information(module.type_information())])
+        .map(|string| string + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_type(type_: &Type) -> String {
+    match type_ {
+        Type::Boolean => "boolean".into(),
+        Type::ByteString => "string".into(),
+        Type::Function(function) => format_function_type(function),
+        Type::None => "none".into(),
+        Type::Number => "number".into(),
+        Type::Record(record) => record.name().into(),
+        Type::Variant => "variant".into(),
+    }
+}
+
+fn format_function_type(function: &types::Function) -> String {
+    format!(
+        "\\({}) {}",
+        format_types(function.arguments()),
+        format_type(function.result())
+    )
+}
+
+fn format_types(types: &[Type]) -> String {
+    types.iter().map(format_type).collect::<Vec<_>>().join(", ")
+}
+
+fn format_type_definition(definition: &TypeDefinition) -> String {
+    format!(
+        "type {} = {{{}}}",
+        definition.name(),
+        format_types(definition.type_().fields())
+    )
+}
+
+fn format_foreign_declaration(declaration: &ForeignDeclaration) -> String {
+    format!(
+        "foreign declare {} {} = {} {}",
+        format_calling_convention(declaration.calling_convention()),
+        declaration.foreign_name(),
+        declaration.name(),
+        format_function_type(declaration.type_())
+    )
+}
+
+fn format_foreign_definition(definition: &ForeignDefinition) -> String {
+    format!(
+        "foreign define {} {} = {}",
+        format_calling_convention(definition.calling_convention()),
+        definition.foreign_name(),
+        definition.name()
+    )
+}
+
+fn format_calling_convention(calling_convention: CallingConvention) -> &'static str {
+    match calling_convention {
+        CallingConvention::Source => "source",
+        CallingConvention::Target => "target",
+    }
+}
+
+fn format_function_declaration(declaration: &FunctionDeclaration) -> String {
+    format!(
+        "declare {} {}",
+        declaration.name(),
+        format_function_type(declaration.type_())
+    )
+}
+
+fn format_function_definition(definition: &FunctionDefinition) -> String {
+    format!(
+        "{} {}{}({}) {}{} {}",
+        if definition.is_thunk() {
+            "thunk"
+        } else {
+            "define"
+        },
+        definition.name(),
+        if definition.environment().is_empty() {
+            "".into()
+        } else {
+            format!("[{}]", format_arguments(definition.environment()))
+        },
+        format_arguments(definition.arguments()),
+        format_type(definition.result_type()),
+        format_position(definition.position()),
+        format_block(definition.body())
+    )
+}
+
+fn format_arguments(arguments: &[Argument]) -> String {
+    arguments
+        .iter()
+        .map(|argument| format!("{} {}", argument.name(), format_type(argument.type_())))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_position(position: Option<&Position>) -> String {
+    position
+        .map(|position| {
+            format!(
+                " @ {}:{}:{}",
+                position.path(),
+                position.line_number(),
+                position.column_number()
+            )
+        })
+        .unwrap_or_default()
+}
+
+fn format_type_information(information: &TypeInformation) -> String {
+    let mut entries = information
+        .information()
+        .iter()
+        .map(|(type_, name)| format!("{INDENT}{} => {}\n", format_type(type_), name))
+        .collect::<Vec<_>>();
+
+    entries.sort();
+
+    format!(
+        "type_information {{\n{}{INDENT}_ => {}\n}}",
+        entries.concat(),
+        information.fallback()
+    )
+}
+
+fn format_block(expression: &Expression) -> String {
+    format!("{{\n{}\n}}", indent(&format_expression(expression)))
+}
+
+pub fn format_expression(expression: &Expression) -> String {
+    match expression {
+        Expression::ArithmeticOperation(operation) => format!(
+            "({} {} {})",
+            format_expression(operation.lhs()),
+            match operation.operator() {
+                ArithmeticOperator::Add => "+",
+                ArithmeticOperator::Subtract => "-",
+                ArithmeticOperator::Multiply => "*",
+                ArithmeticOperator::Divide => "/",
+                ArithmeticOperator::IntegerDivide => "//",
+                ArithmeticOperator::Remainder => "%",
+                ArithmeticOperator::And => "&",
+                ArithmeticOperator::Or => "|",
+                ArithmeticOperator::Xor => "^",
+                ArithmeticOperator::LeftShift => "<<",
+                ArithmeticOperator::RightShift => ">>",
+            },
+            format_expression(operation.rhs())
+        ),
+        Expression::Boolean(boolean) => boolean.to_string(),
+        Expression::ByteString(string) => {
+            format!("{:?}", String::from_utf8_lossy(string.value()))
+        }
+        Expression::Call(call) => format!(
+            "{}({}){}",
+            format_expression(call.function()),
+            format_expressions(call.arguments()),
+            format_position(call.position())
+        ),
+        Expression::Case(case) => format!(
+            "case {} {{\n{}\n}}",
+            format_expression(case.argument()),
+            indent(
+                &case
+                    .alternatives()
+                    .iter()
+                    .map(|alternative| format!(
+                        "{} {} => {}",
+                        alternative.name(),
+                        format_types(alternative.types()),
+                        format_block(alternative.expression())
+                    ))
+                    .chain(case.default_alternative().map(|alternative| format!(
+                        "{} => {}",
+                        alternative.name(),
+                        format_block(alternative.expression())
+                    )))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        ),
+        Expression::CloneVariables(clone) => format!(
+            "clone {}\n{}",
+            format_variables(clone.variables()),
+            format_expression(clone.expression())
+        ),
+        Expression::ComparisonOperation(operation) => format!(
+            "({} {} {})",
+            format_expression(operation.lhs()),
+            match operation.operator() {
+                ComparisonOperator::Equal => "==",
+                ComparisonOperator::NotEqual => "!=",
+                ComparisonOperator::LessThan => "<",
+                ComparisonOperator::GreaterThan => ">",
+                ComparisonOperator::LessThanOrEqual => "<=",
+                ComparisonOperator::GreaterThanOrEqual => ">=",
+            },
+            format_expression(operation.rhs())
+        ),
+        Expression::DropVariables(drop) => format!(
+            "drop {}\n{}",
+            format_variables(drop.variables()),
+            format_expression(drop.expression())
+        ),
+        Expression::If(if_) => format!(
+            "if {} {} else {}",
+            format_expression(if_.condition()),
+            format_block(if_.then()),
+            format_block(if_.else_())
+        ),
+        Expression::Let(let_) => format!(
+            "let {} {} = {}\n{}",
+            let_.name(),
+            format_type(let_.type_()),
+            format_expression(let_.bound_expression()),
+            format_expression(let_.expression())
+        ),
+        Expression::LetRecursive(let_) => format!(
+            "{}\n{}",
+            format_function_definition(let_.definition()),
+            format_expression(let_.expression())
+        ),
+        Expression::Synchronize(synchronize) => format!(
+            "synchronize<{}>({})",
+            format_type(synchronize.type_()),
+            format_expression(synchronize.expression())
+        ),
+        Expression::None => "none".into(),
+        Expression::Number(number) => number.to_string(),
+        Expression::Record(record) => format!(
+            "{}{{{}}}",
+            record.type_().name(),
+            format_expressions(record.fields())
+        ),
+        Expression::ReuseRecord(reuse) => format!(
+            "reuse {} {}{{{}}}",
+            reuse.variable(),
+            reuse.record().type_().name(),
+            format_expressions(reuse.record().fields())
+        ),
+        Expression::RecordField(field) => {
+            format!("{}.{}", format_expression(field.record()), field.index())
+        }
+        Expression::RecordUpdate(update) => format!(
+            "{}{{...{}, {}}}",
+            update.type_().name(),
+            format_expression(update.record()),
+            update
+                .fields()
+                .iter()
+                .map(|field| format!(
+                    "{}: {}",
+                    field.index(),
+                    format_expression(field.expression())
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Expression::StringConcatenation(concatenation) => {
+            format!(
+                "concatenate({})",
+                format_expressions(concatenation.operands())
+            )
+        }
+        Expression::TryOperation(operation) => format!(
+            "try {} as {} {} {}",
+            format_expression(operation.operand()),
+            operation.name(),
+            format_type(operation.type_()),
+            format_block(operation.then())
+        ),
+        Expression::TypeInformationFunction(information) => {
+            format!(
+                "type_information({})",
+                format_expression(information.variant())
+            )
+        }
+        Expression::Variable(variable) => variable.name().into(),
+        Expression::Variant(variant) => format!(
+            "variant<{}>({})",
+            format_type(variant.type_()),
+            format_expression(variant.payload())
+        ),
+    }
+}
+
+fn format_expressions(expressions: &[Expression]) -> String {
+    expressions
+        .iter()
+        .map(format_expression)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_variables(variables: &FnvHashMap<String, Type>) -> String {
+    let mut variables = variables
+        .iter()
+        .map(|(name, type_)| format!("{} {}", name, format_type(type_)))
+        .collect::<Vec<_>>();
+
+    variables.sort();
+
+    variables.join(", ")
+}
+
+fn indent(string: &str) -> String {
+    string
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                line.into()
+            } else {
+                INDENT.to_owned() + line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleFake;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_empty_module() {
+        assert_eq!(
+            format_module(&Module::empty()),
+            "type_information {\n  _ => \n}\n"
+        );
+    }
+
+    #[test]
+    fn format_function_definition() {
+        assert_eq!(
+            format_module(&Module::empty().set_global_function_definitions(vec![
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "f",
+                        vec![Argument::new("x", Type::Number)],
+                        Type::Number,
+                        Variable::new("x"),
+                    ),
+                    true,
+                )
+            ])),
+            "public define f(x number) number {\n  x\n}\n\ntype_information {\n  _ => \n}\n"
+        );
+    }
+
+    #[test]
+    fn format_function_definition_with_position() {
+        assert_eq!(
+            super::format_function_definition(
+                &FunctionDefinition::new(
+                    "f",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    Variable::new("x"),
+                )
+                .set_position(Some(Position::new("foo.pen", 1, 2, "")))
+            ),
+            "define f(x number) number @ foo.pen:1:2 {\n  x\n}"
+        );
+    }
+
+    #[test]
+    fn format_call_with_position() {
+        assert_eq!(
+            format_expression(
+                &Call::with_position(
+                    types::Function::new(vec![Type::Number], Type::Number),
+                    Variable::new("f"),
+                    vec![Variable::new("x").into()],
+                    Some(Position::new("foo.pen", 3, 4, "")),
+                )
+                .into()
+            ),
+            "f(x) @ foo.pen:3:4"
+        );
+    }
+
+    #[test]
+    fn format_let() {
+        assert_eq!(
+            format_expression(&Let::new("x", Type::Number, 42.0, Variable::new("x")).into()),
+            "let x number = 42\nx"
+        );
+    }
+
+    #[test]
+    fn format_case() {
+        assert_eq!(
+            format_expression(
+                &Case::new(
+                    Variable::new("x"),
+                    vec![Alternative::new(
+                        vec![Type::Number],
+                        "y",
+                        Variable::new("y")
+                    )],
+                    Some(DefaultAlternative::new("z", Expression::None)),
+                )
+                .into()
+            ),
+            "case x {\n  y number => {\n    y\n  }\n  z => {\n    none\n  }\n}"
+        );
+    }
+}
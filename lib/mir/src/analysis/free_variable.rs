@@ -42,6 +42,10 @@ fn find_in_expression(expression: &Expression) -> FnvHashSet<String> {
             .collect(),
         Expression::Synchronize(synchronize) => find_in_expression(synchronize.expression()),
         Expression::Record(record) => find_in_record(record),
+        Expression::ReuseRecord(reuse) => [reuse.variable().into()]
+            .into_iter()
+            .chain(find_in_record(reuse.record()))
+            .collect(),
         Expression::RecordField(field) => find_in_expression(field.record()),
         Expression::RecordUpdate(update) => find_in_expression(update.record())
             .into_iter()
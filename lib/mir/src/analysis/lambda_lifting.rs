@@ -46,6 +46,7 @@ fn transform_function_definition(
         definition.result_type().clone(),
         transform_expression(context, definition.body()),
         definition.is_thunk(),
+        definition.position().cloned(),
     )
 }
 
@@ -88,10 +89,11 @@ fn transform_expression(context: &mut Context, expression: &Expression) -> Expre
         Expression::DropVariables(drop) => {
             DropVariables::new(drop.variables().clone(), transform(drop.expression())).into()
         }
-        Expression::Call(call) => Call::new(
+        Expression::Call(call) => Call::with_position(
             call.type_().clone(),
             transform(call.function()),
             call.arguments().iter().map(transform).collect(),
+            call.position().cloned(),
         )
         .into(),
         Expression::If(if_) => If::new(
@@ -158,6 +160,7 @@ fn transform_expression(context: &mut Context, expression: &Expression) -> Expre
                         definition.result_type().clone(),
                         transform(definition.body()),
                         definition.is_thunk(),
+                        definition.position().cloned(),
                     ));
 
                 Let::new(
@@ -183,6 +186,14 @@ fn transform_expression(context: &mut Context, expression: &Expression) -> Expre
             record.fields().iter().map(transform).collect(),
         )
         .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            reuse.variable(),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
         Expression::RecordField(field) => RecordField::new(
             field.type_().clone(),
             field.index(),
@@ -386,6 +397,7 @@ mod tests {
                             Type::Number,
                             Variable::new("x"),
                             false,
+                            None,
                         ),
                         42.0,
                     )
@@ -420,6 +432,7 @@ mod tests {
                         )
                     ),
                     false,
+                    None,
                 )
             ])
         );
@@ -442,6 +455,7 @@ mod tests {
                             Type::Number,
                             42.0,
                             false,
+                            None,
                         ),
                         42.0,
                     )
@@ -471,6 +485,7 @@ mod tests {
                         Let::new("fv:x:0", Type::None, Variable::new("x"), 42.0)
                     ),
                     false,
+                    None,
                 )
             ])
         );
@@ -494,6 +509,7 @@ mod tests {
                             Type::Number,
                             42.0,
                             false,
+                            None,
                         ),
                         Call::new(
                             types::Function::new(vec![], Type::Number),
@@ -536,6 +552,7 @@ mod tests {
                         Let::new("fv:x:0", Type::None, Variable::new("x"), 42.0)
                     ),
                     false,
+                    None,
                 )
             ])
         );
@@ -619,6 +636,7 @@ mod tests {
                                 vec![]
                             ),
                             false,
+                            None,
                         ),
                         42.0
                     )
@@ -657,6 +675,7 @@ mod tests {
                         )
                     ),
                     false,
+                    None,
                 )
             ])
         );
@@ -48,6 +48,7 @@ pub fn is_boxed(expression: &Expression, name: &str) -> bool {
                 || let_.definition().name() != name && is_uniform(let_.expression())
         }
         Expression::Record(record) => record.fields().iter().any(is_uniform),
+        Expression::ReuseRecord(reuse) => reuse.record().fields().iter().any(is_uniform),
         Expression::RecordField(field) => is_uniform(field.record()),
         Expression::RecordUpdate(update) => {
             is_uniform(update.record())
@@ -306,7 +307,8 @@ mod tests {
                     vec![],
                     Type::None,
                     Expression::None,
-                    false
+                    false,
+                    None,
                 ),
                 Expression::None,
             )
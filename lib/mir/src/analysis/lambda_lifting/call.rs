@@ -55,7 +55,7 @@ pub fn transform(expression: &Expression, name: &str, free_variables: &[Argument
 
             if let Expression::Variable(variable) = call.function() {
                 if variable.name() == name {
-                    Call::new(
+                    Call::with_position(
                         types::Function::new(
                             call.type_()
                                 .arguments()
@@ -78,12 +78,23 @@ pub fn transform(expression: &Expression, name: &str, free_variables: &[Argument
                                 }),
                             )
                             .collect(),
+                        call.position().cloned(),
                     )
                 } else {
-                    Call::new(call.type_().clone(), function, arguments.collect())
+                    Call::with_position(
+                        call.type_().clone(),
+                        function,
+                        arguments.collect(),
+                        call.position().cloned(),
+                    )
                 }
             } else {
-                Call::new(call.type_().clone(), function, arguments.collect())
+                Call::with_position(
+                    call.type_().clone(),
+                    function,
+                    arguments.collect(),
+                    call.position().cloned(),
+                )
             }
             .into()
         }
@@ -115,6 +126,14 @@ pub fn transform(expression: &Expression, name: &str, free_variables: &[Argument
             record.fields().iter().map(transform).collect(),
         )
         .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            reuse.variable(),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
         Expression::RecordField(field) => RecordField::new(
             field.type_().clone(),
             field.index(),
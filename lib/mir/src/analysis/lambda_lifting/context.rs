@@ -35,6 +35,7 @@ impl Context {
                         definition.body().clone(),
                     ),
                     definition.is_thunk(),
+                    definition.position().cloned(),
                 ),
                 false,
             ));
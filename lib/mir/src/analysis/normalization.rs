@@ -47,6 +47,7 @@ fn transform_function_definition(
         definition.result_type().clone(),
         alias_removal::transform(&transform_expression(context, definition.body(), &identity)),
         definition.is_thunk(),
+        definition.position().cloned(),
     )
 }
 
@@ -83,7 +84,13 @@ fn transform_expression(
                         .collect::<Vec<_>>(),
                     &|arguments| {
                         continue_(
-                            Call::new(call.type_().clone(), function.clone(), arguments).into(),
+                            Call::with_position(
+                                call.type_().clone(),
+                                function.clone(),
+                                arguments,
+                                call.position().cloned(),
+                            )
+                            .into(),
                         )
                     },
                 )
@@ -170,6 +177,24 @@ fn transform_expression(
                 .collect::<Vec<_>>(),
             &|fields| continue_(Record::new(record.type_().clone(), fields).into()),
         ),
+        Expression::ReuseRecord(reuse) => transform_expressions(
+            context,
+            &reuse
+                .record()
+                .fields()
+                .iter()
+                .zip(context.record_fields()[reuse.record().type_().name()].fields())
+                .collect::<Vec<_>>(),
+            &|fields| {
+                continue_(
+                    ReuseRecord::new(
+                        reuse.variable(),
+                        Record::new(reuse.record().type_().clone(), fields),
+                    )
+                    .into(),
+                )
+            },
+        ),
         Expression::RecordField(field) => transform_expression(field.record(), &|expression| {
             continue_(RecordField::new(field.type_().clone(), field.index(), expression).into())
         }),
@@ -52,10 +52,11 @@ fn transform_expression(expression: &Expression, variables: &hamt::Map<&str, &st
         Expression::DropVariables(drop) => {
             DropVariables::new(drop.variables().clone(), transform(drop.expression())).into()
         }
-        Expression::Call(call) => Call::new(
+        Expression::Call(call) => Call::with_position(
             call.type_().clone(),
             transform(call.function()),
             call.arguments().iter().map(transform).collect(),
+            call.position().cloned(),
         )
         .into(),
         Expression::If(if_) => If::new(
@@ -95,6 +96,17 @@ fn transform_expression(expression: &Expression, variables: &hamt::Map<&str, &st
             record.fields().iter().map(transform).collect(),
         )
         .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            variables
+                .get(reuse.variable())
+                .copied()
+                .unwrap_or_else(|| reuse.variable()),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
         Expression::RecordField(field) => RecordField::new(
             field.type_().clone(),
             field.index(),
@@ -172,6 +184,7 @@ fn transform_function_definition(
             transform_expression(definition.body(), &variables)
         },
         definition.is_thunk(),
+        definition.position().cloned(),
     )
 }
 
@@ -407,7 +420,8 @@ mod tests {
                                 vec![],
                                 Type::Number,
                                 42.0,
-                                false
+                                false,
+                                None,
                             ),
                             42.0,
                         )
@@ -421,7 +435,8 @@ mod tests {
                         vec![],
                         Type::Number,
                         42.0,
-                        false
+                        false,
+                        None,
                     ),
                     42.0,
                 )
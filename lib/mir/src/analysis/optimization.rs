@@ -1 +1,273 @@
+mod case_simplification;
+mod constant_folding;
+mod dead_code_elimination;
+pub mod external_definition;
+mod inlining;
+mod specialization;
 pub mod string_concatenation;
+
+use crate::ir::*;
+
+pub fn transform(module: &Module) -> Module {
+    let module = inlining::transform(module);
+    let module = specialization::transform(&module);
+    let module = constant_folding::transform(&module);
+    let module = case_simplification::transform(&module);
+    let module = constant_folding::transform(&module);
+
+    dead_code_elimination::transform(&module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::free_variable::find_free_variables, test::ModuleFake, types, types::Type,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn optimize_call_of_small_function() {
+        assert_eq!(
+            transform(&Module::empty().set_global_function_definitions(vec![
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "f",
+                        vec![],
+                        Type::Number,
+                        Call::new(
+                            types::Function::new(vec![Type::Number], Type::Number),
+                            Variable::new("g"),
+                            vec![41.0.into()]
+                        ),
+                    ),
+                    true,
+                ),
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "g",
+                        vec![Argument::new("x", Type::Number)],
+                        Type::Number,
+                        ArithmeticOperation::new(ArithmeticOperator::Add, Variable::new("x"), 1.0),
+                    ),
+                    false,
+                ),
+            ])),
+            Module::empty().set_global_function_definitions(vec![GlobalFunctionDefinition::new(
+                FunctionDefinition::new("f", vec![], Type::Number, 42.0),
+                true,
+            )])
+        );
+    }
+
+    // This is the same program as the inlining benchmark.
+    #[test]
+    fn optimize_calls_of_small_functions_in_recursive_function() {
+        let binary_function_type =
+            types::Function::new(vec![Type::Number, Type::Number], Type::Number);
+        let unary_function_type = types::Function::new(vec![Type::Number], Type::Number);
+        let module = transform(&Module::empty().set_global_function_definitions(vec![
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new(
+                    "sum",
+                    vec![
+                        Argument::new("x", Type::Number),
+                        Argument::new("i", Type::Number),
+                    ],
+                    Type::Number,
+                    If::new(
+                        ComparisonOperation::new(
+                            ComparisonOperator::Equal,
+                            Variable::new("i"),
+                            0.0,
+                        ),
+                        Variable::new("x"),
+                        Call::new(
+                            binary_function_type.clone(),
+                            Variable::new("sum"),
+                            vec![
+                                Call::new(
+                                    binary_function_type.clone(),
+                                    Variable::new("add"),
+                                    vec![
+                                        Variable::new("x").into(),
+                                        Call::new(
+                                            unary_function_type.clone(),
+                                            Variable::new("square"),
+                                            vec![1.0.into()],
+                                        )
+                                        .into(),
+                                    ],
+                                )
+                                .into(),
+                                Call::new(
+                                    unary_function_type,
+                                    Variable::new("decrement"),
+                                    vec![Variable::new("i").into()],
+                                )
+                                .into(),
+                            ],
+                        ),
+                    ),
+                ),
+                true,
+            ),
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new(
+                    "add",
+                    vec![
+                        Argument::new("x", Type::Number),
+                        Argument::new("y", Type::Number),
+                    ],
+                    Type::Number,
+                    ArithmeticOperation::new(
+                        ArithmeticOperator::Add,
+                        Variable::new("x"),
+                        Variable::new("y"),
+                    ),
+                ),
+                false,
+            ),
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new(
+                    "square",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    ArithmeticOperation::new(
+                        ArithmeticOperator::Multiply,
+                        Variable::new("x"),
+                        Variable::new("x"),
+                    ),
+                ),
+                false,
+            ),
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new(
+                    "decrement",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    ArithmeticOperation::new(ArithmeticOperator::Subtract, Variable::new("x"), 1.0),
+                ),
+                false,
+            ),
+        ]));
+
+        assert_eq!(module.function_definitions().len(), 1);
+        assert_eq!(
+            find_free_variables(module.function_definitions()[0].definition().body()),
+            ["i", "sum", "x"].into_iter().map(String::from).collect()
+        );
+    }
+
+    #[test]
+    fn optimize_case_of_variant() {
+        assert_eq!(
+            transform(&Module::empty().set_global_function_definitions(vec![
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "f",
+                        vec![],
+                        Type::Number,
+                        Let::new(
+                            "x",
+                            Type::Variant,
+                            Variant::new(Type::Number, 42.0),
+                            Case::new(
+                                Variable::new("x"),
+                                vec![Alternative::new(
+                                    vec![Type::Number],
+                                    "y",
+                                    Variable::new("y")
+                                )],
+                                None,
+                            ),
+                        ),
+                    ),
+                    true,
+                ),
+            ])),
+            Module::empty().set_global_function_definitions(vec![GlobalFunctionDefinition::new(
+                FunctionDefinition::new("f", vec![], Type::Number, 42.0),
+                true,
+            )])
+        );
+    }
+
+    #[test]
+    fn optimize_call_with_variant_argument() {
+        let any_function_type = types::Function::new(vec![Type::Variant], Type::Number);
+        let number_function_type = types::Function::new(vec![Type::Number], Type::Number);
+
+        assert_eq!(
+            transform(&Module::empty().set_global_function_definitions(vec![
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "f",
+                        vec![Argument::new("x", Type::Number)],
+                        Type::Number,
+                        Call::new(
+                            any_function_type.clone(),
+                            Variable::new("g"),
+                            vec![Variant::new(Type::Number, Variable::new("x")).into()]
+                        ),
+                    ),
+                    true,
+                ),
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "g",
+                        vec![Argument::new("x", Type::Variant)],
+                        Type::Number,
+                        Case::new(
+                            Variable::new("x"),
+                            vec![Alternative::new(
+                                vec![Type::Number],
+                                "y",
+                                Call::new(
+                                    any_function_type,
+                                    Variable::new("g"),
+                                    vec![Variant::new(Type::Number, Variable::new("y")).into()]
+                                ),
+                            )],
+                            None,
+                        ),
+                    ),
+                    false,
+                ),
+            ])),
+            Module::empty().set_global_function_definitions(vec![
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "f",
+                        vec![Argument::new("x", Type::Number)],
+                        Type::Number,
+                        Call::new(
+                            number_function_type.clone(),
+                            Variable::new("g:specialized:0"),
+                            vec![Variable::new("x").into()]
+                        ),
+                    ),
+                    true,
+                ),
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "g:specialized:0",
+                        vec![Argument::new("x", Type::Number)],
+                        Type::Number,
+                        Let::new(
+                            "y",
+                            Type::Number,
+                            Variable::new("x"),
+                            Call::new(
+                                number_function_type,
+                                Variable::new("g:specialized:0"),
+                                vec![Variable::new("y").into()]
+                            ),
+                        ),
+                    ),
+                    false,
+                ),
+            ])
+        );
+    }
+}
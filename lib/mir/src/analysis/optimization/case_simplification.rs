@@ -0,0 +1,126 @@
+use crate::{analysis::expression_conversion, ir::*, types::Type};
+
+// Replace case expressions of variants whose types are known statically with
+// let expressions of their matched alternatives.
+pub fn transform(module: &Module) -> Module {
+    expression_conversion::transform(module, |expression| match expression {
+        Expression::Case(case) => simplify_case(case).unwrap_or_else(|| expression.clone()),
+        _ => expression.clone(),
+    })
+}
+
+fn simplify_case(case: &Case) -> Option<Expression> {
+    let Expression::Variant(variant) = case.argument() else {
+        return None;
+    };
+
+    Some(
+        if let Some(alternative) = case
+            .alternatives()
+            .iter()
+            .find(|alternative| alternative.types().contains(variant.type_()))
+        {
+            if let [type_] = alternative.types() {
+                Let::new(
+                    alternative.name(),
+                    type_.clone(),
+                    variant.payload().clone(),
+                    alternative.expression().clone(),
+                )
+            } else {
+                Let::new(
+                    alternative.name(),
+                    Type::Variant,
+                    variant.clone(),
+                    alternative.expression().clone(),
+                )
+            }
+        } else {
+            let alternative = case.default_alternative()?;
+
+            Let::new(
+                alternative.name(),
+                Type::Variant,
+                variant.clone(),
+                alternative.expression().clone(),
+            )
+        }
+        .into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleFake;
+    use pretty_assertions::assert_eq;
+
+    fn transform_body(body: impl Into<Expression>) -> Expression {
+        transform(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec![],
+                Type::Number,
+                body,
+            )]),
+        )
+        .function_definitions()[0]
+            .definition()
+            .body()
+            .clone()
+    }
+
+    #[test]
+    fn simplify_case_with_matched_alternative() {
+        assert_eq!(
+            transform_body(Case::new(
+                Variant::new(Type::Number, 42.0),
+                vec![
+                    Alternative::new(vec![Type::Boolean], "x", 1.0),
+                    Alternative::new(vec![Type::Number], "y", Variable::new("y")),
+                ],
+                None,
+            )),
+            Let::new("y", Type::Number, 42.0, Variable::new("y")).into()
+        );
+    }
+
+    #[test]
+    fn simplify_case_with_alternative_of_multiple_types() {
+        assert_eq!(
+            transform_body(Case::new(
+                Variant::new(Type::Number, 42.0),
+                vec![Alternative::new(
+                    vec![Type::Boolean, Type::Number],
+                    "x",
+                    1.0
+                )],
+                None,
+            )),
+            Let::new("x", Type::Variant, Variant::new(Type::Number, 42.0), 1.0).into()
+        );
+    }
+
+    #[test]
+    fn simplify_case_with_default_alternative() {
+        assert_eq!(
+            transform_body(Case::new(
+                Variant::new(Type::Number, 42.0),
+                vec![Alternative::new(vec![Type::Boolean], "x", 1.0)],
+                Some(DefaultAlternative::new("y", 2.0)),
+            )),
+            Let::new("y", Type::Variant, Variant::new(Type::Number, 42.0), 2.0).into()
+        );
+    }
+
+    #[test]
+    fn do_not_simplify_case_of_variable() {
+        let case = Case::new(
+            Variable::new("x"),
+            vec![Alternative::new(vec![Type::Number], "y", 1.0)],
+            None,
+        );
+
+        assert_eq!(transform_body(case.clone()), case.into());
+    }
+}
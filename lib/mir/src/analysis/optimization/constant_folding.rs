@@ -0,0 +1,455 @@
+use crate::ir::*;
+
+type Constants<'a> = hamt::Map<&'a str, Expression>;
+
+pub fn transform(module: &Module) -> Module {
+    Module::new(
+        module.type_definitions().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.foreign_definitions().to_vec(),
+        module.function_declarations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| {
+                GlobalFunctionDefinition::new(
+                    transform_function_definition(definition.definition(), &Default::default()),
+                    definition.is_public(),
+                )
+            })
+            .collect(),
+        module.type_information().clone(),
+    )
+}
+
+fn transform_function_definition(
+    definition: &FunctionDefinition,
+    constants: &Constants,
+) -> FunctionDefinition {
+    FunctionDefinition::with_options(
+        definition.name(),
+        definition.environment().to_vec(),
+        definition.arguments().to_vec(),
+        definition.result_type().clone(),
+        {
+            let mut constants = constants.remove(definition.name());
+
+            for argument in definition
+                .environment()
+                .iter()
+                .chain(definition.arguments())
+            {
+                constants = constants.remove(argument.name());
+            }
+
+            transform_expression(definition.body(), &constants)
+        },
+        definition.is_thunk(),
+        definition.position().cloned(),
+    )
+}
+
+fn transform_expression<'a>(expression: &'a Expression, constants: &Constants<'a>) -> Expression {
+    let transform = |expression| transform_expression(expression, constants);
+
+    match expression {
+        Expression::ArithmeticOperation(operation) => {
+            match (transform(operation.lhs()), transform(operation.rhs())) {
+                (Expression::Number(lhs), Expression::Number(rhs)) => {
+                    fold_arithmetic_operation(operation.operator(), lhs, rhs).into()
+                }
+                (lhs, rhs) => ArithmeticOperation::new(operation.operator(), lhs, rhs).into(),
+            }
+        }
+        Expression::Case(case) => Case::new(
+            transform(case.argument()),
+            case.alternatives()
+                .iter()
+                .map(|alternative| {
+                    Alternative::new(
+                        alternative.types().to_vec(),
+                        alternative.name(),
+                        transform_expression(
+                            alternative.expression(),
+                            &constants.remove(alternative.name()),
+                        ),
+                    )
+                })
+                .collect(),
+            case.default_alternative().map(|alternative| {
+                DefaultAlternative::new(
+                    alternative.name(),
+                    transform_expression(
+                        alternative.expression(),
+                        &constants.remove(alternative.name()),
+                    ),
+                )
+            }),
+        )
+        .into(),
+        Expression::CloneVariables(clone) => {
+            CloneVariables::new(clone.variables().clone(), transform(clone.expression())).into()
+        }
+        Expression::ComparisonOperation(operation) => {
+            match (transform(operation.lhs()), transform(operation.rhs())) {
+                (Expression::Number(lhs), Expression::Number(rhs)) => {
+                    Expression::Boolean(match operation.operator() {
+                        ComparisonOperator::Equal => lhs == rhs,
+                        ComparisonOperator::NotEqual => lhs != rhs,
+                        ComparisonOperator::LessThan => lhs < rhs,
+                        ComparisonOperator::GreaterThan => lhs > rhs,
+                        ComparisonOperator::LessThanOrEqual => lhs <= rhs,
+                        ComparisonOperator::GreaterThanOrEqual => lhs >= rhs,
+                    })
+                }
+                (lhs, rhs) => ComparisonOperation::new(operation.operator(), lhs, rhs).into(),
+            }
+        }
+        Expression::DropVariables(drop) => {
+            DropVariables::new(drop.variables().clone(), transform(drop.expression())).into()
+        }
+        Expression::Call(call) => Call::with_position(
+            call.type_().clone(),
+            transform(call.function()),
+            call.arguments().iter().map(transform).collect(),
+            call.position().cloned(),
+        )
+        .into(),
+        Expression::If(if_) => match transform(if_.condition()) {
+            Expression::Boolean(true) => transform(if_.then()),
+            Expression::Boolean(false) => transform(if_.else_()),
+            condition => If::new(condition, transform(if_.then()), transform(if_.else_())).into(),
+        },
+        Expression::Let(let_) => {
+            let bound_expression = transform(let_.bound_expression());
+            let constants = if is_constant(&bound_expression) {
+                constants.insert(let_.name(), bound_expression.clone())
+            } else {
+                constants.remove(let_.name())
+            };
+
+            Let::new(
+                let_.name(),
+                let_.type_().clone(),
+                bound_expression,
+                transform_expression(let_.expression(), &constants),
+            )
+            .into()
+        }
+        Expression::LetRecursive(let_) => LetRecursive::new(
+            transform_function_definition(let_.definition(), constants),
+            transform_expression(
+                let_.expression(),
+                &constants.remove(let_.definition().name()),
+            ),
+        )
+        .into(),
+        Expression::Synchronize(synchronize) => Synchronize::new(
+            synchronize.type_().clone(),
+            transform(synchronize.expression()),
+        )
+        .into(),
+        Expression::Record(record) => Record::new(
+            record.type_().clone(),
+            record.fields().iter().map(transform).collect(),
+        )
+        .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            reuse.variable(),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
+        Expression::RecordField(field) => RecordField::new(
+            field.type_().clone(),
+            field.index(),
+            transform(field.record()),
+        )
+        .into(),
+        Expression::RecordUpdate(update) => RecordUpdate::new(
+            update.type_().clone(),
+            transform(update.record()),
+            update
+                .fields()
+                .iter()
+                .map(|field| RecordUpdateField::new(field.index(), transform(field.expression())))
+                .collect(),
+        )
+        .into(),
+        Expression::StringConcatenation(concatenation) => {
+            StringConcatenation::new(concatenation.operands().iter().map(transform).collect())
+                .into()
+        }
+        Expression::TryOperation(operation) => TryOperation::new(
+            transform(operation.operand()),
+            operation.name(),
+            operation.type_().clone(),
+            transform_expression(operation.then(), &constants.remove(operation.name())),
+        )
+        .into(),
+        Expression::TypeInformationFunction(information) => {
+            TypeInformationFunction::new(transform(information.variant())).into()
+        }
+        Expression::Variant(variant) => {
+            Variant::new(variant.type_().clone(), transform(variant.payload())).into()
+        }
+        Expression::Variable(variable) => constants
+            .get(variable.name())
+            .cloned()
+            .unwrap_or_else(|| variable.clone().into()),
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_) => expression.clone(),
+    }
+}
+
+// Constants are cheap enough to be duplicated at every use.
+fn is_constant(expression: &Expression) -> bool {
+    match expression {
+        Expression::Boolean(_) | Expression::None | Expression::Number(_) => true,
+        Expression::Variant(variant) => is_constant(variant.payload()),
+        _ => false,
+    }
+}
+
+// Bitwise operations convert numbers into 64-bit integers and back. Shift
+// counts are masked into the range of 0 to 63.
+fn fold_arithmetic_operation(operator: ArithmeticOperator, lhs: f64, rhs: f64) -> f64 {
+    let (x, y) = (lhs as i64, rhs as i64);
+
+    match operator {
+        ArithmeticOperator::Add => lhs + rhs,
+        ArithmeticOperator::Subtract => lhs - rhs,
+        ArithmeticOperator::Multiply => lhs * rhs,
+        ArithmeticOperator::Divide => lhs / rhs,
+        ArithmeticOperator::IntegerDivide => (lhs / rhs).trunc(),
+        ArithmeticOperator::Remainder => lhs % rhs,
+        ArithmeticOperator::And => (x & y) as f64,
+        ArithmeticOperator::Or => (x | y) as f64,
+        ArithmeticOperator::Xor => (x ^ y) as f64,
+        ArithmeticOperator::LeftShift => x.wrapping_shl(y as u32) as f64,
+        ArithmeticOperator::RightShift => x.wrapping_shr(y as u32) as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::ModuleFake, types::Type};
+    use pretty_assertions::assert_eq;
+
+    fn transform_body(type_: Type, body: impl Into<Expression>) -> Expression {
+        transform(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec![],
+                type_,
+                body,
+            )]),
+        )
+        .function_definitions()[0]
+            .definition()
+            .body()
+            .clone()
+    }
+
+    #[test]
+    fn fold_arithmetic_operation() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(
+                    ArithmeticOperator::Add,
+                    1.0,
+                    ArithmeticOperation::new(ArithmeticOperator::Multiply, 2.0, 3.0),
+                ),
+            ),
+            Expression::Number(7.0)
+        );
+    }
+
+    #[test]
+    fn fold_integer_division() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::IntegerDivide, -7.0, 2.0),
+            ),
+            Expression::Number(-3.0)
+        );
+    }
+
+    #[test]
+    fn fold_remainder() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::Remainder, -7.0, 2.0),
+            ),
+            Expression::Number(-1.0)
+        );
+    }
+
+    #[test]
+    fn fold_remainder_of_large_number() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::Remainder, 1e300, 3.0),
+            ),
+            Expression::Number(1e300 % 3.0)
+        );
+    }
+
+    #[test]
+    fn fold_bitwise_operation() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::Xor, 12.0, 10.0),
+            ),
+            Expression::Number(6.0)
+        );
+    }
+
+    #[test]
+    fn fold_bitwise_operation_on_negative_number() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::And, -1.0, 255.0),
+            ),
+            Expression::Number(255.0)
+        );
+    }
+
+    #[test]
+    fn fold_shift() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::LeftShift, 3.0, 2.0),
+            ),
+            Expression::Number(12.0)
+        );
+    }
+
+    #[test]
+    fn fold_right_shift_of_negative_number() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::RightShift, -8.0, 1.0),
+            ),
+            Expression::Number(-4.0)
+        );
+    }
+
+    #[test]
+    fn fold_overflowing_shift() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                ArithmeticOperation::new(ArithmeticOperator::LeftShift, 1.0, 65.0),
+            ),
+            Expression::Number(2.0)
+        );
+    }
+
+    #[test]
+    fn fold_comparison_operation() {
+        assert_eq!(
+            transform_body(
+                Type::Boolean,
+                ComparisonOperation::new(ComparisonOperator::LessThan, 1.0, 2.0),
+            ),
+            Expression::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn fold_if() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                If::new(
+                    ComparisonOperation::new(ComparisonOperator::Equal, 1.0, 2.0),
+                    1.0,
+                    2.0
+                ),
+            ),
+            Expression::Number(2.0)
+        );
+    }
+
+    #[test]
+    fn propagate_constant() {
+        assert_eq!(
+            transform_body(
+                Type::Number,
+                Let::new(
+                    "x",
+                    Type::Number,
+                    1.0,
+                    ArithmeticOperation::new(ArithmeticOperator::Add, Variable::new("x"), 2.0),
+                ),
+            ),
+            Let::new("x", Type::Number, 1.0, 3.0).into()
+        );
+    }
+
+    #[test]
+    fn do_not_propagate_shadowed_constant() {
+        let expression = Let::new(
+            "x",
+            Type::Number,
+            1.0,
+            Case::new(
+                Variable::new("y"),
+                vec![Alternative::new(
+                    vec![Type::Number],
+                    "x",
+                    Variable::new("x"),
+                )],
+                None,
+            ),
+        );
+
+        assert_eq!(
+            transform_body(Type::Number, expression.clone()),
+            expression.into()
+        );
+    }
+
+    #[test]
+    fn do_not_propagate_constant_to_shadowing_argument() {
+        let expression = Let::new(
+            "x",
+            Type::Number,
+            1.0,
+            LetRecursive::new(
+                FunctionDefinition::new(
+                    "g",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    Variable::new("x"),
+                ),
+                Variable::new("g"),
+            ),
+        );
+
+        assert_eq!(
+            transform_body(
+                Type::Function(crate::types::Function::new(
+                    vec![Type::Number],
+                    Type::Number
+                )),
+                expression.clone()
+            ),
+            expression.into()
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{
+    analysis::{expression_conversion, free_variable::find_free_variables},
+    ir::*,
+};
+use fnv::{FnvHashMap, FnvHashSet};
+
+pub fn transform(module: &Module) -> Module {
+    remove_functions(&expression_conversion::transform(
+        module,
+        |expression| match expression {
+            Expression::Let(let_)
+                if is_pure(let_.bound_expression())
+                    && !find_free_variables(let_.expression()).contains(let_.name()) =>
+            {
+                let_.expression().clone()
+            }
+            Expression::LetRecursive(let_)
+                if !find_free_variables(let_.expression()).contains(let_.definition().name()) =>
+            {
+                let_.expression().clone()
+            }
+            _ => expression.clone(),
+        },
+    ))
+}
+
+// Remove private functions unreachable from public functions, foreign
+// definitions, or type information.
+fn remove_functions(module: &Module) -> Module {
+    let definitions = module
+        .function_definitions()
+        .iter()
+        .map(|definition| (definition.definition().name(), definition))
+        .collect::<FnvHashMap<_, _>>();
+    let mut names = module
+        .function_definitions()
+        .iter()
+        .filter(|definition| definition.is_public())
+        .map(|definition| definition.definition().name().to_owned())
+        .chain(
+            module
+                .foreign_definitions()
+                .iter()
+                .map(|definition| definition.name().into()),
+        )
+        .chain(module.type_information().information().values().cloned())
+        .chain([module.type_information().fallback().into()])
+        .collect::<Vec<_>>();
+    let mut reachable_names = FnvHashSet::default();
+
+    while let Some(name) = names.pop() {
+        if reachable_names.contains(&name) {
+            continue;
+        }
+
+        if let Some(definition) = definitions.get(name.as_str()) {
+            names.extend(find_free_variables(definition.definition().body()));
+        }
+
+        reachable_names.insert(name);
+    }
+
+    Module::new(
+        module.type_definitions().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.foreign_definitions().to_vec(),
+        module.function_declarations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .filter(|definition| reachable_names.contains(definition.definition().name()))
+            .cloned()
+            .collect(),
+        module.type_information().clone(),
+    )
+}
+
+// Pure expressions never have side effects and always terminate.
+fn is_pure(expression: &Expression) -> bool {
+    match expression {
+        Expression::ArithmeticOperation(operation) => {
+            is_pure(operation.lhs()) && is_pure(operation.rhs())
+        }
+        Expression::ComparisonOperation(operation) => {
+            is_pure(operation.lhs()) && is_pure(operation.rhs())
+        }
+        Expression::Record(record) => record.fields().iter().all(is_pure),
+        Expression::RecordField(field) => is_pure(field.record()),
+        Expression::StringConcatenation(concatenation) => {
+            concatenation.operands().iter().all(is_pure)
+        }
+        Expression::Variant(variant) => is_pure(variant.payload()),
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_)
+        | Expression::Variable(_) => true,
+        Expression::Call(_)
+        | Expression::Case(_)
+        | Expression::CloneVariables(_)
+        | Expression::DropVariables(_)
+        | Expression::If(_)
+        | Expression::Let(_)
+        | Expression::LetRecursive(_)
+        | Expression::RecordUpdate(_)
+        | Expression::ReuseRecord(_)
+        | Expression::Synchronize(_)
+        | Expression::TryOperation(_)
+        | Expression::TypeInformationFunction(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::ModuleFake, types::Type};
+    use pretty_assertions::assert_eq;
+
+    fn transform_body(body: impl Into<Expression>) -> Expression {
+        transform(&Module::empty().set_global_function_definitions(vec![
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new("f", vec![], Type::Number, body),
+                true,
+            ),
+        ]))
+        .function_definitions()[0]
+            .definition()
+            .body()
+            .clone()
+    }
+
+    #[test]
+    fn remove_unused_let() {
+        assert_eq!(
+            transform_body(Let::new("x", Type::Number, 1.0, 2.0)),
+            Expression::Number(2.0)
+        );
+    }
+
+    #[test]
+    fn keep_used_let() {
+        let expression = Let::new("x", Type::Number, 1.0, Variable::new("x"));
+
+        assert_eq!(transform_body(expression.clone()), expression.into());
+    }
+
+    #[test]
+    fn keep_unused_let_with_call() {
+        let expression = Let::new(
+            "x",
+            Type::Number,
+            Call::new(
+                crate::types::Function::new(vec![], Type::Number),
+                Variable::new("g"),
+                vec![],
+            ),
+            1.0,
+        );
+
+        assert_eq!(transform_body(expression.clone()), expression.into());
+    }
+
+    #[test]
+    fn remove_unused_let_recursive() {
+        assert_eq!(
+            transform_body(LetRecursive::new(
+                FunctionDefinition::new("g", vec![], Type::Number, 1.0),
+                2.0
+            )),
+            Expression::Number(2.0)
+        );
+    }
+
+    #[test]
+    fn remove_unused_private_function() {
+        let definition = GlobalFunctionDefinition::new(
+            FunctionDefinition::new("f", vec![], Type::Number, 1.0),
+            true,
+        );
+
+        assert_eq!(
+            transform(&Module::empty().set_global_function_definitions(vec![
+                definition.clone(),
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new("g", vec![], Type::Number, 1.0),
+                    false,
+                ),
+            ])),
+            Module::empty().set_global_function_definitions(vec![definition])
+        );
+    }
+
+    #[test]
+    fn keep_private_function_referenced_by_public_function() {
+        let module = Module::empty().set_global_function_definitions(vec![
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new("f", vec![], Type::Number, Variable::new("g")),
+                true,
+            ),
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new("g", vec![], Type::Number, Variable::new("h")),
+                false,
+            ),
+            GlobalFunctionDefinition::new(
+                FunctionDefinition::new("h", vec![], Type::Number, 1.0),
+                false,
+            ),
+        ]);
+
+        assert_eq!(transform(&module), module);
+    }
+
+    #[test]
+    fn keep_private_function_referenced_by_foreign_definition() {
+        let module = Module::empty()
+            .set_foreign_definitions(vec![ForeignDefinition::new(
+                "f",
+                "g",
+                CallingConvention::Target,
+            )])
+            .set_function_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec![],
+                Type::Number,
+                1.0,
+            )]);
+
+        assert_eq!(transform(&module), module);
+    }
+
+    #[test]
+    fn keep_private_function_referenced_by_type_information() {
+        let module = Module::empty()
+            .set_type_information(TypeInformation::new(
+                [(Type::Number, "f".into())].into_iter().collect(),
+                "g".into(),
+            ))
+            .set_function_definitions(vec![
+                FunctionDefinition::new("f", vec![], Type::Number, 1.0),
+                FunctionDefinition::new("g", vec![], Type::Number, 1.0),
+            ]);
+
+        assert_eq!(transform(&module), module);
+    }
+}
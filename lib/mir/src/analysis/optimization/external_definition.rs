@@ -0,0 +1,504 @@
+use super::specialization;
+use crate::{
+    analysis::{free_variable::find_free_variables, type_check, variant_type_collection},
+    ir::*,
+};
+use fnv::FnvHashSet;
+
+// Definitions of public functions are exported through module interfaces so
+// that other modules can specialize them for variant arguments of concrete
+// types.
+
+// Find public functions which can be specialized in other modules. Their bodies
+// must refer to only functions with external linkage.
+pub fn export(module: &Module) -> Vec<FunctionDefinition> {
+    let names = module
+        .function_definitions()
+        .iter()
+        .filter(|definition| definition.is_public())
+        .map(|definition| definition.definition().name())
+        .chain(
+            module
+                .function_declarations()
+                .iter()
+                .map(|declaration| declaration.name()),
+        )
+        .collect::<FnvHashSet<_>>();
+
+    module
+        .function_definitions()
+        .iter()
+        .filter(|definition| definition.is_public())
+        .map(GlobalFunctionDefinition::definition)
+        .filter(|definition| {
+            specialization::is_specializable(definition)
+                && find_free_variables(definition.body()).iter().all(|name| {
+                    names.contains(name.as_str())
+                        || definition
+                            .arguments()
+                            .iter()
+                            .any(|argument| argument.name() == name)
+                })
+        })
+        .cloned()
+        .collect()
+}
+
+// Import definitions of declared functions called with variant arguments as
+// private functions so that they are specialized in this module. Definitions
+// referring to functions or types unavailable in this module are skipped.
+pub fn import(module: &Module, definitions: &[FunctionDefinition]) -> Module {
+    let mut names = module
+        .function_definitions()
+        .iter()
+        .flat_map(|definition| find_callees(definition.definition().body()))
+        .collect::<Vec<_>>();
+    let mut imported_names = FnvHashSet::default();
+    let mut imported_definitions = vec![];
+
+    while let Some(name) = names.pop() {
+        if imported_names.contains(name) {
+            continue;
+        }
+
+        let Some(definition) = definitions
+            .iter()
+            .find(|definition| definition.name() == name)
+        else {
+            continue;
+        };
+
+        if !is_importable(module, definition) {
+            continue;
+        }
+
+        names.extend(find_callees(definition.body()));
+        imported_names.insert(name);
+        imported_definitions.push(GlobalFunctionDefinition::new(definition.clone(), false));
+    }
+
+    if imported_definitions.is_empty() {
+        return module.clone();
+    }
+
+    Module::new(
+        module.type_definitions().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.foreign_definitions().to_vec(),
+        module
+            .function_declarations()
+            .iter()
+            .filter(|declaration| !imported_names.contains(declaration.name()))
+            .cloned()
+            .collect(),
+        module
+            .function_definitions()
+            .iter()
+            .cloned()
+            .chain(imported_definitions)
+            .collect(),
+        module.type_information().clone(),
+    )
+}
+
+fn is_importable(module: &Module, definition: &FunctionDefinition) -> bool {
+    let definition_module = Module::new(
+        module.type_definitions().to_vec(),
+        module.foreign_declarations().to_vec(),
+        vec![],
+        module
+            .function_declarations()
+            .iter()
+            .filter(|declaration| declaration.name() != definition.name())
+            .cloned()
+            .chain(module.function_definitions().iter().map(|definition| {
+                let definition = definition.definition();
+
+                FunctionDeclaration::new(definition.name(), definition.type_().clone())
+            }))
+            .collect(),
+        vec![GlobalFunctionDefinition::new(definition.clone(), false)],
+        module.type_information().clone(),
+    );
+
+    module.function_declarations().iter().any(|declaration| {
+        declaration.name() == definition.name() && declaration.type_() == definition.type_()
+    }) && type_check::check(&definition_module).is_ok()
+        && variant_type_collection::collect(&definition_module)
+            .iter()
+            .all(|type_| module.type_information().information().contains_key(type_))
+}
+
+// Find functions called with variant arguments.
+fn find_callees(expression: &Expression) -> Vec<&str> {
+    let mut names = vec![];
+
+    find_callees_in_expression(expression, &mut names);
+
+    names
+}
+
+fn find_callees_in_expression<'a>(expression: &'a Expression, names: &mut Vec<&'a str>) {
+    let mut find = |expression| find_callees_in_expression(expression, names);
+
+    match expression {
+        Expression::ArithmeticOperation(operation) => {
+            find(operation.lhs());
+            find(operation.rhs());
+        }
+        Expression::Case(case) => {
+            find(case.argument());
+
+            for alternative in case.alternatives() {
+                find(alternative.expression());
+            }
+
+            if let Some(alternative) = case.default_alternative() {
+                find(alternative.expression());
+            }
+        }
+        Expression::CloneVariables(clone) => find(clone.expression()),
+        Expression::ComparisonOperation(operation) => {
+            find(operation.lhs());
+            find(operation.rhs());
+        }
+        Expression::DropVariables(drop) => find(drop.expression()),
+        Expression::Call(call) => {
+            if let Expression::Variable(variable) = call.function() {
+                if call
+                    .arguments()
+                    .iter()
+                    .any(|argument| matches!(argument, Expression::Variant(_)))
+                {
+                    names.push(variable.name());
+                }
+            }
+
+            find_callees_in_expression(call.function(), names);
+
+            for argument in call.arguments() {
+                find_callees_in_expression(argument, names);
+            }
+        }
+        Expression::If(if_) => {
+            find(if_.condition());
+            find(if_.then());
+            find(if_.else_());
+        }
+        Expression::Let(let_) => {
+            find(let_.bound_expression());
+            find(let_.expression());
+        }
+        Expression::LetRecursive(let_) => {
+            find(let_.definition().body());
+            find(let_.expression());
+        }
+        Expression::Synchronize(synchronize) => find(synchronize.expression()),
+        Expression::Record(record) => {
+            for field in record.fields() {
+                find(field);
+            }
+        }
+        Expression::RecordField(field) => find(field.record()),
+        Expression::RecordUpdate(update) => {
+            find(update.record());
+
+            for field in update.fields() {
+                find(field.expression());
+            }
+        }
+        Expression::ReuseRecord(reuse) => {
+            for field in reuse.record().fields() {
+                find(field);
+            }
+        }
+        Expression::StringConcatenation(concatenation) => {
+            for operand in concatenation.operands() {
+                find(operand);
+            }
+        }
+        Expression::TryOperation(operation) => {
+            find(operation.operand());
+            find(operation.then());
+        }
+        Expression::TypeInformationFunction(information) => find(information.variant()),
+        Expression::Variant(variant) => find(variant.payload()),
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_)
+        | Expression::Variable(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::ModuleFake,
+        types::{self, Type},
+    };
+
+    fn function_type() -> types::Function {
+        types::Function::new(vec![Type::Variant], Type::Variant)
+    }
+
+    fn identity_definition(name: &str) -> FunctionDefinition {
+        FunctionDefinition::new(
+            name,
+            vec![Argument::new("x", Type::Variant)],
+            Type::Variant,
+            Variable::new("x"),
+        )
+    }
+
+    fn call_definition(function: &str, argument: impl Into<Expression>) -> FunctionDefinition {
+        FunctionDefinition::new(
+            "g",
+            vec![],
+            Type::Variant,
+            Call::new(
+                function_type(),
+                Variable::new(function),
+                vec![argument.into()],
+            ),
+        )
+    }
+
+    mod export {
+        use super::*;
+
+        #[test]
+        fn export_public_function() {
+            assert_eq!(
+                export(&Module::empty().set_global_function_definitions(vec![
+                    GlobalFunctionDefinition::new(identity_definition("f"), true)
+                ])),
+                vec![identity_definition("f")]
+            );
+        }
+
+        #[test]
+        fn do_not_export_private_function() {
+            assert_eq!(
+                export(&Module::empty().set_global_function_definitions(vec![
+                    GlobalFunctionDefinition::new(identity_definition("f"), false)
+                ])),
+                vec![]
+            );
+        }
+
+        #[test]
+        fn do_not_export_function_without_variant_argument() {
+            assert_eq!(
+                export(&Module::empty().set_global_function_definitions(vec![
+                    GlobalFunctionDefinition::new(
+                        FunctionDefinition::new(
+                            "f",
+                            vec![Argument::new("x", Type::Number)],
+                            Type::Number,
+                            Variable::new("x"),
+                        ),
+                        true
+                    )
+                ])),
+                vec![]
+            );
+        }
+
+        #[test]
+        fn export_function_calling_declared_function() {
+            let definition = FunctionDefinition::new(
+                "f",
+                vec![Argument::new("x", Type::Variant)],
+                Type::Variant,
+                Call::new(
+                    function_type(),
+                    Variable::new("h"),
+                    vec![Variable::new("x").into()],
+                ),
+            );
+
+            assert_eq!(
+                export(
+                    &Module::empty()
+                        .set_function_declarations(vec![FunctionDeclaration::new(
+                            "h",
+                            function_type()
+                        )])
+                        .set_global_function_definitions(vec![GlobalFunctionDefinition::new(
+                            definition.clone(),
+                            true
+                        )])
+                ),
+                vec![definition]
+            );
+        }
+
+        #[test]
+        fn do_not_export_function_calling_private_function() {
+            assert_eq!(
+                export(&Module::empty().set_global_function_definitions(vec![
+                    GlobalFunctionDefinition::new(
+                        FunctionDefinition::new(
+                            "f",
+                            vec![Argument::new("x", Type::Variant)],
+                            Type::Variant,
+                            Call::new(
+                                function_type(),
+                                Variable::new("h"),
+                                vec![Variable::new("x").into()]
+                            ),
+                        ),
+                        true
+                    ),
+                    GlobalFunctionDefinition::new(identity_definition("h"), false),
+                ])),
+                vec![]
+            );
+        }
+    }
+
+    mod import {
+        use super::*;
+
+        fn empty_module() -> Module {
+            Module::empty()
+                .set_type_information(TypeInformation::new(Default::default(), "g".into()))
+        }
+
+        #[test]
+        fn import_function_called_with_variant() {
+            let module = empty_module()
+                .set_function_declarations(vec![FunctionDeclaration::new("f", function_type())])
+                .set_function_definitions(vec![call_definition(
+                    "f",
+                    Variant::new(Type::Number, 42.0),
+                )]);
+
+            assert_eq!(
+                import(&module, &[identity_definition("f")]),
+                empty_module().set_function_definitions(vec![
+                    call_definition("f", Variant::new(Type::Number, 42.0)),
+                    identity_definition("f"),
+                ])
+            );
+        }
+
+        #[test]
+        fn do_not_import_function_called_without_variant() {
+            let module = empty_module()
+                .set_function_declarations(vec![FunctionDeclaration::new("f", function_type())])
+                .set_function_definitions(vec![FunctionDefinition::new(
+                    "g",
+                    vec![Argument::new("x", Type::Variant)],
+                    Type::Variant,
+                    Call::new(
+                        function_type(),
+                        Variable::new("f"),
+                        vec![Variable::new("x").into()],
+                    ),
+                )]);
+
+            assert_eq!(import(&module, &[identity_definition("f")]), module);
+        }
+
+        #[test]
+        fn do_not_import_undeclared_function() {
+            let module = empty_module().set_function_definitions(vec![call_definition(
+                "f",
+                Variant::new(Type::Number, 42.0),
+            )]);
+
+            assert_eq!(import(&module, &[identity_definition("f")]), module);
+        }
+
+        #[test]
+        fn do_not_import_function_calling_unavailable_function() {
+            let module = empty_module()
+                .set_function_declarations(vec![FunctionDeclaration::new("f", function_type())])
+                .set_function_definitions(vec![call_definition(
+                    "f",
+                    Variant::new(Type::Number, 42.0),
+                )]);
+
+            assert_eq!(
+                import(
+                    &module,
+                    &[FunctionDefinition::new(
+                        "f",
+                        vec![Argument::new("x", Type::Variant)],
+                        Type::Variant,
+                        Call::new(
+                            function_type(),
+                            Variable::new("h"),
+                            vec![Variable::new("x").into()]
+                        ),
+                    )]
+                ),
+                module
+            );
+        }
+
+        #[test]
+        fn import_function_called_by_imported_function() {
+            let definition = FunctionDefinition::new(
+                "f",
+                vec![Argument::new("x", Type::Variant)],
+                Type::Variant,
+                Call::new(
+                    function_type(),
+                    Variable::new("h"),
+                    vec![Variant::new(Type::Number, 42.0).into()],
+                ),
+            );
+            let module = empty_module()
+                .set_type_information(TypeInformation::new(
+                    [(Type::Number, "g".into())].into_iter().collect(),
+                    "g".into(),
+                ))
+                .set_function_declarations(vec![
+                    FunctionDeclaration::new("f", function_type()),
+                    FunctionDeclaration::new("h", function_type()),
+                ])
+                .set_function_definitions(vec![call_definition(
+                    "f",
+                    Variant::new(Type::Number, 42.0),
+                )]);
+
+            assert_eq!(
+                import(&module, &[definition.clone(), identity_definition("h")]),
+                module
+                    .set_function_declarations(vec![])
+                    .set_function_definitions(vec![
+                        call_definition("f", Variant::new(Type::Number, 42.0)),
+                        definition,
+                        identity_definition("h"),
+                    ])
+            );
+        }
+
+        #[test]
+        fn do_not_import_function_with_variant_of_unknown_type() {
+            let module = empty_module()
+                .set_function_declarations(vec![FunctionDeclaration::new("f", function_type())])
+                .set_function_definitions(vec![call_definition(
+                    "f",
+                    Variant::new(Type::Number, 42.0),
+                )]);
+
+            assert_eq!(
+                import(
+                    &module,
+                    &[FunctionDefinition::new(
+                        "f",
+                        vec![Argument::new("x", Type::Variant)],
+                        Type::Variant,
+                        Variant::new(Type::Number, 42.0),
+                    )]
+                ),
+                module
+            );
+        }
+    }
+}
@@ -0,0 +1,613 @@
+use crate::{analysis::free_variable::find_free_variables, ir::*};
+use fnv::{FnvHashMap, FnvHashSet};
+use std::cell::Cell;
+
+const MAXIMUM_FUNCTION_SIZE: usize = 32;
+const ARGUMENT_PREFIX: &str = "$inline_argument:";
+
+struct Context<'a> {
+    functions: FnvHashMap<&'a str, (&'a FunctionDefinition, FnvHashSet<String>)>,
+    argument_count: Cell<usize>,
+}
+
+type LocalVariables<'a> = hamt::Map<&'a str, ()>;
+
+// Inline calls to small global functions.
+pub fn transform(module: &Module) -> Module {
+    let context = Context {
+        functions: module
+            .function_definitions()
+            .iter()
+            .map(|definition| definition.definition())
+            .filter(|definition| {
+                !definition.is_thunk()
+                    && definition.environment().is_empty()
+                    && measure_expression(definition.body()) <= MAXIMUM_FUNCTION_SIZE
+                    && !has_try_operation(definition.body())
+            })
+            .map(|definition| {
+                (
+                    definition.name(),
+                    (
+                        definition,
+                        find_free_variables(definition.body())
+                            .into_iter()
+                            .filter(|name| {
+                                definition
+                                    .arguments()
+                                    .iter()
+                                    .all(|argument| argument.name() != name)
+                            })
+                            .collect::<FnvHashSet<_>>(),
+                    ),
+                )
+            })
+            .filter(|(name, (_, free_variables))| !free_variables.contains(*name))
+            .collect(),
+        argument_count: Cell::new(0),
+    };
+
+    Module::new(
+        module.type_definitions().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.foreign_definitions().to_vec(),
+        module.function_declarations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| {
+                GlobalFunctionDefinition::new(
+                    transform_function_definition(
+                        &context,
+                        definition.definition(),
+                        &Default::default(),
+                    ),
+                    definition.is_public(),
+                )
+            })
+            .collect(),
+        module.type_information().clone(),
+    )
+}
+
+fn transform_function_definition<'a>(
+    context: &Context,
+    definition: &'a FunctionDefinition,
+    variables: &LocalVariables<'a>,
+) -> FunctionDefinition {
+    FunctionDefinition::with_options(
+        definition.name(),
+        definition.environment().to_vec(),
+        definition.arguments().to_vec(),
+        definition.result_type().clone(),
+        {
+            let mut variables = variables.insert(definition.name(), ());
+
+            for argument in definition
+                .environment()
+                .iter()
+                .chain(definition.arguments())
+            {
+                variables = variables.insert(argument.name(), ());
+            }
+
+            transform_expression(context, definition.body(), &variables)
+        },
+        definition.is_thunk(),
+        definition.position().cloned(),
+    )
+}
+
+fn transform_expression<'a>(
+    context: &Context,
+    expression: &'a Expression,
+    variables: &LocalVariables<'a>,
+) -> Expression {
+    let transform = |expression| transform_expression(context, expression, variables);
+
+    match expression {
+        Expression::ArithmeticOperation(operation) => ArithmeticOperation::new(
+            operation.operator(),
+            transform(operation.lhs()),
+            transform(operation.rhs()),
+        )
+        .into(),
+        Expression::Case(case) => Case::new(
+            transform(case.argument()),
+            case.alternatives()
+                .iter()
+                .map(|alternative| {
+                    Alternative::new(
+                        alternative.types().to_vec(),
+                        alternative.name(),
+                        transform_expression(
+                            context,
+                            alternative.expression(),
+                            &variables.insert(alternative.name(), ()),
+                        ),
+                    )
+                })
+                .collect(),
+            case.default_alternative().map(|alternative| {
+                DefaultAlternative::new(
+                    alternative.name(),
+                    transform_expression(
+                        context,
+                        alternative.expression(),
+                        &variables.insert(alternative.name(), ()),
+                    ),
+                )
+            }),
+        )
+        .into(),
+        Expression::CloneVariables(clone) => {
+            CloneVariables::new(clone.variables().clone(), transform(clone.expression())).into()
+        }
+        Expression::ComparisonOperation(operation) => ComparisonOperation::new(
+            operation.operator(),
+            transform(operation.lhs()),
+            transform(operation.rhs()),
+        )
+        .into(),
+        Expression::DropVariables(drop) => {
+            DropVariables::new(drop.variables().clone(), transform(drop.expression())).into()
+        }
+        Expression::Call(call) => {
+            let arguments = call.arguments().iter().map(transform).collect::<Vec<_>>();
+
+            if let Expression::Variable(variable) = call.function() {
+                if let Some((definition, free_variables)) = context.functions.get(variable.name()) {
+                    if variables.get(variable.name()).is_none()
+                        && free_variables
+                            .iter()
+                            .all(|name| variables.get(name.as_str()).is_none())
+                    {
+                        return inline_call(context, definition, arguments);
+                    }
+                }
+            }
+
+            Call::with_position(
+                call.type_().clone(),
+                transform(call.function()),
+                arguments,
+                call.position().cloned(),
+            )
+            .into()
+        }
+        Expression::If(if_) => If::new(
+            transform(if_.condition()),
+            transform(if_.then()),
+            transform(if_.else_()),
+        )
+        .into(),
+        Expression::Let(let_) => Let::new(
+            let_.name(),
+            let_.type_().clone(),
+            transform(let_.bound_expression()),
+            transform_expression(
+                context,
+                let_.expression(),
+                &variables.insert(let_.name(), ()),
+            ),
+        )
+        .into(),
+        Expression::LetRecursive(let_) => LetRecursive::new(
+            transform_function_definition(context, let_.definition(), variables),
+            transform_expression(
+                context,
+                let_.expression(),
+                &variables.insert(let_.definition().name(), ()),
+            ),
+        )
+        .into(),
+        Expression::Synchronize(synchronize) => Synchronize::new(
+            synchronize.type_().clone(),
+            transform(synchronize.expression()),
+        )
+        .into(),
+        Expression::Record(record) => Record::new(
+            record.type_().clone(),
+            record.fields().iter().map(transform).collect(),
+        )
+        .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            reuse.variable(),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
+        Expression::RecordField(field) => RecordField::new(
+            field.type_().clone(),
+            field.index(),
+            transform(field.record()),
+        )
+        .into(),
+        Expression::RecordUpdate(update) => RecordUpdate::new(
+            update.type_().clone(),
+            transform(update.record()),
+            update
+                .fields()
+                .iter()
+                .map(|field| RecordUpdateField::new(field.index(), transform(field.expression())))
+                .collect(),
+        )
+        .into(),
+        Expression::StringConcatenation(concatenation) => {
+            StringConcatenation::new(concatenation.operands().iter().map(transform).collect())
+                .into()
+        }
+        Expression::TryOperation(operation) => TryOperation::new(
+            transform(operation.operand()),
+            operation.name(),
+            operation.type_().clone(),
+            transform_expression(
+                context,
+                operation.then(),
+                &variables.insert(operation.name(), ()),
+            ),
+        )
+        .into(),
+        Expression::TypeInformationFunction(information) => {
+            TypeInformationFunction::new(transform(information.variant())).into()
+        }
+        Expression::Variant(variant) => {
+            Variant::new(variant.type_().clone(), transform(variant.payload())).into()
+        }
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_)
+        | Expression::Variable(_) => expression.clone(),
+    }
+}
+
+// Arguments are bound to unique names first so that they are not captured by
+// arguments of an inlined function.
+fn inline_call(
+    context: &Context,
+    definition: &FunctionDefinition,
+    arguments: Vec<Expression>,
+) -> Expression {
+    let names = definition
+        .arguments()
+        .iter()
+        .map(|_| {
+            let count = context.argument_count.get();
+
+            context.argument_count.set(count + 1);
+
+            format!("{ARGUMENT_PREFIX}{count}")
+        })
+        .collect::<Vec<_>>();
+
+    let body = definition.arguments().iter().zip(&names).rev().fold(
+        definition.body().clone(),
+        |body, (argument, name)| {
+            Let::new(
+                argument.name(),
+                argument.type_().clone(),
+                Variable::new(name),
+                body,
+            )
+            .into()
+        },
+    );
+
+    definition
+        .arguments()
+        .iter()
+        .zip(names)
+        .zip(arguments)
+        .rev()
+        .fold(body, |body, ((argument, name), expression)| {
+            Let::new(name, argument.type_().clone(), expression, body).into()
+        })
+}
+
+// Try operations return early from their functions and cannot be inlined.
+fn has_try_operation(expression: &Expression) -> bool {
+    match expression {
+        Expression::ArithmeticOperation(operation) => {
+            has_try_operation(operation.lhs()) || has_try_operation(operation.rhs())
+        }
+        Expression::Case(case) => {
+            has_try_operation(case.argument())
+                || case
+                    .alternatives()
+                    .iter()
+                    .any(|alternative| has_try_operation(alternative.expression()))
+                || case
+                    .default_alternative()
+                    .map(|alternative| has_try_operation(alternative.expression()))
+                    .unwrap_or_default()
+        }
+        Expression::CloneVariables(clone) => has_try_operation(clone.expression()),
+        Expression::ComparisonOperation(operation) => {
+            has_try_operation(operation.lhs()) || has_try_operation(operation.rhs())
+        }
+        Expression::DropVariables(drop) => has_try_operation(drop.expression()),
+        Expression::Call(call) => {
+            has_try_operation(call.function()) || call.arguments().iter().any(has_try_operation)
+        }
+        Expression::If(if_) => {
+            has_try_operation(if_.condition())
+                || has_try_operation(if_.then())
+                || has_try_operation(if_.else_())
+        }
+        Expression::Let(let_) => {
+            has_try_operation(let_.bound_expression()) || has_try_operation(let_.expression())
+        }
+        // Try operations in closures return from the closures.
+        Expression::LetRecursive(let_) => has_try_operation(let_.expression()),
+        Expression::Synchronize(synchronize) => has_try_operation(synchronize.expression()),
+        Expression::Record(record) => record.fields().iter().any(has_try_operation),
+        Expression::RecordField(field) => has_try_operation(field.record()),
+        Expression::ReuseRecord(reuse) => reuse.record().fields().iter().any(has_try_operation),
+        Expression::RecordUpdate(update) => {
+            has_try_operation(update.record())
+                || update
+                    .fields()
+                    .iter()
+                    .any(|field| has_try_operation(field.expression()))
+        }
+        Expression::StringConcatenation(concatenation) => {
+            concatenation.operands().iter().any(has_try_operation)
+        }
+        Expression::TryOperation(_) => true,
+        Expression::TypeInformationFunction(information) => {
+            has_try_operation(information.variant())
+        }
+        Expression::Variant(variant) => has_try_operation(variant.payload()),
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_)
+        | Expression::Variable(_) => false,
+    }
+}
+
+fn measure_expression(expression: &Expression) -> usize {
+    1 + match expression {
+        Expression::ArithmeticOperation(operation) => {
+            measure_expression(operation.lhs()) + measure_expression(operation.rhs())
+        }
+        Expression::Case(case) => {
+            measure_expression(case.argument())
+                + case
+                    .alternatives()
+                    .iter()
+                    .map(|alternative| measure_expression(alternative.expression()))
+                    .sum::<usize>()
+                + case
+                    .default_alternative()
+                    .map(|alternative| measure_expression(alternative.expression()))
+                    .unwrap_or_default()
+        }
+        Expression::CloneVariables(clone) => measure_expression(clone.expression()),
+        Expression::ComparisonOperation(operation) => {
+            measure_expression(operation.lhs()) + measure_expression(operation.rhs())
+        }
+        Expression::DropVariables(drop) => measure_expression(drop.expression()),
+        Expression::Call(call) => {
+            measure_expression(call.function())
+                + call
+                    .arguments()
+                    .iter()
+                    .map(measure_expression)
+                    .sum::<usize>()
+        }
+        Expression::If(if_) => {
+            measure_expression(if_.condition())
+                + measure_expression(if_.then())
+                + measure_expression(if_.else_())
+        }
+        Expression::Let(let_) => {
+            measure_expression(let_.bound_expression()) + measure_expression(let_.expression())
+        }
+        Expression::LetRecursive(let_) => {
+            measure_expression(let_.definition().body()) + measure_expression(let_.expression())
+        }
+        Expression::Synchronize(synchronize) => measure_expression(synchronize.expression()),
+        Expression::Record(record) => record.fields().iter().map(measure_expression).sum(),
+        Expression::RecordField(field) => measure_expression(field.record()),
+        Expression::ReuseRecord(reuse) => {
+            reuse.record().fields().iter().map(measure_expression).sum()
+        }
+        Expression::RecordUpdate(update) => {
+            measure_expression(update.record())
+                + update
+                    .fields()
+                    .iter()
+                    .map(|field| measure_expression(field.expression()))
+                    .sum::<usize>()
+        }
+        Expression::StringConcatenation(concatenation) => concatenation
+            .operands()
+            .iter()
+            .map(measure_expression)
+            .sum(),
+        Expression::TryOperation(operation) => {
+            measure_expression(operation.operand()) + measure_expression(operation.then())
+        }
+        Expression::TypeInformationFunction(information) => {
+            measure_expression(information.variant())
+        }
+        Expression::Variant(variant) => measure_expression(variant.payload()),
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_)
+        | Expression::Variable(_) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::ModuleFake, types, types::Type};
+    use pretty_assertions::assert_eq;
+
+    fn function_type() -> types::Function {
+        types::Function::new(vec![Type::Number], Type::Number)
+    }
+
+    fn identity_definition() -> FunctionDefinition {
+        FunctionDefinition::new(
+            "g",
+            vec![Argument::new("x", Type::Number)],
+            Type::Number,
+            Variable::new("x"),
+        )
+    }
+
+    fn transform_body(
+        definitions: Vec<FunctionDefinition>,
+        body: impl Into<Expression>,
+    ) -> Expression {
+        transform(
+            &Module::empty().set_function_definitions(
+                [FunctionDefinition::new(
+                    "f",
+                    vec![Argument::new("y", Type::Number)],
+                    Type::Number,
+                    body,
+                )]
+                .into_iter()
+                .chain(definitions)
+                .collect(),
+            ),
+        )
+        .function_definitions()[0]
+            .definition()
+            .body()
+            .clone()
+    }
+
+    #[test]
+    fn inline_small_function() {
+        assert_eq!(
+            transform_body(
+                vec![identity_definition()],
+                Call::new(function_type(), Variable::new("g"), vec![42.0.into()]),
+            ),
+            Let::new(
+                "$inline_argument:0",
+                Type::Number,
+                42.0,
+                Let::new(
+                    "x",
+                    Type::Number,
+                    Variable::new("$inline_argument:0"),
+                    Variable::new("x")
+                )
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn do_not_inline_recursive_function() {
+        let call = Call::new(function_type(), Variable::new("g"), vec![42.0.into()]);
+
+        assert_eq!(
+            transform_body(
+                vec![FunctionDefinition::new(
+                    "g",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    Call::new(function_type(), Variable::new("g"), vec![42.0.into()]),
+                )],
+                call.clone(),
+            ),
+            call.into()
+        );
+    }
+
+    #[test]
+    fn do_not_inline_shadowed_function() {
+        let expression = Let::new(
+            "g",
+            function_type(),
+            Variable::new("h"),
+            Call::new(function_type(), Variable::new("g"), vec![42.0.into()]),
+        );
+
+        assert_eq!(
+            transform_body(vec![identity_definition()], expression.clone()),
+            expression.into()
+        );
+    }
+
+    #[test]
+    fn do_not_inline_function_with_shadowed_free_variable() {
+        let expression = Let::new(
+            "h",
+            Type::Number,
+            1.0,
+            Call::new(function_type(), Variable::new("g"), vec![42.0.into()]),
+        );
+
+        assert_eq!(
+            transform_body(
+                vec![FunctionDefinition::new(
+                    "g",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    Variable::new("h"),
+                )],
+                expression.clone()
+            ),
+            expression.into()
+        );
+    }
+
+    #[test]
+    fn do_not_inline_function_with_try_operation() {
+        let call = Call::new(function_type(), Variable::new("g"), vec![42.0.into()]);
+
+        assert_eq!(
+            transform_body(
+                vec![FunctionDefinition::new(
+                    "g",
+                    vec![Argument::new("x", Type::Variant)],
+                    Type::Variant,
+                    TryOperation::new(
+                        Variable::new("x"),
+                        "y",
+                        Type::Number,
+                        Variant::new(Type::Number, Variable::new("y")),
+                    ),
+                )],
+                call.clone(),
+            ),
+            call.into()
+        );
+    }
+
+    #[test]
+    fn do_not_inline_large_function() {
+        let call = Call::new(function_type(), Variable::new("g"), vec![42.0.into()]);
+
+        assert_eq!(
+            transform_body(
+                vec![FunctionDefinition::new(
+                    "g",
+                    vec![Argument::new("x", Type::Number)],
+                    Type::Number,
+                    (0..MAXIMUM_FUNCTION_SIZE).fold(
+                        Expression::from(Variable::new("x")),
+                        |expression, _| {
+                            ArithmeticOperation::new(ArithmeticOperator::Add, expression, 1.0)
+                                .into()
+                        }
+                    ),
+                )],
+                call.clone(),
+            ),
+            call.into()
+        );
+    }
+}
@@ -0,0 +1,562 @@
+use crate::{
+    ir::*,
+    types::{self, Type},
+};
+use fnv::FnvHashMap;
+use std::cell::{Cell, RefCell};
+
+const NAME_SEPARATOR: &str = ":specialized:";
+
+type Signature = Vec<Option<Type>>;
+
+struct Context<'a> {
+    functions: FnvHashMap<&'a str, &'a FunctionDefinition>,
+    specializations: RefCell<FnvHashMap<(&'a str, Signature), String>>,
+    pending_specializations: RefCell<Vec<(&'a FunctionDefinition, Signature, String)>>,
+    specialization_count: Cell<usize>,
+}
+
+// Local variables which shadow global functions or are substituted with
+// expressions.
+type LocalVariables<'a> = hamt::Map<&'a str, Option<Expression>>;
+
+// Clone private functions for calls with variant arguments of concrete types so
+// that the functions take their payloads directly without boxing. Functions in
+// other modules are specialized after their definitions are imported as
+// private functions.
+pub fn transform(module: &Module) -> Module {
+    let context = Context {
+        functions: module
+            .function_definitions()
+            .iter()
+            .filter(|definition| !definition.is_public())
+            .map(|definition| definition.definition())
+            .filter(|definition| is_specializable(definition))
+            .map(|definition| (definition.name(), definition))
+            .collect(),
+        specializations: Default::default(),
+        pending_specializations: Default::default(),
+        specialization_count: Cell::new(0),
+    };
+
+    let mut definitions = module
+        .function_definitions()
+        .iter()
+        .map(|definition| {
+            GlobalFunctionDefinition::new(
+                transform_function_definition(
+                    &context,
+                    definition.definition(),
+                    &Default::default(),
+                ),
+                definition.is_public(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    loop {
+        let specialization = context.pending_specializations.borrow_mut().pop();
+        let Some((definition, signature, name)) = specialization else {
+            break;
+        };
+
+        definitions.push(GlobalFunctionDefinition::new(
+            specialize_function_definition(&context, definition, &signature, name),
+            false,
+        ));
+    }
+
+    Module::new(
+        module.type_definitions().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.foreign_definitions().to_vec(),
+        module.function_declarations().to_vec(),
+        definitions,
+        module.type_information().clone(),
+    )
+}
+
+pub fn is_specializable(definition: &FunctionDefinition) -> bool {
+    !definition.is_thunk()
+        && definition.environment().is_empty()
+        && definition
+            .arguments()
+            .iter()
+            .any(|argument| argument.type_() == &Type::Variant)
+}
+
+fn specialize_function_definition<'a>(
+    context: &Context<'a>,
+    definition: &'a FunctionDefinition,
+    signature: &[Option<Type>],
+    name: String,
+) -> FunctionDefinition {
+    let mut variables = LocalVariables::default();
+
+    for (argument, type_) in definition.arguments().iter().zip(signature) {
+        variables = variables.insert(
+            argument.name(),
+            type_
+                .as_ref()
+                .map(|type_| Variant::new(type_.clone(), Variable::new(argument.name())).into()),
+        );
+    }
+
+    FunctionDefinition::new(
+        name,
+        definition
+            .arguments()
+            .iter()
+            .zip(signature)
+            .map(|(argument, type_)| {
+                Argument::new(
+                    argument.name(),
+                    type_.clone().unwrap_or_else(|| argument.type_().clone()),
+                )
+            })
+            .collect(),
+        definition.result_type().clone(),
+        transform_expression(context, definition.body(), &variables),
+    )
+    .set_position(definition.position().cloned())
+}
+
+fn transform_function_definition<'a>(
+    context: &Context<'a>,
+    definition: &'a FunctionDefinition,
+    variables: &LocalVariables<'a>,
+) -> FunctionDefinition {
+    FunctionDefinition::with_options(
+        definition.name(),
+        definition.environment().to_vec(),
+        definition.arguments().to_vec(),
+        definition.result_type().clone(),
+        {
+            let mut variables = variables.insert(definition.name(), None);
+
+            for argument in definition
+                .environment()
+                .iter()
+                .chain(definition.arguments())
+            {
+                variables = variables.insert(argument.name(), None);
+            }
+
+            transform_expression(context, definition.body(), &variables)
+        },
+        definition.is_thunk(),
+        definition.position().cloned(),
+    )
+}
+
+fn transform_expression<'a>(
+    context: &Context<'a>,
+    expression: &'a Expression,
+    variables: &LocalVariables<'a>,
+) -> Expression {
+    let transform = |expression| transform_expression(context, expression, variables);
+
+    match expression {
+        Expression::ArithmeticOperation(operation) => ArithmeticOperation::new(
+            operation.operator(),
+            transform(operation.lhs()),
+            transform(operation.rhs()),
+        )
+        .into(),
+        Expression::Case(case) => Case::new(
+            transform(case.argument()),
+            case.alternatives()
+                .iter()
+                .map(|alternative| {
+                    Alternative::new(
+                        alternative.types().to_vec(),
+                        alternative.name(),
+                        transform_expression(
+                            context,
+                            alternative.expression(),
+                            &variables.insert(alternative.name(), None),
+                        ),
+                    )
+                })
+                .collect(),
+            case.default_alternative().map(|alternative| {
+                DefaultAlternative::new(
+                    alternative.name(),
+                    transform_expression(
+                        context,
+                        alternative.expression(),
+                        &variables.insert(alternative.name(), None),
+                    ),
+                )
+            }),
+        )
+        .into(),
+        Expression::CloneVariables(clone) => {
+            CloneVariables::new(clone.variables().clone(), transform(clone.expression())).into()
+        }
+        Expression::ComparisonOperation(operation) => ComparisonOperation::new(
+            operation.operator(),
+            transform(operation.lhs()),
+            transform(operation.rhs()),
+        )
+        .into(),
+        Expression::DropVariables(drop) => {
+            DropVariables::new(drop.variables().clone(), transform(drop.expression())).into()
+        }
+        Expression::Call(call) => {
+            let arguments = call.arguments().iter().map(transform).collect::<Vec<_>>();
+
+            if let Expression::Variable(variable) = call.function() {
+                if let Some(definition) = context.functions.get(variable.name()) {
+                    if variables.get(variable.name()).is_none() {
+                        if let Some(call) = specialize_call(context, definition, call, &arguments) {
+                            return call.into();
+                        }
+                    }
+                }
+            }
+
+            Call::with_position(
+                call.type_().clone(),
+                transform(call.function()),
+                arguments,
+                call.position().cloned(),
+            )
+            .into()
+        }
+        Expression::If(if_) => If::new(
+            transform(if_.condition()),
+            transform(if_.then()),
+            transform(if_.else_()),
+        )
+        .into(),
+        Expression::Let(let_) => Let::new(
+            let_.name(),
+            let_.type_().clone(),
+            transform(let_.bound_expression()),
+            transform_expression(
+                context,
+                let_.expression(),
+                &variables.insert(let_.name(), None),
+            ),
+        )
+        .into(),
+        Expression::LetRecursive(let_) => LetRecursive::new(
+            transform_function_definition(context, let_.definition(), variables),
+            transform_expression(
+                context,
+                let_.expression(),
+                &variables.insert(let_.definition().name(), None),
+            ),
+        )
+        .into(),
+        Expression::Synchronize(synchronize) => Synchronize::new(
+            synchronize.type_().clone(),
+            transform(synchronize.expression()),
+        )
+        .into(),
+        Expression::Record(record) => Record::new(
+            record.type_().clone(),
+            record.fields().iter().map(transform).collect(),
+        )
+        .into(),
+        Expression::ReuseRecord(reuse) => ReuseRecord::new(
+            reuse.variable(),
+            Record::new(
+                reuse.record().type_().clone(),
+                reuse.record().fields().iter().map(transform).collect(),
+            ),
+        )
+        .into(),
+        Expression::RecordField(field) => RecordField::new(
+            field.type_().clone(),
+            field.index(),
+            transform(field.record()),
+        )
+        .into(),
+        Expression::RecordUpdate(update) => RecordUpdate::new(
+            update.type_().clone(),
+            transform(update.record()),
+            update
+                .fields()
+                .iter()
+                .map(|field| RecordUpdateField::new(field.index(), transform(field.expression())))
+                .collect(),
+        )
+        .into(),
+        Expression::StringConcatenation(concatenation) => {
+            StringConcatenation::new(concatenation.operands().iter().map(transform).collect())
+                .into()
+        }
+        Expression::TryOperation(operation) => TryOperation::new(
+            transform(operation.operand()),
+            operation.name(),
+            operation.type_().clone(),
+            transform_expression(
+                context,
+                operation.then(),
+                &variables.insert(operation.name(), None),
+            ),
+        )
+        .into(),
+        Expression::TypeInformationFunction(information) => {
+            TypeInformationFunction::new(transform(information.variant())).into()
+        }
+        Expression::Variant(variant) => {
+            Variant::new(variant.type_().clone(), transform(variant.payload())).into()
+        }
+        Expression::Variable(variable) => variables
+            .get(variable.name())
+            .cloned()
+            .flatten()
+            .unwrap_or_else(|| variable.clone().into()),
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_) => expression.clone(),
+    }
+}
+
+fn specialize_call<'a>(
+    context: &Context<'a>,
+    definition: &'a FunctionDefinition,
+    call: &Call,
+    arguments: &[Expression],
+) -> Option<Call> {
+    let signature = definition
+        .arguments()
+        .iter()
+        .zip(arguments)
+        .map(|(argument, expression)| match expression {
+            Expression::Variant(variant) if argument.type_() == &Type::Variant => {
+                Some(variant.type_().clone())
+            }
+            _ => None,
+        })
+        .collect::<Signature>();
+
+    if signature.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let name = context
+        .specializations
+        .borrow_mut()
+        .entry((definition.name(), signature.clone()))
+        .or_insert_with(|| {
+            let count = context.specialization_count.get();
+
+            context.specialization_count.set(count + 1);
+
+            let name = format!("{}{NAME_SEPARATOR}{count}", definition.name());
+
+            context.pending_specializations.borrow_mut().push((
+                definition,
+                signature.clone(),
+                name.clone(),
+            ));
+
+            name
+        })
+        .clone();
+
+    Some(Call::with_position(
+        types::Function::new(
+            call.type_()
+                .arguments()
+                .iter()
+                .zip(&signature)
+                .map(|(argument, type_)| type_.clone().unwrap_or_else(|| argument.clone()))
+                .collect(),
+            call.type_().result().clone(),
+        ),
+        Variable::new(name),
+        arguments
+            .iter()
+            .zip(&signature)
+            .map(|(expression, type_)| match (expression, type_) {
+                (Expression::Variant(variant), Some(_)) => variant.payload().clone(),
+                _ => expression.clone(),
+            })
+            .collect(),
+        call.position().cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleFake;
+    use pretty_assertions::assert_eq;
+
+    fn any_function_type() -> types::Function {
+        types::Function::new(vec![Type::Variant], Type::Number)
+    }
+
+    fn caller_definition(body: impl Into<Expression>) -> GlobalFunctionDefinition {
+        GlobalFunctionDefinition::new(
+            FunctionDefinition::new("f", vec![], Type::Number, body),
+            true,
+        )
+    }
+
+    fn callee_definition(body: impl Into<Expression>, public: bool) -> GlobalFunctionDefinition {
+        GlobalFunctionDefinition::new(
+            FunctionDefinition::new(
+                "g",
+                vec![Argument::new("x", Type::Variant)],
+                Type::Number,
+                body,
+            ),
+            public,
+        )
+    }
+
+    fn call_with_number(function: &str) -> Call {
+        Call::new(
+            any_function_type(),
+            Variable::new(function),
+            vec![Variant::new(Type::Number, 42.0).into()],
+        )
+    }
+
+    #[test]
+    fn specialize_function() {
+        let callee = callee_definition(
+            Case::new(
+                Variable::new("x"),
+                vec![Alternative::new(
+                    vec![Type::Number],
+                    "y",
+                    Variable::new("y"),
+                )],
+                None,
+            ),
+            false,
+        );
+
+        assert_eq!(
+            transform(&Module::empty().set_global_function_definitions(vec![
+                caller_definition(call_with_number("g")),
+                callee.clone(),
+            ])),
+            Module::empty().set_global_function_definitions(vec![
+                caller_definition(Call::new(
+                    types::Function::new(vec![Type::Number], Type::Number),
+                    Variable::new("g:specialized:0"),
+                    vec![42.0.into()],
+                )),
+                callee,
+                GlobalFunctionDefinition::new(
+                    FunctionDefinition::new(
+                        "g:specialized:0",
+                        vec![Argument::new("x", Type::Number)],
+                        Type::Number,
+                        Case::new(
+                            Variant::new(Type::Number, Variable::new("x")),
+                            vec![Alternative::new(
+                                vec![Type::Number],
+                                "y",
+                                Variable::new("y")
+                            )],
+                            None,
+                        ),
+                    ),
+                    false,
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn specialize_recursive_function_once() {
+        let module = transform(&Module::empty().set_global_function_definitions(vec![
+            caller_definition(call_with_number("g")),
+            callee_definition(
+                Call::new(
+                    any_function_type(),
+                    Variable::new("g"),
+                    vec![Variable::new("x").into()],
+                ),
+                false,
+            ),
+        ]));
+
+        assert_eq!(module.function_definitions().len(), 3);
+        assert_eq!(
+            module.function_definitions()[2].definition().body(),
+            &Call::new(
+                types::Function::new(vec![Type::Number], Type::Number),
+                Variable::new("g:specialized:0"),
+                vec![Variable::new("x").into()],
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn do_not_specialize_public_function() {
+        let module = Module::empty().set_global_function_definitions(vec![
+            caller_definition(call_with_number("g")),
+            callee_definition(42.0, true),
+        ]);
+
+        assert_eq!(transform(&module), module);
+    }
+
+    #[test]
+    fn do_not_specialize_call_with_variant_variable() {
+        let module = Module::empty().set_global_function_definitions(vec![
+            caller_definition(Call::new(
+                any_function_type(),
+                Variable::new("g"),
+                vec![Variable::new("y").into()],
+            )),
+            callee_definition(42.0, false),
+        ]);
+
+        assert_eq!(transform(&module), module);
+    }
+
+    #[test]
+    fn do_not_specialize_shadowed_function() {
+        let module = Module::empty().set_global_function_definitions(vec![
+            caller_definition(Let::new(
+                "g",
+                any_function_type(),
+                Variable::new("h"),
+                call_with_number("g"),
+            )),
+            callee_definition(42.0, false),
+        ]);
+
+        assert_eq!(transform(&module), module);
+    }
+
+    #[test]
+    fn do_not_substitute_shadowed_argument() {
+        let module = transform(&Module::empty().set_global_function_definitions(vec![
+            caller_definition(call_with_number("g")),
+            callee_definition(
+                Let::new(
+                    "x",
+                    Type::Variant,
+                    Variable::new("z"),
+                    TypeInformationFunction::new(Variable::new("x")),
+                ),
+                false,
+            ),
+        ]));
+
+        assert_eq!(
+            module.function_definitions()[2].definition().body(),
+            &Let::new(
+                "x",
+                Type::Variant,
+                Variable::new("z"),
+                TypeInformationFunction::new(Variable::new("x")),
+            )
+            .into()
+        );
+    }
+}
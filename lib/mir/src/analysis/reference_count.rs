@@ -1,4 +1,5 @@
 mod error;
+mod reuse;
 mod transformation;
 mod validation;
 
@@ -7,7 +8,7 @@ pub use error::ReferenceCountError;
 use validation::validate;
 
 pub fn transform(module: &Module) -> Result<Module, ReferenceCountError> {
-    let module = transformation::transform(module)?;
+    let module = reuse::transform(&transformation::transform(module)?);
 
     validate(&module)?;
 
@@ -0,0 +1,396 @@
+use crate::{
+    analysis::expression_conversion,
+    ir::*,
+    types::{self, Type},
+};
+
+// Reuse memory blocks of dropped records for constructions of records of the
+// same types.
+//
+// Drops of records are delayed until the first record constructions of the
+// same types on their evaluation paths. Then, backends can reuse the memory
+// blocks if the dropped records are uniquely referenced.
+//
+// Record updates are not transformed here as backends update uniquely
+// referenced records in place already.
+//
+// Records moved into record field operations at their last uses are freed
+// there and never reused. For example, map headers updated by the `SetMap`
+// function in a prelude module are not reused.
+//
+// Lists are not reused specially. Their nodes are records of types in a
+// prelude module and constructed by its functions. So their memory blocks are
+// reused only when drops and constructions of them meet in the same functions
+// after inlining.
+pub fn transform(module: &Module) -> Module {
+    expression_conversion::transform(module, |expression| match expression {
+        Expression::DropVariables(drop) => transform_drop_variables(drop),
+        _ => expression.clone(),
+    })
+}
+
+fn transform_drop_variables(drop: &DropVariables) -> Expression {
+    let mut variables = drop.variables().clone();
+    let mut expression = drop.expression().clone();
+    let mut records = drop
+        .variables()
+        .iter()
+        .filter_map(|(name, type_)| match type_ {
+            Type::Record(record_type) => Some((name, record_type)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    records.sort_by_key(|(name, _)| *name);
+
+    for (name, record_type) in records {
+        if let Some(reused) = reuse(name, record_type, &expression) {
+            expression = reused;
+            variables.remove(name);
+        }
+    }
+
+    if variables.is_empty() {
+        expression
+    } else {
+        DropVariables::new(variables, expression).into()
+    }
+}
+
+fn reuse(name: &str, type_: &types::Record, expression: &Expression) -> Option<Expression> {
+    match expression {
+        Expression::Record(record) if record.type_() == type_ => {
+            Some(ReuseRecord::new(name, record.clone()).into())
+        }
+        Expression::Case(case) => {
+            let mut reused = false;
+            let alternatives = case
+                .alternatives()
+                .iter()
+                .map(|alternative| {
+                    Alternative::new(
+                        alternative.types().to_vec(),
+                        alternative.name(),
+                        reuse_branch(name, type_, alternative.expression(), &mut reused),
+                    )
+                })
+                .collect();
+            let default_alternative = case.default_alternative().map(|alternative| {
+                DefaultAlternative::new(
+                    alternative.name(),
+                    reuse_branch(name, type_, alternative.expression(), &mut reused),
+                )
+            });
+
+            reused.then(|| {
+                Case::new(case.argument().clone(), alternatives, default_alternative).into()
+            })
+        }
+        Expression::CloneVariables(clone) => Some(
+            CloneVariables::new(
+                clone.variables().clone(),
+                reuse(name, type_, clone.expression())?,
+            )
+            .into(),
+        ),
+        Expression::DropVariables(drop) => Some(
+            DropVariables::new(
+                drop.variables().clone(),
+                reuse(name, type_, drop.expression())?,
+            )
+            .into(),
+        ),
+        Expression::If(if_) => {
+            let mut reused = false;
+            let then = reuse_branch(name, type_, if_.then(), &mut reused);
+            let else_ = reuse_branch(name, type_, if_.else_(), &mut reused);
+
+            reused.then(|| If::new(if_.condition().clone(), then, else_).into())
+        }
+        Expression::Let(let_) => Some(
+            if let Some(bound_expression) = reuse(name, type_, let_.bound_expression()) {
+                Let::new(
+                    let_.name(),
+                    let_.type_().clone(),
+                    bound_expression,
+                    let_.expression().clone(),
+                )
+            } else {
+                Let::new(
+                    let_.name(),
+                    let_.type_().clone(),
+                    let_.bound_expression().clone(),
+                    reuse(name, type_, let_.expression())?,
+                )
+            }
+            .into(),
+        ),
+        Expression::LetRecursive(let_) => Some(
+            LetRecursive::new(
+                let_.definition().clone(),
+                reuse(name, type_, let_.expression())?,
+            )
+            .into(),
+        ),
+        _ => None,
+    }
+}
+
+// Branches where records are not reused drop them at their beginnings.
+fn reuse_branch(
+    name: &str,
+    type_: &types::Record,
+    expression: &Expression,
+    reused: &mut bool,
+) -> Expression {
+    if let Some(expression) = reuse(name, type_, expression) {
+        *reused = true;
+        expression
+    } else {
+        DropVariables::new(
+            [(name.into(), type_.clone().into())].into_iter().collect(),
+            expression.clone(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleFake;
+    use fnv::FnvHashMap;
+    use pretty_assertions::assert_eq;
+
+    fn transform_body(body: impl Into<Expression>) -> Expression {
+        transform(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec![],
+                Type::Number,
+                body,
+            )]),
+        )
+        .function_definitions()[0]
+            .definition()
+            .body()
+            .clone()
+    }
+
+    fn drop_variable(name: &str, type_: impl Into<Type>) -> FnvHashMap<String, Type> {
+        [(name.into(), type_.into())].into_iter().collect()
+    }
+
+    #[test]
+    fn reuse_record() {
+        let record_type = types::Record::new("a");
+        let record = Record::new(record_type.clone(), vec![Expression::Number(42.0)]);
+
+        assert_eq!(
+            transform_body(DropVariables::new(
+                drop_variable("x", record_type),
+                record.clone()
+            )),
+            ReuseRecord::new("x", record).into()
+        );
+    }
+
+    #[test]
+    fn do_not_reuse_record_of_different_type() {
+        let expression = DropVariables::new(
+            drop_variable("x", types::Record::new("a")),
+            Record::new(types::Record::new("b"), vec![]),
+        );
+
+        assert_eq!(transform_body(expression.clone()), expression.into());
+    }
+
+    #[test]
+    fn do_not_reuse_non_record() {
+        let expression = DropVariables::new(
+            drop_variable("x", Type::ByteString),
+            Record::new(types::Record::new("a"), vec![]),
+        );
+
+        assert_eq!(transform_body(expression.clone()), expression.into());
+    }
+
+    #[test]
+    fn reuse_record_in_let() {
+        let record_type = types::Record::new("a");
+        let record = Record::new(record_type.clone(), vec![Variable::new("y").into()]);
+
+        assert_eq!(
+            transform_body(DropVariables::new(
+                drop_variable("x", record_type.clone()),
+                Let::new("y", Type::Number, 42.0, record.clone()),
+            )),
+            Let::new("y", Type::Number, 42.0, ReuseRecord::new("x", record)).into()
+        );
+    }
+
+    #[test]
+    fn reuse_record_in_bound_expression_of_let() {
+        let record_type = types::Record::new("a");
+        let record = Record::new(record_type.clone(), vec![]);
+
+        assert_eq!(
+            transform_body(DropVariables::new(
+                drop_variable("x", record_type.clone()),
+                Let::new("y", record_type.clone(), record.clone(), Variable::new("y")),
+            )),
+            Let::new(
+                "y",
+                record_type,
+                ReuseRecord::new("x", record),
+                Variable::new("y")
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn reuse_record_in_if() {
+        let record_type = types::Record::new("a");
+        let record = Record::new(record_type.clone(), vec![]);
+
+        assert_eq!(
+            transform_body(DropVariables::new(
+                drop_variable("x", record_type.clone()),
+                If::new(true, record.clone(), Variable::new("y")),
+            )),
+            If::new(
+                true,
+                ReuseRecord::new("x", record),
+                DropVariables::new(drop_variable("x", record_type), Variable::new("y")),
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn reuse_record_in_case() {
+        let record_type = types::Record::new("a");
+        let record = Record::new(record_type.clone(), vec![]);
+
+        assert_eq!(
+            transform_body(DropVariables::new(
+                drop_variable("x", record_type.clone()),
+                Case::new(
+                    Variable::new("z"),
+                    vec![Alternative::new(vec![Type::Number], "y", record.clone())],
+                    Some(DefaultAlternative::new("y", Variable::new("y"))),
+                ),
+            )),
+            Case::new(
+                Variable::new("z"),
+                vec![Alternative::new(
+                    vec![Type::Number],
+                    "y",
+                    ReuseRecord::new("x", record)
+                )],
+                Some(DefaultAlternative::new(
+                    "y",
+                    DropVariables::new(drop_variable("x", record_type), Variable::new("y"))
+                )),
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn do_not_reuse_record_in_function_definition() {
+        let record_type = types::Record::new("a");
+        let expression = DropVariables::new(
+            drop_variable("x", record_type.clone()),
+            LetRecursive::new(
+                FunctionDefinition::new(
+                    "g",
+                    vec![],
+                    record_type.clone(),
+                    Record::new(record_type, vec![]),
+                ),
+                Variable::new("g"),
+            ),
+        );
+
+        assert_eq!(transform_body(expression.clone()), expression.into());
+    }
+
+    #[test]
+    fn keep_other_dropped_variables() {
+        let record_type = types::Record::new("a");
+        let record = Record::new(record_type.clone(), vec![]);
+
+        assert_eq!(
+            transform_body(DropVariables::new(
+                [
+                    ("x".into(), record_type.into()),
+                    ("y".into(), Type::ByteString),
+                ]
+                .into_iter()
+                .collect(),
+                record.clone(),
+            )),
+            DropVariables::new(
+                drop_variable("y", Type::ByteString),
+                ReuseRecord::new("x", record)
+            )
+            .into()
+        );
+    }
+
+    // This is the same as the update of a map header in the `SetMap` function
+    // of the prelude package.
+    #[test]
+    fn do_not_reuse_record_moved_into_record_field() {
+        let map_type = types::Record::new("map");
+        let function_type = types::Function::new(vec![Type::Variant], Type::Variant);
+        let call = Call::new(
+            function_type.clone(),
+            Variable::new("g"),
+            vec![RecordField::new(map_type.clone(), 0, Variable::new("x")).into()],
+        );
+        let record = Record::new(
+            map_type.clone(),
+            vec![Variable::new("h").into(), Variable::new("s").into()],
+        );
+
+        assert_eq!(
+            crate::analysis::reference_count::transform(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::new(
+                        "map",
+                        types::RecordBody::new(vec![Type::Variant, Type::Number]),
+                    )])
+                    .set_function_declarations(vec![FunctionDeclaration::new("g", function_type)])
+                    .set_function_definitions(vec![FunctionDefinition::new(
+                        "f",
+                        vec![Argument::new("x", map_type.clone())],
+                        map_type.clone(),
+                        Let::new(
+                            "s",
+                            Type::Number,
+                            RecordField::new(map_type.clone(), 1, Variable::new("x")),
+                            Let::new("h", Type::Variant, call.clone(), record.clone()),
+                        ),
+                    )]),
+            )
+            .unwrap()
+            .function_definitions()[0]
+                .definition()
+                .body(),
+            &Let::new(
+                "s",
+                Type::Number,
+                RecordField::new(
+                    map_type.clone(),
+                    1,
+                    CloneVariables::new(drop_variable("x", map_type), Variable::new("x")),
+                ),
+                Let::new("h", Type::Variant, call, record),
+            )
+            .into()
+        );
+    }
+}
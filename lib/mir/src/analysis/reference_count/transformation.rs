@@ -62,6 +62,7 @@ fn transform_function_definition(
             &owned_variables,
         ),
         definition.is_thunk(),
+        definition.position().cloned(),
     ))
 }
 
@@ -262,7 +263,13 @@ fn transform_expression(
                 transform_expression(call.function(), owned_variables, &moved_variables)?;
 
             (
-                Call::new(call.type_().clone(), function, arguments).into(),
+                Call::with_position(
+                    call.type_().clone(),
+                    function,
+                    arguments,
+                    call.position().cloned(),
+                )
+                .into(),
                 moved_variables,
             )
         }
@@ -570,7 +577,9 @@ fn transform_expression(
         | Expression::ByteString(_)
         | Expression::None
         | Expression::Number(_) => (expression.clone(), moved_variables.clone()),
-        Expression::CloneVariables(_) | Expression::DropVariables(_) => {
+        Expression::CloneVariables(_)
+        | Expression::DropVariables(_)
+        | Expression::ReuseRecord(_) => {
             return Err(ReferenceCountError::ExpressionNotSupported(
                 expression.clone(),
             ));
@@ -150,6 +150,10 @@ fn move_expression(
         Expression::Record(record) => {
             move_record(record, variables)?;
         }
+        Expression::ReuseRecord(reuse) => {
+            move_record(reuse.record(), variables)?;
+            drop_variable(reuse.variable(), variables);
+        }
         Expression::RecordField(field) => {
             move_expression(field.record(), variables)?;
         }
@@ -496,6 +500,7 @@ mod tests {
                             Type::None,
                             Variable::new("x"),
                             false,
+                            None,
                         ),
                         Variable::new("g"),
                     )
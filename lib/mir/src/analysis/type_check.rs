@@ -215,6 +215,14 @@ fn check_expression(
         Expression::None => Type::None,
         Expression::Number(_) => Type::Number,
         Expression::Record(record) => check_record(context, record, variables, result_type, types)?,
+        Expression::ReuseRecord(reuse) => {
+            check_equality(
+                &check_variable(reuse.variable(), variables)?,
+                &reuse.record().type_().clone().into(),
+            )?;
+
+            check_record(context, reuse.record(), variables, result_type, types)?
+        }
         Expression::RecordField(field) => {
             check_equality(
                 &check_expression(field.record(), variables)?,
@@ -0,0 +1,492 @@
+use crate::{ir::*, types::Type};
+use fnv::{FnvHashMap, FnvHashSet};
+
+// Find variables of records allocated in each global function which never
+// escape from it. Their memory blocks are never synchronized and shared with
+// other threads.
+//
+// Records escape if they are used anywhere other than record field operations,
+// reference count operations, or arguments of global functions in the same
+// module which do not let them escape either. Any other functions, such as
+// foreign functions, function declarations, and closures, can synchronize
+// records or pass them to other threads (e.g. by spawning or racing), so
+// records passed to them escape.
+pub fn find_unsynchronized_variables(module: &Module) -> FnvHashMap<String, FnvHashSet<String>> {
+    let definitions = module
+        .function_definitions()
+        .iter()
+        .map(GlobalFunctionDefinition::definition)
+        .collect::<Vec<_>>();
+    let mut arguments = definitions
+        .iter()
+        .map(|definition| {
+            (
+                definition.name(),
+                (0..definition.arguments().len()).collect::<FnvHashSet<_>>(),
+            )
+        })
+        .collect::<FnvHashMap<_, _>>();
+
+    // Non-escaping arguments are computed as a greatest fixed point so that
+    // recursive functions can keep their arguments.
+    loop {
+        let mut changed = false;
+
+        for definition in &definitions {
+            let context = find_in_global_definition(definition, &arguments);
+            let escaped = definition
+                .arguments()
+                .iter()
+                .enumerate()
+                .filter(|(_, argument)| !context.is_local(argument.name()))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+
+            if let Some(indices) = arguments.get_mut(definition.name()) {
+                for index in escaped {
+                    changed |= indices.remove(&index);
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    definitions
+        .iter()
+        .map(|definition| {
+            let context = find_in_global_definition(definition, &arguments);
+
+            (
+                definition.name().into(),
+                context
+                    .records
+                    .iter()
+                    .filter(|name| context.is_local(name))
+                    .map(|name| name.to_string())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+struct Context<'a, 'b> {
+    arguments: &'b FnvHashMap<&'a str, FnvHashSet<usize>>,
+    binding_counts: FnvHashMap<&'a str, usize>,
+    records: FnvHashSet<&'a str>,
+    escaped: FnvHashSet<&'a str>,
+    calls: Vec<(&'a str, usize, &'a str)>,
+}
+
+impl<'a, 'b> Context<'a, 'b> {
+    fn new(arguments: &'b FnvHashMap<&'a str, FnvHashSet<usize>>) -> Self {
+        Self {
+            arguments,
+            binding_counts: Default::default(),
+            records: Default::default(),
+            escaped: Default::default(),
+            calls: Default::default(),
+        }
+    }
+
+    fn bind(&mut self, name: &'a str) {
+        *self.binding_counts.entry(name).or_default() += 1;
+    }
+
+    fn is_local(&self, name: &str) -> bool {
+        self.binding_counts.get(name) == Some(&1) && !self.escaped.contains(name)
+    }
+}
+
+fn find_in_global_definition<'a, 'b>(
+    definition: &'a FunctionDefinition,
+    arguments: &'b FnvHashMap<&'a str, FnvHashSet<usize>>,
+) -> Context<'a, 'b> {
+    let mut context = Context::new(arguments);
+
+    for argument in definition.arguments() {
+        context.bind(argument.name());
+    }
+
+    find_in_expression(definition.body(), &mut context);
+
+    // Global functions can be shadowed by local variables.
+    for (function, index, argument) in std::mem::take(&mut context.calls) {
+        if context.binding_counts.contains_key(function)
+            || !context
+                .arguments
+                .get(function)
+                .is_some_and(|indices| indices.contains(&index))
+        {
+            context.escaped.insert(argument);
+        }
+    }
+
+    context
+}
+
+fn find_in_definition<'a>(definition: &'a FunctionDefinition, context: &mut Context<'a, '_>) {
+    context.bind(definition.name());
+
+    for argument in definition
+        .environment()
+        .iter()
+        .chain(definition.arguments())
+    {
+        context.bind(argument.name());
+    }
+
+    find_in_expression(definition.body(), context);
+}
+
+fn find_in_expression<'a>(expression: &'a Expression, context: &mut Context<'a, '_>) {
+    match expression {
+        Expression::ArithmeticOperation(operation) => {
+            find_in_expression(operation.lhs(), context);
+            find_in_expression(operation.rhs(), context);
+        }
+        Expression::Case(case) => {
+            find_in_expression(case.argument(), context);
+
+            for alternative in case.alternatives() {
+                context.bind(alternative.name());
+                find_in_expression(alternative.expression(), context);
+            }
+
+            if let Some(alternative) = case.default_alternative() {
+                context.bind(alternative.name());
+                find_in_expression(alternative.expression(), context);
+            }
+        }
+        Expression::CloneVariables(clone) => find_in_expression(clone.expression(), context),
+        Expression::ComparisonOperation(operation) => {
+            find_in_expression(operation.lhs(), context);
+            find_in_expression(operation.rhs(), context);
+        }
+        Expression::DropVariables(drop) => find_in_expression(drop.expression(), context),
+        Expression::Call(call) => {
+            find_in_expression(call.function(), context);
+
+            for (index, argument) in call.arguments().iter().enumerate() {
+                if let (Expression::Variable(function), Expression::Variable(argument)) =
+                    (call.function(), argument)
+                {
+                    context
+                        .calls
+                        .push((function.name(), index, argument.name()));
+                } else {
+                    find_in_expression(argument, context);
+                }
+            }
+        }
+        Expression::If(if_) => {
+            find_in_expression(if_.condition(), context);
+            find_in_expression(if_.then(), context);
+            find_in_expression(if_.else_(), context);
+        }
+        Expression::Let(let_) => {
+            context.bind(let_.name());
+
+            if matches!(
+                (let_.type_(), let_.bound_expression()),
+                (Type::Record(_), Expression::Record(_))
+            ) {
+                context.records.insert(let_.name());
+            }
+
+            find_in_expression(let_.bound_expression(), context);
+            find_in_expression(let_.expression(), context);
+        }
+        Expression::LetRecursive(let_) => {
+            find_in_definition(let_.definition(), context);
+            find_in_expression(let_.expression(), context);
+        }
+        Expression::Synchronize(synchronize) => {
+            find_in_expression(synchronize.expression(), context)
+        }
+        Expression::Record(record) => find_in_record(record, context),
+        Expression::ReuseRecord(reuse) => find_in_record(reuse.record(), context),
+        Expression::RecordField(field) => {
+            if !matches!(field.record(), Expression::Variable(_)) {
+                find_in_expression(field.record(), context);
+            }
+        }
+        Expression::RecordUpdate(update) => {
+            find_in_expression(update.record(), context);
+
+            for field in update.fields() {
+                find_in_expression(field.expression(), context);
+            }
+        }
+        Expression::StringConcatenation(concatenation) => {
+            for operand in concatenation.operands() {
+                find_in_expression(operand, context);
+            }
+        }
+        Expression::TryOperation(operation) => {
+            find_in_expression(operation.operand(), context);
+            context.bind(operation.name());
+            find_in_expression(operation.then(), context);
+        }
+        Expression::TypeInformationFunction(information) => {
+            find_in_expression(information.variant(), context)
+        }
+        Expression::Variant(variant) => find_in_expression(variant.payload(), context),
+        Expression::Variable(variable) => {
+            context.escaped.insert(variable.name());
+        }
+        Expression::Boolean(_)
+        | Expression::ByteString(_)
+        | Expression::None
+        | Expression::Number(_) => {}
+    }
+}
+
+fn find_in_record<'a>(record: &'a Record, context: &mut Context<'a, '_>) {
+    for field in record.fields() {
+        find_in_expression(field, context);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::ModuleFake, types};
+
+    fn find(body: impl Into<Expression>) -> FnvHashSet<String> {
+        find_with_definitions(body, vec![])
+    }
+
+    fn find_with_definitions(
+        body: impl Into<Expression>,
+        definitions: Vec<FunctionDefinition>,
+    ) -> FnvHashSet<String> {
+        find_unsynchronized_variables(
+            &Module::empty().set_function_definitions(
+                [FunctionDefinition::new("f", vec![], Type::Number, body)]
+                    .into_iter()
+                    .chain(definitions)
+                    .collect(),
+            ),
+        )["f"]
+            .clone()
+    }
+
+    fn call_g(argument: impl Into<Expression>) -> Call {
+        Call::new(
+            types::Function::new(vec![record_type().into()], Type::Number),
+            Variable::new("g"),
+            vec![argument.into()],
+        )
+    }
+
+    fn g(body: impl Into<Expression>) -> FunctionDefinition {
+        FunctionDefinition::new(
+            "g",
+            vec![Argument::new("y", record_type())],
+            Type::Number,
+            body,
+        )
+    }
+
+    fn record_type() -> types::Record {
+        types::Record::new("a")
+    }
+
+    fn local_record(expression: impl Into<Expression>) -> Let {
+        Let::new(
+            "x",
+            record_type(),
+            Record::new(record_type(), vec![42.0.into()]),
+            expression,
+        )
+    }
+
+    #[test]
+    fn find_record_used_by_record_field() {
+        assert_eq!(
+            find(local_record(RecordField::new(
+                record_type(),
+                0,
+                Variable::new("x")
+            ))),
+            ["x".into()].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn find_record_dropped() {
+        assert_eq!(
+            find(local_record(DropVariables::new(
+                [("x".into(), record_type().into())].into_iter().collect(),
+                42.0,
+            ))),
+            ["x".into()].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn do_not_find_returned_record() {
+        assert_eq!(find(local_record(Variable::new("x"))), Default::default());
+    }
+
+    #[test]
+    fn do_not_find_record_passed_to_call() {
+        assert_eq!(
+            find(local_record(Call::new(
+                types::Function::new(vec![record_type().into()], Type::Number),
+                Variable::new("g"),
+                vec![Variable::new("x").into()],
+            ))),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn find_record_passed_to_global_function() {
+        assert_eq!(
+            find_with_definitions(
+                local_record(call_g(Variable::new("x"))),
+                vec![g(RecordField::new(record_type(), 0, Variable::new("y")))],
+            ),
+            ["x".into()].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn find_record_passed_to_recursive_global_function() {
+        assert_eq!(
+            find_with_definitions(
+                local_record(call_g(Variable::new("x"))),
+                vec![g(If::new(
+                    ComparisonOperation::new(
+                        ComparisonOperator::Equal,
+                        RecordField::new(record_type(), 0, Variable::new("y")),
+                        0.0,
+                    ),
+                    0.0,
+                    call_g(Variable::new("y")),
+                ))],
+            ),
+            ["x".into()].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn do_not_find_record_passed_to_global_function_returning_it() {
+        assert_eq!(
+            find_with_definitions(
+                local_record(call_g(Variable::new("x"))),
+                vec![FunctionDefinition::new(
+                    "g",
+                    vec![Argument::new("y", record_type())],
+                    record_type(),
+                    Variable::new("y"),
+                )],
+            ),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn do_not_find_record_passed_to_global_function_synchronizing_it() {
+        assert_eq!(
+            find_with_definitions(
+                local_record(call_g(Variable::new("x"))),
+                vec![g(RecordField::new(
+                    record_type(),
+                    0,
+                    Synchronize::new(record_type(), Variable::new("y")),
+                ))],
+            ),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn do_not_find_record_passed_to_global_function_passing_it_to_unknown_function() {
+        assert_eq!(
+            find_with_definitions(
+                local_record(call_g(Variable::new("x"))),
+                vec![g(Call::new(
+                    types::Function::new(vec![record_type().into()], Type::Number),
+                    Variable::new("spawn"),
+                    vec![Variable::new("y").into()],
+                ))],
+            ),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn do_not_find_record_passed_to_shadowed_global_function() {
+        assert_eq!(
+            find_with_definitions(
+                local_record(Let::new(
+                    "g",
+                    types::Function::new(vec![record_type().into()], Type::Number),
+                    Variable::new("h"),
+                    call_g(Variable::new("x")),
+                )),
+                vec![g(RecordField::new(record_type(), 0, Variable::new("y")))],
+            ),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn do_not_find_record_stored_in_record() {
+        assert_eq!(
+            find(local_record(Record::new(
+                record_type(),
+                vec![Variable::new("x").into()]
+            ))),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn do_not_find_record_synchronized() {
+        assert_eq!(
+            find(local_record(Synchronize::new(
+                record_type(),
+                Variable::new("x")
+            ))),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn do_not_find_record_captured_by_closure() {
+        assert_eq!(
+            find(local_record(LetRecursive::new(
+                FunctionDefinition::with_options(
+                    "g",
+                    vec![Argument::new("x", record_type())],
+                    vec![],
+                    Type::Number,
+                    RecordField::new(record_type(), 0, Variable::new("x")),
+                    false,
+                    None,
+                ),
+                42.0,
+            ))),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn do_not_find_non_record() {
+        assert_eq!(
+            find(Let::new(
+                "x",
+                Type::Number,
+                42.0,
+                ArithmeticOperation::new(ArithmeticOperator::Add, Variable::new("x"), 1.0),
+            )),
+            Default::default()
+        );
+    }
+}
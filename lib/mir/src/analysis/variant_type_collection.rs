@@ -71,6 +71,11 @@ fn collect_from_expression(expression: &Expression, types: &mut FnvHashSet<Type>
                 collect_from_expression(field, types);
             }
         }
+        Expression::ReuseRecord(reuse) => {
+            for field in reuse.record().fields() {
+                collect_from_expression(field, types);
+            }
+        }
         Expression::RecordField(field) => collect_from_expression(field.record(), types),
         Expression::RecordUpdate(update) => {
             collect_from_expression(update.record(), types);
@@ -25,6 +25,7 @@ mod record;
 mod record_field;
 mod record_update;
 mod record_update_field;
+mod reuse_record;
 mod string_concatenation;
 mod synchronize;
 mod try_operation;
@@ -61,6 +62,7 @@ pub use record::*;
 pub use record_field::*;
 pub use record_update::*;
 pub use record_update_field::*;
+pub use reuse_record::*;
 pub use string_concatenation::*;
 pub use synchronize::*;
 pub use try_operation::*;
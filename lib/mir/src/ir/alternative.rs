@@ -1,7 +1,8 @@
 use super::expression::Expression;
 use crate::types::Type;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Alternative {
     types: Vec<Type>,
     name: String,
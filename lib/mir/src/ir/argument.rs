@@ -1,6 +1,7 @@
 use crate::types::Type;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Argument {
     name: String,
     type_: Type,
@@ -1,10 +1,11 @@
 use super::{arithmetic_operator::ArithmeticOperator, expression::Expression};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ArithmeticOperation(Rc<ArithmeticOperationInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct ArithmeticOperationInner {
     operator: ArithmeticOperator,
     lhs: Expression,
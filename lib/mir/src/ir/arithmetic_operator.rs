@@ -1,7 +1,15 @@
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+use serde::{Deserialize, Serialize};
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum ArithmeticOperator {
     Add,
     Subtract,
     Multiply,
     Divide,
+    IntegerDivide,
+    Remainder,
+    And,
+    Or,
+    Xor,
+    LeftShift,
+    RightShift,
 }
@@ -1,15 +1,18 @@
 use super::expression::Expression;
 use crate::types;
+use position::Position;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Call(Rc<CallInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct CallInner {
     type_: types::Function,
     function: Expression,
     arguments: Vec<Expression>,
+    position: Option<Position>,
 }
 
 impl Call {
@@ -17,12 +20,22 @@ impl Call {
         type_: types::Function,
         function: impl Into<Expression>,
         arguments: Vec<Expression>,
+    ) -> Self {
+        Self::with_position(type_, function, arguments, None)
+    }
+
+    pub fn with_position(
+        type_: types::Function,
+        function: impl Into<Expression>,
+        arguments: Vec<Expression>,
+        position: Option<Position>,
     ) -> Self {
         Self(
             CallInner {
                 type_,
                 function: function.into(),
                 arguments,
+                position,
             }
             .into(),
         )
@@ -39,4 +52,9 @@ impl Call {
     pub fn arguments(&self) -> &[Expression] {
         &self.0.arguments
     }
+
+    // A source position is kept only for debug information.
+    pub fn position(&self) -> Option<&Position> {
+        self.0.position.as_ref()
+    }
 }
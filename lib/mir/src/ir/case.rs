@@ -1,12 +1,13 @@
 use super::{
     alternative::Alternative, default_alternative::DefaultAlternative, expression::Expression,
 };
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Case(Rc<CaseInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct CaseInner {
     argument: Expression,
     alternatives: Vec<Alternative>,
@@ -1,12 +1,13 @@
 use super::expression::Expression;
 use crate::types::Type;
 use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CloneVariables(Rc<CloneVariablesInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct CloneVariablesInner {
     variables: FnvHashMap<String, Type>,
     expression: Expression,
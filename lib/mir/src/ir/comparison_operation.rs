@@ -1,10 +1,11 @@
 use super::{comparison_operator::ComparisonOperator, expression::Expression};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ComparisonOperation(Rc<ComparisonOperationInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct ComparisonOperationInner {
     operator: ComparisonOperator,
     lhs: Expression,
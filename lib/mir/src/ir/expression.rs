@@ -2,12 +2,13 @@ use super::{
     arithmetic_operation::ArithmeticOperation, byte_string::ByteString, call::Call, case::Case,
     clone_variables::CloneVariables, comparison_operation::ComparisonOperation,
     drop_variables::DropVariables, if_::If, let_::Let, let_recursive::LetRecursive, record::Record,
-    record_field::RecordField, try_operation::TryOperation,
+    record_field::RecordField, reuse_record::ReuseRecord, try_operation::TryOperation,
     type_information_function::TypeInformationFunction, variable::Variable, variant::Variant,
     RecordUpdate, StringConcatenation, Synchronize,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Expression {
     ArithmeticOperation(ArithmeticOperation),
     Boolean(bool),
@@ -26,6 +27,7 @@ pub enum Expression {
     Record(Record),
     RecordField(RecordField),
     RecordUpdate(RecordUpdate),
+    ReuseRecord(ReuseRecord),
     StringConcatenation(StringConcatenation),
     TryOperation(TryOperation),
     TypeInformationFunction(TypeInformationFunction),
@@ -129,6 +131,12 @@ impl From<RecordUpdate> for Expression {
     }
 }
 
+impl From<ReuseRecord> for Expression {
+    fn from(reuse: ReuseRecord) -> Self {
+        Self::ReuseRecord(reuse)
+    }
+}
+
 impl From<StringConcatenation> for Expression {
     fn from(concatenation: StringConcatenation) -> Self {
         Self::StringConcatenation(concatenation)
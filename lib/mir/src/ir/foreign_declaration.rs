@@ -1,7 +1,8 @@
 use super::calling_convention::CallingConvention;
 use crate::types;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct ForeignDeclaration {
     name: String,
     foreign_name: String,
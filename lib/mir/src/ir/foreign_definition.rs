@@ -1,6 +1,7 @@
 use super::CallingConvention;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct ForeignDefinition {
     name: String,
     foreign_name: String,
@@ -1,6 +1,7 @@
 use crate::types;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct FunctionDeclaration {
     name: String,
     type_: types::Function,
@@ -1,8 +1,10 @@
 use super::{argument::Argument, expression::Expression};
 use crate::types::{self, Type};
+use position::Position;
+use serde::{Deserialize, Serialize};
 
 // TODO Consider splitting function and thunk definitions.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct FunctionDefinition {
     name: String,
     // Environment is inferred on module creation and this field is used just
@@ -14,6 +16,7 @@ pub struct FunctionDefinition {
     body: Expression,
     type_: types::Function,
     thunk: bool,
+    position: Option<Position>,
 }
 
 impl FunctionDefinition {
@@ -23,7 +26,7 @@ impl FunctionDefinition {
         result_type: impl Into<Type>,
         body: impl Into<Expression>,
     ) -> Self {
-        Self::with_options(name, vec![], arguments, result_type, body, false)
+        Self::with_options(name, vec![], arguments, result_type, body, false, None)
     }
 
     pub fn thunk(
@@ -31,7 +34,7 @@ impl FunctionDefinition {
         result_type: impl Into<Type>,
         body: impl Into<Expression>,
     ) -> Self {
-        Self::with_options(name, vec![], vec![], result_type, body, true)
+        Self::with_options(name, vec![], vec![], result_type, body, true, None)
     }
 
     pub(crate) fn with_options(
@@ -41,6 +44,7 @@ impl FunctionDefinition {
         result_type: impl Into<Type>,
         body: impl Into<Expression>,
         is_thunk: bool,
+        position: Option<Position>,
     ) -> Self {
         let result_type = result_type.into();
 
@@ -59,9 +63,14 @@ impl FunctionDefinition {
             result_type,
             body: body.into(),
             thunk: is_thunk,
+            position,
         }
     }
 
+    pub fn set_position(self, position: Option<Position>) -> Self {
+        Self { position, ..self }
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -89,4 +98,9 @@ impl FunctionDefinition {
     pub fn is_thunk(&self) -> bool {
         self.thunk
     }
+
+    // A source position is kept only for debug information.
+    pub fn position(&self) -> Option<&Position> {
+        self.position.as_ref()
+    }
 }
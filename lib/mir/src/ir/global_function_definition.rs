@@ -1,6 +1,7 @@
 use super::FunctionDefinition;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GlobalFunctionDefinition {
     definition: FunctionDefinition,
     public: bool,
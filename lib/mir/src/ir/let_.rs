@@ -1,11 +1,12 @@
 use super::expression::Expression;
 use crate::types::Type;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Let(Rc<LetInner>);
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 struct LetInner {
     name: String,
     type_: Type,
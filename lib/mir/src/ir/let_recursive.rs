@@ -1,4 +1,5 @@
 use super::{expression::Expression, function_definition::FunctionDefinition};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 // Function definitions in let-recursive expressions are recursive for the
@@ -16,10 +17,10 @@ use std::rc::Rc;
 // - This recursion is necessary to compile "anonymous" loops in HIR
 //   effectively.
 //   - e.g. list comprehension
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct LetRecursive(Rc<LetRecursiveInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct LetRecursiveInner {
     definition: FunctionDefinition,
     expression: Expression,
@@ -3,8 +3,9 @@ use super::{
     function_declaration::FunctionDeclaration, type_definition::TypeDefinition,
     GlobalFunctionDefinition, TypeInformation,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Module {
     type_definitions: Vec<TypeDefinition>,
     foreign_declarations: Vec<ForeignDeclaration>,
@@ -1,11 +1,12 @@
 use super::expression::Expression;
 use crate::types;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Record(Rc<RecordInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct RecordInner {
     type_: types::Record,
     fields: Vec<Expression>,
@@ -1,11 +1,12 @@
 use super::expression::Expression;
 use crate::types;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RecordField(Rc<RecordFieldInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct RecordFieldInner {
     type_: types::Record,
     index: usize,
@@ -1,11 +1,12 @@
 use super::{Expression, RecordUpdateField};
 use crate::types;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RecordUpdate(Rc<RecordUpdateInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct RecordUpdateInner {
     type_: types::Record,
     record: Expression,
@@ -1,6 +1,7 @@
 use super::expression::Expression;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RecordUpdateField {
     index: usize,
     expression: Expression,
@@ -0,0 +1,34 @@
+use super::record::Record;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+// A record construction which reuses memory of a dropped record of the same
+// type if it is uniquely referenced.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ReuseRecord(Rc<ReuseRecordInner>);
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct ReuseRecordInner {
+    variable: String,
+    record: Record,
+}
+
+impl ReuseRecord {
+    pub fn new(variable: impl Into<String>, record: Record) -> Self {
+        Self(
+            ReuseRecordInner {
+                variable: variable.into(),
+                record,
+            }
+            .into(),
+        )
+    }
+
+    pub fn variable(&self) -> &str {
+        &self.0.variable
+    }
+
+    pub fn record(&self) -> &Record {
+        &self.0.record
+    }
+}
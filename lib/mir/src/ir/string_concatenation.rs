@@ -1,7 +1,8 @@
 use super::expression::Expression;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct StringConcatenation {
     operands: Rc<[Expression]>,
 }
@@ -1,11 +1,12 @@
 use super::expression::Expression;
 use crate::types::Type;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Synchronize(Rc<SynchronizeInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct SynchronizeInner {
     type_: Type,
     expression: Expression,
@@ -1,13 +1,14 @@
 use super::expression::Expression;
 use crate::types::Type;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 // A try operation matches an operand with a type and returns it from a function
 // if it matches.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TryOperation(Rc<TryOperationInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct TryOperationInner {
     operand: Expression,
     name: String,
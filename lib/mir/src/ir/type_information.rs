@@ -1,7 +1,8 @@
 use crate::types::Type;
 use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Default, PartialEq, Eq, Serialize)]
 pub struct TypeInformation {
     information: FnvHashMap<Type, String>,
     fallback: String,
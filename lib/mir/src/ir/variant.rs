@@ -1,11 +1,12 @@
 use super::expression::Expression;
 use crate::types::Type;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Variant(Rc<VariantInner>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 struct VariantInner {
     type_: Type,
     payload: Expression,
@@ -13,6 +13,7 @@ impl FunctionDefinitionFake for FunctionDefinition {
             self.result_type().clone(),
             self.body().clone(),
             self.is_thunk(),
+            self.position().cloned(),
         )
     }
 }
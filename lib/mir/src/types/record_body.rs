@@ -1,6 +1,7 @@
 use super::type_::Type;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct RecordBody {
     fields: Vec<Type>,
 }
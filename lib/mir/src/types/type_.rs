@@ -1,6 +1,7 @@
 use super::{function::Function, record::Record};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Type {
     Boolean,
     ByteString,
@@ -1,8 +1,9 @@
 use super::Type;
 use crate::types;
 use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Default, PartialEq, Eq, Serialize)]
 pub struct TypeInformation {
     types: Vec<types::Function>,
     information: FnvHashMap<Type, Vec<String>>,
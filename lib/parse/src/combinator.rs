@@ -2,7 +2,7 @@ use nom::{
     combinator::opt,
     error::ParseError,
     multi::{separated_list0, separated_list1},
-    Input, Parser,
+    Err, Input, Parser,
 };
 
 pub fn separated_or_terminated_list0<I: Input, O, S, E: ParseError<I>>(
@@ -40,11 +40,27 @@ pub fn separated_or_terminated_list1<I: Input, O, S, E: ParseError<I>>(
     }
 }
 
+// Turn failures into errors so that other alternatives can be tried.
+pub fn backtrack<I, O, E: ParseError<I>>(
+    mut parser: impl Parser<I, Output = O, Error = E>,
+) -> impl Parser<I, Output = O, Error = E> {
+    move |input: I| {
+        parser.parse(input).map_err(|error| match error {
+            Err::Failure(error) => Err::Error(error),
+            error => error,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{error::NomError, input::input};
-    use nom::{bytes::complete::tag, combinator::all_consuming};
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        combinator::{all_consuming, cut},
+    };
     use std::str;
 
     mod separated_or_terminated_list0 {
@@ -125,4 +141,23 @@ mod tests {
             }
         }
     }
+
+    mod backtrack {
+        use super::*;
+
+        #[test]
+        fn parse() {
+            assert_eq!(
+                *alt((
+                    backtrack((tag::<_, _, NomError>("a"), cut(tag("b")))).map(|(_, span)| span),
+                    tag("a")
+                ))
+                .parse(input("ac", ""))
+                .unwrap()
+                .1
+                .as_bytes(),
+                *b"a"
+            );
+        }
+    }
 }
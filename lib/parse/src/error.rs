@@ -59,6 +59,14 @@ impl ParseError {
         }
     }
 
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
     fn unexpected_end(source: &str, path: &str) -> Self {
         let lines = source.split('\n').collect::<Vec<_>>();
         let line = lines
@@ -16,3 +16,7 @@ pub fn position(input: Input) -> Position {
         str::from_utf8(input.get_line_beginning()).unwrap(),
     )
 }
+
+pub fn span(start: Input, end: Input) -> Position {
+    position(start).set_end(end.location_line() as usize, end.get_column())
+}
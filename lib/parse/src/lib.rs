@@ -7,7 +7,7 @@ mod parser;
 use ast::Comment;
 pub use error::ParseError;
 use input::input;
-use parser::{comments, module};
+use parser::{comments, module, standalone_expression};
 
 pub fn parse(source: &str, path: &str) -> Result<ast::Module, ParseError> {
     module(input(source, path))
@@ -15,6 +15,12 @@ pub fn parse(source: &str, path: &str) -> Result<ast::Module, ParseError> {
         .map_err(|error| ParseError::new(source, path, error))
 }
 
+pub fn parse_expression(source: &str, path: &str) -> Result<ast::Expression, ParseError> {
+    standalone_expression(input(source, path))
+        .map(|(_, expression)| expression)
+        .map_err(|error| ParseError::new(source, path, error))
+}
+
 pub fn parse_comments(source: &str, path: &str) -> Result<Vec<Comment>, ParseError> {
     comments(input(source, path))
         .map(|(_, comments)| comments)
@@ -40,4 +46,17 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn parse_expression_with_trailing_blank() {
+        assert_eq!(
+            parse_expression("x # foo\n", ""),
+            Ok(Variable::new("x", Position::fake()).into())
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_expression_with_trailing_characters() {
+        assert!(parse_expression("x y", "").is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    combinator::{separated_or_terminated_list0, separated_or_terminated_list1},
+    combinator::{backtrack, separated_or_terminated_list0, separated_or_terminated_list1},
     error::NomError,
     input::{self, Input},
     operations::{reduce_operations, SuffixOperator},
@@ -26,7 +26,7 @@ use std::{collections::HashSet, str};
 const KEYWORDS: &[&str] = &[
     "as", "else", "export", "for", "foreign", "if", "in", "import", "type",
 ];
-const OPERATOR_CHARACTERS: &str = "+-*/=<>&|!?";
+const OPERATOR_CHARACTERS: &str = "+-*/%=<>&|^!?";
 const OPERATOR_MODIFIERS: &str = "=";
 
 type IResult<'a, T> = nom::IResult<Input<'a>, T, NomError<'a>>;
@@ -54,6 +54,13 @@ pub fn module(input: Input) -> IResult<Module> {
     .parse(input)
 }
 
+pub fn standalone_expression(input: Input) -> IResult<Expression> {
+    map(all_consuming((expression, blank)), |(expression, _)| {
+        expression
+    })
+    .parse(input)
+}
+
 pub fn comments(input: Input) -> IResult<Vec<Comment>> {
     map(
         all_consuming(many0((
@@ -264,17 +271,16 @@ fn function_type(input: Input) -> IResult<types::Function> {
     context(
         "function type",
         map(
-            (
-                position,
+            positioned((
                 sign("\\("),
                 cut((
                     separated_or_terminated_list0(sign(","), type_),
                     sign(")"),
                     type_,
                 )),
-            ),
-            |(position, _, (arguments, _, result))| {
-                types::Function::new(arguments, result, position())
+            )),
+            |(position, (_, (arguments, _, result)))| {
+                types::Function::new(arguments, result, position)
             },
         ),
     )
@@ -285,7 +291,14 @@ fn union_type(input: Input) -> IResult<Type> {
     map(separated_list1(sign("|"), atomic_type), |types| {
         types
             .into_iter()
-            .reduce(|lhs, rhs| types::Union::new(lhs.clone(), rhs, lhs.position().clone()).into())
+            .reduce(|lhs, rhs| {
+                let position = lhs.position().set_end(
+                    rhs.position().end_line_number(),
+                    rhs.position().end_column_number(),
+                );
+
+                types::Union::new(lhs, rhs, position).into()
+            })
             .unwrap()
     })
     .parse(input)
@@ -295,8 +308,8 @@ fn list_type(input: Input) -> IResult<types::List> {
     context(
         "list type",
         map(
-            (position, sign("["), cut(terminated(type_, sign("]")))),
-            |(position, _, element)| types::List::new(element, position()),
+            positioned((sign("["), cut(terminated(type_, sign("]"))))),
+            |(position, (_, element))| types::List::new(element, position),
         ),
     )
     .parse(input)
@@ -306,12 +319,19 @@ fn map_type(input: Input) -> IResult<types::Map> {
     context(
         "map type",
         map(
-            (
-                position,
-                sign("{"),
-                cut((type_, sign(":"), type_, sign("}"))),
-            ),
-            |(position, _, (key, _, value, _))| types::Map::new(key, value, position()),
+            positioned((sign("{"), cut((type_, sign(":"), type_, sign("}"))))),
+            |(position, (_, (key, _, value, _)))| types::Map::new(key, value, position),
+        ),
+    )
+    .parse(input)
+}
+
+fn vector_type(input: Input) -> IResult<types::Vector> {
+    context(
+        "vector type",
+        map(
+            positioned((sign("[|"), cut(terminated(type_, sign("|]"))))),
+            |(position, (_, element))| types::Vector::new(element, position),
         ),
     )
     .parse(input)
@@ -320,6 +340,7 @@ fn map_type(input: Input) -> IResult<types::Map> {
 fn atomic_type(input: Input) -> IResult<Type> {
     alt((
         into(reference_type),
+        into(vector_type),
         into(list_type),
         into(map_type),
         preceded(sign("("), cut(terminated(type_, sign(")")))),
@@ -331,8 +352,8 @@ fn reference_type(input: Input) -> IResult<types::Reference> {
     context(
         "reference type",
         map(
-            (position, token(qualified_identifier)),
-            |(position, identifier)| types::Reference::new(identifier, position()),
+            positioned(token(qualified_identifier)),
+            |(position, identifier)| types::Reference::new(identifier, position),
         ),
     )
     .parse(input)
@@ -385,8 +406,8 @@ fn expression(input: Input) -> IResult<Expression> {
             (
                 prefix_operation_like,
                 many0(map(
-                    (position, binary_operator, cut(prefix_operation_like)),
-                    |(position, operator, expression)| (operator, expression, position()),
+                    positioned((binary_operator, cut(prefix_operation_like))),
+                    |(position, (operator, expression))| (operator, expression, position),
                 )),
             ),
             |(expression, pairs)| reduce_operations(expression, &pairs),
@@ -402,15 +423,20 @@ fn binary_operator(input: Input) -> IResult<BinaryOperator> {
             value(BinaryOperator::Add, sign("+")),
             value(BinaryOperator::Subtract, sign("-")),
             value(BinaryOperator::Multiply, sign("*")),
+            value(BinaryOperator::IntegerDivide, sign("//")),
             value(BinaryOperator::Divide, sign("/")),
+            value(BinaryOperator::Remainder, sign("%")),
             value(BinaryOperator::Equal, sign("==")),
             value(BinaryOperator::NotEqual, sign("!=")),
+            value(BinaryOperator::LeftShift, sign("<<")),
             value(BinaryOperator::LessThanOrEqual, sign("<=")),
             value(BinaryOperator::LessThan, sign("<")),
+            value(BinaryOperator::RightShift, sign(">>")),
             value(BinaryOperator::GreaterThanOrEqual, sign(">=")),
             value(BinaryOperator::GreaterThan, sign(">")),
             value(BinaryOperator::And, sign("&")),
-            value(BinaryOperator::Or, sign("|")),
+            value(BinaryOperator::Or, terminated(sign("|"), not(char(']')))),
+            value(BinaryOperator::Xor, sign("^")),
         )),
     )
     .parse(input)
@@ -424,9 +450,9 @@ fn prefix_operation(input: Input) -> IResult<UnaryOperation> {
     context(
         "prefix operation",
         map(
-            (position, prefix_operator, cut(prefix_operation_like)),
-            |(position, operator, expression)| {
-                UnaryOperation::new(operator, expression, position())
+            positioned((prefix_operator, cut(prefix_operation_like))),
+            |(position, (operator, expression))| {
+                UnaryOperation::new(operator, expression, position)
             },
         ),
     )
@@ -468,15 +494,14 @@ fn call_operator(input: Input) -> IResult<SuffixOperator> {
     context(
         "call",
         map(
-            (
-                peek(position),
+            span((
                 tag("("),
                 cut(terminated(
                     separated_or_terminated_list0(sign(","), expression),
                     sign(")"),
                 )),
-            ),
-            |(position, _, arguments)| SuffixOperator::Call(arguments, position()),
+            )),
+            |(position, (_, arguments))| SuffixOperator::Call(arguments, position),
         ),
     )
     .parse(input)
@@ -486,8 +511,8 @@ fn record_field_operator(input: Input) -> IResult<SuffixOperator> {
     context(
         "record field",
         map(
-            (position, sign("."), cut(identifier)),
-            |(position, _, identifier)| SuffixOperator::RecordField(identifier, position()),
+            positioned((sign("."), cut(identifier))),
+            |(position, (_, identifier))| SuffixOperator::RecordField(identifier, position),
         ),
     )
     .parse(input)
@@ -496,8 +521,8 @@ fn record_field_operator(input: Input) -> IResult<SuffixOperator> {
 fn try_operator(input: Input) -> IResult<SuffixOperator> {
     context(
         "try operator",
-        map((position, sign("?")), |(position, _)| {
-            SuffixOperator::Try(position())
+        map(positioned(sign("?")), |(position, _)| {
+            SuffixOperator::Try(position)
         }),
     )
     .parse(input)
@@ -511,6 +536,7 @@ fn atomic_expression(input: Input) -> IResult<Expression> {
         into(if_map),
         into(if_),
         into(record),
+        into(vector_literal),
         into(list_comprehension),
         into(list_literal),
         into(map_literal),
@@ -526,18 +552,21 @@ fn lambda(input: Input) -> IResult<Lambda> {
     context(
         "function",
         map(
-            (
-                position,
+            positioned((
                 sign("\\("),
                 cut((
                     separated_or_terminated_list0(sign(","), argument),
                     sign(")"),
-                    type_,
-                    block,
+                    alt((
+                        map((backtrack(type_), block), |(type_, block)| {
+                            (Some(type_), block)
+                        }),
+                        map(block, |block| (None, block)),
+                    )),
                 )),
-            ),
-            |(position, _, (arguments, _, result_type, body))| {
-                Lambda::new(arguments, result_type, body, position())
+            )),
+            |(position, (_, (arguments, _, (result_type, body))))| {
+                Lambda::new(arguments, result_type, body, position)
             },
         ),
     )
@@ -548,8 +577,8 @@ fn argument(input: Input) -> IResult<Argument> {
     context(
         "argument",
         map(
-            (position, identifier, cut(type_)),
-            |(position, name, type_)| Argument::new(name, type_, position()),
+            positioned((identifier, opt(type_))),
+            |(position, (name, type_))| Argument::new(name, type_, position),
         ),
     )
     .parse(input)
@@ -559,8 +588,7 @@ fn if_(input: Input) -> IResult<If> {
     context(
         "if",
         map(
-            (
-                position,
+            positioned((
                 keyword("if"),
                 cut((
                     if_branch,
@@ -568,12 +596,12 @@ fn if_(input: Input) -> IResult<If> {
                     keyword("else"),
                     block,
                 )),
-            ),
-            |(position, _, (first_branch, branches, _, else_block))| {
+            )),
+            |(position, (_, (first_branch, branches, _, else_block)))| {
                 If::new(
                     [first_branch].into_iter().chain(branches).collect(),
                     else_block,
-                    position(),
+                    position,
                 )
             },
         ),
@@ -592,8 +620,7 @@ fn if_list(input: Input) -> IResult<IfList> {
     context(
         "if list",
         map(
-            (
-                position,
+            positioned((
                 keyword("if"),
                 sign("["),
                 cut((
@@ -608,9 +635,9 @@ fn if_list(input: Input) -> IResult<IfList> {
                     keyword("else"),
                     block,
                 )),
-            ),
-            |(position, _, _, (first_name, _, _, rest_name, _, _, argument, then, _, else_))| {
-                IfList::new(argument, first_name, rest_name, then, else_, position())
+            )),
+            |(position, (_, _, (first_name, _, _, rest_name, _, _, argument, then, _, else_)))| {
+                IfList::new(argument, first_name, rest_name, then, else_, position)
             },
         ),
     )
@@ -621,17 +648,16 @@ fn if_map(input: Input) -> IResult<IfMap> {
     context(
         "if map",
         map(
-            (
-                position,
+            positioned((
                 keyword("if"),
                 identifier,
                 sign("="),
                 expression,
                 sign("["),
                 cut((expression, sign("]"), block, keyword("else"), block)),
-            ),
-            |(position, _, name, _, map, _, (key, _, then, _, else_))| {
-                IfMap::new(name, map, key, then, else_, position())
+            )),
+            |(position, (_, name, _, map, _, (key, _, then, _, else_)))| {
+                IfMap::new(name, map, key, then, else_, position)
             },
         ),
     )
@@ -642,8 +668,7 @@ fn if_type(input: Input) -> IResult<IfType> {
     context(
         "if type",
         map(
-            (
-                position,
+            positioned((
                 keyword("if"),
                 identifier,
                 sign("="),
@@ -657,14 +682,14 @@ fn if_type(input: Input) -> IResult<IfType> {
                     )),
                     opt(preceded(keyword("else"), block)),
                 )),
-            ),
-            |(position, _, identifier, _, argument, _, (first_branch, branches, else_))| {
+            )),
+            |(position, (_, identifier, _, argument, _, (first_branch, branches, else_)))| {
                 IfType::new(
                     identifier,
                     argument,
                     [first_branch].into_iter().chain(branches).collect(),
                     else_,
-                    position(),
+                    position,
                 )
             },
         ),
@@ -684,8 +709,7 @@ fn record(input: Input) -> IResult<Record> {
     context(
         "record",
         map(
-            (
-                position,
+            positioned((
                 qualified_identifier,
                 sign("{"),
                 verify(
@@ -709,9 +733,9 @@ fn record(input: Input) -> IResult<Record> {
                     },
                 ),
                 sign("}"),
-            ),
-            |(position, name, _, (record, fields), _)| {
-                Record::new(name, record, fields, position())
+            )),
+            |(position, (name, _, (record, fields), _))| {
+                Record::new(name, record, fields, position)
             },
         ),
     )
@@ -722,8 +746,8 @@ fn record_field(input: Input) -> IResult<RecordField> {
     context(
         "record field",
         map(
-            (position, identifier, sign(":"), cut(expression)),
-            |(position, name, _, expression)| RecordField::new(name, expression, position()),
+            positioned((identifier, sign(":"), cut(expression))),
+            |(position, (name, _, expression))| RecordField::new(name, expression, position),
         ),
     )
     .parse(input)
@@ -733,12 +757,11 @@ fn number_literal(input: Input) -> IResult<Number> {
     context(
         "number",
         map(
-            token((
-                position,
+            positioned((
                 alt((binary_literal, hexadecimal_literal, decimal_literal)),
                 peek(not(digit1)),
             )),
-            |(position, number, _)| Number::new(number, position()),
+            |(position, (number, _))| Number::new(number, position),
         ),
     )
     .parse(input)
@@ -790,24 +813,21 @@ fn string_literal(input: Input) -> IResult<ByteString> {
 
 fn raw_string_literal(input: Input) -> IResult<ByteString> {
     map(
-        (
-            position,
-            preceded(
+        span(preceded(
+            char('"'),
+            cut(terminated(
+                many0(alt((
+                    recognize(none_of("\\\"")),
+                    tag("\\\\"),
+                    tag("\\\""),
+                    tag("\\n"),
+                    tag("\\r"),
+                    tag("\\t"),
+                    recognize((tag("\\x"), count(hexadecimal_digit, 2))),
+                ))),
                 char('"'),
-                cut(terminated(
-                    many0(alt((
-                        recognize(none_of("\\\"")),
-                        tag("\\\\"),
-                        tag("\\\""),
-                        tag("\\n"),
-                        tag("\\r"),
-                        tag("\\t"),
-                        recognize((tag("\\x"), count(hexadecimal_digit, 2))),
-                    ))),
-                    char('"'),
-                )),
-            ),
-        ),
+            )),
+        )),
         |(position, spans)| {
             ByteString::new(
                 spans
@@ -815,7 +835,7 @@ fn raw_string_literal(input: Input) -> IResult<ByteString> {
                     .map(|span| str::from_utf8(span.as_bytes()).unwrap())
                     .collect::<Vec<_>>()
                     .concat(),
-                position(),
+                position,
             )
         },
     )
@@ -826,16 +846,33 @@ fn list_literal(input: Input) -> IResult<List> {
     context(
         "list",
         map(
-            (
-                position,
+            positioned((
                 sign("["),
                 cut((
                     type_,
                     separated_or_terminated_list0(sign(","), list_element),
                     sign("]"),
                 )),
-            ),
-            |(position, _, (type_, elements, _))| List::new(type_, elements, position()),
+            )),
+            |(position, (_, (type_, elements, _)))| List::new(type_, elements, position),
+        ),
+    )
+    .parse(input)
+}
+
+fn vector_literal(input: Input) -> IResult<Vector> {
+    context(
+        "vector",
+        map(
+            positioned((
+                sign("[|"),
+                cut((
+                    type_,
+                    separated_or_terminated_list0(sign(","), expression),
+                    sign("|]"),
+                )),
+            )),
+            |(position, (_, (type_, elements, _)))| Vector::new(type_, elements, position),
         ),
     )
     .parse(input)
@@ -856,16 +893,15 @@ fn list_comprehension(input: Input) -> IResult<Expression> {
     context(
         "list comprehension",
         map(
-            (
-                position,
+            positioned((
                 sign("["),
                 type_,
                 expression,
                 many1(list_comprehension_branch),
                 sign("]"),
-            ),
-            |(position, _, type_, element, branches, _)| {
-                ListComprehension::new(type_, element, branches, position()).into()
+            )),
+            |(position, (_, type_, element, branches, _))| {
+                ListComprehension::new(type_, element, branches, position).into()
             },
         ),
     )
@@ -876,8 +912,7 @@ fn list_comprehension_branch(input: Input) -> IResult<ListComprehensionBranch> {
     context(
         "list comprehension branch",
         map(
-            (
-                position,
+            positioned((
                 keyword("for"),
                 cut((
                     separated_or_terminated_list1(sign(","), identifier),
@@ -885,9 +920,9 @@ fn list_comprehension_branch(input: Input) -> IResult<ListComprehensionBranch> {
                     separated_or_terminated_list1(sign(","), expression),
                     opt(preceded(keyword("if"), expression)),
                 )),
-            ),
-            |(position, _, (element_names, _, iteratees, condition))| {
-                ListComprehensionBranch::new(element_names, iteratees, condition, position())
+            )),
+            |(position, (_, (element_names, _, iteratees, condition)))| {
+                ListComprehensionBranch::new(element_names, iteratees, condition, position)
             },
         ),
     )
@@ -898,8 +933,7 @@ fn map_literal(input: Input) -> IResult<Map> {
     context(
         "map",
         map(
-            (
-                position,
+            positioned((
                 sign("{"),
                 cut((
                     type_,
@@ -908,9 +942,9 @@ fn map_literal(input: Input) -> IResult<Map> {
                     separated_or_terminated_list0(sign(","), map_element),
                     sign("}"),
                 )),
-            ),
-            |(position, _, (key_type, _, value_type, elements, _))| {
-                Map::new(key_type, value_type, elements, position())
+            )),
+            |(position, (_, (key_type, _, value_type, elements, _)))| {
+                Map::new(key_type, value_type, elements, position)
             },
         ),
     )
@@ -920,8 +954,8 @@ fn map_literal(input: Input) -> IResult<Map> {
 fn map_element(input: Input) -> IResult<MapElement> {
     alt((
         map(
-            (position, expression, sign(":"), cut(expression)),
-            |(position, key, _, value)| MapEntry::new(key, value, position()).into(),
+            positioned((expression, sign(":"), cut(expression))),
+            |(position, (key, _, value))| MapEntry::new(key, value, position).into(),
         ),
         map(preceded(sign("..."), cut(expression)), MapElement::Multiple),
     ))
@@ -932,8 +966,8 @@ fn variable(input: Input) -> IResult<Variable> {
     context(
         "variable",
         map(
-            (position, token(qualified_identifier)),
-            |(position, identifier)| Variable::new(identifier, position()),
+            positioned(token(qualified_identifier)),
+            |(position, identifier)| Variable::new(identifier, position),
         ),
     )
     .parse(input)
@@ -1054,6 +1088,30 @@ fn position(input: Input<'_>) -> IResult<'_, impl Fn() -> Position + '_> {
     Ok((input, move || input::position(input)))
 }
 
+// Parse a span with its position from a start to an end of a parser.
+fn positioned<'a, O>(
+    parser: impl Parser<Input<'a>, Output = O, Error = NomError<'a>>,
+) -> impl FnMut(Input<'a>) -> IResult<'a, (Position, O)> {
+    let mut parser = span(parser);
+
+    move |input| {
+        let (input, _) = blank(input)?;
+
+        parser(input)
+    }
+}
+
+// Positions are allocated only after parsers succeed.
+fn span<'a, O>(
+    mut parser: impl Parser<Input<'a>, Output = O, Error = NomError<'a>>,
+) -> impl FnMut(Input<'a>) -> IResult<'a, (Position, O)> {
+    move |start| {
+        let (end, output) = parser.parse(start)?;
+
+        Ok((end, (input::span(start, end), output)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1121,10 +1179,10 @@ mod tests {
                         Lambda::new(
                             vec![Argument::new(
                                 "x",
-                                types::Reference::new("number", Position::fake()),
+                                Some(types::Reference::new("number", Position::fake()).into()),
                                 Position::fake()
                             )],
-                            types::Reference::new("number", Position::fake()),
+                            Some(types::Reference::new("number", Position::fake()).into()),
                             Block::new(
                                 vec![],
                                 Number::new(
@@ -1158,10 +1216,10 @@ mod tests {
                             Lambda::new(
                                 vec![Argument::new(
                                     "x",
-                                    types::Reference::new("number", Position::fake()),
+                                    Some(types::Reference::new("number", Position::fake()).into()),
                                     Position::fake()
                                 )],
-                                types::Reference::new("number", Position::fake()),
+                                Some(types::Reference::new("number", Position::fake()).into()),
                                 Block::new(
                                     vec![],
                                     Number::new(
@@ -1180,10 +1238,10 @@ mod tests {
                             Lambda::new(
                                 vec![Argument::new(
                                     "y",
-                                    types::Reference::new("number", Position::fake()),
+                                    Some(types::Reference::new("number", Position::fake()).into()),
                                     Position::fake()
                                 )],
-                                types::Reference::new("number", Position::fake()),
+                                Some(types::Reference::new("number", Position::fake()).into()),
                                 Block::new(
                                     vec![],
                                     Number::new(
@@ -1432,10 +1490,10 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Reference::new("number", Position::fake()),
+                            Some(types::Reference::new("number", Position::fake()).into()),
                             Position::fake()
                         )],
-                        types::Reference::new("number", Position::fake()),
+                        Some(types::Reference::new("number", Position::fake()).into()),
                         Block::new(
                             vec![],
                             Number::new(
@@ -1463,10 +1521,10 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Reference::new("number", Position::fake()),
+                            Some(types::Reference::new("number", Position::fake()).into()),
                             Position::fake()
                         )],
-                        types::Reference::new("number", Position::fake()),
+                        Some(types::Reference::new("number", Position::fake()).into()),
                         Block::new(
                             vec![],
                             Number::new(
@@ -1494,10 +1552,10 @@ mod tests {
                     Lambda::new(
                         vec![Argument::new(
                             "x",
-                            types::Reference::new("number", Position::fake()),
+                            Some(types::Reference::new("number", Position::fake()).into()),
                             Position::fake()
                         )],
-                        types::Reference::new("number", Position::fake()),
+                        Some(types::Reference::new("number", Position::fake()).into()),
                         Block::new(
                             vec![],
                             Number::new(
@@ -1524,7 +1582,7 @@ mod tests {
                     "importA",
                     Lambda::new(
                         vec![],
-                        types::Reference::new("number", Position::fake()),
+                        Some(types::Reference::new("number", Position::fake()).into()),
                         Block::new(
                             vec![],
                             Number::new(
@@ -1803,6 +1861,43 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_vector_type() {
+            assert_eq!(
+                type_(input("[|number|]", "")).unwrap().1,
+                types::Vector::new(
+                    types::Reference::new("number", Position::fake()),
+                    Position::fake()
+                )
+                .into()
+            );
+
+            assert_eq!(
+                type_(input("[|[|number|]|]", "")).unwrap().1,
+                types::Vector::new(
+                    types::Vector::new(
+                        types::Reference::new("number", Position::fake()),
+                        Position::fake()
+                    ),
+                    Position::fake()
+                )
+                .into()
+            );
+
+            assert_eq!(
+                type_(input("[|number|none|]", "")).unwrap().1,
+                types::Vector::new(
+                    types::Union::new(
+                        types::Reference::new("number", Position::fake()),
+                        types::Reference::new("none", Position::fake()),
+                        Position::fake()
+                    ),
+                    Position::fake()
+                )
+                .into()
+            );
+        }
+
         #[test]
         fn parse_map_type() {
             assert_eq!(
@@ -1919,10 +2014,10 @@ mod tests {
                 Lambda::new(
                     vec![Argument::new(
                         "x",
-                        types::Reference::new("number", Position::fake()),
+                        Some(types::Reference::new("number", Position::fake()).into()),
                         Position::fake()
                     )],
-                    types::Reference::new("number", Position::fake()),
+                    Some(types::Reference::new("number", Position::fake()).into()),
                     Block::new(
                         vec![],
                         Number::new(
@@ -1943,16 +2038,39 @@ mod tests {
                     vec![
                         Argument::new(
                             "x",
-                            types::Reference::new("number", Position::fake()),
+                            Some(types::Reference::new("number", Position::fake()).into()),
                             Position::fake()
                         ),
                         Argument::new(
                             "y",
-                            types::Reference::new("number", Position::fake()),
+                            Some(types::Reference::new("number", Position::fake()).into()),
                             Position::fake()
                         )
                     ],
-                    types::Reference::new("number", Position::fake()),
+                    Some(types::Reference::new("number", Position::fake()).into()),
+                    Block::new(
+                        vec![],
+                        Number::new(
+                            NumberRepresentation::FloatingPoint("42".into()),
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    ),
+                    Position::fake()
+                ),
+            );
+        }
+
+        #[test]
+        fn parse_lambda_without_type_annotations() {
+            assert_eq!(
+                lambda(input("\\(x, y) { 42 }", "")).unwrap().1,
+                Lambda::new(
+                    vec![
+                        Argument::new("x", None, Position::fake()),
+                        Argument::new("y", None, Position::fake())
+                    ],
+                    None,
                     Block::new(
                         vec![],
                         Number::new(
@@ -1966,13 +2084,59 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_lambda_with_map_result_type() {
+            assert_eq!(
+                lambda(input("\\() {string: number} { x }", "")).unwrap().1,
+                Lambda::new(
+                    vec![],
+                    Some(
+                        types::Map::new(
+                            types::Reference::new("string", Position::fake()),
+                            types::Reference::new("number", Position::fake()),
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    Block::new(
+                        vec![],
+                        Variable::new("x", Position::fake()),
+                        Position::fake()
+                    ),
+                    Position::fake()
+                ),
+            );
+        }
+
+        #[test]
+        fn parse_lambda_returning_map_without_result_type() {
+            assert_eq!(
+                lambda(input("\\() { {string: number} }", "")).unwrap().1,
+                Lambda::new(
+                    vec![],
+                    None,
+                    Block::new(
+                        vec![],
+                        Map::new(
+                            types::Reference::new("string", Position::fake()),
+                            types::Reference::new("number", Position::fake()),
+                            vec![],
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    ),
+                    Position::fake()
+                ),
+            );
+        }
+
         #[test]
         fn parse_lambda_with_reference_type() {
             assert_eq!(
                 lambda(input("\\() Foo { 42 }", "")).unwrap().1,
                 Lambda::new(
                     vec![],
-                    types::Reference::new("Foo", Position::fake()),
+                    Some(types::Reference::new("Foo", Position::fake()).into()),
                     Block::new(
                         vec![],
                         Number::new(
@@ -2815,6 +2979,61 @@ mod tests {
                     )
                     .into(),
                 ),
+                (
+                    "x<<x+x%x",
+                    BinaryOperation::new(
+                        BinaryOperator::LeftShift,
+                        Variable::new("x", Position::fake()),
+                        BinaryOperation::new(
+                            BinaryOperator::Add,
+                            Variable::new("x", Position::fake()),
+                            BinaryOperation::new(
+                                BinaryOperator::Remainder,
+                                Variable::new("x", Position::fake()),
+                                Variable::new("x", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                (
+                    "x|x^x&x",
+                    BinaryOperation::new(
+                        BinaryOperator::Or,
+                        Variable::new("x", Position::fake()),
+                        BinaryOperation::new(
+                            BinaryOperator::Xor,
+                            Variable::new("x", Position::fake()),
+                            BinaryOperation::new(
+                                BinaryOperator::And,
+                                Variable::new("x", Position::fake()),
+                                Variable::new("x", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                (
+                    "x//x>>x",
+                    BinaryOperation::new(
+                        BinaryOperator::RightShift,
+                        BinaryOperation::new(
+                            BinaryOperator::IntegerDivide,
+                            Variable::new("x", Position::fake()),
+                            Variable::new("x", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
             ] {
                 assert_eq!(expression(input(source, "")).unwrap().1, target);
             }
@@ -2830,6 +3049,8 @@ mod tests {
                 ("-", BinaryOperator::Subtract),
                 ("*", BinaryOperator::Multiply),
                 ("/", BinaryOperator::Divide),
+                ("//", BinaryOperator::IntegerDivide),
+                ("%", BinaryOperator::Remainder),
                 ("==", BinaryOperator::Equal),
                 ("!=", BinaryOperator::NotEqual),
                 ("<", BinaryOperator::LessThan),
@@ -2838,6 +3059,9 @@ mod tests {
                 (">=", BinaryOperator::GreaterThanOrEqual),
                 ("&", BinaryOperator::And),
                 ("|", BinaryOperator::Or),
+                ("^", BinaryOperator::Xor),
+                ("<<", BinaryOperator::LeftShift),
+                (">>", BinaryOperator::RightShift),
             ] {
                 assert_eq!(binary_operator(input(source, "")).unwrap().1, *expected);
             }
@@ -3034,6 +3258,56 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_span_of_variable() {
+            let position = variable(input(" foo ", "")).unwrap().1.position().clone();
+
+            assert_eq!(
+                (
+                    position.line_number(),
+                    position.column_number(),
+                    position.end_line_number(),
+                    position.end_column_number()
+                ),
+                (1, 2, 1, 5)
+            );
+        }
+
+        #[test]
+        fn parse_span_of_call() {
+            let expression = expression(input("f(x,\n  y)", "")).unwrap().1;
+            let position = expression.position();
+
+            assert_eq!(
+                (
+                    position.line_number(),
+                    position.column_number(),
+                    position.end_line_number(),
+                    position.end_column_number()
+                ),
+                (1, 2, 2, 5)
+            );
+        }
+
+        #[test]
+        fn parse_span_of_union_type() {
+            let position = type_(input("number | none", ""))
+                .unwrap()
+                .1
+                .position()
+                .clone();
+
+            assert_eq!(
+                (
+                    position.line_number(),
+                    position.column_number(),
+                    position.end_line_number(),
+                    position.end_column_number()
+                ),
+                (1, 1, 1, 14)
+            );
+        }
+
         #[test]
         fn parse_number_literal() {
             assert!(number_literal(input("", "")).is_err());
@@ -3210,6 +3484,55 @@ mod tests {
             }
         }
 
+        #[test]
+        fn parse_vector() {
+            for (source, target) in [
+                (
+                    "[|none|]",
+                    Vector::new(
+                        types::Reference::new("none", Position::fake()),
+                        vec![],
+                        Position::fake(),
+                    ),
+                ),
+                (
+                    "[|none none|]",
+                    Vector::new(
+                        types::Reference::new("none", Position::fake()),
+                        vec![Variable::new("none", Position::fake()).into()],
+                        Position::fake(),
+                    ),
+                ),
+                (
+                    "[|none foo, bar,|]",
+                    Vector::new(
+                        types::Reference::new("none", Position::fake()),
+                        vec![
+                            Variable::new("foo", Position::fake()).into(),
+                            Variable::new("bar", Position::fake()).into(),
+                        ],
+                        Position::fake(),
+                    ),
+                ),
+                (
+                    "[|boolean x | y|]",
+                    Vector::new(
+                        types::Reference::new("boolean", Position::fake()),
+                        vec![BinaryOperation::new(
+                            BinaryOperator::Or,
+                            Variable::new("x", Position::fake()),
+                            Variable::new("y", Position::fake()),
+                            Position::fake(),
+                        )
+                        .into()],
+                        Position::fake(),
+                    ),
+                ),
+            ] {
+                assert_eq!(expression(input(source, "")).unwrap().1, target.into());
+            }
+        }
+
         #[test]
         fn parse_list_comprehension() {
             for (source, target) in [
@@ -1,27 +1,111 @@
 use crate::Position;
 
+const PRIMARY_MARKER: char = '^';
+const SECONDARY_MARKER: char = '-';
+
 pub fn format(position: &Position) -> String {
     format!(
-        "{}\n{}\t{}\n{}^",
+        "{}\n{}\t{}\n{}{}",
         position.path(),
         line_information(position),
         position.line(),
-        offset(position),
+        offset(position, position),
+        underline(position, PRIMARY_MARKER),
     )
 }
 
 pub fn format_message(position: &Position, message: &str) -> String {
-    format!("{}\n{}{}", format(position), offset(position), message)
+    format!(
+        "{}\n{}{}",
+        format(position),
+        offset(position, position),
+        message
+    )
+}
+
+// Format labels with the first one as a primary label. Labels on the same line
+// share a snippet.
+pub fn format_labels(labels: &[(&Position, &str)]) -> String {
+    let Some((first, _)) = labels.first() else {
+        return Default::default();
+    };
+
+    if labels.iter().all(|(position, _)| {
+        position.path() == first.path() && position.line_number() == first.line_number()
+    }) {
+        [format!(
+            "{}\n{}\t{}",
+            first.path(),
+            line_information(first),
+            first.line()
+        )]
+        .into_iter()
+        .chain(
+            labels
+                .iter()
+                .enumerate()
+                .map(|(index, (position, message))| {
+                    format!(
+                        "{}{} {}",
+                        offset(first, position),
+                        underline(position, marker(index)),
+                        message
+                    )
+                }),
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+    } else {
+        labels
+            .iter()
+            .enumerate()
+            .map(|(index, (position, message))| {
+                format!(
+                    "{}\n{}\t{}\n{}{} {}",
+                    position.path(),
+                    line_information(position),
+                    position.line(),
+                    offset(position, position),
+                    underline(position, marker(index)),
+                    message
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn marker(index: usize) -> char {
+    if index == 0 {
+        PRIMARY_MARKER
+    } else {
+        SECONDARY_MARKER
+    }
 }
 
 fn line_information(position: &Position) -> String {
     format!("{}:{}:", position.line_number(), position.column_number())
 }
 
-fn offset(position: &Position) -> String {
+// Spans over multiple lines are underlined until the ends of their first lines.
+fn underline(position: &Position, marker: char) -> String {
+    let end_column_number = if position.end_line_number() > position.line_number() {
+        position.line().trim_end().len() + 1
+    } else {
+        position.end_column_number()
+    };
+
+    marker.to_string().repeat(
+        end_column_number
+            .saturating_sub(position.column_number())
+            .max(1),
+    )
+}
+
+fn offset(header: &Position, position: &Position) -> String {
     format!(
         "{}\t{}",
-        " ".repeat(line_information(position).len()),
+        " ".repeat(line_information(header).len()),
         " ".repeat(position.column_number() - 1)
     )
 }
@@ -50,4 +134,47 @@ mod tests {
             "file\n1:2:\tx\n    \t ^\n    \t foo"
         );
     }
+
+    #[test]
+    fn format_span() {
+        assert_eq!(
+            format(&Position::new("file", 1, 2, " foo").set_end(1, 5)),
+            "file\n1:2:\t foo\n    \t ^^^"
+        );
+    }
+
+    #[test]
+    fn format_multi_line_span() {
+        assert_eq!(
+            format(&Position::new("file", 1, 2, " foo(x, ").set_end(2, 3)),
+            "file\n1:2:\t foo(x, \n    \t ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn format_labels_in_line() {
+        assert_eq!(
+            format_labels(&[
+                (&Position::new("file", 1, 1, "foo(x)").set_end(1, 7), "foo"),
+                (&Position::new("file", 1, 5, "foo(x)").set_end(1, 6), "bar"),
+            ]),
+            "file\n1:1:\tfoo(x)\n    \t^^^^^^ foo\n    \t    - bar"
+        );
+    }
+
+    #[test]
+    fn format_labels_in_lines() {
+        assert_eq!(
+            format_labels(&[
+                (&Position::new("file", 2, 1, "x"), "foo"),
+                (&Position::new("file", 1, 3, "  y"), "bar"),
+            ]),
+            "file\n2:1:\tx\n    \t^ foo\nfile\n1:3:\t  y\n    \t  - bar"
+        );
+    }
+
+    #[test]
+    fn format_no_label() {
+        assert_eq!(format_labels(&[]), "");
+    }
 }
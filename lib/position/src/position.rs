@@ -15,6 +15,8 @@ struct PositionInner {
     path: String,
     line_number: usize,
     column_number: usize,
+    end_line_number: usize,
+    end_column_number: usize,
     line: String,
 }
 
@@ -30,12 +32,26 @@ impl Position {
                 path: path.into(),
                 line_number,
                 column_number,
+                end_line_number: line_number,
+                end_column_number: column_number,
                 line: line.into(),
             }
             .into(),
         )
     }
 
+    // An end column number is exclusive.
+    pub fn set_end(&self, line_number: usize, column_number: usize) -> Self {
+        Self(
+            PositionInner {
+                end_line_number: line_number,
+                end_column_number: column_number,
+                ..self.0.as_ref().clone()
+            }
+            .into(),
+        )
+    }
+
     pub fn path(&self) -> &str {
         &self.0.path
     }
@@ -48,6 +64,14 @@ impl Position {
         self.0.column_number
     }
 
+    pub fn end_line_number(&self) -> usize {
+        self.0.end_line_number
+    }
+
+    pub fn end_column_number(&self) -> usize {
+        self.0.end_column_number
+    }
+
     pub fn line(&self) -> &str {
         &self.0.line
     }
@@ -99,4 +123,14 @@ mod tests {
             "file\n1:2:\t x\n    \t ^"
         );
     }
+
+    #[test]
+    fn set_end() {
+        let position = Position::new("file", 1, 2, "foo").set_end(3, 4);
+
+        assert_eq!(position.line_number(), 1);
+        assert_eq!(position.column_number(), 2);
+        assert_eq!(position.end_line_number(), 3);
+        assert_eq!(position.end_column_number(), 4);
+    }
 }
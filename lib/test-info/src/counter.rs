@@ -0,0 +1,28 @@
+use position::Position;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Counter {
+    kind: CounterKind,
+    position: Position,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum CounterKind {
+    Function(String),
+    Branch { block: usize, branch: usize },
+}
+
+impl Counter {
+    pub fn new(kind: CounterKind, position: Position) -> Self {
+        Self { kind, position }
+    }
+
+    pub fn kind(&self) -> &CounterKind {
+        &self.kind
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
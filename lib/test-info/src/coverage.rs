@@ -0,0 +1,25 @@
+use crate::counter::Counter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Coverage {
+    path: String,
+    counters: Vec<Counter>,
+}
+
+impl Coverage {
+    pub fn new(path: impl Into<String>, counters: Vec<Counter>) -> Self {
+        Self {
+            path: path.into(),
+            counters,
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn counters(&self) -> &[Counter] {
+        &self.counters
+    }
+}
@@ -1,7 +1,11 @@
+mod counter;
+mod coverage;
 mod function;
 mod module;
 mod package;
 
+pub use counter::*;
+pub use coverage::*;
 pub use function::*;
 pub use module::*;
 pub use package::*;
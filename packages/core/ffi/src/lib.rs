@@ -4,6 +4,7 @@ extern crate alloc;
 
 mod bit;
 mod character;
+mod list;
 mod number;
 mod string;
 mod view;
@@ -0,0 +1,54 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::mem::swap;
+use futures::StreamExt;
+
+#[ffi::bindgen]
+async fn _pen_core_sort_list(list: ffi::List, less: ffi::Closure<ffi::Boolean>) -> ffi::List {
+    let mut xs = ffi::future::stream::from_list(list)
+        .collect::<Vec<_>>()
+        .await;
+    let mut ys = Vec::with_capacity(xs.len());
+    let mut width = 1;
+
+    // We merge sorted runs bottom-up as asynchronous functions cannot recurse
+    // without boxing.
+    while width < xs.len() {
+        for start in (0..xs.len()).step_by(2 * width) {
+            let middle = (start + width).min(xs.len());
+            let end = (start + 2 * width).min(xs.len());
+            let (mut i, mut j) = (start, middle);
+
+            while i < middle && j < end {
+                // Take elements on the left side first if they are equal to
+                // keep the sort stable.
+                if is_less(&less, &xs[j], &xs[i]).await {
+                    ys.push(xs[j].clone());
+                    j += 1;
+                } else {
+                    ys.push(xs[i].clone());
+                    i += 1;
+                }
+            }
+
+            ys.extend_from_slice(&xs[i..middle]);
+            ys.extend_from_slice(&xs[j..end]);
+        }
+
+        swap(&mut xs, &mut ys);
+        ys.clear();
+        width *= 2;
+    }
+
+    xs.into()
+}
+
+async fn is_less(less: &ffi::Closure<ffi::Boolean>, x: &ffi::Any, y: &ffi::Any) -> bool {
+    ffi::call!(
+        fn(ffi::Any, ffi::Any) -> ffi::Boolean,
+        less.clone(),
+        x.clone(),
+        y.clone()
+    )
+    .await
+    .into()
+}
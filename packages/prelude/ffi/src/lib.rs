@@ -18,6 +18,11 @@ fn _pen_prelude_equal_strings(one: ffi::ByteString, other: ffi::ByteString) -> f
     (one.as_slice() == other.as_slice()).into()
 }
 
+#[ffi::bindgen]
+fn _pen_prelude_compare_strings(one: ffi::ByteString, other: ffi::ByteString) -> ffi::Number {
+    (one.as_slice().cmp(other.as_slice()) as i8 as f64).into()
+}
+
 #[ffi::bindgen]
 fn _pen_prelude_combine_hashes(one: ffi::Number, other: ffi::Number) -> ffi::Number {
     f64::from_bits(
@@ -69,6 +74,13 @@ fn _pen_prelude_hash_to_index(
         .into()
 }
 
+#[ffi::bindgen]
+fn _pen_prelude_vector_index(index: ffi::Number, size: ffi::Number) -> ffi::Boolean {
+    let index = f64::from(index);
+
+    (index.fract() == 0.0 && index >= 1.0 && index <= f64::from(size)).into()
+}
+
 fn hash(value: &impl Hash) -> ffi::Number {
     let mut hasher = SipHasher::new();
 
@@ -87,6 +99,30 @@ mod tests {
         assert_eq!(_pen_prelude_debug_number(42.0.into()), "42".into());
     }
 
+    #[test]
+    fn vector_index() {
+        assert_eq!(
+            _pen_prelude_vector_index(1.0.into(), 1.0.into()),
+            true.into()
+        );
+        assert_eq!(
+            _pen_prelude_vector_index(2.0.into(), 3.0.into()),
+            true.into()
+        );
+        assert_eq!(
+            _pen_prelude_vector_index(0.0.into(), 1.0.into()),
+            false.into()
+        );
+        assert_eq!(
+            _pen_prelude_vector_index(2.0.into(), 1.0.into()),
+            false.into()
+        );
+        assert_eq!(
+            _pen_prelude_vector_index(1.5.into(), 2.0.into()),
+            false.into()
+        );
+    }
+
     mod equal_string {
         use super::*;
 
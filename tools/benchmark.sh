@@ -48,13 +48,20 @@ benchmark_rust() {
   )
 }
 
+# Build applications also without optimization to compare them.
+build_pen() {
+  pen build --no-optimization
+  mv app app-unoptimized
+  pen build
+}
+
 build() {
-  pen_packages pen build
+  pen_packages build_pen
   rust_crates cargo build --release
 }
 
 run() {
-  pen_packages benchmark ./app
+  pen_packages benchmark ./app-unoptimized ./app
   rust_crates benchmark_rust
 }
 